@@ -56,6 +56,24 @@ export const RoleDetailsCard: React.FC<RoleDetailsProps> = ({ role }) => {
               </div>
             ))}
         </div>
+        {role.conditions.length > 0 && (
+          <>
+            <Label className="block mt-6">{t("conditions")}</Label>
+            <div className="grid grid-cols-1 md:grid-cols-2 gap-4 gap-x-8 mt-2">
+              {role.conditions.map((condition) => (
+                <div
+                  key={`${condition.action}-${condition.description}`}
+                  className="rounded-md py-2 space-y-1"
+                >
+                  <div className="font-mono text-sm">{condition.action}</div>
+                  <p className="text-muted-foreground text-sm">
+                    {condition.description}
+                  </p>
+                </div>
+              ))}
+            </div>
+          </>
+        )}
       </div>
     </>
   )
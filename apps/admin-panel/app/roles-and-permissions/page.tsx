@@ -34,6 +34,10 @@ gql`
     permissionSets {
       ...PermissionSetFields
     }
+    conditions {
+      action
+      description
+    }
   }
 
   query Roles($first: Int!, $after: String) {
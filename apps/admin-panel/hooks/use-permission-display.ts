@@ -77,6 +77,11 @@ export function usePermissionDisplay() {
           label: t("deposit_writer.label"),
           description: t("deposit_writer.description"),
         }
+      case PermissionSetName.DepositApprover:
+        return {
+          label: t("deposit_approver.label"),
+          description: t("deposit_approver.description"),
+        }
       case PermissionSetName.GovernanceViewer:
         return {
           label: t("governance_viewer.label"),
@@ -87,6 +92,11 @@ export function usePermissionDisplay() {
           label: t("governance_writer.label"),
           description: t("governance_writer.description"),
         }
+      case PermissionSetName.GovernanceApprover:
+        return {
+          label: t("governance_approver.label"),
+          description: t("governance_approver.description"),
+        }
       case PermissionSetName.CustodyViewer:
         return {
           label: t("custody_viewer.label"),
@@ -99,6 +99,20 @@ teardown_file() {
   [[ "$control_account_code" == "201" ]] || exit 1
 }
 
+@test "accounting: can export chart of accounts with module references" {
+  exec_admin_graphql 'chart-of-accounts-export' '{"format":"JSON"}'
+  export=$(graphql_output '.data.chartOfAccountsExport')
+
+  assets_name=$(echo "$export" | jq -r '.nodes[] | select(.code == "1") | .name')
+  [[ "$assets_name" == "Assets" ]] || exit 1
+  deposit_refs=$(echo "$export" | jq '[.nodes[].integrations[] | select(startswith("deposit."))] | length')
+  [[ "$deposit_refs" -gt 0 ]] || exit 1
+
+  exec_admin_graphql 'chart-of-accounts-export' '{"format":"CSV"}'
+  first_row=$(graphql_output '.data.chartOfAccountsExport' | head -n 1)
+  [[ "$first_row" =~ ^1,.*,Assets,Debit, ]] || exit 1
+}
+
 @test "accounting: can execute manual transaction" {
 
   # expects chart of accounts from 'import CSV' step to exist
@@ -132,6 +146,8 @@ teardown_file() {
   )
 
   exec_admin_graphql 'manual-transaction-execute' "$variables"
+  status=$(graphql_output .data.manualTransactionExecute.manualTransaction.status)
+  [[ "$status" == "POSTED" ]] || exit 1
 
   exec_admin_graphql 'ledger-account-by-code' '{"code":"201"}'
   txId1=$(graphql_output .data.ledgerAccountByCode.history.nodes[0].txId)
@@ -151,3 +167,215 @@ teardown_file() {
   [[ "$direction1" != "$direction2" ]] || exit 1
   [[ "$entryType1" != "$entryType2" ]] || exit 1
 }
+
+@test "accounting: can rename and deactivate chart of accounts node" {
+  exec_admin_graphql 'chart-of-accounts'
+  chart_id=$(graphql_output '.data.chartOfAccounts.chartId')
+
+  temp_file=$(mktemp)
+  node_code=$((((RANDOM % 1000)) + 3000))
+  echo "
+    $node_code,,,Temporary Node,,
+  " > "$temp_file"
+  variables=$(jq -n --arg chartId "$chart_id" '{ input: { chartId: $chartId, file: null } }')
+  response=$(exec_admin_graphql_upload 'chart-of-accounts-csv-import' "$variables" "$temp_file" "input.file")
+  success=$(echo "$response" | jq -r '.data.chartOfAccountsCsvImport.success')
+  [[ "$success" == "true" ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg chartId "$chart_id" \
+    --arg code "$node_code" \
+    '{ input: { chartId: $chartId, code: $code, name: "Renamed Node" } }'
+  )
+  exec_admin_graphql 'chart-of-accounts-node-rename' "$variables"
+  name=$(graphql_output \
+    --arg code "$node_code" \
+    '.data.chartOfAccountsNodeRename.chartOfAccounts.children[] | select(.accountCode == $code) | .name')
+  [[ "$name" == "Renamed Node" ]] || exit 1
+
+  # 201 carries the balance posted by the manual transaction test
+  variables=$(jq -n --arg chartId "$chart_id" '{ input: { chartId: $chartId, code: "201" } }')
+  exec_admin_graphql 'chart-of-accounts-node-deactivate' "$variables"
+  errors=$(graphql_output '.errors[0].message')
+  [[ "$errors" =~ "NodeHasNonZeroBalance" ]] || exit 1
+
+  variables=$(jq -n --arg chartId "$chart_id" --arg code "$node_code" '{ input: { chartId: $chartId, code: $code } }')
+  exec_admin_graphql 'chart-of-accounts-node-deactivate' "$variables"
+  res=$(graphql_output \
+    --arg code "$node_code" \
+    '[.data.chartOfAccountsNodeDeactivate.chartOfAccounts.children[] | select(.accountCode == $code)] | length')
+  [[ "$res" == "0" ]] || exit 1
+}
+
+@test "accounting: can compare balance sheet across periods" {
+  today=$(date -u +%Y-%m-%d)
+  month_start=$(date -u +%Y-%m-01)
+  variables=$(
+    jq -n \
+    --arg today "$today" \
+    --arg month_start "$month_start" \
+    '{ periods: [
+      { from: $month_start, until: $today },
+      { from: "2000-01-01", until: "2000-01-31" }
+    ] }'
+  )
+  exec_admin_graphql 'comparative-balance-sheet' "$variables"
+  periods=$(graphql_output '.data.comparativeBalanceSheet.periods | length')
+  [[ "$periods" == "2" ]] || exit 1
+  depth=$(graphql_output '.data.comparativeBalanceSheet.lines[0].depth')
+  [[ "$depth" == "0" ]] || exit 1
+  assets_variance=$(graphql_output \
+    '.data.comparativeBalanceSheet.lines[] | select(.name == "Assets" and .depth == 1) | .usd[1].variance')
+  assets_current=$(graphql_output \
+    '.data.comparativeBalanceSheet.lines[] | select(.name == "Assets" and .depth == 1) | .usd[0].amount')
+  [[ "$assets_variance" == "$assets_current" ]] || exit 1
+
+  variables=$(echo "$variables" | jq '{ input: { statementType: "BALANCE_SHEET", periods: .periods } }')
+  exec_admin_graphql 'comparative-statement-csv-create' "$variables"
+  csv_id=$(graphql_output '.data.comparativeStatementCsvCreate.accountingCsv.csvId')
+  [[ "$csv_id" != "null" ]] || exit 1
+}
+
+@test "accounting: can build cash flow statement from tagged chart nodes" {
+  exec_admin_graphql 'cash-flow-config'
+  cash_code=$(graphql_output '.data.cashFlowStatementConfig.chartOfAccountsCashCodes[0]')
+  [[ "$cash_code" == "11.01" ]] || exit 1
+
+  today=$(date -u +%Y-%m-%d)
+  variables=$(jq -n --arg today "$today" '{ from: "2000-01-01", until: $today }')
+  exec_admin_graphql 'cash-flow-statement' "$variables"
+  sections=$(graphql_output '[.data.cashFlowStatement.sections[].activity] | join(",")')
+  [[ "$sections" == "OPERATING,INVESTING,FINANCING" ]] || exit 1
+  first_line=$(graphql_output '.data.cashFlowStatement.sections[0].lines[0].name')
+  [[ "$first_line" == "Net Income" ]] || exit 1
+
+  variables=$(jq -n --arg today "$today" '{ input: { period: { from: "2000-01-01", until: $today } } }')
+  exec_admin_graphql 'cash-flow-statement-csv-create' "$variables"
+  csv_id=$(graphql_output '.data.cashFlowStatementCsvCreate.accountingCsv.csvId')
+  [[ "$csv_id" != "null" ]] || exit 1
+}
+
+@test "accounting: can export general ledger and trial balance for auditors" {
+  today=$(date -u +%Y-%m-%d)
+  variables=$(jq -n --arg today "$today" '{ input: { period: { from: "2000-01-01", until: $today } } }')
+  exec_admin_graphql 'general-ledger-export-create' "$variables"
+  csv_id=$(graphql_output '.data.generalLedgerExportCreate.accountingCsv.csvId')
+  [[ "$csv_id" != "null" ]] || exit 1
+
+  variables=$(echo "$variables" | jq '.input.format = "XLSX"')
+  exec_admin_graphql 'trial-balance-export-create' "$variables"
+  csv_id=$(graphql_output '.data.trialBalanceExportCreate.accountingCsv.csvId')
+  [[ "$csv_id" != "null" ]] || exit 1
+}
+
+@test "accounting: can create and deactivate recurring entry template" {
+  start_date=$(date -u -d "+1 day" +%Y-%m-%d)
+
+  variables=$(
+    jq -n \
+    --arg startDate "$start_date" \
+    '{
+      input: {
+        name: "Monthly rent accrual",
+        description: "Rent accrual",
+        frequency: "MONTHLY",
+        startDate: $startDate,
+        autoReverse: true,
+        entries: [
+          {
+             "accountRef": "201",
+             "amount": "100",
+             "currency": "USD",
+             "direction": "CREDIT",
+             "description": "Accrued rent"
+          },
+          {
+             "accountRef": "202",
+             "amount": "100",
+             "currency": "USD",
+             "direction": "DEBIT",
+             "description": "Rent expense"
+          }]
+        }
+      }'
+  )
+
+  exec_admin_graphql 'recurring-entry-template-create' "$variables"
+  template_id=$(graphql_output .data.recurringEntryTemplateCreate.recurringEntryTemplate.recurringEntryTemplateId)
+  [[ "$template_id" != "null" ]] || exit 1
+  status=$(graphql_output .data.recurringEntryTemplateCreate.recurringEntryTemplate.status)
+  [[ "$status" == "ACTIVE" ]] || exit 1
+  next_run_date=$(graphql_output .data.recurringEntryTemplateCreate.recurringEntryTemplate.nextRunDate)
+  [[ "$next_run_date" == "$start_date" ]] || exit 1
+
+  exec_admin_graphql 'recurring-entry-template' "$(jq -n --arg id "$template_id" '{ id: $id }')"
+  entries=$(graphql_output '.data.recurringEntryTemplate.entries | length')
+  [[ "$entries" == "2" ]] || exit 1
+
+  variables=$(jq -n --arg id "$template_id" '{ input: { recurringEntryTemplateId: $id } }')
+  exec_admin_graphql 'recurring-entry-template-deactivate' "$variables"
+  status=$(graphql_output .data.recurringEntryTemplateDeactivate.recurringEntryTemplate.status)
+  [[ "$status" == "DEACTIVATED" ]] || exit 1
+  next_run_date=$(graphql_output .data.recurringEntryTemplateDeactivate.recurringEntryTemplate.nextRunDate)
+  [[ "$next_run_date" == "null" ]] || exit 1
+}
+
+@test "accounting: can acquire and dispose fixed asset" {
+  today=$(date -u +%Y-%m-%d)
+
+  variables=$(
+    jq -n \
+    --arg acquiredOn "$today" \
+    '{
+      input: {
+        name: "Office server",
+        description: "Rack server for the office",
+        cost: 120000,
+        usefulLifeMonths: 36,
+        depreciationMethod: "STRAIGHT_LINE",
+        acquiredOn: $acquiredOn,
+        assetAccountCode: "12.01.0103",
+        accumulatedDepreciationAccountCode: "12.01",
+        depreciationExpenseAccountCode: "61.04",
+        fundingAccountCode: "11.01.0101"
+      }
+    }'
+  )
+  exec_admin_graphql 'fixed-asset-acquire' "$variables"
+  fixed_asset_id=$(graphql_output .data.fixedAssetAcquire.fixedAsset.fixedAssetId)
+  [[ "$fixed_asset_id" != "null" ]] || exit 1
+  status=$(graphql_output .data.fixedAssetAcquire.fixedAsset.status)
+  [[ "$status" == "IN_SERVICE" ]] || exit 1
+  tx_id=$(graphql_output .data.fixedAssetAcquire.fixedAsset.acquisitionTransaction.ledgerTransactionId)
+  [[ "$tx_id" != "null" ]] || exit 1
+
+  exec_admin_graphql 'fixed-asset-register' "$(jq -n --arg asOf "$today" '{ asOf: $asOf }')"
+  line_cost=$(graphql_output --arg id "$fixed_asset_id" '.data.fixedAssetRegister.lines[] | select(.fixedAssetId == $id) | .cost')
+  [[ "$line_cost" == "120000" ]] || exit 1
+
+  variables=$(
+    jq -n \
+    --arg id "$fixed_asset_id" \
+    --arg disposedOn "$today" \
+    '{
+      input: {
+        fixedAssetId: $id,
+        disposedOn: $disposedOn,
+        proceeds: 50000,
+        proceedsAccountCode: "11.01.0101",
+        gainOrLossAccountCode: "72.01"
+      }
+    }'
+  )
+  exec_admin_graphql 'fixed-asset-dispose' "$variables"
+  status=$(graphql_output .data.fixedAssetDispose.fixedAsset.status)
+  [[ "$status" == "DISPOSED" ]] || exit 1
+  book_value=$(graphql_output .data.fixedAssetDispose.fixedAsset.disposal.bookValue)
+  gain_or_loss=$(graphql_output .data.fixedAssetDispose.fixedAsset.disposal.gainOrLoss)
+  [[ $((50000 - book_value)) == $gain_or_loss ]] || exit 1
+
+  exec_admin_graphql 'fixed-asset-register' "$(jq -n --arg asOf "$today" '{ asOf: $asOf }')"
+  lines=$(graphql_output --arg id "$fixed_asset_id" '[.data.fixedAssetRegister.lines[] | select(.fixedAssetId == $id)] | length')
+  [[ "$lines" == "0" ]] || exit 1
+}
@@ -40,3 +40,17 @@ teardown_file() {
   edges_length=$(graphql_output '.data.audit.edges | length')
   [[ "$edges_length" -eq 2 ]] || exit 1
 }
+
+@test "audit: filter audit logs" {
+  exec_admin_graphql 'audit-logs' '{"first": 10, "filter": {"action": "app:audit:list", "authorized": true}}'
+
+  edges_length=$(graphql_output '.data.audit.edges | length')
+  [[ "$edges_length" -gt 0 ]] || exit 1
+
+  other_actions=$(graphql_output '[.data.audit.edges[].node | select(.action != "app:audit:list" or .authorized != true)] | length')
+  [[ "$other_actions" -eq 0 ]] || exit 1
+
+  exec_admin_graphql 'audit-logs' '{"first": 10, "filter": {"object": "app/audit", "recordedAfter": "2100-01-01T00:00:00Z"}}'
+  edges_length=$(graphql_output '.data.audit.edges | length')
+  [[ "$edges_length" -eq 0 ]] || exit 1
+}
@@ -67,3 +67,156 @@ trigger_withdraw_approval_process() {
   status=$(graphql_output .data.approvalProcess.status)
   [[ "$status" == "APPROVED" ]] || exit 1
 }
+
+@test "governance: rule tier is chosen by process amount" {
+  deposit_account_id=$(read_value "deposit_account_id")
+
+  exec_admin_graphql 'policies' '{ "first": 10 }'
+  policy_id=$(graphql_output '.data.policies.nodes[] | select(.approvalProcessType == "WITHDRAWAL_APPROVAL") | .policyId')
+  [[ "$policy_id" != "" ]] || exit 1
+
+  variables=$(
+    jq -n \
+      --arg policy_id "$policy_id" \
+    '{
+      input: {
+        policyId: $policy_id,
+        tiers: [
+          { upTo: 100000 },
+          { upTo: null }
+        ]
+      }
+    }'
+  )
+  exec_admin_graphql 'policy-rule-tiers-update' "$variables"
+  n_tiers=$(graphql_output '.data.policyRuleTiersUpdate.policy.ruleTiers | length')
+  [[ "$n_tiers" == "2" ]] || exit 1
+
+  process_id=$(trigger_withdraw_approval_process $deposit_account_id)
+  exec_admin_graphql 'find-approval-process' "$(jq -n --arg id "$process_id" '{ id: $id }')"
+  amount=$(graphql_output .data.approvalProcess.amount)
+  [[ "$amount" == "150000" ]] || exit 1
+  status=$(graphql_output .data.approvalProcess.status)
+  [[ "$status" == "APPROVED" ]] || exit 1
+
+  variables=$(jq -n --arg policy_id "$policy_id" '{ input: { policyId: $policy_id, tiers: [] } }')
+  exec_admin_graphql 'policy-rule-tiers-update' "$variables"
+  n_tiers=$(graphql_output '.data.policyRuleTiersUpdate.policy.ruleTiers | length')
+  [[ "$n_tiers" == "0" ]] || exit 1
+}
+
+@test "governance: sequential rules are approved stage by stage" {
+  deposit_account_id=$(read_value "deposit_account_id")
+
+  exec_admin_graphql 'policies' '{ "first": 10 }'
+  policy_id=$(graphql_output '.data.policies.nodes[] | select(.approvalProcessType == "WITHDRAWAL_APPROVAL") | .policyId')
+  [[ "$policy_id" != "" ]] || exit 1
+
+  variables=$(
+    jq -n \
+      --arg policy_id "$policy_id" \
+    '{
+      input: {
+        policyId: $policy_id,
+        rules: {
+          sequential: [
+            { systemAutoApprove: true },
+            { systemAutoApprove: true }
+          ]
+        }
+      }
+    }'
+  )
+  exec_admin_graphql 'policy-rules-update' "$variables"
+  n_stages=$(graphql_output '.data.policyRulesUpdate.policy.rules.stages | length')
+  [[ "$n_stages" == "2" ]] || exit 1
+
+  process_id=$(trigger_withdraw_approval_process $deposit_account_id)
+  exec_admin_graphql 'find-approval-process' "$(jq -n --arg id "$process_id" '{ id: $id }')"
+  status=$(graphql_output .data.approvalProcess.status)
+  [[ "$status" == "APPROVED" ]] || exit 1
+  approved_stages=$(graphql_output '[.data.approvalProcess.stages[] | select(.status == "APPROVED")] | length')
+  [[ "$approved_stages" == "2" ]] || exit 1
+
+  variables=$(jq -n --arg policy_id "$policy_id" '{ input: { policyId: $policy_id, rules: { systemAutoApprove: true } } }')
+  exec_admin_graphql 'policy-rules-update' "$variables"
+  auto_approve=$(graphql_output '.data.policyRulesUpdate.policy.rules.autoApprove')
+  [[ "$auto_approve" == "true" ]] || exit 1
+}
+
+@test "governance: process keeps the sla of its policy" {
+  deposit_account_id=$(read_value "deposit_account_id")
+
+  exec_admin_graphql 'policies' '{ "first": 10 }'
+  policy_id=$(graphql_output '.data.policies.nodes[] | select(.approvalProcessType == "WITHDRAWAL_APPROVAL") | .policyId')
+  [[ "$policy_id" != "" ]] || exit 1
+
+  variables=$(
+    jq -n \
+      --arg policy_id "$policy_id" \
+    '{
+      input: {
+        policyId: $policy_id,
+        remindAfterHours: 4,
+        denyAfterHours: 72
+      }
+    }'
+  )
+  exec_admin_graphql 'policy-sla-update' "$variables"
+  deny_after=$(graphql_output '.data.policySlaUpdate.policy.sla.denyAfterHours')
+  [[ "$deny_after" == "72" ]] || exit 1
+
+  process_id=$(trigger_withdraw_approval_process $deposit_account_id)
+  exec_admin_graphql 'find-approval-process' "$(jq -n --arg id "$process_id" '{ id: $id }')"
+  deny_after=$(graphql_output .data.approvalProcess.sla.denyAfterHours)
+  [[ "$deny_after" == "72" ]] || exit 1
+  expired=$(graphql_output .data.approvalProcess.expired)
+  [[ "$expired" == "false" ]] || exit 1
+
+  variables=$(jq -n --arg policy_id "$policy_id" '{ input: { policyId: $policy_id } }')
+  exec_admin_graphql 'policy-sla-update' "$variables"
+  sla=$(graphql_output '.data.policySlaUpdate.policy.sla')
+  [[ "$sla" == "null" ]] || exit 1
+}
+
+@test "governance: committee member can delegate votes for a period" {
+  exec_admin_graphql 'committee-create' "$(jq -n --arg name "committee-$(date +%s%N)" '{ input: { name: $name } }')"
+  committee_id=$(graphql_output .data.committeeCreate.committee.committeeId)
+  [[ "$committee_id" != "null" ]] || exit 1
+
+  exec_admin_graphql 'user-create' "$(jq -n --arg email "$(generate_email)" '{ input: { email: $email } }')"
+  member_id=$(graphql_output .data.userCreate.user.userId)
+  exec_admin_graphql 'user-create' "$(jq -n --arg email "$(generate_email)" '{ input: { email: $email } }')"
+  delegate_id=$(graphql_output .data.userCreate.user.userId)
+
+  variables=$(jq -n --arg committee_id "$committee_id" --arg user_id "$member_id" \
+    '{ input: { committeeId: $committee_id, userId: $user_id } }')
+  exec_admin_graphql 'committee-add-user' "$variables"
+
+  variables=$(
+    jq -n \
+      --arg committee_id "$committee_id" \
+      --arg delegator_id "$member_id" \
+      --arg delegate_id "$delegate_id" \
+      --arg starts_at "$(date -u +%Y-%m-%dT%H:%M:%SZ)" \
+      --arg ends_at "$(date -u -d '+7 days' +%Y-%m-%dT%H:%M:%SZ)" \
+    '{
+      input: {
+        committeeId: $committee_id,
+        delegatorId: $delegator_id,
+        delegateId: $delegate_id,
+        startsAt: $starts_at,
+        endsAt: $ends_at
+      }
+    }'
+  )
+  exec_admin_graphql 'committee-delegation-grant' "$variables"
+  delegated_to=$(graphql_output '.data.committeeDelegationGrant.committee.delegations[0].delegate.userId')
+  [[ "$delegated_to" == "$delegate_id" ]] || exit 1
+
+  variables=$(jq -n --arg committee_id "$committee_id" --arg delegator_id "$member_id" \
+    '{ input: { committeeId: $committee_id, delegatorId: $delegator_id } }')
+  exec_admin_graphql 'committee-delegation-revoke' "$variables"
+  n_delegations=$(graphql_output '.data.committeeDelegationRevoke.committee.delegations | length')
+  [[ "$n_delegations" == "0" ]] || exit 1
+}
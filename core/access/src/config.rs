@@ -9,4 +9,6 @@ pub struct AccessConfig {
     pub action_descriptions: Vec<ActionDescription<FullPath>>,
     #[serde(skip)]
     pub predefined_roles: &'static [(&'static str, &'static [&'static str])],
+    #[serde(skip)]
+    pub separation_of_duties: &'static [(&'static str, &'static str)],
 }
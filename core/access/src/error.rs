@@ -16,4 +16,12 @@ pub enum CoreAccessError {
     RoleError(#[from] super::role::RoleError),
     #[error("CoreAccessError - PermissionSetError: {0}")]
     PermissionSetError(#[from] super::permission_set::PermissionSetError),
+    #[error("CoreAccessError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CoreAccessError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("CoreAccessError - SeparationOfDutiesViolation: {0} cannot be combined with {1}")]
+    SeparationOfDutiesViolation(String, String),
+    #[error("CoreAccessError - InvalidAction: Could not parse '{0}'")]
+    InvalidAction(String),
 }
@@ -9,12 +9,17 @@ pub mod permission_set;
 pub mod primitives;
 mod publisher;
 pub mod role;
+mod role_elevation;
 pub mod user;
 
 use tracing::instrument;
 
+use std::{collections::HashSet, sync::Arc};
+
 use audit::AuditSvc;
-use authz::{Authorization, PermissionCheck as _};
+use authz::{Authorization, PermissionCheck as _, PermissionCondition};
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
 use permission_set::{PermissionSet, PermissionSetRepo, PermissionSetsByIdCursor};
 
@@ -31,6 +36,7 @@ pub mod event_schema {
 use config::AccessConfig;
 pub use publisher::UserPublisher;
 pub use role::*;
+pub use role_elevation::*;
 pub use user::*;
 
 use error::CoreAccessError;
@@ -38,32 +44,43 @@ use error::CoreAccessError;
 pub struct CoreAccess<Audit, E>
 where
     Audit: AuditSvc,
-    E: OutboxEventMarker<CoreAccessEvent>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     authz: Authorization<Audit, AuthRoleToken>,
     users: Users<Audit, E>,
     roles: RoleRepo<E>,
     permission_sets: PermissionSetRepo,
+    governance: Governance<Authorization<Audit, AuthRoleToken>, E>,
+    approve_role_elevation: ApproveRoleElevation<Audit, E>,
+    separation_of_duties: &'static [(&'static str, &'static str)],
+    action_names: Arc<HashSet<String>>,
 }
 
 impl<Audit, E> CoreAccess<Audit, E>
 where
     Audit: AuditSvc,
     <Audit as AuditSvc>::Subject: From<UserId>,
-    <Audit as AuditSvc>::Action: From<CoreAccessAction>,
-    <Audit as AuditSvc>::Object: From<CoreAccessObject>,
-    E: OutboxEventMarker<CoreAccessEvent>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     pub async fn init(
         pool: &sqlx::PgPool,
         config: AccessConfig,
         authz: &Authorization<Audit, AuthRoleToken>,
+        jobs: &Jobs,
+        governance: &Governance<Authorization<Audit, AuthRoleToken>, E>,
         outbox: &Outbox<E>,
     ) -> Result<Self, CoreAccessError> {
-        let users = Users::init(pool, authz, outbox).await?;
+        let users = Users::init(pool, authz, jobs, outbox).await?;
         let publisher = UserPublisher::new(outbox);
         let role_repo = RoleRepo::new(pool, &publisher);
         let permission_set_repo = PermissionSetRepo::new(pool);
+        let action_names = config
+            .action_descriptions
+            .iter()
+            .map(|action| action.action_name())
+            .collect();
 
         if let Some(email) = config.superuser_email {
             let bootstrap =
@@ -77,11 +94,30 @@ where
                 .await?;
         }
 
+        let approve_role_elevation = ApproveRoleElevation::new(&users, &role_repo, governance);
+        jobs.add_initializer_and_spawn_unique(
+            RoleElevationApprovalJobInitializer::new(outbox, &approve_role_elevation),
+            RoleElevationApprovalJobConfig::<Audit, E>::new(),
+        )
+        .await?;
+
+        match governance.init_policy(APPROVE_ROLE_ELEVATION_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
         let core_access = Self {
             authz: authz.clone(),
             users,
             roles: role_repo,
             permission_sets: permission_set_repo,
+            governance: governance.clone(),
+            approve_role_elevation,
+            separation_of_duties: config.separation_of_duties,
+            action_names: Arc::new(action_names),
         };
 
         Ok(core_access)
@@ -114,6 +150,8 @@ where
             .collect::<Vec<_>>();
         self.ensure_permission_sets_exist(&permission_set_ids)
             .await?;
+        self.ensure_separation_of_duties(&permission_set_ids)
+            .await?;
         let new_role = NewRole::builder()
             .id(RoleId::new())
             .name(name)
@@ -150,6 +188,15 @@ where
         let mut changed = false;
         self.ensure_permission_sets_exist(&permission_set_ids)
             .await?;
+        self.ensure_separation_of_duties(
+            &role
+                .permission_sets()
+                .iter()
+                .chain(permission_set_ids.iter())
+                .copied()
+                .collect::<Vec<_>>(),
+        )
+        .await?;
         for permission_set_id in permission_set_ids {
             if role
                 .add_permission_set(permission_set_id, audit_info.clone())
@@ -211,6 +258,88 @@ where
         Ok(role)
     }
 
+    /// Restricts the permissions on `action` granted through the role to requests
+    /// satisfying `condition`.
+    #[instrument(name = "access.add_condition_to_role", skip(self), err)]
+    pub async fn add_condition_to_role(
+        &self,
+        sub: &<Audit as AuditSvc>::Subject,
+        role_id: impl Into<RoleId> + std::fmt::Debug,
+        action: String,
+        condition: PermissionCondition,
+    ) -> Result<Role, CoreAccessError> {
+        let role_id = role_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccessObject::role(role_id),
+                CoreAccessAction::ROLE_UPDATE,
+            )
+            .await?;
+
+        let parsed_action = self.parse_action(&action)?;
+
+        let mut role = self.roles.find_by_id(role_id).await?;
+        if role
+            .add_condition(
+                RoleCondition {
+                    action,
+                    condition: condition.clone(),
+                },
+                audit_info,
+            )
+            .did_execute()
+        {
+            self.roles.update(&mut role).await?;
+            self.authz
+                .add_condition_to_role(&role.id, parsed_action, &condition)
+                .await?;
+        }
+
+        Ok(role)
+    }
+
+    #[instrument(name = "access.remove_condition_from_role", skip(self), err)]
+    pub async fn remove_condition_from_role(
+        &self,
+        sub: &<Audit as AuditSvc>::Subject,
+        role_id: impl Into<RoleId> + std::fmt::Debug,
+        action: String,
+        condition: PermissionCondition,
+    ) -> Result<Role, CoreAccessError> {
+        let role_id = role_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccessObject::role(role_id),
+                CoreAccessAction::ROLE_UPDATE,
+            )
+            .await?;
+
+        let parsed_action = self.parse_action(&action)?;
+
+        let mut role = self.roles.find_by_id(role_id).await?;
+        if role
+            .remove_condition(
+                RoleCondition {
+                    action,
+                    condition: condition.clone(),
+                },
+                audit_info,
+            )
+            .did_execute()
+        {
+            self.roles.update(&mut role).await?;
+            self.authz
+                .remove_condition_from_role(&role.id, parsed_action, &condition)
+                .await?;
+        }
+
+        Ok(role)
+    }
+
     #[instrument(name = "access.find_role_by_name", skip(self), err)]
     pub async fn find_role_by_name(
         &self,
@@ -227,12 +356,15 @@ where
         self.roles.find_by_name(name.as_ref().to_owned()).await
     }
 
+    /// Grants the role to the user, until `expires_at` if given. The superuser
+    /// role can only be obtained through [`Self::request_role_elevation`].
     #[instrument(name = "core_access.update_role_of_user", skip(self))]
     pub async fn update_role_of_user(
         &self,
         sub: &<Audit as AuditSvc>::Subject,
         user_id: impl Into<UserId> + std::fmt::Debug,
         role_id: impl Into<RoleId> + std::fmt::Debug,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<User, CoreAccessError> {
         let user_id = user_id.into();
         let role_id = role_id.into();
@@ -252,12 +384,66 @@ where
                 authz::error::AuthorizationError::NotAuthorized,
             ));
         }
+        self.ensure_separation_of_duties(
+            &role.permission_sets().iter().copied().collect::<Vec<_>>(),
+        )
+        .await?;
 
-        let user = self.users.update_role_of_user(sub, user_id, &role).await?;
+        let user = self
+            .users
+            .update_role_of_user(sub, user_id, &role, expires_at)
+            .await?;
 
         Ok(user)
     }
 
+    /// Requests granting the role to the user until `expires_at`. The role is
+    /// granted once the role elevation approval process approves it.
+    ///
+    /// Separation of duties is checked against the requested role like for
+    /// any other grant, since it replaces the user's role rather than adding
+    /// to it. The superuser role is the one exception: it holds every
+    /// permission set by design and is only obtained through an approved,
+    /// time-bounded elevation.
+    #[instrument(name = "core_access.request_role_elevation", skip(self))]
+    pub async fn request_role_elevation(
+        &self,
+        sub: &<Audit as AuditSvc>::Subject,
+        user_id: impl Into<UserId> + std::fmt::Debug,
+        role_id: impl Into<RoleId> + std::fmt::Debug,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<User, CoreAccessError> {
+        let user_id = user_id.into();
+        let role_id = role_id.into();
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccessObject::user(user_id),
+                CoreAccessAction::USER_REQUEST_ROLE_ELEVATION,
+            )
+            .await?;
+
+        let role = self.roles.find_by_id(role_id).await?;
+        if role.name != ROLE_NAME_SUPERUSER {
+            self.ensure_separation_of_duties(
+                &role.permission_sets().iter().copied().collect::<Vec<_>>(),
+            )
+            .await?;
+        }
+        let user = self
+            .users
+            .request_role_elevation(user_id, &role, expires_at, audit_info, &self.governance)
+            .await?;
+
+        Ok(self
+            .approve_role_elevation
+            .execute_from_svc(&user)
+            .await?
+            .unwrap_or(user))
+    }
+
     #[instrument(name = "core_access.revoke_role_from_user", skip(self))]
     pub async fn revoke_role_from_user(
         &self,
@@ -387,12 +573,49 @@ where
 
         Ok(())
     }
+
+    fn parse_action(&self, action: &str) -> Result<<Audit as AuditSvc>::Action, CoreAccessError> {
+        if !self.action_names.contains(action) {
+            return Err(CoreAccessError::InvalidAction(action.to_string()));
+        }
+        action
+            .parse()
+            .map_err(|_| CoreAccessError::InvalidAction(action.to_string()))
+    }
+
+    /// Rejects combinations of permission sets that the configured
+    /// separation-of-duties rules forbid a single user to hold. Not applied
+    /// to the superuser role, see [`Self::request_role_elevation`].
+    async fn ensure_separation_of_duties(
+        &self,
+        permission_set_ids: &[PermissionSetId],
+    ) -> Result<(), CoreAccessError> {
+        let names = self
+            .permission_sets
+            .find_all::<PermissionSet>(permission_set_ids)
+            .await?
+            .into_values()
+            .map(|permission_set| permission_set.name)
+            .collect::<std::collections::HashSet<_>>();
+
+        match self
+            .separation_of_duties
+            .iter()
+            .find(|(first, second)| names.contains(*first) && names.contains(*second))
+        {
+            Some((first, second)) => Err(CoreAccessError::SeparationOfDutiesViolation(
+                first.to_string(),
+                second.to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<Audit, E> Clone for CoreAccess<Audit, E>
 where
     Audit: AuditSvc,
-    E: OutboxEventMarker<CoreAccessEvent>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -400,6 +623,10 @@ where
             users: self.users.clone(),
             roles: self.roles.clone(),
             permission_sets: self.permission_sets.clone(),
+            governance: self.governance.clone(),
+            approve_role_elevation: self.approve_role_elevation.clone(),
+            separation_of_duties: self.separation_of_duties,
+            action_names: self.action_names.clone(),
         }
     }
 }
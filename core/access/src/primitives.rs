@@ -3,14 +3,12 @@ use std::{fmt::Display, str::FromStr};
 
 pub use audit::AuditInfo;
 pub use authz::{AllOrOne, action_description::*};
+pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 
-#[cfg(feature = "governance")]
 es_entity::entity_id! {
     UserId;
     UserId => governance::CommitteeMemberId,
 }
-#[cfg(not(feature = "governance"))]
-es_entity::entity_id! { UserId }
 
 es_entity::entity_id! { AuthenticationId, PermissionSetId, RoleId }
 
@@ -66,6 +64,18 @@ impl Display for AuthRoleToken {
     }
 }
 
+impl FromStr for AuthRoleToken {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("role", id)) => Ok(Self::new("role", id)),
+            Some(("permission_set", id)) => Ok(Self::new("permission_set", id)),
+            _ => Err("could not parse AuthRoleToken"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Permission<O, A> {
     object: O,
@@ -127,6 +137,11 @@ impl CoreAccessAction {
     pub const USER_LIST: Self = CoreAccessAction::User(UserAction::List);
     pub const USER_UPDATE_ROLE: Self = CoreAccessAction::User(UserAction::UpdateRole);
     pub const USER_REVOKE_ROLE: Self = CoreAccessAction::User(UserAction::RevokeRole);
+    pub const USER_EXPIRE_ROLE: Self = CoreAccessAction::User(UserAction::ExpireRole);
+    pub const USER_REQUEST_ROLE_ELEVATION: Self =
+        CoreAccessAction::User(UserAction::RequestRoleElevation);
+    pub const USER_CONCLUDE_ROLE_ELEVATION: Self =
+        CoreAccessAction::User(UserAction::ConcludeRoleElevation);
     pub const USER_UPDATE_AUTHENTICATION_ID: Self =
         CoreAccessAction::User(UserAction::UpdateAuthenticationId);
 
@@ -221,6 +236,9 @@ pub enum UserAction {
     Update,
     UpdateRole,
     RevokeRole,
+    ExpireRole,
+    RequestRoleElevation,
+    ConcludeRoleElevation,
     UpdateAuthenticationId,
 }
 
@@ -246,6 +264,15 @@ impl UserAction {
                 Self::RevokeRole => {
                     ActionDescription::new(variant, &[PERMISSION_SET_ACCESS_WRITER])
                 }
+                Self::ExpireRole => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCESS_WRITER])
+                }
+                Self::RequestRoleElevation => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCESS_WRITER])
+                }
+                Self::ConcludeRoleElevation => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCESS_WRITER])
+                }
                 Self::UpdateAuthenticationId => {
                     ActionDescription::new(variant, &[PERMISSION_SET_ACCESS_WRITER])
                 }
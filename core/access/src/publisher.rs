@@ -55,7 +55,9 @@ where
                     id: entity.id,
                     role_id: *id,
                 }),
-                AuthenticationIdUpdated { .. } => None,
+                AuthenticationIdUpdated { .. }
+                | RoleElevationRequested { .. }
+                | RoleElevationConcluded { .. } => None,
             })
             .collect::<Vec<_>>();
 
@@ -72,23 +74,24 @@ where
     ) -> Result<(), RoleError> {
         use RoleEvent::*;
         let events = new_events
-            .map(|event| match &event.event {
-                Initialized { id, name, .. } => CoreAccessEvent::RoleCreated {
+            .filter_map(|event| match &event.event {
+                Initialized { id, name, .. } => Some(CoreAccessEvent::RoleCreated {
                     id: *id,
                     name: name.clone(),
-                },
+                }),
                 PermissionSetAdded {
                     permission_set_id, ..
-                } => CoreAccessEvent::RoleGainedPermissionSet {
+                } => Some(CoreAccessEvent::RoleGainedPermissionSet {
                     id: entity.id,
                     permission_set_id: *permission_set_id,
-                },
+                }),
                 PermissionSetRemoved {
                     permission_set_id, ..
-                } => CoreAccessEvent::RoleLostPermissionSet {
+                } => Some(CoreAccessEvent::RoleLostPermissionSet {
                     id: entity.id,
                     permission_set_id: *permission_set_id,
-                },
+                }),
+                ConditionAdded { .. } | ConditionRemoved { .. } => None,
             })
             .collect::<Vec<_>>();
 
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 use audit::AuditInfo;
+use authz::PermissionCondition;
 use es_entity::*;
 
 use crate::{PermissionSetId, primitives::RoleId};
@@ -27,6 +28,22 @@ pub enum RoleEvent {
         permission_set_id: PermissionSetId,
         audit_info: AuditInfo,
     },
+    ConditionAdded {
+        condition: RoleCondition,
+        audit_info: AuditInfo,
+    },
+    ConditionRemoved {
+        condition: RoleCondition,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Restricts the permissions on `action` granted through the role.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct RoleCondition {
+    pub action: String,
+    pub condition: PermissionCondition,
 }
 
 #[derive(EsEntity, Builder)]
@@ -36,6 +53,8 @@ pub struct Role {
     pub id: RoleId,
     pub name: String,
     pub permission_sets: HashSet<PermissionSetId>,
+    #[builder(default)]
+    pub conditions: Vec<RoleCondition>,
     events: EntityEvents<RoleEvent>,
 }
 
@@ -89,12 +108,49 @@ impl Role {
     pub fn permission_sets(&self) -> &HashSet<PermissionSetId> {
         &self.permission_sets
     }
+
+    pub(crate) fn add_condition(
+        &mut self,
+        condition: RoleCondition,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.conditions.contains(&condition) {
+            return Idempotent::Ignored;
+        }
+
+        self.conditions.push(condition.clone());
+        self.events.push(RoleEvent::ConditionAdded {
+            condition,
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn remove_condition(
+        &mut self,
+        condition: RoleCondition,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if !self.conditions.contains(&condition) {
+            return Idempotent::Ignored;
+        }
+
+        self.conditions.retain(|c| c != &condition);
+        self.events.push(RoleEvent::ConditionRemoved {
+            condition,
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<RoleEvent> for Role {
     fn try_from_events(events: EntityEvents<RoleEvent>) -> Result<Self, EsEntityError> {
         let mut builder = RoleBuilder::default();
         let mut new_permission_sets = HashSet::new();
+        let mut conditions = Vec::new();
 
         for event in events.iter_all() {
             match event {
@@ -117,11 +173,18 @@ impl TryFromEvents<RoleEvent> for Role {
                 } => {
                     new_permission_sets.remove(permission_set_id);
                 }
+                RoleEvent::ConditionAdded { condition, .. } => {
+                    conditions.push(condition.clone());
+                }
+                RoleEvent::ConditionRemoved { condition, .. } => {
+                    conditions.retain(|c| c != condition);
+                }
             }
         }
 
         builder
             .permission_sets(new_permission_sets)
+            .conditions(conditions)
             .events(events)
             .build()
     }
@@ -2,7 +2,7 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::{NewRole, Role};
+pub use entity::{NewRole, Role, RoleCondition};
 // RoleEvent is available internally and conditionally publicly
 #[cfg(feature = "json-schema")]
 pub use entity::RoleEvent;
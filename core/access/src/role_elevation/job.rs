@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use audit::AuditSvc;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{event::CoreAccessEvent, primitives::*};
+
+use super::ApproveRoleElevation;
+
+#[derive(serde::Serialize)]
+pub struct RoleElevationApprovalJobConfig<Audit, E> {
+    _phantom: std::marker::PhantomData<(Audit, E)>,
+}
+impl<Audit, E> RoleElevationApprovalJobConfig<Audit, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Audit, E> JobConfig for RoleElevationApprovalJobConfig<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = RoleElevationApprovalJobInitializer<Audit, E>;
+}
+
+pub struct RoleElevationApprovalJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    process: ApproveRoleElevation<Audit, E>,
+}
+
+impl<Audit, E> RoleElevationApprovalJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(outbox: &Outbox<E>, process: &ApproveRoleElevation<Audit, E>) -> Self {
+        Self {
+            process: process.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const ROLE_ELEVATION_APPROVAL_JOB: JobType = JobType::new("role-elevation-approval");
+impl<Audit, E> JobInitializer for RoleElevationApprovalJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        ROLE_ELEVATION_APPROVAL_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(RoleElevationApprovalJobRunner {
+            outbox: self.outbox.clone(),
+            process: self.process.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct RoleElevationApprovalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct RoleElevationApprovalJobRunner<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    process: ApproveRoleElevation<Audit, E>,
+}
+#[async_trait]
+impl<Audit, E> JobRunner for RoleElevationApprovalJobRunner<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    #[allow(clippy::single_match)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<RoleElevationApprovalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    id,
+                    approved,
+                    process_type,
+                    target_ref,
+                    ..
+                }) if process_type == &super::APPROVE_ROLE_ELEVATION_PROCESS => {
+                    let user_id = target_ref.parse::<UserId>()?;
+                    self.process.execute(user_id, *id, *approved).await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(chrono::Utc::now()))
+    }
+}
@@ -0,0 +1,116 @@
+mod job;
+
+use audit::AuditSvc;
+use authz::Authorization;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, Governance, GovernanceAction,
+    GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    error::CoreAccessError,
+    event::CoreAccessEvent,
+    primitives::*,
+    role::RoleRepo,
+    user::{User, Users},
+};
+
+pub use job::*;
+
+pub const APPROVE_ROLE_ELEVATION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("role-elevation");
+
+pub struct ApproveRoleElevation<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    users: Users<Audit, E>,
+    roles: RoleRepo<E>,
+    governance: Governance<Authorization<Audit, AuthRoleToken>, E>,
+}
+
+impl<Audit, E> Clone for ApproveRoleElevation<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            users: self.users.clone(),
+            roles: self.roles.clone(),
+            governance: self.governance.clone(),
+        }
+    }
+}
+
+impl<Audit, E> ApproveRoleElevation<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction> + From<GovernanceAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreAccessEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub(crate) fn new(
+        users: &Users<Audit, E>,
+        roles: &RoleRepo<E>,
+        governance: &Governance<Authorization<Audit, AuthRoleToken>, E>,
+    ) -> Self {
+        Self {
+            users: users.clone(),
+            roles: roles.clone(),
+            governance: governance.clone(),
+        }
+    }
+
+    pub async fn execute_from_svc(&self, user: &User) -> Result<Option<User>, CoreAccessError> {
+        let Some(elevation) = user.pending_role_elevation() else {
+            return Ok(None);
+        };
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[elevation.approval_process_id])
+            .await?
+            .remove(&elevation.approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => Some(
+                self.execute(user.id, elevation.approval_process_id, true)
+                    .await?,
+            ),
+            ApprovalProcessStatus::Denied => Some(
+                self.execute(user.id, elevation.approval_process_id, false)
+                    .await?,
+            ),
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn execute(
+        &self,
+        user_id: impl es_entity::RetryableInto<UserId>,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<User, CoreAccessError> {
+        let user_id = user_id.into();
+        let user = self.users.find_by_id_without_audit(user_id).await?;
+        let Some(elevation) = user
+            .pending_role_elevation()
+            .filter(|elevation| elevation.approval_process_id == approval_process_id)
+        else {
+            return Ok(user);
+        };
+
+        let role = self.roles.find_by_id(elevation.role_id).await?;
+        Ok(self
+            .users
+            .conclude_role_elevation(user_id, approval_process_id, approved, &role)
+            .await?)
+    }
+}
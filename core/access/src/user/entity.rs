@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
@@ -7,6 +8,8 @@ use es_entity::*;
 
 use crate::{Role, primitives::*};
 
+use super::error::UserError;
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -22,12 +25,37 @@ pub enum UserEvent {
     },
     RoleGranted {
         id: RoleId,
+        /// The grant is revoked once this moment has passed.
+        #[serde(default)]
+        expires_at: Option<DateTime<Utc>>,
+        /// Role granted again once a time-bounded grant lapses.
+        #[serde(default)]
+        replaced: Option<RoleId>,
         audit_info: AuditInfo,
     },
     RoleRevoked {
         id: RoleId,
         audit_info: AuditInfo,
     },
+    RoleElevationRequested {
+        approval_process_id: ApprovalProcessId,
+        role_id: RoleId,
+        expires_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    RoleElevationConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+}
+
+/// A request for a time-bounded role grant that waits on an approval process.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleElevation {
+    pub approval_process_id: ApprovalProcessId,
+    pub role_id: RoleId,
+    pub expires_at: DateTime<Utc>,
 }
 
 #[derive(EsEntity, Builder)]
@@ -47,16 +75,25 @@ impl User {
             .expect("entity_first_persisted_at not found")
     }
 
-    /// Sets user's role to `role`. Returns previous role or `None`
-    /// if no role was previously set.
+    /// Sets user's role to `role`, until `expires_at` if given. Returns previous
+    /// role or `None` if no role was previously set. A time-bounded grant
+    /// remembers the role it replaced so it can be restored on expiry.
     pub(crate) fn update_role(
         &mut self,
         role: &Role,
+        expires_at: Option<DateTime<Utc>>,
         audit_info: AuditInfo,
     ) -> Idempotent<Option<RoleId>> {
         match self.current_role() {
-            Some(current) if role.id == current => Idempotent::Ignored,
+            Some(current) if role.id == current && self.role_expires_at() == expires_at => {
+                Idempotent::Ignored
+            }
             previous => {
+                let replaced = match expires_at {
+                    Some(_) if self.role_expires_at().is_some() => self.replaced_role(),
+                    Some(_) => previous,
+                    None => None,
+                };
                 if let Some(previous) = previous {
                     self.events.push(UserEvent::RoleRevoked {
                         id: previous,
@@ -66,6 +103,8 @@ impl User {
 
                 self.events.push(UserEvent::RoleGranted {
                     id: role.id,
+                    expires_at,
+                    replaced,
                     audit_info,
                 });
 
@@ -103,6 +142,128 @@ impl User {
             .flatten()
     }
 
+    /// Returns when the current role grant lapses. `None` if the user has no
+    /// role or it was granted without an expiry.
+    pub fn role_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                UserEvent::RoleGranted { expires_at, .. } => Some(*expires_at),
+                UserEvent::RoleRevoked { .. } => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Role the current time-bounded grant replaced, if any.
+    fn replaced_role(&self) -> Option<RoleId> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                UserEvent::RoleGranted { replaced, .. } => Some(*replaced),
+                UserEvent::RoleRevoked { .. } => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Revokes the current role if its grant has lapsed by `now` and grants
+    /// back the role it replaced. Returns the revoked and the restored role.
+    pub(crate) fn expire_role(
+        &mut self,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<(RoleId, Option<RoleId>)> {
+        match (self.current_role(), self.role_expires_at()) {
+            (Some(role_id), Some(expires_at)) if expires_at <= now => {
+                let replaced = self.replaced_role();
+                self.events.push(UserEvent::RoleRevoked {
+                    id: role_id,
+                    audit_info: audit_info.clone(),
+                });
+                if let Some(replaced) = replaced {
+                    self.events.push(UserEvent::RoleGranted {
+                        id: replaced,
+                        expires_at: None,
+                        replaced: None,
+                        audit_info,
+                    });
+                }
+                Idempotent::Executed((role_id, replaced))
+            }
+            _ => Idempotent::Ignored,
+        }
+    }
+
+    /// Returns the role elevation waiting on its approval process, if any.
+    pub fn pending_role_elevation(&self) -> Option<RoleElevation> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                UserEvent::RoleElevationRequested {
+                    approval_process_id,
+                    role_id,
+                    expires_at,
+                    ..
+                } => Some(Some(RoleElevation {
+                    approval_process_id: *approval_process_id,
+                    role_id: *role_id,
+                    expires_at: *expires_at,
+                })),
+                UserEvent::RoleElevationConcluded { .. } => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    pub(crate) fn request_role_elevation(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        role_id: RoleId,
+        expires_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Result<(), UserError> {
+        if self.pending_role_elevation().is_some() {
+            return Err(UserError::RoleElevationAlreadyPending(self.id));
+        }
+
+        self.events.push(UserEvent::RoleElevationRequested {
+            approval_process_id,
+            role_id,
+            expires_at,
+            audit_info,
+        });
+
+        Ok(())
+    }
+
+    /// Concludes the pending elevation of `approval_process_id`. Returns the
+    /// elevation to grant when it was approved.
+    pub(crate) fn role_elevation_concluded(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<RoleElevation>> {
+        let Some(elevation) = self
+            .pending_role_elevation()
+            .filter(|elevation| elevation.approval_process_id == approval_process_id)
+        else {
+            return Idempotent::Ignored;
+        };
+
+        self.events.push(UserEvent::RoleElevationConcluded {
+            approval_process_id,
+            approved,
+            audit_info,
+        });
+
+        Idempotent::Executed(approved.then_some(elevation))
+    }
+
     pub fn update_authentication_id(
         &mut self,
         authentication_id: AuthenticationId,
@@ -135,6 +296,8 @@ impl TryFromEvents<UserEvent> for User {
                 }
                 UserEvent::RoleGranted { .. } => (),
                 UserEvent::RoleRevoked { .. } => (),
+                UserEvent::RoleElevationRequested { .. } => (),
+                UserEvent::RoleElevationConcluded { .. } => (),
                 UserEvent::AuthenticationIdUpdated { authentication_id } => {
                     builder = builder.authentication_id(*authentication_id);
                 }
@@ -180,9 +343,10 @@ impl IntoEvents<UserEvent> for NewUser {
 #[cfg(test)]
 mod tests {
     use audit::{AuditEntryId, AuditInfo};
+    use chrono::{Duration, Utc};
     use es_entity::{Idempotent, IntoEvents as _, TryFromEvents as _};
 
-    use crate::{NewRole, Role, RoleId, UserId};
+    use crate::{ApprovalProcessId, NewRole, Role, RoleId, UserId};
 
     use super::{NewUser, User};
 
@@ -225,14 +389,14 @@ mod tests {
         assert!(user.revoke_role(audit_info()).was_ignored());
 
         let role_1 = new_role();
-        let previous = user.update_role(&role_1, audit_info());
+        let previous = user.update_role(&role_1, None, audit_info());
         assert!(matches!(previous, Idempotent::Executed(None)));
 
-        let previous = user.update_role(&role_1, audit_info());
+        let previous = user.update_role(&role_1, None, audit_info());
         assert!(matches!(previous, Idempotent::Ignored));
 
         let role_2 = new_role();
-        let previous = user.update_role(&role_2, audit_info());
+        let previous = user.update_role(&role_2, None, audit_info());
         assert!(matches!(previous, Idempotent::Executed(Some(id)) if id == role_1.id));
         assert_eq!(user.current_role(), Some(role_2.id));
 
@@ -240,4 +404,79 @@ mod tests {
         assert!(matches!(previous, Idempotent::Executed(id) if id == role_2.id));
         assert_eq!(user.current_role(), None);
     }
+
+    #[test]
+    fn time_bounded_role_expires() {
+        let mut user = new_user();
+        let role = new_role();
+        let expires_at = Utc::now() + Duration::hours(1);
+        assert!(
+            user.update_role(&role, Some(expires_at), audit_info())
+                .did_execute()
+        );
+        assert_eq!(user.role_expires_at(), Some(expires_at));
+
+        assert!(
+            user.expire_role(expires_at - Duration::minutes(1), audit_info())
+                .was_ignored()
+        );
+        let expired = user.expire_role(expires_at, audit_info());
+        assert!(matches!(expired, Idempotent::Executed((id, None)) if id == role.id));
+        assert_eq!(user.current_role(), None);
+        assert_eq!(user.role_expires_at(), None);
+    }
+
+    #[test]
+    fn expired_role_restores_replaced_role() {
+        let mut user = new_user();
+        let permanent = new_role();
+        let _ = user.update_role(&permanent, None, audit_info());
+
+        let elevated = new_role();
+        let expires_at = Utc::now() + Duration::hours(1);
+        let _ = user.update_role(&elevated, Some(expires_at), audit_info());
+        let extended = new_role();
+        let _ = user.update_role(&extended, Some(expires_at), audit_info());
+
+        let expired = user.expire_role(expires_at, audit_info());
+        assert!(matches!(
+            expired,
+            Idempotent::Executed((id, Some(restored))) if id == extended.id && restored == permanent.id
+        ));
+        assert_eq!(user.current_role(), Some(permanent.id));
+        assert_eq!(user.role_expires_at(), None);
+        assert!(user.expire_role(expires_at, audit_info()).was_ignored());
+    }
+
+    #[test]
+    fn role_elevation_waits_for_its_process() {
+        let mut user = new_user();
+        let role = new_role();
+        let process_id = ApprovalProcessId::new();
+        let expires_at = Utc::now() + Duration::hours(4);
+        assert!(
+            user.request_role_elevation(process_id, role.id, expires_at, audit_info())
+                .is_ok()
+        );
+        assert!(
+            user.request_role_elevation(
+                ApprovalProcessId::new(),
+                role.id,
+                expires_at,
+                audit_info()
+            )
+            .is_err()
+        );
+
+        assert!(
+            user.role_elevation_concluded(ApprovalProcessId::new(), true, audit_info())
+                .was_ignored()
+        );
+        let concluded = user.role_elevation_concluded(process_id, true, audit_info());
+        assert!(
+            matches!(concluded, Idempotent::Executed(Some(elevation)) if elevation.role_id == role.id)
+        );
+        assert!(user.pending_role_elevation().is_none());
+        assert_eq!(user.current_role(), None);
+    }
 }
@@ -12,6 +12,14 @@ pub enum UserError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("UserError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
+    #[error("UserError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("UserError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("UserError - RoleExpiryNotInFuture")]
+    RoleExpiryNotInFuture,
+    #[error("UserError - RoleElevationAlreadyPending: {0}")]
+    RoleElevationAlreadyPending(crate::primitives::UserId),
     #[error("SubjectError - SubjectIsNotUser")]
     SubjectIsNotUser,
 }
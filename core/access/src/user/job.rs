@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreAccessEvent, primitives::*};
+
+use super::Users;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RoleGrantExpiryJobConfig<Audit, E> {
+    pub user_id: UserId,
+    pub _phantom: std::marker::PhantomData<(Audit, E)>,
+}
+impl<Audit, E> JobConfig for RoleGrantExpiryJobConfig<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject>,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    type Initializer = RoleGrantExpiryJobInitializer<Audit, E>;
+}
+
+pub struct RoleGrantExpiryJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    users: Users<Audit, E>,
+}
+
+impl<Audit, E> RoleGrantExpiryJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject>,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    pub fn new(users: &Users<Audit, E>) -> Self {
+        Self {
+            users: users.clone(),
+        }
+    }
+}
+
+const ROLE_GRANT_EXPIRY_JOB: JobType = JobType::new("role-grant-expiry");
+impl<Audit, E> JobInitializer for RoleGrantExpiryJobInitializer<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject>,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        ROLE_GRANT_EXPIRY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(RoleGrantExpiryJobRunner::<Audit, E> {
+            config: job.config()?,
+            users: self.users.clone(),
+        }))
+    }
+}
+
+pub struct RoleGrantExpiryJobRunner<Audit, E>
+where
+    Audit: AuditSvc,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    config: RoleGrantExpiryJobConfig<Audit, E>,
+    users: Users<Audit, E>,
+}
+
+#[async_trait]
+impl<Audit, E> JobRunner for RoleGrantExpiryJobRunner<Audit, E>
+where
+    Audit: AuditSvc,
+    <Audit as AuditSvc>::Subject: From<UserId>,
+    <Audit as AuditSvc>::Action: From<CoreAccessAction>,
+    <Audit as AuditSvc>::Object: From<CoreAccessObject>,
+    E: OutboxEventMarker<CoreAccessEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        match self.users.expire_role_of_user(self.config.user_id).await? {
+            Some(expires_at) => Ok(JobCompletion::RescheduleAt(expires_at)),
+            None => Ok(JobCompletion::Complete),
+        }
+    }
+}
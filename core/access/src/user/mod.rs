@@ -1,19 +1,26 @@
 mod entity;
 pub mod error;
+mod job;
 mod repo;
 
+use chrono::{DateTime, Utc};
 use es_entity::{DbOp, Idempotent};
 use std::collections::HashMap;
 use tracing::instrument;
 
+use ::job::{JobId, Jobs};
 use audit::AuditSvc;
 use authz::{Authorization, PermissionCheck};
+use governance::{Governance, GovernanceEvent};
 use outbox::{Outbox, OutboxEventMarker};
 
-use crate::{Role, event::*, primitives::*, publisher::UserPublisher};
+use crate::{
+    APPROVE_ROLE_ELEVATION_PROCESS, Role, event::*, primitives::*, publisher::UserPublisher,
+};
 
-pub use entity::User;
 use entity::*;
+pub use entity::{RoleElevation, User};
+use job::*;
 // UserEvent is available internally and conditionally publicly
 #[cfg(feature = "json-schema")]
 pub use entity::UserEvent;
@@ -30,6 +37,7 @@ where
 {
     authz: Authorization<Audit, AuthRoleToken>,
     repo: UserRepo<E>,
+    jobs: Jobs,
 }
 
 impl<Audit, E> Clone for Users<Audit, E>
@@ -41,6 +49,7 @@ where
         Self {
             authz: self.authz.clone(),
             repo: self.repo.clone(),
+            jobs: self.jobs.clone(),
         }
     }
 }
@@ -56,15 +65,19 @@ where
     pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Authorization<Audit, AuthRoleToken>,
+        jobs: &Jobs,
         outbox: &Outbox<E>,
     ) -> Result<Self, UserError> {
         let publisher = UserPublisher::new(outbox);
         let repo = UserRepo::new(pool, &publisher);
-
-        Ok(Self {
+        let users = Self {
             repo,
             authz: authz.clone(),
-        })
+            jobs: jobs.clone(),
+        };
+        jobs.add_initializer(RoleGrantExpiryJobInitializer::new(&users));
+
+        Ok(users)
     }
 
     pub async fn subject_can_create_user(
@@ -137,6 +150,10 @@ where
         }
     }
 
+    pub(crate) async fn find_by_id_without_audit(&self, id: UserId) -> Result<User, UserError> {
+        self.repo.find_by_id(id).await
+    }
+
     #[instrument(name = "core_access.find_by_email", skip(self))]
     pub async fn find_by_email(
         &self,
@@ -255,6 +272,7 @@ where
         sub: &<Audit as AuditSvc>::Subject,
         user_id: impl Into<UserId> + std::fmt::Debug,
         role: &Role,
+        expires_at: Option<DateTime<Utc>>,
     ) -> Result<User, UserError> {
         let id = user_id.into();
 
@@ -262,18 +280,182 @@ where
             .subject_can_update_role_of_user(sub, id, true)
             .await?
             .expect("audit info missing");
+        ensure_in_future(expires_at)?;
 
         let mut user = self.repo.find_by_id(id).await?;
 
-        if let Idempotent::Executed(previous) = user.update_role(role, audit_info) {
-            if let Some(previous) = previous {
+        let mut db = self.repo.begin_op().await?;
+        if self
+            .grant_role_in_op(&mut db, &mut user, role, expires_at, audit_info)
+            .await?
+            .did_execute()
+        {
+            self.repo.update_in_op(&mut db, &mut user).await?;
+            db.commit().await?;
+        }
+        self.sync_role(&user).await?;
+
+        Ok(user)
+    }
+
+    /// Grants `role` to `user` and, for a time-bounded grant, schedules its
+    /// revocation. Returns the previous role. The caller persists `user` and
+    /// syncs its role once committed.
+    async fn grant_role_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        user: &mut User,
+        role: &Role,
+        expires_at: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<Option<RoleId>>, UserError> {
+        let Idempotent::Executed(previous) = user.update_role(role, expires_at, audit_info) else {
+            return Ok(Idempotent::Ignored);
+        };
+
+        if let Some(expires_at) = expires_at {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    RoleGrantExpiryJobConfig::<Audit, E> {
+                        user_id: user.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    expires_at,
+                )
+                .await?;
+        }
+
+        Ok(Idempotent::Executed(previous))
+    }
+
+    /// Aligns the authorization policies with the role `user` currently
+    /// holds, revoking any other role assigned to it. Derived from the
+    /// committed entity alone, so running it again after a failed sync
+    /// completes it.
+    async fn sync_role(&self, user: &User) -> Result<(), UserError> {
+        let current = user.current_role().map(AuthRoleToken::from);
+        for assigned in self.authz.roles_for_subject(user.id).await? {
+            if current.as_ref().map(|role| role.to_string()) != Some(assigned.to_string()) {
                 self.authz
-                    .revoke_role_from_subject(user.id, previous)
+                    .revoke_role_from_subject(user.id, assigned)
                     .await?;
             }
-            self.authz.assign_role_to_subject(user.id, role.id).await?;
-            self.repo.update(&mut user).await?;
         }
+        if let Some(current) = current {
+            self.authz.assign_role_to_subject(user.id, current).await?;
+        }
+        Ok(())
+    }
+
+    /// Revokes the role of `user_id` once its grant has lapsed, restoring the
+    /// role it replaced. Returns when the current grant lapses if it has not
+    /// yet.
+    pub(crate) async fn expire_role_of_user(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<DateTime<Utc>>, UserError> {
+        let mut user = self.repo.find_by_id(user_id).await?;
+
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreAccessObject::user(user_id),
+                CoreAccessAction::USER_EXPIRE_ROLE,
+            )
+            .await?;
+        if user.expire_role(Utc::now(), audit_info).did_execute() {
+            self.repo.update_in_op(&mut db, &mut user).await?;
+            db.commit().await?;
+        }
+        self.sync_role(&user).await?;
+
+        Ok(user.role_expires_at())
+    }
+
+    /// Records a request to grant `role` until `expires_at` and starts the
+    /// approval process deciding it.
+    pub(crate) async fn request_role_elevation(
+        &self,
+        user_id: UserId,
+        role: &Role,
+        expires_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+        governance: &Governance<Authorization<Audit, AuthRoleToken>, E>,
+    ) -> Result<User, UserError>
+    where
+        <Audit as AuditSvc>::Action: From<governance::GovernanceAction>,
+        <Audit as AuditSvc>::Object: From<governance::GovernanceObject>,
+        E: OutboxEventMarker<GovernanceEvent>,
+    {
+        ensure_in_future(Some(expires_at))?;
+
+        let mut user = self.repo.find_by_id(user_id).await?;
+        let approval_process_id = ApprovalProcessId::new();
+        user.request_role_elevation(approval_process_id, role.id, expires_at, audit_info.clone())?;
+
+        let mut db = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut db, &mut user).await?;
+        governance
+            .start_process(
+                &mut db,
+                approval_process_id,
+                user.id.to_string(),
+                APPROVE_ROLE_ELEVATION_PROCESS,
+                None,
+                Some(&audit_info),
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(user)
+    }
+
+    /// Concludes the pending elevation of `user_id`, granting `role` until the
+    /// requested expiry when it was approved in time.
+    pub(crate) async fn conclude_role_elevation(
+        &self,
+        user_id: UserId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        role: &Role,
+    ) -> Result<User, UserError> {
+        let mut user = self.repo.find_by_id(user_id).await?;
+
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreAccessObject::user(user_id),
+                CoreAccessAction::USER_CONCLUDE_ROLE_ELEVATION,
+            )
+            .await?;
+        let Idempotent::Executed(elevation) =
+            user.role_elevation_concluded(approval_process_id, approved, audit_info.clone())
+        else {
+            self.sync_role(&user).await?;
+            return Ok(user);
+        };
+
+        if let Some(elevation) = elevation.filter(|elevation| elevation.expires_at > Utc::now()) {
+            self.grant_role_in_op(
+                &mut db,
+                &mut user,
+                role,
+                Some(elevation.expires_at),
+                audit_info,
+            )
+            .await?;
+        }
+        self.repo.update_in_op(&mut db, &mut user).await?;
+        db.commit().await?;
+        self.sync_role(&user).await?;
 
         Ok(user)
     }
@@ -308,12 +490,10 @@ where
             .expect("audit info missing");
 
         let mut user = self.repo.find_by_id(id).await?;
-        if let Idempotent::Executed(previous) = user.revoke_role(audit_role) {
-            self.authz
-                .revoke_role_from_subject(user.id, previous)
-                .await?;
+        if user.revoke_role(audit_role).did_execute() {
             self.repo.update(&mut user).await?;
         }
+        self.sync_role(&user).await?;
 
         Ok(user)
     }
@@ -347,7 +527,7 @@ where
 
                 let mut user = self.repo.create_in_op(db, new_user).await?;
 
-                if user.update_role(role, audit_info).did_execute() {
+                if user.update_role(role, None, audit_info).did_execute() {
                     self.repo.update_in_op(db, &mut user).await?;
                 }
 
@@ -355,7 +535,7 @@ where
             }
             Err(e) => return Err(e),
             Ok(mut user) => {
-                if user.update_role(role, audit_info).did_execute() {
+                if user.update_role(role, None, audit_info).did_execute() {
                     self.repo.update_in_op(db, &mut user).await?;
                 };
 
@@ -366,3 +546,10 @@ where
         Ok(user)
     }
 }
+
+fn ensure_in_future(expires_at: Option<DateTime<Utc>>) -> Result<(), UserError> {
+    match expires_at {
+        Some(expires_at) if expires_at <= Utc::now() => Err(UserError::RoleExpiryNotInFuture),
+        _ => Ok(()),
+    }
+}
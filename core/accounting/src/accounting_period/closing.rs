@@ -0,0 +1,169 @@
+use rust_decimal::Decimal;
+
+use cala_ledger::{AccountId, Currency, DebitOrCredit};
+
+use crate::{
+    manual_transaction::{AccountIdOrCode, ManualEntryInput},
+    primitives::AccountCode,
+};
+
+/// Settled balance of a P&L account at the end of the year, as debits minus credits.
+#[derive(Debug, Clone)]
+pub struct ClosingBalance {
+    pub account_id: AccountId,
+    pub currency: Currency,
+    pub net_debit: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ClosingTarget {
+    Account(AccountId),
+    RetainedEarnings,
+}
+
+#[derive(Debug, Clone)]
+struct ClosingEntry {
+    target: ClosingTarget,
+    currency: Currency,
+    amount: Decimal,
+    direction: DebitOrCredit,
+}
+
+impl ClosingEntry {
+    fn new(target: ClosingTarget, currency: Currency, net_debit: Decimal) -> Self {
+        Self {
+            target,
+            currency,
+            amount: net_debit.abs(),
+            direction: if net_debit.is_sign_positive() {
+                DebitOrCredit::Debit
+            } else {
+                DebitOrCredit::Credit
+            },
+        }
+    }
+}
+
+/// Zeroes every P&L balance and books the offsetting net result per currency
+/// against retained earnings.
+fn closing_entries(balances: &[ClosingBalance]) -> Vec<ClosingEntry> {
+    let mut entries = Vec::new();
+    let mut net_result: Vec<(Currency, Decimal)> = Vec::new();
+    for balance in balances.iter().filter(|b| !b.net_debit.is_zero()) {
+        entries.push(ClosingEntry::new(
+            ClosingTarget::Account(balance.account_id),
+            balance.currency,
+            -balance.net_debit,
+        ));
+        match net_result.iter_mut().find(|(c, _)| *c == balance.currency) {
+            Some((_, total)) => *total += balance.net_debit,
+            None => net_result.push((balance.currency, balance.net_debit)),
+        }
+    }
+    for (currency, total) in net_result {
+        if !total.is_zero() {
+            entries.push(ClosingEntry::new(
+                ClosingTarget::RetainedEarnings,
+                currency,
+                total,
+            ));
+        }
+    }
+    entries
+}
+
+pub(super) fn closing_entry_inputs(
+    balances: &[ClosingBalance],
+    retained_earnings_code: &AccountCode,
+    description: &str,
+) -> Vec<ManualEntryInput> {
+    closing_entries(balances)
+        .into_iter()
+        .map(|entry| {
+            let account_id_or_code = match entry.target {
+                ClosingTarget::Account(id) => AccountIdOrCode::Id(id.into()),
+                ClosingTarget::RetainedEarnings => {
+                    AccountIdOrCode::Code(retained_earnings_code.clone())
+                }
+            };
+            ManualEntryInput::builder()
+                .account_id_or_code(account_id_or_code)
+                .amount(entry.amount)
+                .currency(entry.currency)
+                .description(description)
+                .direction(entry.direction)
+                .build()
+                .expect("Couldn't build closing entry")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn balance(account_id: AccountId, net_debit: Decimal) -> ClosingBalance {
+        ClosingBalance {
+            account_id,
+            currency: Currency::USD,
+            net_debit,
+        }
+    }
+
+    #[test]
+    fn profit_is_credited_to_retained_earnings() {
+        let revenue = AccountId::new();
+        let expenses = AccountId::new();
+        let entries = closing_entries(&[balance(revenue, dec!(-100)), balance(expenses, dec!(60))]);
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].target, ClosingTarget::Account(revenue));
+        assert_eq!(entries[0].direction, DebitOrCredit::Debit);
+        assert_eq!(entries[0].amount, dec!(100));
+        assert_eq!(entries[1].target, ClosingTarget::Account(expenses));
+        assert_eq!(entries[1].direction, DebitOrCredit::Credit);
+        assert_eq!(entries[1].amount, dec!(60));
+        assert_eq!(entries[2].target, ClosingTarget::RetainedEarnings);
+        assert_eq!(entries[2].direction, DebitOrCredit::Credit);
+        assert_eq!(entries[2].amount, dec!(40));
+    }
+
+    #[test]
+    fn break_even_year_has_no_retained_earnings_entry() {
+        let entries = closing_entries(&[
+            balance(AccountId::new(), dec!(-50)),
+            balance(AccountId::new(), dec!(50)),
+        ]);
+        assert_eq!(entries.len(), 2);
+        assert!(
+            entries
+                .iter()
+                .all(|e| e.target != ClosingTarget::RetainedEarnings)
+        );
+    }
+
+    #[test]
+    fn entries_balance_per_currency() {
+        let entries = closing_entries(&[
+            balance(AccountId::new(), dec!(-100)),
+            ClosingBalance {
+                account_id: AccountId::new(),
+                currency: Currency::BTC,
+                net_debit: dec!(2),
+            },
+        ]);
+        for currency in [Currency::USD, Currency::BTC] {
+            let net: Decimal = entries
+                .iter()
+                .filter(|e| e.currency == currency)
+                .map(|e| match e.direction {
+                    DebitOrCredit::Debit => e.amount,
+                    DebitOrCredit::Credit => -e.amount,
+                })
+                .sum();
+            assert_eq!(net, Decimal::ZERO);
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use chrono::{Datelike, Months, NaiveDate};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{AccountingPeriodId, ManualTransactionId};
+
+use super::error::AccountingPeriodError;
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum AccountingPeriodType {
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+impl AccountingPeriodType {
+    fn months(&self) -> u32 {
+        match self {
+            Self::Monthly => 1,
+            Self::Quarterly => 3,
+            Self::Annual => 12,
+        }
+    }
+
+    /// Last day of the period starting on `period_start`.
+    pub fn period_end(&self, period_start: NaiveDate) -> Option<NaiveDate> {
+        period_start
+            .checked_add_months(Months::new(self.months()))
+            .and_then(|next_start| next_start.pred_opt())
+    }
+}
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum AccountingPeriodStatus {
+    Open,
+    Closing,
+    Closed,
+}
+
+impl AccountingPeriodStatus {
+    /// Manual adjustments can still be posted while a period is closing.
+    pub fn accepts_manual_postings(&self) -> bool {
+        !matches!(self, Self::Closed)
+    }
+
+    pub fn accepts_module_postings(&self) -> bool {
+        matches!(self, Self::Open)
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AccountingPeriodId")]
+pub enum AccountingPeriodEvent {
+    Initialized {
+        id: AccountingPeriodId,
+        period_type: AccountingPeriodType,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    ClosingStarted {
+        audit_info: AuditInfo,
+    },
+    Closed {
+        closing_transaction_id: Option<ManualTransactionId>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct AccountingPeriod {
+    pub id: AccountingPeriodId,
+    pub period_type: AccountingPeriodType,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+
+    events: EntityEvents<AccountingPeriodEvent>,
+}
+
+impl AccountingPeriod {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for accounting period")
+    }
+
+    pub fn status(&self) -> AccountingPeriodStatus {
+        self.events
+            .iter_all()
+            .fold(AccountingPeriodStatus::Open, |status, event| match event {
+                AccountingPeriodEvent::ClosingStarted { .. } => AccountingPeriodStatus::Closing,
+                AccountingPeriodEvent::Closed { .. } => AccountingPeriodStatus::Closed,
+                _ => status,
+            })
+    }
+
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.period_start <= date && date <= self.period_end
+    }
+
+    pub fn is_year_end(&self) -> bool {
+        self.period_type == AccountingPeriodType::Annual
+    }
+
+    /// Manual transaction that moved the P&L balances into retained earnings
+    /// when the year was closed.
+    pub fn closing_transaction_id(&self) -> Option<ManualTransactionId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            AccountingPeriodEvent::Closed {
+                closing_transaction_id,
+                ..
+            } => *closing_transaction_id,
+            _ => None,
+        })
+    }
+
+    pub fn start_closing(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        match self.status() {
+            AccountingPeriodStatus::Closing => return Ok(Idempotent::Ignored),
+            AccountingPeriodStatus::Closed => {
+                return Err(AccountingPeriodError::AlreadyClosed(self.id));
+            }
+            AccountingPeriodStatus::Open => (),
+        }
+        self.events
+            .push(AccountingPeriodEvent::ClosingStarted { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn close(
+        &mut self,
+        closing_transaction_id: Option<ManualTransactionId>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        match self.status() {
+            AccountingPeriodStatus::Closed => return Ok(Idempotent::Ignored),
+            AccountingPeriodStatus::Open => {
+                return Err(AccountingPeriodError::NotClosing(self.id));
+            }
+            AccountingPeriodStatus::Closing => (),
+        }
+        self.events.push(AccountingPeriodEvent::Closed {
+            closing_transaction_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<AccountingPeriodEvent> for AccountingPeriod {
+    fn try_from_events(events: EntityEvents<AccountingPeriodEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = AccountingPeriodBuilder::default();
+        for event in events.iter_all() {
+            if let AccountingPeriodEvent::Initialized {
+                id,
+                period_type,
+                period_start,
+                period_end,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .period_type(*period_type)
+                    .period_start(*period_start)
+                    .period_end(*period_end)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewAccountingPeriod {
+    #[builder(setter(into))]
+    pub(super) id: AccountingPeriodId,
+    pub(super) period_type: AccountingPeriodType,
+    pub(super) period_start: NaiveDate,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewAccountingPeriod {
+    pub fn builder() -> NewAccountingPeriodBuilder {
+        NewAccountingPeriodBuilder::default()
+    }
+
+    pub(super) fn period_end(&self) -> NaiveDate {
+        self.period_type
+            .period_end(self.period_start)
+            .expect("period end validated on build")
+    }
+
+    pub(super) fn status(&self) -> AccountingPeriodStatus {
+        AccountingPeriodStatus::Open
+    }
+}
+
+impl NewAccountingPeriodBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(start) = self.period_start {
+            if start.day() != 1 {
+                return Err("Accounting period must start on the first day of a month".to_string());
+            }
+            if let Some(period_type) = self.period_type
+                && period_type.period_end(start).is_none()
+            {
+                return Err("Accounting period end is out of range".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<AccountingPeriodEvent> for NewAccountingPeriod {
+    fn into_events(self) -> EntityEvents<AccountingPeriodEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountingPeriodEvent::Initialized {
+                id: self.id,
+                period_type: self.period_type,
+                period_start: self.period_start,
+                period_end: self.period_end(),
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn period(period_type: AccountingPeriodType, period_start: NaiveDate) -> AccountingPeriod {
+        let new_period = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(period_type)
+            .period_start(period_start)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        AccountingPeriod::try_from_events(new_period.into_events()).unwrap()
+    }
+
+    #[test]
+    fn period_end_by_type() {
+        let start = date(2024, 1, 1);
+        assert_eq!(
+            AccountingPeriodType::Monthly.period_end(date(2024, 2, 1)),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            AccountingPeriodType::Quarterly.period_end(start),
+            Some(date(2024, 3, 31))
+        );
+        assert_eq!(
+            AccountingPeriodType::Annual.period_end(start),
+            Some(date(2024, 12, 31))
+        );
+    }
+
+    #[test]
+    fn period_must_start_on_first_of_month() {
+        let res = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(AccountingPeriodType::Monthly)
+            .period_start(date(2024, 1, 15))
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn closing_locks_module_postings_before_manual_postings() {
+        let mut period = period(AccountingPeriodType::Monthly, date(2024, 1, 1));
+        assert!(period.covers(date(2024, 1, 31)));
+        assert!(!period.covers(date(2024, 2, 1)));
+        assert!(period.close(None, dummy_audit_info()).is_err());
+
+        assert!(
+            period
+                .start_closing(dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        let status = period.status();
+        assert!(!status.accepts_module_postings());
+        assert!(status.accepts_manual_postings());
+
+        assert!(
+            period
+                .close(None, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(period.status(), AccountingPeriodStatus::Closed);
+        assert!(!period.status().accepts_manual_postings());
+        assert!(period.start_closing(dummy_audit_info()).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::primitives::AccountingPeriodId;
+
+#[derive(Error, Debug)]
+pub enum AccountingPeriodError {
+    #[error("AccountingPeriodError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountingPeriodError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("AccountingPeriodError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("AccountingPeriodError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("AccountingPeriodError - CalaLedger: {0}")]
+    CalaLedger(#[from] cala_ledger::error::LedgerError),
+    #[error("AccountingPeriodError - CalaAccountSet: {0}")]
+    CalaAccountSet(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("AccountingPeriodError - CalaBalance: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("AccountingPeriodError - InvalidPeriod: {0}")]
+    InvalidPeriod(String),
+    #[error("AccountingPeriodError - Overlapping: period overlaps {0}")]
+    Overlapping(AccountingPeriodId),
+    #[error("AccountingPeriodError - AlreadyClosed: {0}")]
+    AlreadyClosed(AccountingPeriodId),
+    #[error("AccountingPeriodError - NotClosing: {0}")]
+    NotClosing(AccountingPeriodId),
+    #[error("AccountingPeriodError - PeriodLocked: effective date {0} is in period {1}")]
+    PeriodLocked(NaiveDate, AccountingPeriodId),
+    #[error("AccountingPeriodError - RetainedEarningsAccountRequired: {0}")]
+    RetainedEarningsAccountRequired(AccountingPeriodId),
+}
+
+es_entity::from_es_entity_error!(AccountingPeriodError);
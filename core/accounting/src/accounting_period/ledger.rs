@@ -0,0 +1,101 @@
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+use cala_ledger::{
+    AccountId, AccountSetId, CalaLedger, Currency, JournalId,
+    account_set::{AccountSetMemberId, AccountSetMembersByExternalIdCursor},
+};
+
+use super::{closing::ClosingBalance, error::AccountingPeriodError};
+
+#[derive(Clone)]
+pub struct AccountingPeriodLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl AccountingPeriodLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    /// Settled balances as of `until` of every leaf account below the given account sets.
+    pub async fn leaf_account_balances(
+        &self,
+        account_set_ids: Vec<AccountSetId>,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<ClosingBalance>, AccountingPeriodError> {
+        let account_ids = self.leaf_account_ids(account_set_ids).await?;
+        let balance_ids = account_ids
+            .iter()
+            .flat_map(|id| {
+                [
+                    (self.journal_id, *id, Currency::USD),
+                    (self.journal_id, *id, Currency::BTC),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, Some(until))
+            .await?;
+
+        Ok(balances
+            .into_iter()
+            .map(|((_, account_id, currency), range)| {
+                let settled = &range.close.details.settled;
+                ClosingBalance {
+                    account_id,
+                    currency,
+                    net_debit: settled.dr_balance - settled.cr_balance,
+                }
+            })
+            .filter(|balance| !balance.net_debit.is_zero())
+            .collect())
+    }
+
+    async fn leaf_account_ids(
+        &self,
+        account_set_ids: Vec<AccountSetId>,
+    ) -> Result<Vec<AccountId>, AccountingPeriodError> {
+        let mut visited = HashSet::new();
+        let mut account_ids = HashSet::new();
+        let mut pending = account_set_ids;
+        while let Some(account_set_id) = pending.pop() {
+            if !visited.insert(account_set_id) {
+                continue;
+            }
+
+            let mut has_next_page = true;
+            let mut after: Option<AccountSetMembersByExternalIdCursor> = None;
+            while has_next_page {
+                let members = self
+                    .cala
+                    .account_sets()
+                    .list_members_by_external_id(
+                        account_set_id,
+                        es_entity::PaginatedQueryArgs { first: 100, after },
+                    )
+                    .await?;
+                (after, has_next_page) = (members.end_cursor, members.has_next_page);
+
+                for member in members.entities {
+                    match member.id {
+                        AccountSetMemberId::Account(id) => {
+                            account_ids.insert(id);
+                        }
+                        AccountSetMemberId::AccountSet(id) => pending.push(id),
+                    }
+                }
+            }
+        }
+        Ok(account_ids.into_iter().collect())
+    }
+}
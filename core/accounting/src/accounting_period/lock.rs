@@ -0,0 +1,58 @@
+use chrono::NaiveDate;
+
+use super::{entity::*, error::AccountingPeriodError, repo::*};
+
+/// Rejects postings whose effective date falls into a period that no longer
+/// accepts them. Shared with the module ledgers so they check the same periods
+/// as manual transactions.
+#[derive(Clone)]
+pub struct AccountingPeriodLock {
+    repo: AccountingPeriodRepo,
+}
+
+impl AccountingPeriodLock {
+    pub fn new(pool: &sqlx::PgPool) -> Self {
+        Self {
+            repo: AccountingPeriodRepo::new(pool),
+        }
+    }
+
+    pub async fn ensure_accepts_module_posting_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: NaiveDate,
+    ) -> Result<(), AccountingPeriodError> {
+        self.ensure_accepts_in_op(
+            db,
+            effective,
+            AccountingPeriodStatus::accepts_module_postings,
+        )
+        .await
+    }
+
+    pub async fn ensure_accepts_manual_posting_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: NaiveDate,
+    ) -> Result<(), AccountingPeriodError> {
+        self.ensure_accepts_in_op(
+            db,
+            effective,
+            AccountingPeriodStatus::accepts_manual_postings,
+        )
+        .await
+    }
+
+    async fn ensure_accepts_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: NaiveDate,
+        accepts: fn(&AccountingPeriodStatus) -> bool,
+    ) -> Result<(), AccountingPeriodError> {
+        let periods = self.repo.covering_statuses_in_op(db, effective).await?;
+        if let Some((id, _)) = periods.into_iter().find(|(_, status)| !accepts(status)) {
+            return Err(AccountingPeriodError::PeriodLocked(effective, id));
+        }
+        Ok(())
+    }
+}
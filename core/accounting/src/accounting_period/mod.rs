@@ -0,0 +1,239 @@
+mod closing;
+mod entity;
+pub mod error;
+mod ledger;
+mod lock;
+mod repo;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::{AccountSetId, CalaLedger, JournalId};
+
+use crate::{
+    manual_transaction::ManualEntryInput,
+    primitives::{
+        AccountCode, AccountingPeriodId, CoreAccountingAction, CoreAccountingObject,
+        ManualTransactionId,
+    },
+};
+
+#[cfg(feature = "json-schema")]
+pub use entity::AccountingPeriodEvent;
+pub(super) use entity::*;
+pub use entity::{AccountingPeriod, AccountingPeriodStatus, AccountingPeriodType};
+use error::*;
+use ledger::*;
+pub use lock::AccountingPeriodLock;
+pub use repo::accounting_period_cursor::AccountingPeriodsByCreatedAtCursor;
+use repo::*;
+
+#[derive(Clone)]
+pub struct AccountingPeriods<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: AccountingPeriodRepo,
+    ledger: AccountingPeriodLedger,
+    lock: AccountingPeriodLock,
+    authz: Perms,
+}
+
+impl<Perms> AccountingPeriods<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            repo: AccountingPeriodRepo::new(pool),
+            ledger: AccountingPeriodLedger::new(cala, journal_id),
+            lock: AccountingPeriodLock::new(pool),
+            authz: authz.clone(),
+        }
+    }
+
+    pub fn lock(&self) -> &AccountingPeriodLock {
+        &self.lock
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        period_type: AccountingPeriodType,
+        period_start: NaiveDate,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CREATE,
+            )
+            .await?;
+
+        let new_period = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(period_type)
+            .period_start(period_start)
+            .audit_info(audit_info)
+            .build()
+            .map_err(|e| AccountingPeriodError::InvalidPeriod(e.to_string()))?;
+
+        if let Some(existing) = self
+            .repo
+            .find_overlapping(period_type, period_start, new_period.period_end())
+            .await?
+        {
+            return Err(AccountingPeriodError::Overlapping(existing.id));
+        }
+
+        self.repo.create(new_period).await
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(period) => Ok(Some(period)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<AccountingPeriodsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<AccountingPeriod, AccountingPeriodsByCreatedAtCursor>,
+        AccountingPeriodError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.find_all", skip(self), err)]
+    pub async fn find_all<T: From<AccountingPeriod>>(
+        &self,
+        ids: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, T>, AccountingPeriodError> {
+        self.repo.find_all(ids).await
+    }
+
+    #[instrument(
+        name = "core_accounting.accounting_period.start_closing",
+        skip(self),
+        err
+    )]
+    pub async fn start_closing(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.start_closing(audit_info)?.did_execute() {
+            self.repo.update(&mut period).await?;
+        }
+        Ok(period)
+    }
+
+    /// Closes a period that is in `Closing`. For year-end periods the caller
+    /// passes the manual transaction that moved P&L into retained earnings.
+    pub(crate) async fn close(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: AccountingPeriodId,
+        closing_transaction_id: Option<ManualTransactionId>,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period
+            .close(closing_transaction_id, audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut period).await?;
+        }
+        Ok(period)
+    }
+
+    /// Entries moving the year's P&L balances below `pl_account_set_ids` into
+    /// the retained earnings account.
+    pub(crate) async fn year_end_closing_entries(
+        &self,
+        period: &AccountingPeriod,
+        pl_account_set_ids: Vec<AccountSetId>,
+        retained_earnings_code: &AccountCode,
+    ) -> Result<Vec<ManualEntryInput>, AccountingPeriodError> {
+        let balances = self
+            .ledger
+            .leaf_account_balances(pl_account_set_ids, period.period_start, period.period_end)
+            .await?;
+        Ok(closing::closing_entry_inputs(
+            &balances,
+            retained_earnings_code,
+            &year_end_close_description(period),
+        ))
+    }
+}
+
+pub(crate) fn year_end_close_description(period: &AccountingPeriod) -> String {
+    format!(
+        "Year-end close {} to {}",
+        period.period_start, period.period_end
+    )
+}
+
+pub(crate) fn year_end_close_reference(period: &AccountingPeriod) -> String {
+    format!("year-end-close:{}", period.id)
+}
@@ -0,0 +1,159 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::AccountingPeriodId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "AccountingPeriod",
+    err = "AccountingPeriodError",
+    columns(
+        period_type(ty = "AccountingPeriodType", update(persist = false)),
+        period_start(ty = "NaiveDate", update(persist = false)),
+        period_end(
+            ty = "NaiveDate",
+            create(accessor = "period_end()"),
+            update(persist = false)
+        ),
+        status(
+            ty = "AccountingPeriodStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct AccountingPeriodRepo {
+    pool: PgPool,
+}
+
+impl AccountingPeriodRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    /// Status of the periods covering `date`. The rows are locked for the
+    /// rest of the operation so a period cannot be closed while a posting
+    /// dated into it is in flight.
+    pub async fn covering_statuses_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        date: NaiveDate,
+    ) -> Result<Vec<(AccountingPeriodId, AccountingPeriodStatus)>, AccountingPeriodError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: AccountingPeriodId", status AS "status: AccountingPeriodStatus"
+            FROM core_accounting_periods
+            WHERE period_start <= $1 AND period_end >= $1
+            FOR SHARE"#,
+            date
+        )
+        .fetch_all(&mut **db.tx())
+        .await?;
+        Ok(rows.into_iter().map(|row| (row.id, row.status)).collect())
+    }
+
+    /// A period of `period_type` overlapping `period_start..=period_end`, if any.
+    pub async fn find_overlapping(
+        &self,
+        period_type: AccountingPeriodType,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        let row = sqlx::query!(
+            r#"SELECT id AS "id: AccountingPeriodId"
+            FROM core_accounting_periods
+            WHERE period_type = $1 AND period_start <= $3 AND period_end >= $2
+            LIMIT 1"#,
+            period_type.to_string(),
+            period_start,
+            period_end
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        match row {
+            Some(row) => Ok(Some(self.find_by_id(row.id).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+mod accounting_period_type_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::AccountingPeriodType;
+
+    impl Type<Postgres> for AccountingPeriodType {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AccountingPeriodType {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AccountingPeriodType {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for AccountingPeriodType {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
+
+mod accounting_period_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::AccountingPeriodStatus;
+
+    impl Type<Postgres> for AccountingPeriodStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AccountingPeriodStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AccountingPeriodStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for AccountingPeriodStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -10,6 +10,10 @@ pub enum BalanceSheetError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("BalanceSheetError - BalanceSheetLedgerError: {0}")]
     BalanceSheetLedgerError(#[from] super::ledger::error::BalanceSheetLedgerError),
+    #[error("BalanceSheetError - ComparativeStatementError: {0}")]
+    ComparativeStatementError(
+        #[from] crate::comparative_statement::error::ComparativeStatementError,
+    ),
     #[error("BalanceSheetError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
     #[error("BalanceSheetError - BalanceSheetConfigAlreadyExists")]
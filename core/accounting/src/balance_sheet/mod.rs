@@ -12,6 +12,9 @@ use chrono::NaiveDate;
 use crate::{
     LedgerAccountId,
     chart_of_accounts::Chart,
+    comparative_statement::{
+        ComparativeStatement, ComparativeStatementLedger, StatementBalance, StatementPeriod,
+    },
     primitives::{BalanceRange, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject},
 };
 
@@ -76,6 +79,7 @@ where
     pool: sqlx::PgPool,
     authz: Perms,
     balance_sheet_ledger: BalanceSheetLedger,
+    comparative_statement_ledger: ComparativeStatementLedger,
 }
 
 impl<Perms> BalanceSheets<Perms>
@@ -91,10 +95,12 @@ where
         journal_id: cala_ledger::JournalId,
     ) -> Self {
         let balance_sheet_ledger = BalanceSheetLedger::new(cala, journal_id);
+        let comparative_statement_ledger = ComparativeStatementLedger::new(cala, journal_id);
 
         Self {
             pool: pool.clone(),
             balance_sheet_ledger,
+            comparative_statement_ledger,
             authz: authz.clone(),
         }
     }
@@ -229,6 +235,50 @@ where
             .get_balance_sheet(reference, from, until)
             .await?)
     }
+
+    #[instrument(
+        name = "core_accounting.balance_sheet.comparative_balance_sheet",
+        skip(self),
+        err
+    )]
+    pub async fn comparative_balance_sheet(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<ComparativeStatement, BalanceSheetError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_balance_sheet(),
+                CoreAccountingAction::BALANCE_SHEET_READ,
+            )
+            .await?;
+
+        self.load_comparative_balance_sheet(reference, periods)
+            .await
+    }
+
+    pub(crate) async fn load_comparative_balance_sheet(
+        &self,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<ComparativeStatement, BalanceSheetError> {
+        let ids = self
+            .balance_sheet_ledger
+            .get_ids_from_reference(reference)
+            .await?;
+
+        Ok(self
+            .comparative_statement_ledger
+            .load(
+                ids.id,
+                &[ids.assets, ids.liabilities, ids.equity],
+                periods,
+                StatementBalance::Closing,
+            )
+            .await?)
+    }
 }
 
 #[derive(Clone)]
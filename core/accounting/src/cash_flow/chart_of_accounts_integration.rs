@@ -0,0 +1,56 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{AccountCode, ChartId};
+
+/// Tags chart nodes for the indirect-method cash flow statement. Nodes under
+/// the cash codes are reconciled against the sum of the three activities.
+#[derive(Builder, Debug, Serialize, Deserialize, Clone)]
+pub struct ChartOfAccountsIntegrationConfig {
+    #[builder(setter(into))]
+    pub chart_of_accounts_id: ChartId,
+    pub chart_of_accounts_cash_codes: Vec<AccountCode>,
+    pub chart_of_accounts_operating_codes: Vec<AccountCode>,
+    pub chart_of_accounts_investing_codes: Vec<AccountCode>,
+    pub chart_of_accounts_financing_codes: Vec<AccountCode>,
+}
+
+impl ChartOfAccountsIntegrationConfig {
+    pub fn builder() -> ChartOfAccountsIntegrationConfigBuilder {
+        ChartOfAccountsIntegrationConfigBuilder::default()
+    }
+
+    /// The first code that is tagged more than once, if any.
+    pub(super) fn duplicate_code(&self) -> Option<&AccountCode> {
+        let mut seen = std::collections::HashSet::new();
+        self.chart_of_accounts_cash_codes
+            .iter()
+            .chain(self.chart_of_accounts_operating_codes.iter())
+            .chain(self.chart_of_accounts_investing_codes.iter())
+            .chain(self.chart_of_accounts_financing_codes.iter())
+            .find(|code| !seen.insert(*code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(codes: &[&str]) -> Vec<AccountCode> {
+        codes.iter().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn detects_code_tagged_under_two_activities() {
+        let config = ChartOfAccountsIntegrationConfig::builder()
+            .chart_of_accounts_id(ChartId::new())
+            .chart_of_accounts_cash_codes(codes(&["11"]))
+            .chart_of_accounts_operating_codes(codes(&["12", "21"]))
+            .chart_of_accounts_investing_codes(codes(&["13"]))
+            .chart_of_accounts_financing_codes(codes(&["21"]))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.duplicate_code(), Some(&"21".parse().unwrap()));
+    }
+}
@@ -0,0 +1,391 @@
+pub mod error;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use audit::AuditInfo;
+use cala_ledger::{
+    AccountSetId, BalanceId, CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation,
+    account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
+    balance::AccountBalance,
+};
+
+use crate::primitives::CalaBalanceRange;
+
+use super::{
+    CASH_NAME, CashFlowActivity, CashFlowAmount, CashFlowLine, CashFlowSection, CashFlowStatement,
+    CashFlowStatementIds, ChartOfAccountsIntegrationConfig, FINANCING_NAME, INVESTING_NAME,
+    NET_INCOME_NAME, OPERATING_NAME,
+};
+
+use error::*;
+
+#[derive(Clone)]
+pub struct CashFlowStatementLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl CashFlowStatementLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    async fn create_unique_account_set(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        reference: &str,
+        normal_balance_type: DebitOrCredit,
+    ) -> Result<AccountSetId, CashFlowStatementLedgerError> {
+        let id = AccountSetId::new();
+        let new_account_set = NewAccountSet::builder()
+            .id(id)
+            .journal_id(self.journal_id)
+            .external_id(reference)
+            .name(reference)
+            .description(reference)
+            .normal_balance_type(normal_balance_type)
+            .build()
+            .expect("Could not build new account set");
+        self.cala
+            .account_sets()
+            .create_in_op(op, new_account_set)
+            .await?;
+
+        Ok(id)
+    }
+
+    async fn create_account_set(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        name: &str,
+        normal_balance_type: DebitOrCredit,
+        parent_id: AccountSetId,
+    ) -> Result<AccountSetId, CashFlowStatementLedgerError> {
+        let id = AccountSetId::new();
+        let new_account_set = NewAccountSet::builder()
+            .id(id)
+            .journal_id(self.journal_id)
+            .name(name)
+            .description(name)
+            .normal_balance_type(normal_balance_type)
+            .build()
+            .expect("Could not build new account set");
+        self.cala
+            .account_sets()
+            .create_in_op(op, new_account_set)
+            .await?;
+        self.cala
+            .account_sets()
+            .add_member_in_op(op, parent_id, id)
+            .await?;
+
+        Ok(id)
+    }
+
+    /// Creates the statement with its activity sections. The profit and loss
+    /// statement identified by `pl_statement_reference` becomes the net income
+    /// line that the indirect method starts from.
+    pub async fn create(
+        &self,
+        op: es_entity::DbOp<'_>,
+        reference: &str,
+        pl_statement_reference: &str,
+    ) -> Result<CashFlowStatementIds, CashFlowStatementLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let pl_statement_id = self
+            .cala
+            .account_sets()
+            .find_by_external_id(pl_statement_reference.to_string())
+            .await?
+            .id;
+
+        let statement_id = self
+            .create_unique_account_set(&mut op, reference, DebitOrCredit::Credit)
+            .await?;
+
+        let net_income_id = self
+            .create_account_set(
+                &mut op,
+                NET_INCOME_NAME,
+                DebitOrCredit::Credit,
+                statement_id,
+            )
+            .await?;
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, net_income_id, pl_statement_id)
+            .await?;
+
+        let operating_id = self
+            .create_account_set(&mut op, OPERATING_NAME, DebitOrCredit::Credit, statement_id)
+            .await?;
+        let investing_id = self
+            .create_account_set(&mut op, INVESTING_NAME, DebitOrCredit::Credit, statement_id)
+            .await?;
+        let financing_id = self
+            .create_account_set(&mut op, FINANCING_NAME, DebitOrCredit::Credit, statement_id)
+            .await?;
+        let cash_id = self
+            .create_account_set(&mut op, CASH_NAME, DebitOrCredit::Debit, statement_id)
+            .await?;
+
+        op.commit().await?;
+
+        Ok(CashFlowStatementIds {
+            id: statement_id,
+            net_income: net_income_id,
+            operating: operating_id,
+            investing: investing_id,
+            financing: financing_id,
+            cash: cash_id,
+        })
+    }
+
+    async fn get_member_account_sets(
+        &self,
+        id: AccountSetId,
+    ) -> Result<Vec<AccountSet>, CashFlowStatementLedgerError> {
+        let member_ids = self
+            .cala
+            .account_sets()
+            .list_members_by_created_at(id, Default::default())
+            .await?
+            .entities
+            .into_iter()
+            .map(|m| match m.id {
+                AccountSetMemberId::AccountSet(id) => Ok(id),
+                _ => Err(CashFlowStatementLedgerError::NonAccountSetMemberTypeFound),
+            })
+            .collect::<Result<Vec<AccountSetId>, CashFlowStatementLedgerError>>()?;
+
+        let mut members = self
+            .cala
+            .account_sets()
+            .find_all::<AccountSet>(&member_ids)
+            .await?
+            .into_values()
+            .collect::<Vec<_>>();
+        members.sort_by(|a, b| a.values().external_id.cmp(&b.values().external_id));
+
+        Ok(members)
+    }
+
+    pub async fn get_ids_from_reference(
+        &self,
+        reference: String,
+    ) -> Result<CashFlowStatementIds, CashFlowStatementLedgerError> {
+        let statement_id = self
+            .cala
+            .account_sets()
+            .find_by_external_id(reference)
+            .await?
+            .id;
+
+        let members = self
+            .get_member_account_sets(statement_id)
+            .await?
+            .into_iter()
+            .map(|set| {
+                let id = set.id;
+                (set.into_values().name, id)
+            })
+            .collect::<HashMap<_, _>>();
+        let find = |name: &str| {
+            members
+                .get(name)
+                .copied()
+                .ok_or(CashFlowStatementLedgerError::NotFound(name.to_string()))
+        };
+
+        Ok(CashFlowStatementIds {
+            id: statement_id,
+            net_income: find(NET_INCOME_NAME)?,
+            operating: find(OPERATING_NAME)?,
+            investing: find(INVESTING_NAME)?,
+            financing: find(FINANCING_NAME)?,
+            cash: find(CASH_NAME)?,
+        })
+    }
+
+    pub async fn get_chart_of_accounts_integration_config(
+        &self,
+        reference: String,
+    ) -> Result<Option<ChartOfAccountsIntegrationConfig>, CashFlowStatementLedgerError> {
+        let account_set_id = self
+            .get_ids_from_reference(reference)
+            .await?
+            .account_set_id_for_config();
+
+        let account_set = self.cala.account_sets().find(account_set_id).await?;
+        if let Some(meta) = account_set.values().metadata.as_ref() {
+            let meta: ChartOfAccountsIntegrationMeta =
+                serde_json::from_value(meta.clone()).expect("Could not deserialize metadata");
+            Ok(Some(meta.config))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn attach_chart_of_accounts_account_sets(
+        &self,
+        reference: String,
+        charts_integration_meta: ChartOfAccountsIntegrationMeta,
+    ) -> Result<(), CashFlowStatementLedgerError> {
+        let mut op = self.cala.begin_operation().await?;
+
+        let ids = self.get_ids_from_reference(reference).await?;
+
+        let ChartOfAccountsIntegrationMeta {
+            config: _,
+            audit_info: _,
+
+            cash_account_set_ids_from_chart,
+            operating_account_set_ids_from_chart,
+            investing_account_set_ids_from_chart,
+            financing_account_set_ids_from_chart,
+        } = &charts_integration_meta;
+
+        for (internal_account_set_id, members) in [
+            (ids.cash, cash_account_set_ids_from_chart),
+            (ids.operating, operating_account_set_ids_from_chart),
+            (ids.investing, investing_account_set_ids_from_chart),
+            (ids.financing, financing_account_set_ids_from_chart),
+        ] {
+            for member_id in members {
+                self.cala
+                    .account_sets()
+                    .add_member_in_op(&mut op, internal_account_set_id, *member_id)
+                    .await?;
+            }
+        }
+
+        let mut config_account_set = self
+            .cala
+            .account_sets()
+            .find_all_in_op::<AccountSet>(&mut op, &[ids.account_set_id_for_config()])
+            .await?
+            .remove(&ids.account_set_id_for_config())
+            .expect("config account set not found");
+        let mut update = AccountSetUpdate::default();
+        update
+            .metadata(&charts_integration_meta)
+            .expect("Could not update metadata");
+        config_account_set.update(update);
+        self.cala
+            .account_sets()
+            .persist_in_op(&mut op, &mut config_account_set)
+            .await?;
+
+        op.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn get_cash_flow_statement(
+        &self,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementLedgerError> {
+        let ids = self.get_ids_from_reference(reference).await?;
+        let statement = self.cala.account_sets().find(ids.id).await?;
+
+        let mut section_members = Vec::new();
+        for (activity, set_id) in [
+            (CashFlowActivity::Operating, ids.operating),
+            (CashFlowActivity::Investing, ids.investing),
+            (CashFlowActivity::Financing, ids.financing),
+        ] {
+            section_members.push((activity, self.get_member_account_sets(set_id).await?));
+        }
+        let net_income = self.cala.account_sets().find(ids.net_income).await?;
+
+        let mut all_ids = vec![ids.net_income, ids.cash];
+        all_ids.extend(
+            section_members
+                .iter()
+                .flat_map(|(_, members)| members.iter().map(|m| m.id)),
+        );
+        let balance_ids = all_ids
+            .iter()
+            .flat_map(|id| {
+                [
+                    (self.journal_id, (*id).into(), Currency::USD),
+                    (self.journal_id, (*id).into(), Currency::BTC),
+                ]
+            })
+            .collect::<Vec<BalanceId>>();
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, Some(until))
+            .await?;
+
+        let amount = |id: AccountSetId, f: fn(&CalaBalanceRange) -> Decimal| CashFlowAmount {
+            usd: balances
+                .get(&(self.journal_id, id.into(), Currency::USD))
+                .map(f)
+                .unwrap_or_default(),
+            btc: balances
+                .get(&(self.journal_id, id.into(), Currency::BTC))
+                .map(f)
+                .unwrap_or_default(),
+        };
+
+        let sections = section_members
+            .into_iter()
+            .map(|(activity, members)| {
+                let mut lines = Vec::new();
+                if activity == CashFlowActivity::Operating {
+                    lines.push(CashFlowLine {
+                        id: ids.net_income.into(),
+                        name: net_income.values().name.clone(),
+                        amount: amount(ids.net_income, |r| net_credit(&r.period)),
+                    });
+                }
+                lines.extend(members.into_iter().map(|member| CashFlowLine {
+                    id: member.id.into(),
+                    amount: amount(member.id, |r| net_credit(&r.period)),
+                    name: member.into_values().name,
+                }));
+                CashFlowSection::new(activity, lines)
+            })
+            .collect();
+
+        let values = statement.into_values();
+        Ok(CashFlowStatement {
+            id: values.id.into(),
+            name: values.name,
+            from,
+            until,
+            sections,
+            opening_cash: amount(ids.cash, |r| -net_credit(&r.open)),
+            closing_cash: amount(ids.cash, |r| -net_credit(&r.close)),
+        })
+    }
+}
+
+/// Settled credits less debits. Increases in liabilities and decreases in
+/// assets are positive, which is their effect on cash.
+fn net_credit(balance: &AccountBalance) -> Decimal {
+    balance.details.settled.cr_balance - balance.details.settled.dr_balance
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChartOfAccountsIntegrationMeta {
+    pub config: ChartOfAccountsIntegrationConfig,
+    pub audit_info: AuditInfo,
+
+    pub cash_account_set_ids_from_chart: Vec<AccountSetId>,
+    pub operating_account_set_ids_from_chart: Vec<AccountSetId>,
+    pub investing_account_set_ids_from_chart: Vec<AccountSetId>,
+    pub financing_account_set_ids_from_chart: Vec<AccountSetId>,
+}
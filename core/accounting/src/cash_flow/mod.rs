@@ -0,0 +1,447 @@
+mod chart_of_accounts_integration;
+pub mod error;
+pub mod ledger;
+
+use chrono::NaiveDate;
+use csv::Writer;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::CalaLedger;
+
+use crate::{
+    LedgerAccountId,
+    chart_of_accounts::Chart,
+    primitives::{AccountCode, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject},
+};
+
+pub use chart_of_accounts_integration::{
+    ChartOfAccountsIntegrationConfig, ChartOfAccountsIntegrationConfigBuilderError,
+};
+use error::*;
+use ledger::*;
+
+pub(crate) const NET_INCOME_NAME: &str = "Net Income";
+pub(crate) const OPERATING_NAME: &str = "Operating Activities";
+pub(crate) const INVESTING_NAME: &str = "Investing Activities";
+pub(crate) const FINANCING_NAME: &str = "Financing Activities";
+pub(crate) const CASH_NAME: &str = "Cash and Cash Equivalents";
+
+#[derive(Clone, Copy)]
+pub struct CashFlowStatementIds {
+    pub id: CalaAccountSetId,
+    pub net_income: CalaAccountSetId,
+    pub operating: CalaAccountSetId,
+    pub investing: CalaAccountSetId,
+    pub financing: CalaAccountSetId,
+    pub cash: CalaAccountSetId,
+}
+
+impl CashFlowStatementIds {
+    fn account_set_id_for_config(&self) -> CalaAccountSetId {
+        self.operating
+    }
+}
+
+#[derive(Clone)]
+pub struct CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pool: sqlx::PgPool,
+    authz: Perms,
+    cash_flow_ledger: CashFlowStatementLedger,
+}
+
+impl<Perms> CashFlowStatements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: cala_ledger::JournalId,
+    ) -> Self {
+        let cash_flow_ledger = CashFlowStatementLedger::new(cala, journal_id);
+
+        Self {
+            pool: pool.clone(),
+            cash_flow_ledger,
+            authz: authz.clone(),
+        }
+    }
+
+    #[instrument(name = "core_accounting.cash_flow.create", skip(self), err)]
+    pub async fn create_cash_flow_statement(
+        &self,
+        name: String,
+        pl_statement_reference: String,
+    ) -> Result<(), CashFlowStatementError> {
+        let mut op = es_entity::DbOp::init(&self.pool).await?;
+
+        self.authz
+            .audit()
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreAccountingObject::all_cash_flow(),
+                CoreAccountingAction::CASH_FLOW_CREATE,
+            )
+            .await?;
+
+        match self
+            .cash_flow_ledger
+            .create(op, &name, &pl_statement_reference)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if e.account_set_exists() => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(
+        name = "core_accounting.cash_flow.get_integration_config",
+        skip(self),
+        err
+    )]
+    pub async fn get_chart_of_accounts_integration_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+    ) -> Result<Option<ChartOfAccountsIntegrationConfig>, CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_configuration(),
+                CoreAccountingAction::CASH_FLOW_CONFIGURATION_READ,
+            )
+            .await?;
+        Ok(self
+            .cash_flow_ledger
+            .get_chart_of_accounts_integration_config(reference)
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.cash_flow.set_integration_config",
+        skip(self, chart),
+        err
+    )]
+    pub async fn set_chart_of_accounts_integration_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        chart: &Chart,
+        config: ChartOfAccountsIntegrationConfig,
+    ) -> Result<ChartOfAccountsIntegrationConfig, CashFlowStatementError> {
+        if chart.id != config.chart_of_accounts_id {
+            return Err(CashFlowStatementError::ChartIdMismatch);
+        }
+
+        if let Some(code) = config.duplicate_code() {
+            return Err(CashFlowStatementError::AccountCodeTaggedTwice(code.clone()));
+        }
+
+        if self
+            .cash_flow_ledger
+            .get_chart_of_accounts_integration_config(reference.to_string())
+            .await?
+            .is_some()
+        {
+            return Err(CashFlowStatementError::CashFlowStatementConfigAlreadyExists);
+        }
+
+        let set_ids = |codes: &[AccountCode]| {
+            codes
+                .iter()
+                .map(|code| chart.account_set_id_from_code(code))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let cash_account_set_ids_from_chart = set_ids(&config.chart_of_accounts_cash_codes)?;
+        let operating_account_set_ids_from_chart =
+            set_ids(&config.chart_of_accounts_operating_codes)?;
+        let investing_account_set_ids_from_chart =
+            set_ids(&config.chart_of_accounts_investing_codes)?;
+        let financing_account_set_ids_from_chart =
+            set_ids(&config.chart_of_accounts_financing_codes)?;
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow_configuration(),
+                CoreAccountingAction::CASH_FLOW_CONFIGURATION_UPDATE,
+            )
+            .await?;
+
+        let charts_integration_meta = ChartOfAccountsIntegrationMeta {
+            audit_info,
+            config: config.clone(),
+
+            cash_account_set_ids_from_chart,
+            operating_account_set_ids_from_chart,
+            investing_account_set_ids_from_chart,
+            financing_account_set_ids_from_chart,
+        };
+
+        self.cash_flow_ledger
+            .attach_chart_of_accounts_account_sets(reference, charts_integration_meta)
+            .await?;
+
+        Ok(config)
+    }
+
+    #[instrument(
+        name = "core_accounting.cash_flow.cash_flow_statement",
+        skip(self),
+        err
+    )]
+    pub async fn cash_flow_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_cash_flow(),
+                CoreAccountingAction::CASH_FLOW_READ,
+            )
+            .await?;
+
+        self.load_cash_flow_statement(reference, from, until).await
+    }
+
+    pub(crate) async fn load_cash_flow_statement(
+        &self,
+        reference: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<CashFlowStatement, CashFlowStatementError> {
+        if from > until {
+            return Err(CashFlowStatementError::InvalidPeriod);
+        }
+
+        Ok(self
+            .cash_flow_ledger
+            .get_cash_flow_statement(reference, from, until)
+            .await?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum CashFlowActivity {
+    Operating,
+    Investing,
+    Financing,
+}
+
+impl std::fmt::Display for CashFlowActivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Operating => f.write_str(OPERATING_NAME),
+            Self::Investing => f.write_str(INVESTING_NAME),
+            Self::Financing => f.write_str(FINANCING_NAME),
+        }
+    }
+}
+
+/// Effect on cash per currency; inflows are positive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CashFlowAmount {
+    pub usd: Decimal,
+    pub btc: Decimal,
+}
+
+impl std::ops::Add for CashFlowAmount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            usd: self.usd + other.usd,
+            btc: self.btc + other.btc,
+        }
+    }
+}
+
+impl std::ops::Sub for CashFlowAmount {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            usd: self.usd - other.usd,
+            btc: self.btc - other.btc,
+        }
+    }
+}
+
+impl std::iter::Sum for CashFlowAmount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, amount| acc + amount)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CashFlowLine {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub amount: CashFlowAmount,
+}
+
+#[derive(Debug, Clone)]
+pub struct CashFlowSection {
+    pub activity: CashFlowActivity,
+    pub lines: Vec<CashFlowLine>,
+    pub total: CashFlowAmount,
+}
+
+impl CashFlowSection {
+    fn new(activity: CashFlowActivity, lines: Vec<CashFlowLine>) -> Self {
+        let total = lines.iter().map(|line| line.amount).sum();
+        Self {
+            activity,
+            lines,
+            total,
+        }
+    }
+}
+
+/// Indirect-method cash flow statement. The operating section starts from
+/// net income and adjusts it by the change in each tagged node.
+#[derive(Debug, Clone)]
+pub struct CashFlowStatement {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub from: NaiveDate,
+    pub until: NaiveDate,
+    pub sections: Vec<CashFlowSection>,
+    pub opening_cash: CashFlowAmount,
+    pub closing_cash: CashFlowAmount,
+}
+
+impl CashFlowStatement {
+    pub fn net_change_in_cash(&self) -> CashFlowAmount {
+        self.sections.iter().map(|section| section.total).sum()
+    }
+
+    /// Movement in the cash nodes not explained by the three activities.
+    /// Non-zero when some balance sheet node is left untagged.
+    pub fn unreconciled_difference(&self) -> CashFlowAmount {
+        (self.closing_cash - self.opening_cash) - self.net_change_in_cash()
+    }
+
+    pub fn to_csv(&self) -> Result<Vec<u8>, csv::Error> {
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(["Section", "Line", "USD", "BTC"])?;
+
+        let mut write = |section: &str, line: &str, amount: CashFlowAmount| {
+            wtr.write_record([
+                section.to_string(),
+                line.to_string(),
+                amount.usd.to_string(),
+                amount.btc.to_string(),
+            ])
+        };
+        for section in self.sections.iter() {
+            let activity = section.activity.to_string();
+            for line in section.lines.iter() {
+                write(&activity, &line.name, line.amount)?;
+            }
+            write(&activity, "Total", section.total)?;
+        }
+        write("Summary", "Net Change in Cash", self.net_change_in_cash())?;
+        write("Summary", "Opening Cash", self.opening_cash)?;
+        write("Summary", "Closing Cash", self.closing_cash)?;
+        write("Summary", "Unreconciled", self.unreconciled_difference())?;
+
+        Ok(wtr.into_inner().expect("Could not flush cash flow writer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn line(name: &str, usd: Decimal) -> CashFlowLine {
+        CashFlowLine {
+            id: LedgerAccountId::new(),
+            name: name.to_string(),
+            amount: CashFlowAmount {
+                usd,
+                btc: Decimal::ZERO,
+            },
+        }
+    }
+
+    fn statement() -> CashFlowStatement {
+        CashFlowStatement {
+            id: LedgerAccountId::new(),
+            name: "Cash Flow Statement".to_string(),
+            from: "2024-01-01".parse().unwrap(),
+            until: "2024-01-31".parse().unwrap(),
+            sections: vec![
+                CashFlowSection::new(
+                    CashFlowActivity::Operating,
+                    vec![
+                        line(NET_INCOME_NAME, dec!(100)),
+                        line("Interest Receivable", dec!(-30)),
+                    ],
+                ),
+                CashFlowSection::new(CashFlowActivity::Investing, vec![line("Loans", dec!(-500))]),
+                CashFlowSection::new(
+                    CashFlowActivity::Financing,
+                    vec![line("Deposits", dec!(600))],
+                ),
+            ],
+            opening_cash: CashFlowAmount {
+                usd: dec!(1000),
+                btc: Decimal::ZERO,
+            },
+            closing_cash: CashFlowAmount {
+                usd: dec!(1170),
+                btc: Decimal::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn sections_reconcile_to_change_in_cash() {
+        let statement = statement();
+
+        assert_eq!(statement.sections[0].total.usd, dec!(70));
+        assert_eq!(statement.net_change_in_cash().usd, dec!(170));
+        assert_eq!(
+            statement.unreconciled_difference(),
+            CashFlowAmount::default()
+        );
+    }
+
+    #[test]
+    fn untagged_movements_show_as_unreconciled() {
+        let mut statement = statement();
+        statement.closing_cash.usd = dec!(1200);
+
+        assert_eq!(statement.unreconciled_difference().usd, dec!(30));
+    }
+
+    #[test]
+    fn csv_lists_lines_and_section_totals() {
+        let csv = String::from_utf8(statement().to_csv().unwrap()).unwrap();
+        let rows = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(rows[0], "Section,Line,USD,BTC");
+        assert_eq!(rows[1], "Operating Activities,Net Income,100,0");
+        assert_eq!(rows[3], "Operating Activities,Total,70,0");
+        assert_eq!(rows.last().unwrap(), &"Summary,Unreconciled,0,0");
+    }
+}
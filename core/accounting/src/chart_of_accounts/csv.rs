@@ -3,7 +3,7 @@ use csv::{ReaderBuilder, Trim};
 use std::io::Cursor;
 
 use crate::primitives::{
-    AccountCodeSection, AccountCodeSectionParseError, AccountName, AccountSpec,
+    AccountCode, AccountCodeSection, AccountCodeSectionParseError, AccountName, AccountSpec,
 };
 
 use thiserror::Error;
@@ -35,6 +35,7 @@ impl CsvParser {
                     let mut sections = vec![];
                     let mut category = None;
                     let mut normal_balance_type = None;
+                    let mut explicit_parent = None;
                     if record.iter().all(|field| field.is_empty()) {
                         continue;
                     }
@@ -42,6 +43,10 @@ impl CsvParser {
                     for (idx, field) in record.iter().enumerate() {
                         if let Ok(balance_type) = field.parse::<DebitOrCredit>() {
                             normal_balance_type = Some(balance_type);
+                            if let Some(parent) = record.get(idx + 1).filter(|p| !p.is_empty()) {
+                                explicit_parent =
+                                    Some(parent.parse::<AccountCode>().map_err(|_| CsvParseError)?);
+                            }
                             break;
                         }
 
@@ -75,6 +80,21 @@ impl CsvParser {
                     }
 
                     if let Some(category) = category {
+                        if let Some(parent) = explicit_parent {
+                            let parent_balance_type = specs
+                                .iter()
+                                .rfind(|s| s.code == parent)
+                                .map(|s| s.normal_balance_type);
+                            specs.push(AccountSpec::new(
+                                Some(parent),
+                                sections,
+                                category,
+                                normal_balance_type
+                                    .or(parent_balance_type)
+                                    .unwrap_or_default(),
+                            ));
+                            continue;
+                        }
                         if let Some(s) = specs.iter().rposition(|s| s.code.is_parent(&sections)) {
                             let parent = specs[s].clone();
                             specs.push(AccountSpec::new(
@@ -3,7 +3,7 @@ use derive_builder::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use audit::AuditInfo;
 
@@ -29,6 +29,20 @@ pub enum ChartEvent {
         ledger_account_set_id: CalaAccountSetId,
         audit_info: AuditInfo,
     },
+    NodeRenamed {
+        code: AccountCode,
+        name: AccountName,
+        audit_info: AuditInfo,
+    },
+    NodeMoved {
+        code: AccountCode,
+        parent: Option<AccountCode>,
+        audit_info: AuditInfo,
+    },
+    NodeDeactivated {
+        code: AccountCode,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -38,6 +52,7 @@ pub struct Chart {
     pub reference: String,
     pub name: String,
     all_accounts: HashMap<AccountCode, (AccountSpec, CalaAccountSetId)>,
+    deactivated_accounts: HashSet<AccountCode>,
 
     events: EntityEvents<ChartEvent>,
 }
@@ -67,6 +82,158 @@ impl Chart {
         Idempotent::Executed((parent, ledger_account_set_id))
     }
 
+    pub fn rename_node(
+        &mut self,
+        code: &AccountCode,
+        name: AccountName,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaAccountSetId>, ChartOfAccountsError> {
+        let (spec, account_set_id) = self.active_account(code)?;
+        if spec.name.to_string() == name.to_string() {
+            return Ok(Idempotent::Ignored);
+        }
+        let account_set_id = *account_set_id;
+
+        self.events.push(ChartEvent::NodeRenamed {
+            code: code.clone(),
+            name: name.clone(),
+            audit_info,
+        });
+        if let Some((spec, _)) = self.all_accounts.get_mut(code) {
+            spec.name = name;
+        }
+        Ok(Idempotent::Executed(account_set_id))
+    }
+
+    /// Moves the node under `parent` (or to the root of the chart when `None`).
+    /// Returns the node's account set id together with the account set ids
+    /// of its old and new parents.
+    #[allow(clippy::type_complexity)]
+    pub fn move_node(
+        &mut self,
+        code: &AccountCode,
+        parent: Option<AccountCode>,
+        audit_info: AuditInfo,
+    ) -> Result<
+        Idempotent<(
+            CalaAccountSetId,
+            Option<CalaAccountSetId>,
+            Option<CalaAccountSetId>,
+        )>,
+        ChartOfAccountsError,
+    > {
+        let (spec, account_set_id) = self.active_account(code)?;
+        if spec.parent == parent {
+            return Ok(Idempotent::Ignored);
+        }
+        let account_set_id = *account_set_id;
+        let old_parent_id = spec
+            .parent
+            .as_ref()
+            .and_then(|p| self.all_accounts.get(p))
+            .map(|(_, id)| *id);
+
+        let new_parent_id = match parent.as_ref() {
+            Some(new_parent) => {
+                if new_parent == code || self.is_descendant_of(new_parent, code) {
+                    return Err(ChartOfAccountsError::InvalidNodeParent(
+                        code.clone(),
+                        new_parent.clone(),
+                    ));
+                }
+                let (_, id) = self.active_account(new_parent)?;
+                Some(*id)
+            }
+            None => None,
+        };
+
+        self.events.push(ChartEvent::NodeMoved {
+            code: code.clone(),
+            parent: parent.clone(),
+            audit_info,
+        });
+        if let Some((spec, _)) = self.all_accounts.get_mut(code) {
+            spec.parent = parent;
+        }
+        Ok(Idempotent::Executed((
+            account_set_id,
+            old_parent_id,
+            new_parent_id,
+        )))
+    }
+
+    /// Retires the node. Nodes that still have active children cannot be deactivated.
+    /// Returns the node's account set id together with the account set id of its parent.
+    pub fn deactivate_node(
+        &mut self,
+        code: &AccountCode,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<(CalaAccountSetId, Option<CalaAccountSetId>)>, ChartOfAccountsError>
+    {
+        if self.deactivated_accounts.contains(code) {
+            return Ok(Idempotent::Ignored);
+        }
+        let (spec, account_set_id) = self.active_account(code)?;
+        if self
+            .all_accounts
+            .values()
+            .any(|(child, _)| child.parent.as_ref() == Some(code) && self.is_active(&child.code))
+        {
+            return Err(ChartOfAccountsError::NodeHasActiveChildren(code.clone()));
+        }
+        let account_set_id = *account_set_id;
+        let parent_id = spec
+            .parent
+            .as_ref()
+            .and_then(|p| self.all_accounts.get(p))
+            .map(|(_, id)| *id);
+
+        self.events.push(ChartEvent::NodeDeactivated {
+            code: code.clone(),
+            audit_info,
+        });
+        self.deactivated_accounts.insert(code.clone());
+        Ok(Idempotent::Executed((account_set_id, parent_id)))
+    }
+
+    pub fn is_active(&self, code: &AccountCode) -> bool {
+        self.all_accounts.contains_key(code) && !self.deactivated_accounts.contains(code)
+    }
+
+    /// Account set ids of every node in the chart, including deactivated ones.
+    pub fn all_account_set_ids(&self) -> impl Iterator<Item = CalaAccountSetId> + '_ {
+        self.all_accounts.values().map(|(_, id)| *id)
+    }
+
+    fn active_account(
+        &self,
+        code: &AccountCode,
+    ) -> Result<&(AccountSpec, CalaAccountSetId), ChartOfAccountsError> {
+        if self.deactivated_accounts.contains(code) {
+            return Err(ChartOfAccountsError::NodeDeactivated(code.clone()));
+        }
+        self.all_accounts
+            .get(code)
+            .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))
+    }
+
+    fn is_descendant_of(&self, code: &AccountCode, ancestor: &AccountCode) -> bool {
+        let mut current = self
+            .all_accounts
+            .get(code)
+            .and_then(|(spec, _)| spec.parent.as_ref());
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self
+                .all_accounts
+                .get(parent)
+                .and_then(|(spec, _)| spec.parent.as_ref());
+        }
+        false
+    }
+
     pub fn trial_balance_account_ids_from_new_accounts(
         &self,
         new_account_set_ids: &[CalaAccountSetId],
@@ -141,9 +308,7 @@ impl Chart {
         &self,
         code: &AccountCode,
     ) -> Result<CalaAccountSetId, ChartOfAccountsError> {
-        self.account_spec(code)
-            .map(|(_, id)| *id)
-            .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))
+        self.active_account(code).map(|(_, id)| *id)
     }
 
     pub fn chart(&self) -> tree::ChartTree {
@@ -154,7 +319,9 @@ impl Chart {
 impl TryFromEvents<ChartEvent> for Chart {
     fn try_from_events(events: EntityEvents<ChartEvent>) -> Result<Self, EsEntityError> {
         let mut builder = ChartBuilder::default();
-        let mut all_accounts = HashMap::new();
+        let mut all_accounts: HashMap<AccountCode, (AccountSpec, CalaAccountSetId)> =
+            HashMap::new();
+        let mut deactivated_accounts = HashSet::new();
         for event in events.iter_all() {
             match event {
                 ChartEvent::Initialized {
@@ -175,9 +342,26 @@ impl TryFromEvents<ChartEvent> for Chart {
                 } => {
                     all_accounts.insert(spec.code.clone(), (spec.clone(), *ledger_account_set_id));
                 }
+                ChartEvent::NodeRenamed { code, name, .. } => {
+                    if let Some((spec, _)) = all_accounts.get_mut(code) {
+                        spec.name = name.clone();
+                    }
+                }
+                ChartEvent::NodeMoved { code, parent, .. } => {
+                    if let Some((spec, _)) = all_accounts.get_mut(code) {
+                        spec.parent = parent.clone();
+                    }
+                }
+                ChartEvent::NodeDeactivated { code, .. } => {
+                    deactivated_accounts.insert(code.clone());
+                }
             }
         }
-        builder.all_accounts(all_accounts).events(events).build()
+        builder
+            .all_accounts(all_accounts)
+            .deactivated_accounts(deactivated_accounts)
+            .events(events)
+            .build()
     }
 }
 
@@ -320,4 +504,134 @@ mod test {
         assert!(new_ids.contains(&new_account_set_id));
         assert_eq!(new_ids.len(), 1);
     }
+
+    #[test]
+    fn rename_node() {
+        let (mut chart, (_, _, level_3_id)) = default_chart();
+
+        let res = chart
+            .rename_node(
+                &code("1.1.1"),
+                "Cash and Equivalents".parse().unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(res.unwrap(), level_3_id);
+        let (spec, _) = chart.account_spec(&code("1.1.1")).unwrap();
+        assert_eq!(spec.name.to_string(), "Cash and Equivalents");
+
+        assert!(
+            chart
+                .rename_node(
+                    &code("1.1.1"),
+                    "Cash and Equivalents".parse().unwrap(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn move_node() {
+        let (mut chart, (level_1_id, level_2_id, level_3_id)) = default_chart();
+
+        let (id, old_parent, new_parent) = chart
+            .move_node(&code("1.1.1"), Some(code("1")), dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(id, level_3_id);
+        assert_eq!(old_parent, Some(level_2_id));
+        assert_eq!(new_parent, Some(level_1_id));
+
+        let (spec, _) = chart.account_spec(&code("1.1.1")).unwrap();
+        assert_eq!(spec.parent, Some(code("1")));
+        assert_eq!(chart.children::<CalaAccountSetId>(&code("1")).len(), 2);
+    }
+
+    #[test]
+    fn cannot_move_node_under_descendant() {
+        let (mut chart, _) = default_chart();
+
+        let res = chart.move_node(&code("1"), Some(code("1.1.1")), dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::InvalidNodeParent(_, _))
+        ));
+    }
+
+    #[test]
+    fn deactivate_node() {
+        let (mut chart, (_, level_2_id, level_3_id)) = default_chart();
+
+        let (id, parent) = chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(id, level_3_id);
+        assert_eq!(parent, Some(level_2_id));
+        assert!(!chart.is_active(&code("1.1.1")));
+        assert!(matches!(
+            chart.account_set_id_from_code(&code("1.1.1")),
+            Err(ChartOfAccountsError::NodeDeactivated(_))
+        ));
+        assert!(
+            chart
+                .deactivate_node(&code("1.1.1"), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn cannot_deactivate_node_with_active_children() {
+        let (mut chart, _) = default_chart();
+
+        let res = chart.deactivate_node(&code("1.1"), dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::NodeHasActiveChildren(_))
+        ));
+
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap();
+        assert!(
+            chart
+                .deactivate_node(&code("1.1"), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+    }
+
+    #[test]
+    fn edits_are_rehydrated_from_events() {
+        let (mut chart, _) = default_chart();
+        chart
+            .rename_node(
+                &code("1.1"),
+                "Liquid Assets".parse().unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        chart
+            .move_node(&code("1.1.1"), Some(code("1")), dummy_audit_info())
+            .unwrap();
+        chart
+            .deactivate_node(&code("1.1"), dummy_audit_info())
+            .unwrap();
+
+        let events = chart.events.iter_all().cloned().collect::<Vec<_>>();
+        let chart = chart_from(events);
+        let (spec, _) = chart.account_spec(&code("1.1")).unwrap();
+        assert_eq!(spec.name.to_string(), "Liquid Assets");
+        let (spec, _) = chart.account_spec(&code("1.1.1")).unwrap();
+        assert_eq!(spec.parent, Some(code("1")));
+        assert!(!chart.is_active(&code("1.1")));
+
+        let tree = chart.chart();
+        let assets = &tree.children[0];
+        assert_eq!(assets.children.len(), 1);
+        assert_eq!(assets.children[0].code, code("1.1.1"));
+    }
 }
@@ -12,14 +12,35 @@ pub enum ChartOfAccountsError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("ChartOfAccountsError - CodeNotFoundInChart: {0}")]
     CodeNotFoundInChart(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeDeactivated: {0}")]
+    NodeDeactivated(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeHasActiveChildren: {0}")]
+    NodeHasActiveChildren(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeHasNonZeroBalance: {0}")]
+    NodeHasNonZeroBalance(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeReferencedByIntegrationConfig: {0}")]
+    NodeReferencedByIntegrationConfig(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(crate::primitives::CurrencyCode),
+    #[error("ChartOfAccountsError - InvalidNodeParent: cannot move {0} under {1}")]
+    InvalidNodeParent(
+        crate::primitives::AccountCode,
+        crate::primitives::AccountCode,
+    ),
     #[error("ChartOfAccountsError - CsvParseError: {0}")]
     CsvParse(#[from] super::CsvParseError),
+    #[error("ChartOfAccountsError - CsvError: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("ChartOfAccountsError - JsonError: {0}")]
+    Json(#[from] serde_json::Error),
     #[error("ChartOfAccountsError - CalaLedgerError: {0}")]
     CalaLedger(#[from] cala_ledger::error::LedgerError),
     #[error("ChartOfAccountsError - CalaAccountError: {0}")]
     CalaAccount(#[from] cala_ledger::account::error::AccountError),
     #[error("ChartOfAccountsError - CalaAccountSetError: {0}")]
     CalaAccountSet(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("ChartOfAccountsError - CalaBalanceError: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
 }
 
 es_entity::from_es_entity_error!(ChartOfAccountsError);
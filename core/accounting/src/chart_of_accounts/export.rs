@@ -0,0 +1,247 @@
+use cala_ledger::DebitOrCredit;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use crate::primitives::{AccountCode, AccountName, AccountSpec};
+
+use super::{csv::CsvParseError, entity::Chart, tree::TreeNode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ChartExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartExportNode {
+    pub code: String,
+    pub parent: Option<String>,
+    pub name: String,
+    pub normal_balance_type: DebitOrCredit,
+    #[serde(default)]
+    pub integrations: Vec<String>,
+}
+
+/// Serializable snapshot of the active nodes of a chart, ordered so that
+/// every parent precedes its children. Importing it yields the same tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartExport {
+    pub name: String,
+    pub reference: String,
+    pub nodes: Vec<ChartExportNode>,
+}
+
+impl ChartExport {
+    /// `integrations` lists, per node, the module config fields that point at it.
+    pub fn new(chart: &Chart, integrations: &HashMap<AccountCode, Vec<String>>) -> Self {
+        let mut nodes = Vec::new();
+        let mut pending = chart.chart().children;
+        pending.reverse();
+        while let Some(node) = pending.pop() {
+            let TreeNode {
+                code,
+                name,
+                parent,
+                mut children,
+                ..
+            } = node;
+            let (spec, _) = chart
+                .account_spec(&code)
+                .expect("tree node missing from chart");
+            nodes.push(ChartExportNode {
+                code: code.to_string(),
+                parent: parent.map(|p| p.to_string()),
+                name: name.to_string(),
+                normal_balance_type: spec.normal_balance_type,
+                integrations: integrations.get(&code).cloned().unwrap_or_default(),
+            });
+            children.reverse();
+            pending.extend(children);
+        }
+
+        Self {
+            name: chart.name.clone(),
+            reference: chart.reference.clone(),
+            nodes,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(data: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(data)
+    }
+
+    /// Renders the nodes in the import CSV layout: one column per code section,
+    /// then name and normal balance type, followed by the explicit parent code
+    /// and the `;`-separated integrations.
+    pub fn to_csv(&self) -> Result<String, csv::Error> {
+        let sections = self
+            .nodes
+            .iter()
+            .map(|n| n.code.split('.').count())
+            .max()
+            .unwrap_or(1);
+
+        let mut wtr = csv::WriterBuilder::new()
+            .flexible(true)
+            .from_writer(Vec::new());
+        for node in self.nodes.iter() {
+            let mut record = node.code.split('.').map(str::to_string).collect::<Vec<_>>();
+            record.resize(sections, String::new());
+            record.push(node.name.clone());
+            record.push(
+                match node.normal_balance_type {
+                    DebitOrCredit::Debit => "Debit",
+                    DebitOrCredit::Credit => "Credit",
+                }
+                .to_string(),
+            );
+            record.push(node.parent.clone().unwrap_or_default());
+            record.push(node.integrations.join(";"));
+            wtr.write_record(&record)?;
+        }
+
+        let data = wtr
+            .into_inner()
+            .expect("Could not flush chart export writer");
+        Ok(String::from_utf8(data).expect("Chart export is not valid utf8"))
+    }
+
+    pub(super) fn account_specs(self) -> Result<Vec<AccountSpec>, CsvParseError> {
+        self.nodes
+            .into_iter()
+            .map(|node| {
+                let code = node
+                    .code
+                    .parse::<AccountCode>()
+                    .map_err(|_| CsvParseError)?;
+                let parent = node
+                    .parent
+                    .map(|p| p.parse::<AccountCode>())
+                    .transpose()
+                    .map_err(|_| CsvParseError)?;
+                let name = node
+                    .name
+                    .parse::<AccountName>()
+                    .map_err(|_| CsvParseError)?;
+                Ok(AccountSpec {
+                    parent,
+                    code,
+                    name,
+                    normal_balance_type: node.normal_balance_type,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use audit::{AuditEntryId, AuditInfo};
+    use es_entity::*;
+
+    use crate::{
+        chart_of_accounts::{CsvParser, NewChart},
+        primitives::ChartId,
+    };
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn chart_from_specs(specs: &[AccountSpec]) -> Chart {
+        let new_chart = NewChart::builder()
+            .id(ChartId::new())
+            .name("Test Chart".to_string())
+            .reference("ref-01".to_string())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let mut chart = Chart::try_from_events(new_chart.into_events()).unwrap();
+        for spec in specs {
+            let _ = chart.create_node(spec, dummy_audit_info());
+        }
+        chart
+    }
+
+    fn code(s: &str) -> AccountCode {
+        s.parse().unwrap()
+    }
+
+    fn source_chart() -> Chart {
+        let data = r#"
+        1,,,Assets,Debit,
+        11,,,Current Assets,,
+        ,01,,Cash,,
+        ,,0101,Operating Cash,,
+        2,,,Liabilities,Credit,
+        21,,,Deposits,,
+        "#;
+        let specs = CsvParser::new(data.to_string()).account_specs().unwrap();
+        let mut chart = chart_from_specs(&specs);
+        chart
+            .move_node(&code("11.01"), Some(code("2")), dummy_audit_info())
+            .unwrap();
+        chart
+            .rename_node(
+                &code("21"),
+                "Customer Deposits".parse().unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        chart
+    }
+
+    fn assert_same_tree(a: &[TreeNode], b: &[TreeNode]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert_eq!(a.code, b.code);
+            assert_eq!(a.parent, b.parent);
+            assert_eq!(a.name.to_string(), b.name.to_string());
+            assert_same_tree(&a.children, &b.children);
+        }
+    }
+
+    #[test]
+    fn csv_export_round_trips() {
+        let chart = source_chart();
+        let export = ChartExport::new(&chart, &HashMap::new());
+
+        let specs = CsvParser::new(export.to_csv().unwrap())
+            .account_specs()
+            .unwrap();
+        let imported = chart_from_specs(&specs);
+
+        assert_same_tree(&chart.chart().children, &imported.chart().children);
+        let (spec, _) = imported.account_spec(&code("11.01.0101")).unwrap();
+        assert_eq!(spec.normal_balance_type, DebitOrCredit::Debit);
+    }
+
+    #[test]
+    fn json_export_round_trips() {
+        let chart = source_chart();
+        let integrations = HashMap::from([(
+            code("21"),
+            vec!["deposit.chart_of_accounts_omnibus_parent_code".to_string()],
+        )]);
+        let export = ChartExport::new(&chart, &integrations);
+
+        let json = export.to_json().unwrap();
+        let parsed = ChartExport::from_json(&json).unwrap();
+        let deposits = parsed.nodes.iter().find(|n| n.code == "21").unwrap();
+        assert_eq!(deposits.integrations.len(), 1);
+
+        let imported = chart_from_specs(&parsed.account_specs().unwrap());
+        assert_same_tree(&chart.chart().children, &imported.chart().children);
+    }
+}
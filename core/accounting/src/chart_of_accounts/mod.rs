@@ -1,6 +1,7 @@
 mod csv;
 mod entity;
 pub mod error;
+mod export;
 mod repo;
 pub mod tree;
 
@@ -9,10 +10,16 @@ use tracing::instrument;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 
-use cala_ledger::{CalaLedger, account_set::NewAccountSet};
+use cala_ledger::{
+    CalaLedger, Currency,
+    account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
+};
+
+use std::collections::HashMap;
 
 use crate::primitives::{
-    CalaAccountSetId, CalaJournalId, ChartId, CoreAccountingAction, CoreAccountingObject,
+    AccountCode, AccountName, AccountSpec, CalaAccountSetId, CalaJournalId, ChartId,
+    CoreAccountingAction, CoreAccountingObject, CurrencyCode,
 };
 
 pub(super) use csv::{CsvParseError, CsvParser};
@@ -21,6 +28,7 @@ pub use entity::Chart;
 pub use entity::ChartEvent;
 pub(super) use entity::*;
 use error::*;
+pub use export::{ChartExport, ChartExportFormat, ChartExportNode};
 pub(super) use repo::*;
 
 pub struct ChartOfAccounts<Perms>
@@ -129,6 +137,77 @@ where
 
         let data = data.as_ref().to_string();
         let account_specs = CsvParser::new(data).account_specs()?;
+        self.create_nodes(&mut chart, account_specs, audit_info)
+            .await
+    }
+
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.import_from_json",
+        skip(self, data),
+        err
+    )]
+    pub async fn import_from_json(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        data: impl AsRef<str>,
+    ) -> Result<Option<Vec<CalaAccountSetId>>, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_IMPORT_ACCOUNTS,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let account_specs = ChartExport::from_json(data.as_ref())?.account_specs()?;
+        self.create_nodes(&mut chart, account_specs, audit_info)
+            .await
+    }
+
+    /// Exports the active nodes of the chart. `integrations` maps node codes to the
+    /// module config fields referencing them; they are informational and not re-applied
+    /// on import.
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.export",
+        skip(self, integrations),
+        err
+    )]
+    pub async fn export(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        format: ChartExportFormat,
+        integrations: &HashMap<AccountCode, Vec<String>>,
+    ) -> Result<String, ChartOfAccountsError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_EXPORT_ACCOUNTS,
+            )
+            .await?;
+        let chart = self.repo.find_by_id(id).await?;
+
+        let export = ChartExport::new(&chart, integrations);
+        let data = match format {
+            ChartExportFormat::Csv => export.to_csv()?,
+            ChartExportFormat::Json => export.to_json()?,
+        };
+        Ok(data)
+    }
+
+    async fn create_nodes(
+        &self,
+        chart: &mut Chart,
+        account_specs: Vec<AccountSpec>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Option<Vec<CalaAccountSetId>>, ChartOfAccountsError> {
+        let id = chart.id;
         let mut new_account_sets = Vec::new();
         let mut new_connections = Vec::new();
         for spec in account_specs {
@@ -156,7 +235,7 @@ where
         }
 
         let mut op = self.repo.begin_op().await?;
-        self.repo.update_in_op(&mut op, &mut chart).await?;
+        self.repo.update_in_op(&mut op, chart).await?;
 
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
@@ -179,6 +258,208 @@ where
         ))
     }
 
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.rename_node",
+        skip(self),
+        err
+    )]
+    pub async fn rename_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: &AccountCode,
+        name: AccountName,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_EDIT_ACCOUNTS,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let account_set_id = match chart.rename_node(code, name.clone(), audit_info)? {
+            es_entity::Idempotent::Executed(account_set_id) => account_set_id,
+            es_entity::Idempotent::Ignored => return Ok(chart),
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let mut account_set = self
+            .cala
+            .account_sets()
+            .find_all_in_op::<AccountSet>(&mut op, &[account_set_id])
+            .await?
+            .remove(&account_set_id)
+            .expect("Chart account set not found");
+        let mut update = AccountSetUpdate::default();
+        update.name(name.to_string()).description(name.to_string());
+        account_set.update(update);
+        self.cala
+            .account_sets()
+            .persist_in_op(&mut op, &mut account_set)
+            .await?;
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
+    #[instrument(name = "core_accounting.chart_of_accounts.move_node", skip(self), err)]
+    pub async fn move_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: &AccountCode,
+        parent: Option<AccountCode>,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_EDIT_ACCOUNTS,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let (account_set_id, old_parent, new_parent) =
+            match chart.move_node(code, parent, audit_info)? {
+                es_entity::Idempotent::Executed(res) => res,
+                es_entity::Idempotent::Ignored => return Ok(chart),
+            };
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        if let Some(old_parent) = old_parent {
+            self.cala
+                .account_sets()
+                .remove_member_in_op(&mut op, old_parent, account_set_id)
+                .await?;
+        }
+        if let Some(new_parent) = new_parent {
+            self.cala
+                .account_sets()
+                .add_member_in_op(&mut op, new_parent, account_set_id)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
+    /// Deactivates a node that carries no balance and is not referenced by any
+    /// module integration config. `referenced_codes` lists the codes used by configs
+    /// that attach the chart from above (balance sheet, profit and loss); configs that
+    /// attach module account sets below a node are detected from the node's members.
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.deactivate_node",
+        skip(self),
+        err
+    )]
+    pub async fn deactivate_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: &AccountCode,
+        referenced_codes: &[AccountCode],
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_EDIT_ACCOUNTS,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        if referenced_codes.contains(code) {
+            return Err(ChartOfAccountsError::NodeReferencedByIntegrationConfig(
+                code.clone(),
+            ));
+        }
+        if chart.is_active(code) {
+            let account_set_id = chart.account_set_id_from_code(code)?;
+            self.ensure_not_in_use(&chart, code, account_set_id).await?;
+        }
+
+        let (account_set_id, parent) = match chart.deactivate_node(code, audit_info)? {
+            es_entity::Idempotent::Executed(res) => res,
+            es_entity::Idempotent::Ignored => return Ok(chart),
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        if let Some(parent) = parent {
+            self.cala
+                .account_sets()
+                .remove_member_in_op(&mut op, parent, account_set_id)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
+    async fn ensure_not_in_use(
+        &self,
+        chart: &Chart,
+        code: &AccountCode,
+        account_set_id: CalaAccountSetId,
+    ) -> Result<(), ChartOfAccountsError> {
+        // Collateral is held in BTC which is not one of the deposit currencies.
+        let mut currencies = vec![Currency::BTC];
+        for currency in CurrencyCode::ALL {
+            currencies.push(
+                currency
+                    .code()
+                    .parse()
+                    .map_err(|_| ChartOfAccountsError::UnsupportedCurrency(currency))?,
+            );
+        }
+        let balance_ids = currencies
+            .into_iter()
+            .map(|currency| (self.journal_id, account_set_id.into(), currency))
+            .collect::<Vec<_>>();
+        let balances = self.cala.balances().find_all(&balance_ids).await?;
+        if balances.values().any(|balance| {
+            !balance.settled().is_zero()
+                || !balance.pending().is_zero()
+                || !balance.encumbrance().is_zero()
+        }) {
+            return Err(ChartOfAccountsError::NodeHasNonZeroBalance(code.clone()));
+        }
+
+        let chart_account_set_ids = chart
+            .all_account_set_ids()
+            .collect::<std::collections::HashSet<_>>();
+        let members = self
+            .cala
+            .account_sets()
+            .list_members_by_created_at(account_set_id, Default::default())
+            .await?;
+        if members.entities.into_iter().any(|member| {
+            matches!(member.id, AccountSetMemberId::AccountSet(id) if !chart_account_set_ids.contains(&id))
+        }) {
+            return Err(ChartOfAccountsError::NodeReferencedByIntegrationConfig(
+                code.clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
     #[instrument(name = "core_accounting.chart_of_accounts.find_by_id", skip(self), err)]
     pub async fn find_by_id(
         &self,
@@ -82,6 +82,19 @@ pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent
                 id: *id,
                 spec: spec.clone(),
             }),
+            ChartEvent::NodeRenamed { code, name, .. } => {
+                if let Some(node) = entity_nodes.iter_mut().find(|n| &n.spec.code == code) {
+                    node.spec.name = name.clone();
+                }
+            }
+            ChartEvent::NodeMoved { code, parent, .. } => {
+                if let Some(node) = entity_nodes.iter_mut().find(|n| &n.spec.code == code) {
+                    node.spec.parent = parent.clone();
+                }
+            }
+            ChartEvent::NodeDeactivated { code, .. } => {
+                entity_nodes.retain(|n| &n.spec.code != code);
+            }
         }
     }
 
@@ -90,15 +103,26 @@ pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent
         HashMap::new();
 
     entity_nodes.sort_by_key(|l| l.spec.code.clone());
-    for node in entity_nodes {
-        let node_rc = Rc::new(RefCell::new(TreeNodeWithRef {
-            id: node.id,
-            code: node.spec.code.clone(),
-            name: node.spec.name.clone(),
-            parent: node.spec.parent.clone(),
-            children: vec![],
-        }));
-        if let Some(parent) = node.spec.parent {
+    let node_rcs = entity_nodes
+        .into_iter()
+        .map(|node| {
+            let node_rc = Rc::new(RefCell::new(TreeNodeWithRef {
+                id: node.id,
+                code: node.spec.code.clone(),
+                name: node.spec.name.clone(),
+                parent: node.spec.parent.clone(),
+                children: vec![],
+            }));
+            tree_nodes_by_code
+                .entry(node.spec.code)
+                .or_insert_with(|| Rc::downgrade(&node_rc));
+            node_rc
+        })
+        .collect::<Vec<_>>();
+
+    for node_rc in node_rcs {
+        let parent = node_rc.borrow().parent.clone();
+        if let Some(parent) = parent {
             tree_nodes_by_code
                 .get_mut(&parent)
                 .expect("Parent missing in tree_nodes_by_code for code")
@@ -106,14 +130,10 @@ pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent
                 .expect("Parent node for code was dropped")
                 .borrow_mut()
                 .children
-                .push(Rc::clone(&node_rc));
+                .push(node_rc);
         } else {
-            chart_children.push(Rc::clone(&node_rc));
+            chart_children.push(node_rc);
         }
-
-        tree_nodes_by_code
-            .entry(node.spec.code)
-            .or_insert_with(|| Rc::downgrade(&node_rc));
     }
 
     ChartTree {
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ComparativeStatementError {
+    #[error("ComparativeStatementError - CalaAccountSet: {0}")]
+    CalaAccountSet(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("ComparativeStatementError - CalaBalance: {0}")]
+    CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("ComparativeStatementError - NoPeriodsRequested")]
+    NoPeriodsRequested,
+    #[error("ComparativeStatementError - InvalidPeriod: {0} is after {1}")]
+    InvalidPeriod(chrono::NaiveDate, chrono::NaiveDate),
+}
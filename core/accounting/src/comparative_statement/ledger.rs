@@ -0,0 +1,164 @@
+use cala_ledger::{
+    AccountSetId, BalanceId, CalaLedger, Currency, JournalId,
+    account_set::{AccountSet, AccountSetMemberId},
+};
+use rust_decimal::Decimal;
+
+use crate::primitives::CalaBalanceRange;
+
+use super::{ComparativeStatement, ComparativeStatementLine, StatementPeriod, error::*};
+
+/// Statement root, categories, the chart nodes attached to them and one
+/// level of their children.
+const MAX_LINE_DEPTH: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum StatementBalance {
+    /// Balance as of the end of each period (balance sheet).
+    Closing,
+    /// Movement within each period (profit and loss).
+    PeriodActivity,
+}
+
+#[derive(Clone)]
+pub(crate) struct ComparativeStatementLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl ComparativeStatementLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    pub async fn load(
+        &self,
+        statement_id: AccountSetId,
+        category_ids: &[AccountSetId],
+        periods: Vec<StatementPeriod>,
+        balance: StatementBalance,
+    ) -> Result<ComparativeStatement, ComparativeStatementError> {
+        if periods.is_empty() {
+            return Err(ComparativeStatementError::NoPeriodsRequested);
+        }
+
+        let mut top_level_ids = vec![statement_id];
+        top_level_ids.extend_from_slice(category_ids);
+        let mut top_level = self
+            .cala
+            .account_sets()
+            .find_all::<AccountSet>(&top_level_ids)
+            .await?;
+        let statement = top_level
+            .remove(&statement_id)
+            .expect("statement account set not found");
+
+        let mut line_sets = vec![(statement, 0)];
+        for category_id in category_ids {
+            let category = top_level
+                .remove(category_id)
+                .expect("category account set not found");
+            let mut pending = vec![(category, 1)];
+            while let Some((set, depth)) = pending.pop() {
+                if depth < MAX_LINE_DEPTH {
+                    let mut children = self.member_account_sets(set.id).await?;
+                    children.reverse();
+                    pending.extend(children.into_iter().map(|child| (child, depth + 1)));
+                }
+                line_sets.push((set, depth));
+            }
+        }
+
+        let balance_ids = line_sets
+            .iter()
+            .flat_map(|(set, _)| {
+                [
+                    (self.journal_id, set.id.into(), Currency::USD),
+                    (self.journal_id, set.id.into(), Currency::BTC),
+                ]
+            })
+            .collect::<Vec<BalanceId>>();
+        let balances_by_period = futures::future::try_join_all(periods.iter().map(|period| {
+            self.cala.balances().effective().find_all_in_range(
+                &balance_ids,
+                period.from,
+                Some(period.until),
+            )
+        }))
+        .await?;
+
+        let amounts = |set_id: AccountSetId, currency: Currency| {
+            balances_by_period
+                .iter()
+                .map(|balances| {
+                    balances
+                        .get(&(self.journal_id, set_id.into(), currency))
+                        .map(|range| amount_for(range, balance))
+                        .unwrap_or(Decimal::ZERO)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut lines = Vec::with_capacity(line_sets.len());
+        for (set, depth) in line_sets {
+            let id = set.id;
+            let values = set.into_values();
+            lines.push(ComparativeStatementLine::new(
+                id.into(),
+                values.name,
+                depth,
+                amounts(id, Currency::USD),
+                amounts(id, Currency::BTC),
+            ));
+        }
+
+        let name = lines[0].name.clone();
+        Ok(ComparativeStatement {
+            name,
+            periods,
+            lines,
+        })
+    }
+
+    async fn member_account_sets(
+        &self,
+        id: AccountSetId,
+    ) -> Result<Vec<AccountSet>, ComparativeStatementError> {
+        let member_ids = self
+            .cala
+            .account_sets()
+            .list_members_by_created_at(id, Default::default())
+            .await?
+            .entities
+            .into_iter()
+            .filter_map(|m| match m.id {
+                AccountSetMemberId::AccountSet(id) => Some(id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self
+            .cala
+            .account_sets()
+            .find_all::<AccountSet>(&member_ids)
+            .await?
+            .into_values()
+            .collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            let (a, b) = (a.values(), b.values());
+            (&a.external_id, &a.name).cmp(&(&b.external_id, &b.name))
+        });
+
+        Ok(members)
+    }
+}
+
+fn amount_for(range: &CalaBalanceRange, balance: StatementBalance) -> Decimal {
+    match balance {
+        StatementBalance::Closing => range.close.settled(),
+        StatementBalance::PeriodActivity => range.period.settled(),
+    }
+}
@@ -0,0 +1,230 @@
+pub mod error;
+mod ledger;
+
+use chrono::{Datelike, Months, NaiveDate};
+use csv::Writer;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::LedgerAccountId;
+
+use error::*;
+pub(crate) use ledger::{ComparativeStatementLedger, StatementBalance};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StatementPeriod {
+    pub from: NaiveDate,
+    pub until: NaiveDate,
+}
+
+impl StatementPeriod {
+    pub fn new(from: NaiveDate, until: NaiveDate) -> Result<Self, ComparativeStatementError> {
+        if from > until {
+            return Err(ComparativeStatementError::InvalidPeriod(from, until));
+        }
+        Ok(Self { from, until })
+    }
+
+    /// The calendar month containing `date`, the month before it and the same
+    /// month one year earlier, in that order.
+    pub fn month_comparison(date: NaiveDate) -> Vec<Self> {
+        let current = Self::month_of(date);
+        let prior = Self::month_of(current.from - Months::new(1));
+        let prior_year = Self::month_of(current.from - Months::new(12));
+        vec![current, prior, prior_year]
+    }
+
+    fn month_of(date: NaiveDate) -> Self {
+        let from = date.with_day(1).expect("first day of month");
+        let until = from + Months::new(1) - chrono::Days::new(1);
+        Self { from, until }
+    }
+
+    fn label(&self) -> String {
+        format!("{} to {}", self.from, self.until)
+    }
+}
+
+/// Amount for one period column. Variances compare the first (current)
+/// period against this one and are absent on the first column itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComparativeAmount {
+    pub amount: Decimal,
+    pub variance: Option<Decimal>,
+    pub variance_percentage: Option<Decimal>,
+}
+
+impl ComparativeAmount {
+    fn columns(amounts: Vec<Decimal>) -> Vec<Self> {
+        let current = amounts.first().copied().unwrap_or_default();
+        amounts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, amount)| {
+                if idx == 0 {
+                    return Self {
+                        amount,
+                        variance: None,
+                        variance_percentage: None,
+                    };
+                }
+                let variance = current - amount;
+                let variance_percentage = (!amount.is_zero()).then(|| {
+                    (variance / amount.abs() * Decimal::ONE_HUNDRED)
+                        .round_dp(2)
+                        .normalize()
+                });
+                Self {
+                    amount,
+                    variance: Some(variance),
+                    variance_percentage,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparativeStatementLine {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub depth: usize,
+    pub usd: Vec<ComparativeAmount>,
+    pub btc: Vec<ComparativeAmount>,
+}
+
+impl ComparativeStatementLine {
+    fn new(
+        id: LedgerAccountId,
+        name: String,
+        depth: usize,
+        usd_amounts: Vec<Decimal>,
+        btc_amounts: Vec<Decimal>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            depth,
+            usd: ComparativeAmount::columns(usd_amounts),
+            btc: ComparativeAmount::columns(btc_amounts),
+        }
+    }
+}
+
+/// A statement with one column per requested period. Lines are ordered
+/// depth-first starting at the statement root.
+#[derive(Debug, Clone)]
+pub struct ComparativeStatement {
+    pub name: String,
+    pub periods: Vec<StatementPeriod>,
+    pub lines: Vec<ComparativeStatementLine>,
+}
+
+impl ComparativeStatement {
+    pub fn to_csv(&self) -> Result<Vec<u8>, csv::Error> {
+        let mut header = vec![
+            "Account".to_string(),
+            "Level".to_string(),
+            "Currency".to_string(),
+        ];
+        for (idx, period) in self.periods.iter().enumerate() {
+            header.push(period.label());
+            if idx > 0 {
+                header.push(format!("Variance vs {}", period.label()));
+                header.push(format!("Variance % vs {}", period.label()));
+            }
+        }
+
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(&header)?;
+        for line in self.lines.iter() {
+            for (currency, amounts) in [("USD", &line.usd), ("BTC", &line.btc)] {
+                let mut record = vec![line.name.clone(), line.depth.to_string(), currency.into()];
+                for amount in amounts {
+                    record.push(amount.amount.to_string());
+                    if let Some(variance) = amount.variance {
+                        record.push(variance.to_string());
+                        record.push(
+                            amount
+                                .variance_percentage
+                                .map(|p| p.to_string())
+                                .unwrap_or_default(),
+                        );
+                    }
+                }
+                wtr.write_record(&record)?;
+            }
+        }
+
+        Ok(wtr.into_inner().expect("Could not flush statement writer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn month_comparison_periods() {
+        let periods = StatementPeriod::month_comparison(date("2024-03-15"));
+        assert_eq!(
+            periods,
+            vec![
+                StatementPeriod::new(date("2024-03-01"), date("2024-03-31")).unwrap(),
+                StatementPeriod::new(date("2024-02-01"), date("2024-02-29")).unwrap(),
+                StatementPeriod::new(date("2023-03-01"), date("2023-03-31")).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn period_must_not_end_before_it_starts() {
+        assert!(matches!(
+            StatementPeriod::new(date("2024-03-02"), date("2024-03-01")),
+            Err(ComparativeStatementError::InvalidPeriod(_, _))
+        ));
+    }
+
+    #[test]
+    fn variances_are_relative_to_first_period() {
+        let columns = ComparativeAmount::columns(vec![dec!(150), dec!(100), dec!(-200), dec!(0)]);
+
+        assert_eq!(columns[0].variance, None);
+        assert_eq!(columns[1].variance, Some(dec!(50)));
+        assert_eq!(columns[1].variance_percentage, Some(dec!(50)));
+        assert_eq!(columns[2].variance, Some(dec!(350)));
+        assert_eq!(columns[2].variance_percentage, Some(dec!(175)));
+        assert_eq!(columns[3].variance, Some(dec!(150)));
+        assert_eq!(columns[3].variance_percentage, None);
+    }
+
+    #[test]
+    fn csv_has_variance_columns_for_comparison_periods() {
+        let statement = ComparativeStatement {
+            name: "Balance Sheet".to_string(),
+            periods: StatementPeriod::month_comparison(date("2024-03-15")),
+            lines: vec![ComparativeStatementLine::new(
+                LedgerAccountId::new(),
+                "Assets".to_string(),
+                1,
+                vec![dec!(30), dec!(20), dec!(0)],
+                vec![dec!(0), dec!(0), dec!(0)],
+            )],
+        };
+
+        let csv = String::from_utf8(statement.to_csv().unwrap()).unwrap();
+        let mut rows = csv.lines();
+        assert_eq!(rows.next().unwrap().split(',').count(), 3 + 1 + 2 * 3);
+        assert_eq!(rows.next().unwrap(), "Assets,1,USD,30,20,10,50,0,30,");
+        assert_eq!(rows.next().unwrap(), "Assets,1,BTC,0,0,0,,0,0,");
+    }
+}
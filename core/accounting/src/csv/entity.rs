@@ -8,7 +8,8 @@ use audit::AuditInfo;
 use cloud_storage::LocationInStorage;
 use es_entity::*;
 
-use crate::csv::primitives::{AccountingCsvStatus, AccountingCsvType};
+use crate::comparative_statement::StatementPeriod;
+use crate::csv::primitives::{AccountingCsvFormat, AccountingCsvStatus, AccountingCsvType};
 use crate::primitives::{AccountingCsvId, LedgerAccountId};
 
 use super::error::AccountingCsvError;
@@ -22,6 +23,12 @@ pub enum AccountingCsvEvent {
         id: AccountingCsvId,
         csv_type: AccountingCsvType,
         ledger_account_id: Option<LedgerAccountId>,
+        #[serde(default)]
+        statement_reference: Option<String>,
+        #[serde(default)]
+        periods: Vec<StatementPeriod>,
+        #[serde(default)]
+        format: AccountingCsvFormat,
         path_in_bucket: String,
         audit_info: AuditInfo,
     },
@@ -50,6 +57,12 @@ pub struct AccountingCsv {
     pub csv_type: AccountingCsvType,
     #[builder(setter(strip_option), default)]
     pub ledger_account_id: Option<LedgerAccountId>,
+    #[builder(setter(strip_option), default)]
+    pub statement_reference: Option<String>,
+    #[builder(default)]
+    pub periods: Vec<StatementPeriod>,
+    #[builder(default)]
+    pub format: AccountingCsvFormat,
     pub(super) path_in_storage: String,
     events: EntityEvents<AccountingCsvEvent>,
 }
@@ -157,13 +170,24 @@ impl TryFromEvents<AccountingCsvEvent> for AccountingCsv {
                 id,
                 csv_type,
                 ledger_account_id,
+                statement_reference,
+                periods,
+                format,
                 ..
             } = event
             {
-                builder = builder.id(*id).csv_type(*csv_type);
+                builder = builder
+                    .id(*id)
+                    .csv_type(*csv_type)
+                    .periods(periods.clone())
+                    .format(*format)
+                    .path_in_storage(path_in_storage(*id, *format));
                 if let Some(account_id) = ledger_account_id {
                     builder = builder.ledger_account_id(*account_id);
                 }
+                if let Some(reference) = statement_reference {
+                    builder = builder.statement_reference(reference.clone());
+                }
             }
         }
         builder.events(events).build()
@@ -178,6 +202,12 @@ pub struct NewAccountingCsv {
     pub(super) csv_type: AccountingCsvType,
     #[builder(setter(strip_option), default)]
     pub(super) ledger_account_id: Option<LedgerAccountId>,
+    #[builder(setter(into, strip_option), default)]
+    pub(super) statement_reference: Option<String>,
+    #[builder(default)]
+    pub(super) periods: Vec<StatementPeriod>,
+    #[builder(default)]
+    pub(super) format: AccountingCsvFormat,
     #[builder(setter(into))]
     pub(super) audit_info: AuditInfo,
 }
@@ -195,9 +225,16 @@ impl IntoEvents<AccountingCsvEvent> for NewAccountingCsv {
                 id: self.id,
                 csv_type: self.csv_type,
                 ledger_account_id: self.ledger_account_id,
-                path_in_bucket: "".to_string(),
+                statement_reference: self.statement_reference,
+                periods: self.periods,
+                format: self.format,
+                path_in_bucket: path_in_storage(self.id, self.format),
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+fn path_in_storage(id: AccountingCsvId, format: AccountingCsvFormat) -> String {
+    format!("accounting_csvs/{}.{}", id, format.extension())
+}
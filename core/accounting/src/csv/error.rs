@@ -12,12 +12,30 @@ pub enum AccountingCsvError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("AccountingCsvError - LedgerAccountError: {0}")]
     LedgerAccountError(#[from] crate::ledger_account::error::LedgerAccountError),
+    #[error("AccountingCsvError - BalanceSheetError: {0}")]
+    BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("AccountingCsvError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("AccountingCsvError - ComparativeStatementError: {0}")]
+    ComparativeStatementError(
+        #[from] crate::comparative_statement::error::ComparativeStatementError,
+    ),
+    #[error("AccountingCsvError - JournalError: {0}")]
+    JournalError(#[from] crate::journal_error::JournalError),
+    #[error("AccountingCsvError - TrialBalanceError: {0}")]
+    TrialBalanceError(#[from] crate::trial_balance::error::TrialBalanceError),
+    #[error("AccountingCsvError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("AccountingCsvError - StorageError: {0}")]
     StorageError(#[from] cloud_storage::error::StorageError),
     #[error("AccountingCsvError - JobError: {0}")]
     JobError(#[from] job::error::JobError),
+    #[error("AccountingCsvError - ParseCurrencyError: {0}")]
+    ParseCurrencyError(#[from] cala_ledger::ParseCurrencyError),
     #[error("AccountingCsvError - CsvError: {0}")]
     CsvError(String),
+    #[error("AccountingCsvError - XlsxError: {0}")]
+    XlsxError(#[from] rust_xlsxwriter::XlsxError),
     #[error("AccountingCsvError - UnsupportedCsvType")]
     UnsupportedCsvType,
     #[error("AccountingCsvError - CsvNotReady")]
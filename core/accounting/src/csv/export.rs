@@ -0,0 +1,128 @@
+use csv::Writer;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use super::{error::AccountingCsvError, primitives::AccountingCsvFormat};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ExportCell {
+    Text(String),
+    Amount(Decimal),
+}
+
+impl From<String> for ExportCell {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ExportCell {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Decimal> for ExportCell {
+    fn from(amount: Decimal) -> Self {
+        Self::Amount(amount)
+    }
+}
+
+/// A single sheet of tabular data that can be rendered in any
+/// `AccountingCsvFormat`. Amounts are kept numeric so spreadsheets can sum
+/// them.
+pub(super) struct ExportTable {
+    sheet_name: &'static str,
+    header: &'static [&'static str],
+    rows: Vec<Vec<ExportCell>>,
+}
+
+impl ExportTable {
+    pub fn new(sheet_name: &'static str, header: &'static [&'static str]) -> Self {
+        Self {
+            sheet_name,
+            header,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<ExportCell>) {
+        self.rows.push(row);
+    }
+
+    pub fn render(&self, format: AccountingCsvFormat) -> Result<Vec<u8>, AccountingCsvError> {
+        match format {
+            AccountingCsvFormat::Csv => self
+                .to_csv()
+                .map_err(|e| AccountingCsvError::CsvError(e.to_string())),
+            AccountingCsvFormat::Xlsx => Ok(self.to_xlsx()?),
+        }
+    }
+
+    fn to_csv(&self) -> Result<Vec<u8>, csv::Error> {
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(self.header)?;
+        for row in self.rows.iter() {
+            wtr.write_record(row.iter().map(|cell| match cell {
+                ExportCell::Text(text) => text.clone(),
+                ExportCell::Amount(amount) => amount.to_string(),
+            }))?;
+        }
+        Ok(wtr.into_inner().expect("Could not flush export writer"))
+    }
+
+    fn to_xlsx(&self) -> Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(self.sheet_name)?;
+
+        let bold = Format::new().set_bold();
+        for (col, title) in self.header.iter().enumerate() {
+            worksheet.write_string_with_format(0, col as u16, *title, &bold)?;
+        }
+        for (idx, row) in self.rows.iter().enumerate() {
+            let row_num = idx as u32 + 1;
+            for (col, cell) in row.iter().enumerate() {
+                match cell {
+                    ExportCell::Text(text) => {
+                        worksheet.write_string(row_num, col as u16, text)?;
+                    }
+                    ExportCell::Amount(amount) => {
+                        worksheet.write_number(
+                            row_num,
+                            col as u16,
+                            amount.to_f64().unwrap_or_default(),
+                        )?;
+                    }
+                }
+            }
+        }
+
+        workbook.save_to_buffer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn table() -> ExportTable {
+        let mut table = ExportTable::new("Trial Balance", &["Account Code", "Debits"]);
+        table.push(vec!["11.01".into(), dec!(12.50).into()]);
+        table
+    }
+
+    #[test]
+    fn csv_keeps_codes_as_text() {
+        let csv = String::from_utf8(table().render(AccountingCsvFormat::Csv).unwrap()).unwrap();
+        assert_eq!(csv, "Account Code,Debits\n11.01,12.50\n");
+    }
+
+    #[test]
+    fn xlsx_renders_a_zip_archive() {
+        let xlsx = table().render(AccountingCsvFormat::Xlsx).unwrap();
+        assert_eq!(&xlsx[..2], b"PK");
+    }
+}
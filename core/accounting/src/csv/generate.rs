@@ -1,16 +1,46 @@
 use csv::Writer;
 use rust_decimal::Decimal;
+use std::collections::HashSet;
 
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::DebitOrCredit;
 
 use crate::{
-    CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
-    primitives::LedgerAccountId,
+    CoreAccountingAction, CoreAccountingObject,
+    balance_sheet::BalanceSheets,
+    cash_flow::CashFlowStatements,
+    comparative_statement::{ComparativeStatement, StatementPeriod},
+    journal::Journal,
+    ledger_account::LedgerAccounts,
+    primitives::{CalaCurrency, CurrencyCode, LedgerAccountId},
+    profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
 };
 
-use super::error::AccountingCsvError;
+use super::{error::AccountingCsvError, export::ExportTable, primitives::AccountingCsvFormat};
+
+const GENERAL_LEDGER_HEADER: &[&str] = &[
+    "Transaction ID",
+    "Effective Date",
+    "Account Code",
+    "Account Name",
+    "Currency",
+    "Debit",
+    "Credit",
+    "Description",
+    "Entry Type",
+];
+
+const TRIAL_BALANCE_HEADER: &[&str] = &[
+    "Account Code",
+    "Account Name",
+    "Currency",
+    "Opening Balance",
+    "Debits",
+    "Credits",
+    "Closing Balance",
+];
 
 pub struct GenerateCsv<Perms>
 where
@@ -19,6 +49,11 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
     ledger_accounts: LedgerAccounts<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    cash_flows: CashFlowStatements<Perms>,
+    journal: Journal<Perms>,
+    trial_balances: TrialBalances<Perms>,
 }
 
 impl<Perms> GenerateCsv<Perms>
@@ -27,9 +62,21 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
-    pub fn new(ledger_accounts: &LedgerAccounts<Perms>) -> Self {
+    pub fn new(
+        ledger_accounts: &LedgerAccounts<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        cash_flows: &CashFlowStatements<Perms>,
+        journal: &Journal<Perms>,
+        trial_balances: &TrialBalances<Perms>,
+    ) -> Self {
         Self {
             ledger_accounts: ledger_accounts.clone(),
+            balance_sheets: balance_sheets.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            cash_flows: cash_flows.clone(),
+            journal: journal.clone(),
+            trial_balances: trial_balances.clone(),
         }
     }
 
@@ -79,4 +126,163 @@ where
 
         Ok(csv_data)
     }
+
+    pub async fn generate_balance_sheet_csv(
+        &self,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<Vec<u8>, AccountingCsvError> {
+        let statement = self
+            .balance_sheets
+            .load_comparative_balance_sheet(reference, periods)
+            .await?;
+        Self::statement_csv(statement)
+    }
+
+    pub async fn generate_pl_statement_csv(
+        &self,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<Vec<u8>, AccountingCsvError> {
+        let statement = self
+            .profit_and_loss
+            .load_comparative_pl_statement(reference, periods)
+            .await?;
+        Self::statement_csv(statement)
+    }
+
+    pub async fn generate_cash_flow_csv(
+        &self,
+        reference: String,
+        period: StatementPeriod,
+    ) -> Result<Vec<u8>, AccountingCsvError> {
+        let statement = self
+            .cash_flows
+            .load_cash_flow_statement(reference, period.from, period.until)
+            .await?;
+        statement
+            .to_csv()
+            .map_err(|e| AccountingCsvError::CsvError(e.to_string()))
+    }
+
+    pub async fn generate_general_ledger(
+        &self,
+        period: StatementPeriod,
+        format: AccountingCsvFormat,
+    ) -> Result<Vec<u8>, AccountingCsvError> {
+        let entries = self
+            .journal
+            .entries_in_range(period.from, period.until)
+            .await?;
+
+        let account_ids = entries
+            .iter()
+            .map(|entry| entry.ledger_account_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let accounts = self
+            .ledger_accounts
+            .find_names_and_codes(&account_ids)
+            .await?;
+
+        let mut table = ExportTable::new("General Ledger", GENERAL_LEDGER_HEADER);
+        for entry in entries {
+            let (name, code) = accounts
+                .get(&entry.ledger_account_id)
+                .map(|(name, code)| {
+                    (
+                        name.clone(),
+                        code.as_ref().map(|c| c.to_string()).unwrap_or_default(),
+                    )
+                })
+                .unwrap_or_default();
+            let (debit, credit) = match entry.direction {
+                DebitOrCredit::Debit => (entry.units, Decimal::ZERO),
+                DebitOrCredit::Credit => (Decimal::ZERO, entry.units),
+            };
+            table.push(vec![
+                entry.ledger_transaction_id.to_string().into(),
+                entry.effective.to_string().into(),
+                code.into(),
+                name.into(),
+                entry.currency.to_string().into(),
+                debit.into(),
+                credit.into(),
+                entry.description.unwrap_or_default().into(),
+                entry.entry_type.into(),
+            ]);
+        }
+
+        table.render(format)
+    }
+
+    pub async fn generate_trial_balance(
+        &self,
+        reference: String,
+        period: StatementPeriod,
+        format: AccountingCsvFormat,
+    ) -> Result<Vec<u8>, AccountingCsvError> {
+        let trial_balance = self
+            .trial_balances
+            .load_trial_balance(reference, period.from, period.until)
+            .await?;
+        let mut accounts = self
+            .ledger_accounts
+            .complete_children(trial_balance.id, period.from, period.until)
+            .await?;
+        accounts.sort_by_cached_key(|account| account.code.as_ref().map(|c| c.to_string()));
+
+        let currencies = ledger_currencies()?;
+        let account_ids = accounts
+            .iter()
+            .map(|account| account.id)
+            .collect::<Vec<_>>();
+        let mut ranges = self
+            .ledger_accounts
+            .balance_ranges_in_currencies(&account_ids, &currencies, period.from, period.until)
+            .await?;
+
+        let mut table = ExportTable::new("Trial Balance", TRIAL_BALANCE_HEADER);
+        for account in accounts {
+            let code = account
+                .code
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or_default();
+            for currency in &currencies {
+                let Some(range) = ranges.remove(&(account.id, *currency)) else {
+                    continue;
+                };
+                let activity = &range.period.details.settled;
+                table.push(vec![
+                    code.clone().into(),
+                    account.name.clone().into(),
+                    currency.to_string().into(),
+                    range.open.settled().into(),
+                    activity.dr_balance.into(),
+                    activity.cr_balance.into(),
+                    range.close.settled().into(),
+                ]);
+            }
+        }
+
+        table.render(format)
+    }
+
+    fn statement_csv(statement: ComparativeStatement) -> Result<Vec<u8>, AccountingCsvError> {
+        statement
+            .to_csv()
+            .map_err(|e| AccountingCsvError::CsvError(e.to_string()))
+    }
+}
+
+/// Every currency the ledger books: the deposit currencies and BTC collateral.
+fn ledger_currencies() -> Result<Vec<CalaCurrency>, AccountingCsvError> {
+    let mut currencies = CurrencyCode::ALL
+        .iter()
+        .map(|currency| currency.code().parse())
+        .collect::<Result<Vec<CalaCurrency>, _>>()?;
+    currencies.push(CalaCurrency::BTC);
+    Ok(currencies)
 }
@@ -7,7 +7,11 @@ use cloud_storage::Storage;
 use job::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{ledger_account::LedgerAccounts, primitives::AccountingCsvId};
+use crate::{
+    balance_sheet::BalanceSheets, cash_flow::CashFlowStatements, journal::Journal,
+    ledger_account::LedgerAccounts, primitives::AccountingCsvId,
+    profit_and_loss::ProfitAndLossStatements, trial_balance::TrialBalances,
+};
 
 use super::{
     CoreAccountingAction, CoreAccountingObject, error::AccountingCsvError, generate::GenerateCsv,
@@ -38,6 +42,11 @@ where
     repo: AccountingCsvRepo,
     storage: Storage,
     ledger_accounts: LedgerAccounts<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    cash_flows: CashFlowStatements<Perms>,
+    journal: Journal<Perms>,
+    trial_balances: TrialBalances<Perms>,
     audit: Perms::Audit,
 }
 
@@ -51,12 +60,22 @@ where
         repo: &AccountingCsvRepo,
         storage: &Storage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        cash_flows: &CashFlowStatements<Perms>,
+        journal: &Journal<Perms>,
+        trial_balances: &TrialBalances<Perms>,
         audit: &Perms::Audit,
     ) -> Self {
         Self {
             repo: repo.clone(),
             storage: storage.clone(),
             ledger_accounts: ledger_accounts.clone(),
+            balance_sheets: balance_sheets.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            cash_flows: cash_flows.clone(),
+            journal: journal.clone(),
+            trial_balances: trial_balances.clone(),
             audit: audit.clone(),
         }
     }
@@ -82,7 +101,14 @@ where
             config: job.config()?,
             repo: self.repo.clone(),
             storage: self.storage.clone(),
-            generator: GenerateCsv::new(&self.ledger_accounts),
+            generator: GenerateCsv::new(
+                &self.ledger_accounts,
+                &self.balance_sheets,
+                &self.profit_and_loss,
+                &self.cash_flows,
+                &self.journal,
+                &self.trial_balances,
+            ),
             audit: self.audit.clone(),
         }))
     }
@@ -134,16 +160,65 @@ where
                     .generate_ledger_account_csv(ledger_account_id)
                     .await
             }
-            AccountingCsvType::ProfitAndLoss => Err(AccountingCsvError::UnsupportedCsvType),
-            AccountingCsvType::BalanceSheet => Err(AccountingCsvError::UnsupportedCsvType),
+            AccountingCsvType::ProfitAndLoss => {
+                let reference = export.statement_reference.clone().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("statement_reference".to_string())
+                })?;
+
+                self.generator
+                    .generate_pl_statement_csv(reference, export.periods.clone())
+                    .await
+            }
+            AccountingCsvType::BalanceSheet => {
+                let reference = export.statement_reference.clone().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("statement_reference".to_string())
+                })?;
+
+                self.generator
+                    .generate_balance_sheet_csv(reference, export.periods.clone())
+                    .await
+            }
+            AccountingCsvType::CashFlow => {
+                let reference = export.statement_reference.clone().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("statement_reference".to_string())
+                })?;
+                let period = export.periods.first().copied().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("periods".to_string())
+                })?;
+
+                self.generator
+                    .generate_cash_flow_csv(reference, period)
+                    .await
+            }
+            AccountingCsvType::GeneralLedger => {
+                let period = export.periods.first().copied().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("periods".to_string())
+                })?;
+
+                self.generator
+                    .generate_general_ledger(period, export.format)
+                    .await
+            }
+            AccountingCsvType::TrialBalance => {
+                let reference = export.statement_reference.clone().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("statement_reference".to_string())
+                })?;
+                let period = export.periods.first().copied().ok_or_else(|| {
+                    AccountingCsvError::MissingRequiredField("periods".to_string())
+                })?;
+
+                self.generator
+                    .generate_trial_balance(reference, period, export.format)
+                    .await
+            }
         };
 
         match csv_result {
             Ok(csv_data) => {
-                let path_in_bucket = format!("accounting_csvs/{}.csv", export.id);
+                let path_in_bucket = export.path_in_bucket().to_string();
                 match self
                     .storage
-                    .upload(csv_data, &path_in_bucket, "text/csv")
+                    .upload(csv_data, &path_in_bucket, export.format.content_type())
                     .await
                 {
                     Ok(_) => {
@@ -1,5 +1,6 @@
 mod entity;
 pub mod error;
+mod export;
 mod generate;
 mod job;
 mod primitives;
@@ -17,8 +18,14 @@ use es_entity::ListDirection;
 
 use super::{
     CoreAccountingAction, CoreAccountingObject,
+    balance_sheet::BalanceSheets,
+    cash_flow::CashFlowStatements,
+    comparative_statement::{StatementPeriod, error::ComparativeStatementError},
+    journal::Journal,
     ledger_account::LedgerAccounts,
     primitives::{AccountingCsvId, LedgerAccountId},
+    profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
 };
 
 #[cfg(feature = "json-schema")]
@@ -53,6 +60,11 @@ where
         jobs: &Jobs,
         storage: &Storage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        cash_flows: &CashFlowStatements<Perms>,
+        journal: &Journal<Perms>,
+        trial_balances: &TrialBalances<Perms>,
     ) -> Self {
         let repo = AccountingCsvRepo::new(pool);
 
@@ -60,6 +72,11 @@ where
             &repo,
             storage,
             ledger_accounts,
+            balance_sheets,
+            profit_and_loss,
+            cash_flows,
+            journal,
+            trial_balances,
             authz.audit(),
         ));
 
@@ -114,6 +131,136 @@ where
         Ok(csv)
     }
 
+    /// Queues a comparative statement export with one column per period.
+    /// `csv_type` selects whether `reference` names a balance sheet or a
+    /// profit and loss statement.
+    #[instrument(name = "core_accounting.csv.create_statement", skip(self), err)]
+    pub async fn create_comparative_statement_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        csv_type: AccountingCsvType,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        if !matches!(
+            csv_type,
+            AccountingCsvType::BalanceSheet | AccountingCsvType::ProfitAndLoss
+        ) {
+            return Err(AccountingCsvError::UnsupportedCsvType);
+        }
+        if periods.is_empty() {
+            return Err(ComparativeStatementError::NoPeriodsRequested.into());
+        }
+
+        self.create_statement_csv(
+            sub,
+            csv_type,
+            Some(reference),
+            periods,
+            AccountingCsvFormat::Csv,
+        )
+        .await
+    }
+
+    #[instrument(name = "core_accounting.csv.create_cash_flow", skip(self), err)]
+    pub async fn create_cash_flow_statement_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        period: StatementPeriod,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_statement_csv(
+            sub,
+            AccountingCsvType::CashFlow,
+            Some(reference),
+            vec![period],
+            AccountingCsvFormat::Csv,
+        )
+        .await
+    }
+
+    /// Queues an extract of every journal entry recorded within `period`.
+    #[instrument(name = "core_accounting.csv.create_general_ledger", skip(self), err)]
+    pub async fn create_general_ledger_export(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        period: StatementPeriod,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_statement_csv(
+            sub,
+            AccountingCsvType::GeneralLedger,
+            None,
+            vec![period],
+            format,
+        )
+        .await
+    }
+
+    #[instrument(name = "core_accounting.csv.create_trial_balance", skip(self), err)]
+    pub async fn create_trial_balance_export(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        period: StatementPeriod,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_statement_csv(
+            sub,
+            AccountingCsvType::TrialBalance,
+            Some(reference),
+            vec![period],
+            format,
+        )
+        .await
+    }
+
+    async fn create_statement_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        csv_type: AccountingCsvType,
+        reference: Option<String>,
+        periods: Vec<StatementPeriod>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_csvs(),
+                CoreAccountingAction::ACCOUNTING_CSV_CREATE,
+            )
+            .await?;
+
+        let mut builder = NewAccountingCsv::builder();
+        builder
+            .id(AccountingCsvId::new())
+            .csv_type(csv_type)
+            .periods(periods)
+            .format(format)
+            .audit_info(audit_info);
+        if let Some(reference) = reference {
+            builder.statement_reference(reference);
+        }
+        let new_csv = builder.build().expect("Could not build new Accounting CSV");
+
+        let mut db = self.repo.begin_op().await?;
+        let csv = self.repo.create_in_op(&mut db, new_csv).await?;
+        self.jobs
+            .create_and_spawn_in_op::<GenerateAccountingCsvConfig<Perms>>(
+                &mut db,
+                csv.id,
+                GenerateAccountingCsvConfig {
+                    accounting_csv_id: csv.id,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+
+        db.commit().await?;
+        Ok(csv)
+    }
+
     #[instrument(name = "core_accounting.csv.generate_download_link", skip(self), err)]
     pub async fn generate_download_link(
         &self,
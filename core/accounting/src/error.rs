@@ -10,6 +10,10 @@ pub enum CoreAccountingError {
     LedgerAccountError(#[from] super::ledger_account::error::LedgerAccountError),
     #[error("CoreAccountingError - ManualTransaction: {0}")]
     ManualTransactionError(#[from] super::manual_transaction::error::ManualTransactionError),
+    #[error("CoreAccountingError - FixedAsset: {0}")]
+    FixedAsset(#[from] super::fixed_asset::error::FixedAssetError),
+    #[error("CoreAccountingError - RecurringEntryTemplate: {0}")]
+    RecurringEntryTemplate(#[from] super::recurring_entry::error::RecurringEntryTemplateError),
     #[error("CoreAccountingError - LedgerTransaction: {0}")]
     LedgerTransactionError(#[from] super::ledger_transaction::error::LedgerTransactionError),
     #[error("CoreAccountingError - AccountCodeParseError: {0}")]
@@ -18,4 +22,18 @@ pub enum CoreAccountingError {
     AccountingCsvError(#[from] super::csv::error::AccountingCsvError),
     #[error("CoreAccountingError - TrialBalanceError: {0}")]
     TrialBalance(#[from] super::trial_balance::error::TrialBalanceError),
+    #[error("CoreAccountingError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatement(#[from] super::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("CoreAccountingError - BalanceSheetError: {0}")]
+    BalanceSheet(#[from] super::balance_sheet::error::BalanceSheetError),
+    #[error("CoreAccountingError - CashFlowStatementError: {0}")]
+    CashFlowStatement(#[from] super::cash_flow::error::CashFlowStatementError),
+    #[error("CoreAccountingError - AccountingPeriodError: {0}")]
+    AccountingPeriod(#[from] super::accounting_period::error::AccountingPeriodError),
+    #[error("CoreAccountingError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CoreAccountingError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("CoreAccountingError - AccountingPeriodNotFound: {0}")]
+    AccountingPeriodNotFound(super::AccountingPeriodId),
 }
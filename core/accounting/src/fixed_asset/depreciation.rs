@@ -0,0 +1,111 @@
+use chrono::{Datelike, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::UsdCents;
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum DepreciationMethod {
+    /// The depreciable amount is spread evenly over the useful life.
+    StraightLine,
+    /// Double-declining balance: each month charges twice the straight-line
+    /// rate on the remaining book value.
+    DecliningBalance,
+}
+
+impl DepreciationMethod {
+    /// Charge for the `month`-th month of the useful life (0-based) given the
+    /// book value at the start of that month. The last month takes whatever
+    /// is left above the salvage value, so the asset always ends up exactly
+    /// at salvage value.
+    pub fn monthly_charge(
+        &self,
+        cost: UsdCents,
+        salvage_value: UsdCents,
+        useful_life_months: u32,
+        month: u32,
+        book_value: UsdCents,
+    ) -> UsdCents {
+        if month >= useful_life_months || book_value <= salvage_value {
+            return UsdCents::ZERO;
+        }
+        let remaining = book_value - salvage_value;
+        if month + 1 == useful_life_months {
+            return remaining;
+        }
+
+        let life = u64::from(useful_life_months);
+        let charge = match self {
+            Self::StraightLine => UsdCents::from((cost - salvage_value).into_inner() / life),
+            Self::DecliningBalance => UsdCents::from(book_value.into_inner() * 2 / life),
+        };
+        std::cmp::min(charge, remaining)
+    }
+}
+
+/// Last day of the `month`-th month of the useful life, the effective date
+/// of that month's depreciation. The month of acquisition counts as the
+/// first month.
+pub fn period_end(acquired_on: NaiveDate, month: u32) -> Option<NaiveDate> {
+    let start = acquired_on
+        .with_day(1)?
+        .checked_add_months(Months::new(month))?;
+    start.checked_add_months(Months::new(1))?.pred_opt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_schedule(method: DepreciationMethod, cost: u64, salvage: u64, life: u32) -> Vec<u64> {
+        let (cost, salvage) = (UsdCents::from(cost), UsdCents::from(salvage));
+        let mut book_value = cost;
+        (0..life)
+            .map(|month| {
+                let charge = method.monthly_charge(cost, salvage, life, month, book_value);
+                book_value -= charge;
+                charge.into_inner()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn straight_line_puts_rounding_into_last_month() {
+        let charges = run_schedule(DepreciationMethod::StraightLine, 100_000, 0, 3);
+        assert_eq!(charges, vec![33_333, 33_333, 33_334]);
+    }
+
+    #[test]
+    fn declining_balance_ends_at_salvage_value() {
+        let charges = run_schedule(DepreciationMethod::DecliningBalance, 120_000, 20_000, 12);
+        assert_eq!(charges[0], 20_000);
+        assert!(charges[1] < charges[0]);
+        assert_eq!(charges.iter().sum::<u64>(), 100_000);
+    }
+
+    #[test]
+    fn nothing_is_charged_after_useful_life() {
+        let cost = UsdCents::from(1_000);
+        let charge =
+            DepreciationMethod::StraightLine.monthly_charge(cost, UsdCents::ZERO, 12, 12, cost);
+        assert_eq!(charge, UsdCents::ZERO);
+    }
+
+    #[test]
+    fn period_end_is_last_day_of_month() {
+        let acquired_on = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        assert_eq!(
+            period_end(acquired_on, 0),
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+        );
+        assert_eq!(
+            period_end(acquired_on, 1),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+    }
+}
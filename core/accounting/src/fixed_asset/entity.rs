@@ -0,0 +1,478 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{CalaAccountId, CalaTxId, ChartId, FixedAssetId, SignedUsdCents, UsdCents};
+
+use super::{
+    depreciation::{DepreciationMethod, period_end},
+    error::FixedAssetError,
+};
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum FixedAssetStatus {
+    InService,
+    FullyDepreciated,
+    Disposed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "FixedAssetId")]
+pub enum FixedAssetEvent {
+    Initialized {
+        id: FixedAssetId,
+        chart_id: ChartId,
+        name: String,
+        description: String,
+        cost: UsdCents,
+        salvage_value: UsdCents,
+        useful_life_months: u32,
+        depreciation_method: DepreciationMethod,
+        acquired_on: NaiveDate,
+        account_ids: FixedAssetAccountIds,
+        acquisition_tx_id: CalaTxId,
+        audit_info: AuditInfo,
+    },
+    DepreciationRecorded {
+        period_end: NaiveDate,
+        amount: UsdCents,
+        ledger_tx_id: CalaTxId,
+        audit_info: AuditInfo,
+    },
+    Disposed {
+        disposed_on: NaiveDate,
+        proceeds: UsdCents,
+        gain_or_loss: SignedUsdCents,
+        ledger_tx_id: CalaTxId,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Ledger accounts opened for the asset below the chart nodes it was
+/// acquired against.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct FixedAssetAccountIds {
+    pub asset_account_id: CalaAccountId,
+    pub accumulated_depreciation_account_id: CalaAccountId,
+    pub depreciation_expense_account_id: CalaAccountId,
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedAssetDepreciation {
+    pub period_end: NaiveDate,
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTxId,
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedAssetDisposal {
+    pub disposed_on: NaiveDate,
+    pub proceeds: UsdCents,
+    pub book_value: UsdCents,
+    pub accumulated_depreciation: UsdCents,
+    /// Positive for a gain, negative for a loss.
+    pub gain_or_loss: SignedUsdCents,
+    pub ledger_tx_id: CalaTxId,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct FixedAsset {
+    pub id: FixedAssetId,
+    pub chart_id: ChartId,
+    pub name: String,
+    pub description: String,
+    pub cost: UsdCents,
+    pub salvage_value: UsdCents,
+    pub useful_life_months: u32,
+    pub depreciation_method: DepreciationMethod,
+    pub acquired_on: NaiveDate,
+    pub account_ids: FixedAssetAccountIds,
+    pub acquisition_tx_id: CalaTxId,
+
+    events: EntityEvents<FixedAssetEvent>,
+}
+
+impl FixedAsset {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for fixed asset")
+    }
+
+    pub fn depreciations(&self) -> Vec<FixedAssetDepreciation> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                FixedAssetEvent::DepreciationRecorded {
+                    period_end,
+                    amount,
+                    ledger_tx_id,
+                    ..
+                } => Some(FixedAssetDepreciation {
+                    period_end: *period_end,
+                    amount: *amount,
+                    ledger_tx_id: *ledger_tx_id,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn disposal(&self) -> Option<FixedAssetDisposal> {
+        self.events.iter_all().find_map(|event| match event {
+            FixedAssetEvent::Disposed {
+                disposed_on,
+                proceeds,
+                gain_or_loss,
+                ledger_tx_id,
+                ..
+            } => {
+                let accumulated_depreciation = self.accumulated_depreciation();
+                Some(FixedAssetDisposal {
+                    disposed_on: *disposed_on,
+                    proceeds: *proceeds,
+                    book_value: self.cost - accumulated_depreciation,
+                    accumulated_depreciation,
+                    gain_or_loss: *gain_or_loss,
+                    ledger_tx_id: *ledger_tx_id,
+                })
+            }
+            _ => None,
+        })
+    }
+
+    pub fn accumulated_depreciation(&self) -> UsdCents {
+        self.depreciations()
+            .into_iter()
+            .fold(UsdCents::ZERO, |total, d| total + d.amount)
+    }
+
+    /// Depreciation recorded for months ending on or before `date`.
+    pub fn accumulated_depreciation_as_of(&self, date: NaiveDate) -> UsdCents {
+        self.depreciations()
+            .into_iter()
+            .filter(|d| d.period_end <= date)
+            .fold(UsdCents::ZERO, |total, d| total + d.amount)
+    }
+
+    pub fn book_value(&self) -> UsdCents {
+        if self.disposal().is_some() {
+            return UsdCents::ZERO;
+        }
+        self.cost - self.accumulated_depreciation()
+    }
+
+    pub fn status(&self) -> FixedAssetStatus {
+        if self.disposal().is_some() {
+            FixedAssetStatus::Disposed
+        } else if self.depreciations().len() as u32 >= self.useful_life_months
+            || self.book_value() <= self.salvage_value
+        {
+            FixedAssetStatus::FullyDepreciated
+        } else {
+            FixedAssetStatus::InService
+        }
+    }
+
+    /// The next month's depreciation if that month has ended before `today`.
+    pub fn next_depreciation(&self, today: NaiveDate) -> Option<(NaiveDate, UsdCents)> {
+        if self.status() != FixedAssetStatus::InService {
+            return None;
+        }
+        let month = self.depreciations().len() as u32;
+        let period_end = period_end(self.acquired_on, month)?;
+        if period_end >= today {
+            return None;
+        }
+        let amount = self.depreciation_method.monthly_charge(
+            self.cost,
+            self.salvage_value,
+            self.useful_life_months,
+            month,
+            self.book_value(),
+        );
+        Some((period_end, amount))
+    }
+
+    pub(super) fn record_depreciation(
+        &mut self,
+        period_end: NaiveDate,
+        amount: UsdCents,
+        ledger_tx_id: CalaTxId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            FixedAssetEvent::DepreciationRecorded { period_end: p, .. } if p == &period_end
+        );
+
+        self.events.push(FixedAssetEvent::DepreciationRecorded {
+            period_end,
+            amount,
+            ledger_tx_id,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    /// Takes the asset off the books at its current book value. Months not
+    /// yet depreciated by the job are not charged.
+    pub(super) fn dispose(
+        &mut self,
+        disposed_on: NaiveDate,
+        proceeds: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Result<FixedAssetDisposal, FixedAssetError> {
+        if self.disposal().is_some() {
+            return Err(FixedAssetError::AlreadyDisposed(self.id));
+        }
+        if disposed_on < self.acquired_on {
+            return Err(FixedAssetError::DisposalBeforeAcquisition(disposed_on));
+        }
+
+        let book_value = self.book_value();
+        let gain_or_loss = SignedUsdCents::from(proceeds) - SignedUsdCents::from(book_value);
+        let ledger_tx_id = CalaTxId::new();
+        self.events.push(FixedAssetEvent::Disposed {
+            disposed_on,
+            proceeds,
+            gain_or_loss,
+            ledger_tx_id,
+            audit_info,
+        });
+
+        Ok(self.disposal().expect("just disposed"))
+    }
+}
+
+impl TryFromEvents<FixedAssetEvent> for FixedAsset {
+    fn try_from_events(events: EntityEvents<FixedAssetEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = FixedAssetBuilder::default();
+        for event in events.iter_all() {
+            if let FixedAssetEvent::Initialized {
+                id,
+                chart_id,
+                name,
+                description,
+                cost,
+                salvage_value,
+                useful_life_months,
+                depreciation_method,
+                acquired_on,
+                account_ids,
+                acquisition_tx_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .chart_id(*chart_id)
+                    .name(name.clone())
+                    .description(description.clone())
+                    .cost(*cost)
+                    .salvage_value(*salvage_value)
+                    .useful_life_months(*useful_life_months)
+                    .depreciation_method(*depreciation_method)
+                    .acquired_on(*acquired_on)
+                    .account_ids(*account_ids)
+                    .acquisition_tx_id(*acquisition_tx_id);
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewFixedAsset {
+    #[builder(setter(into))]
+    pub(super) id: FixedAssetId,
+    #[builder(setter(into))]
+    pub(super) chart_id: ChartId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    #[builder(setter(into))]
+    pub(super) description: String,
+    pub(super) cost: UsdCents,
+    pub(super) salvage_value: UsdCents,
+    pub(super) useful_life_months: u32,
+    pub(super) depreciation_method: DepreciationMethod,
+    pub(super) acquired_on: NaiveDate,
+    pub(super) account_ids: FixedAssetAccountIds,
+    pub(super) acquisition_tx_id: CalaTxId,
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewFixedAsset {
+    pub fn builder() -> NewFixedAssetBuilder {
+        NewFixedAssetBuilder::default()
+    }
+}
+
+impl NewFixedAssetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (self.cost, self.salvage_value, self.useful_life_months) {
+            (Some(cost), Some(salvage_value), Some(useful_life_months)) => {
+                validate_terms(cost, salvage_value, useful_life_months)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub(super) fn validate_terms(
+    cost: UsdCents,
+    salvage_value: UsdCents,
+    useful_life_months: u32,
+) -> Result<(), String> {
+    if useful_life_months == 0 {
+        return Err("useful life must be at least one month".to_string());
+    }
+    if salvage_value >= cost {
+        return Err("salvage value must be below cost".to_string());
+    }
+    Ok(())
+}
+
+impl IntoEvents<FixedAssetEvent> for NewFixedAsset {
+    fn into_events(self) -> EntityEvents<FixedAssetEvent> {
+        EntityEvents::init(
+            self.id,
+            [FixedAssetEvent::Initialized {
+                id: self.id,
+                chart_id: self.chart_id,
+                name: self.name,
+                description: self.description,
+                cost: self.cost,
+                salvage_value: self.salvage_value,
+                useful_life_months: self.useful_life_months,
+                depreciation_method: self.depreciation_method,
+                acquired_on: self.acquired_on,
+                account_ids: self.account_ids,
+                acquisition_tx_id: self.acquisition_tx_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn asset(method: DepreciationMethod) -> FixedAsset {
+        let new_asset = NewFixedAsset::builder()
+            .id(FixedAssetId::new())
+            .chart_id(ChartId::new())
+            .name("Server rack")
+            .description("Data center equipment")
+            .cost(UsdCents::from(120_000))
+            .salvage_value(UsdCents::ZERO)
+            .useful_life_months(12)
+            .depreciation_method(method)
+            .acquired_on(NaiveDate::from_ymd_opt(2024, 1, 10).unwrap())
+            .account_ids(FixedAssetAccountIds {
+                asset_account_id: CalaAccountId::new(),
+                accumulated_depreciation_account_id: CalaAccountId::new(),
+                depreciation_expense_account_id: CalaAccountId::new(),
+            })
+            .acquisition_tx_id(CalaTxId::new())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        FixedAsset::try_from_events(new_asset.into_events()).unwrap()
+    }
+
+    #[test]
+    fn depreciation_is_due_once_the_month_has_ended() {
+        let asset = asset(DepreciationMethod::StraightLine);
+        assert!(
+            asset
+                .next_depreciation(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+                .is_none()
+        );
+        assert_eq!(
+            asset.next_depreciation(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()),
+            Some((
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+                UsdCents::from(10_000)
+            ))
+        );
+    }
+
+    #[test]
+    fn record_depreciation_is_idempotent() {
+        let mut asset = asset(DepreciationMethod::StraightLine);
+        let period_end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert!(
+            asset
+                .record_depreciation(
+                    period_end,
+                    UsdCents::from(10_000),
+                    CalaTxId::new(),
+                    dummy_audit_info()
+                )
+                .did_execute()
+        );
+        assert!(matches!(
+            asset.record_depreciation(
+                period_end,
+                UsdCents::from(10_000),
+                CalaTxId::new(),
+                dummy_audit_info()
+            ),
+            Idempotent::Ignored
+        ));
+        assert_eq!(asset.book_value(), UsdCents::from(110_000));
+    }
+
+    #[test]
+    fn disposal_records_gain_or_loss_against_book_value() {
+        let mut asset = asset(DepreciationMethod::StraightLine);
+        let _ = asset.record_depreciation(
+            NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            UsdCents::from(10_000),
+            CalaTxId::new(),
+            dummy_audit_info(),
+        );
+
+        let disposal = asset
+            .dispose(
+                NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+                UsdCents::from(100_000),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(disposal.book_value, UsdCents::from(110_000));
+        assert_eq!(disposal.gain_or_loss.into_inner(), -10_000);
+        assert_eq!(asset.status(), FixedAssetStatus::Disposed);
+        assert!(asset.next_depreciation(NaiveDate::MAX).is_none());
+        assert!(matches!(
+            asset.dispose(
+                NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+                UsdCents::ZERO,
+                dummy_audit_info(),
+            ),
+            Err(FixedAssetError::AlreadyDisposed(_))
+        ));
+    }
+}
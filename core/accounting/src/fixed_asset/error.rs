@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+use crate::primitives::FixedAssetId;
+
+#[derive(Error, Debug)]
+pub enum FixedAssetError {
+    #[error("FixedAssetError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("FixedAssetError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("FixedAssetError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("FixedAssetError - CalaError: {0}")]
+    LedgerError(#[from] cala_ledger::error::LedgerError),
+    #[error("FixedAssetError - CalaAccountError: {0}")]
+    AccountError(#[from] cala_ledger::account::error::AccountError),
+    #[error("FixedAssetError - CalaAccountSetError: {0}")]
+    AccountSetError(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("FixedAssetError - CalaTxTemplateError: {0}")]
+    TxTemplateError(#[from] cala_ledger::tx_template::error::TxTemplateError),
+    #[error("FixedAssetError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("FixedAssetError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("FixedAssetError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] crate::accounting_period::error::AccountingPeriodError),
+    #[error("FixedAssetError - ChartOfAccountsError: {0}")]
+    ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("FixedAssetError - Unknown account code: {0}")]
+    UnknownAccountCode(String),
+    #[error("FixedAssetError - InvalidAsset: {0}")]
+    InvalidAsset(String),
+    #[error("FixedAssetError - AlreadyDisposed: {0}")]
+    AlreadyDisposed(FixedAssetId),
+    #[error("FixedAssetError - DisposalBeforeAcquisition: {0}")]
+    DisposalBeforeAcquisition(chrono::NaiveDate),
+}
+
+es_entity::from_es_entity_error!(FixedAssetError);
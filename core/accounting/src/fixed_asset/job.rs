@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Months, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::primitives::{CoreAccountingAction, CoreAccountingObject};
+
+use super::FixedAssets;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FixedAssetDepreciationJobConfig<Perms> {
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+
+impl<Perms> FixedAssetDepreciationJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms> JobConfig for FixedAssetDepreciationJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    type Initializer = FixedAssetDepreciationJobInitializer<Perms>;
+}
+
+pub struct FixedAssetDepreciationJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fixed_assets: FixedAssets<Perms>,
+}
+
+impl<Perms> FixedAssetDepreciationJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(fixed_assets: &FixedAssets<Perms>) -> Self {
+        Self {
+            fixed_assets: fixed_assets.clone(),
+        }
+    }
+}
+
+const DEPRECIATE_FIXED_ASSETS_JOB: JobType = JobType::new("depreciate-fixed-assets");
+impl<Perms> JobInitializer for FixedAssetDepreciationJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPRECIATE_FIXED_ASSETS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(FixedAssetDepreciationJobRunner::<Perms> {
+            fixed_assets: self.fixed_assets.clone(),
+        }))
+    }
+}
+
+pub struct FixedAssetDepreciationJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fixed_assets: FixedAssets<Perms>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for FixedAssetDepreciationJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = Utc::now();
+        let today = now.date_naive();
+
+        for mut asset in self.fixed_assets.all_assets().await? {
+            // A failing asset, e.g. one whose next month falls into a locked
+            // period, must not hold back depreciation of the others.
+            if let Err(e) = self
+                .fixed_assets
+                .post_due_depreciation(&mut asset, today)
+                .await
+            {
+                tracing::warn!(
+                    fixed_asset_id = %asset.id,
+                    error = %e,
+                    "fixed asset depreciation failed"
+                );
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(start_of_next_month(now)))
+    }
+}
+
+fn start_of_next_month(now: DateTime<Utc>) -> DateTime<Utc> {
+    (now.date_naive().with_day(1).expect("first day of month") + Months::new(1))
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+}
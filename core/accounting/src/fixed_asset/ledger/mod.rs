@@ -0,0 +1,184 @@
+mod templates;
+
+use chrono::NaiveDate;
+
+use cala_ledger::{CalaLedger, DebitOrCredit, JournalId, account::NewAccount};
+
+use crate::{
+    Chart,
+    primitives::{AccountCode, CalaAccountId, CalaTxId, SignedUsdCents, UsdCents},
+};
+
+use super::error::FixedAssetError;
+
+#[derive(Clone)]
+pub struct FixedAssetLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl FixedAssetLedger {
+    pub async fn init(cala: &CalaLedger, journal_id: JournalId) -> Result<Self, FixedAssetError> {
+        templates::AcquireFixedAsset::init(cala).await?;
+        templates::DepreciateFixedAsset::init(cala).await?;
+        templates::DisposeFixedAsset::init(cala).await?;
+
+        Ok(Self {
+            cala: cala.clone(),
+            journal_id,
+        })
+    }
+
+    /// Finds or opens the leaf account identified by `external_id` below the
+    /// chart node `code`. The account takes the node's normal balance.
+    pub async fn account_below_node(
+        &self,
+        chart: &Chart,
+        code: &AccountCode,
+        external_id: String,
+        name: String,
+    ) -> Result<CalaAccountId, FixedAssetError> {
+        let (spec, parent_id) = chart
+            .account_spec(code)
+            .ok_or_else(|| FixedAssetError::UnknownAccountCode(code.to_string()))?;
+
+        match self
+            .cala
+            .accounts()
+            .find_by_external_id(external_id.clone())
+            .await
+        {
+            Ok(existing) => return Ok(existing.id()),
+            Err(e) if e.was_not_found() => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let id = CalaAccountId::new();
+        let account = self
+            .cala
+            .accounts()
+            .create(
+                NewAccount::builder()
+                    .id(id)
+                    .name(name.clone())
+                    .description(name)
+                    .code(id.to_string())
+                    .external_id(external_id)
+                    .normal_balance_type(spec.normal_balance_type)
+                    .build()
+                    .expect("Could not build new account"),
+            )
+            .await?;
+        self.cala
+            .account_sets()
+            .add_member(*parent_id, account.id)
+            .await?;
+
+        Ok(account.id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn acquire(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: CalaTxId,
+        description: String,
+        cost: UsdCents,
+        asset_account_id: CalaAccountId,
+        funding_account_id: CalaAccountId,
+        effective: NaiveDate,
+    ) -> Result<(), FixedAssetError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let params = templates::AcquireFixedAssetParams {
+            journal_id: self.journal_id,
+            description,
+            amount: cost.to_usd(),
+            asset_account_id,
+            funding_account_id,
+            effective,
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::ACQUIRE_FIXED_ASSET_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn depreciate(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: CalaTxId,
+        description: String,
+        amount: UsdCents,
+        depreciation_expense_account_id: CalaAccountId,
+        accumulated_depreciation_account_id: CalaAccountId,
+        effective: NaiveDate,
+    ) -> Result<(), FixedAssetError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let params = templates::DepreciateFixedAssetParams {
+            journal_id: self.journal_id,
+            description,
+            amount: amount.to_usd(),
+            depreciation_expense_account_id,
+            accumulated_depreciation_account_id,
+            effective,
+        };
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::DEPRECIATE_FIXED_ASSET_CODE,
+                params,
+            )
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn dispose(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: CalaTxId,
+        description: String,
+        cost: UsdCents,
+        accumulated_depreciation: UsdCents,
+        proceeds: UsdCents,
+        gain_or_loss: SignedUsdCents,
+        asset_account_id: CalaAccountId,
+        accumulated_depreciation_account_id: CalaAccountId,
+        proceeds_account_id: CalaAccountId,
+        gain_or_loss_account_id: CalaAccountId,
+        effective: NaiveDate,
+    ) -> Result<(), FixedAssetError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let gain_or_loss_direction = if gain_or_loss < SignedUsdCents::ZERO {
+            DebitOrCredit::Debit
+        } else {
+            DebitOrCredit::Credit
+        };
+        let params = templates::DisposeFixedAssetParams {
+            journal_id: self.journal_id,
+            description,
+            cost: cost.to_usd(),
+            accumulated_depreciation: accumulated_depreciation.to_usd(),
+            proceeds: proceeds.to_usd(),
+            gain_or_loss: gain_or_loss.to_usd().abs(),
+            gain_or_loss_direction,
+            asset_account_id,
+            accumulated_depreciation_account_id,
+            proceeds_account_id,
+            gain_or_loss_account_id,
+            effective,
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::DISPOSE_FIXED_ASSET_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{fixed_asset::error::FixedAssetError, primitives::CalaAccountId};
+
+pub const ACQUIRE_FIXED_ASSET_CODE: &str = "ACQUIRE_FIXED_ASSET";
+
+#[derive(Debug)]
+pub struct AcquireFixedAssetParams {
+    pub journal_id: JournalId,
+    pub description: String,
+    pub amount: Decimal,
+    pub asset_account_id: CalaAccountId,
+    pub funding_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl AcquireFixedAssetParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("description")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("asset_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("funding_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<AcquireFixedAssetParams> for Params {
+    fn from(
+        AcquireFixedAssetParams {
+            journal_id,
+            description,
+            amount,
+            asset_account_id,
+            funding_account_id,
+            effective,
+        }: AcquireFixedAssetParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("description", description);
+        params.insert("amount", amount);
+        params.insert("asset_account_id", asset_account_id);
+        params.insert("funding_account_id", funding_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct AcquireFixedAsset;
+
+impl AcquireFixedAsset {
+    #[instrument(name = "ledger.acquire_fixed_asset.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), FixedAssetError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("params.description")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'ACQUIRE_FIXED_ASSET_DR'")
+                .currency("'USD'")
+                .account_id("params.asset_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'ACQUIRE_FIXED_ASSET_CR'")
+                .currency("'USD'")
+                .account_id("params.funding_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = AcquireFixedAssetParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(ACQUIRE_FIXED_ASSET_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{fixed_asset::error::FixedAssetError, primitives::CalaAccountId};
+
+pub const DEPRECIATE_FIXED_ASSET_CODE: &str = "DEPRECIATE_FIXED_ASSET";
+
+#[derive(Debug)]
+pub struct DepreciateFixedAssetParams {
+    pub journal_id: JournalId,
+    pub description: String,
+    pub amount: Decimal,
+    pub depreciation_expense_account_id: CalaAccountId,
+    pub accumulated_depreciation_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl DepreciateFixedAssetParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("description")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("depreciation_expense_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("accumulated_depreciation_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<DepreciateFixedAssetParams> for Params {
+    fn from(
+        DepreciateFixedAssetParams {
+            journal_id,
+            description,
+            amount,
+            depreciation_expense_account_id,
+            accumulated_depreciation_account_id,
+            effective,
+        }: DepreciateFixedAssetParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("description", description);
+        params.insert("amount", amount);
+        params.insert(
+            "depreciation_expense_account_id",
+            depreciation_expense_account_id,
+        );
+        params.insert(
+            "accumulated_depreciation_account_id",
+            accumulated_depreciation_account_id,
+        );
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct DepreciateFixedAsset;
+
+impl DepreciateFixedAsset {
+    #[instrument(name = "ledger.depreciate_fixed_asset.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), FixedAssetError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("params.description")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'DEPRECIATE_FIXED_ASSET_DR'")
+                .currency("'USD'")
+                .account_id("params.depreciation_expense_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DEPRECIATE_FIXED_ASSET_CR'")
+                .currency("'USD'")
+                .account_id("params.accumulated_depreciation_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = DepreciateFixedAssetParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(DEPRECIATE_FIXED_ASSET_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
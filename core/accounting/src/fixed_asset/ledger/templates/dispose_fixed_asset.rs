@@ -0,0 +1,201 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{fixed_asset::error::FixedAssetError, primitives::CalaAccountId};
+
+pub const DISPOSE_FIXED_ASSET_CODE: &str = "DISPOSE_FIXED_ASSET";
+
+#[derive(Debug)]
+pub struct DisposeFixedAssetParams {
+    pub journal_id: JournalId,
+    pub description: String,
+    pub cost: Decimal,
+    pub accumulated_depreciation: Decimal,
+    pub proceeds: Decimal,
+    pub gain_or_loss: Decimal,
+    pub gain_or_loss_direction: DebitOrCredit,
+    pub asset_account_id: CalaAccountId,
+    pub accumulated_depreciation_account_id: CalaAccountId,
+    pub proceeds_account_id: CalaAccountId,
+    pub gain_or_loss_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl DisposeFixedAssetParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("description")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("cost")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("accumulated_depreciation")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("proceeds")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("gain_or_loss")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("gain_or_loss_direction")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("asset_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("accumulated_depreciation_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("proceeds_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("gain_or_loss_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<DisposeFixedAssetParams> for Params {
+    fn from(
+        DisposeFixedAssetParams {
+            journal_id,
+            description,
+            cost,
+            accumulated_depreciation,
+            proceeds,
+            gain_or_loss,
+            gain_or_loss_direction,
+            asset_account_id,
+            accumulated_depreciation_account_id,
+            proceeds_account_id,
+            gain_or_loss_account_id,
+            effective,
+        }: DisposeFixedAssetParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("description", description);
+        params.insert("cost", cost);
+        params.insert("accumulated_depreciation", accumulated_depreciation);
+        params.insert("proceeds", proceeds);
+        params.insert("gain_or_loss", gain_or_loss);
+        params.insert("gain_or_loss_direction", gain_or_loss_direction);
+        params.insert("asset_account_id", asset_account_id);
+        params.insert(
+            "accumulated_depreciation_account_id",
+            accumulated_depreciation_account_id,
+        );
+        params.insert("proceeds_account_id", proceeds_account_id);
+        params.insert("gain_or_loss_account_id", gain_or_loss_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+/// Removes the asset's cost and accumulated depreciation from the books and
+/// books the difference to the proceeds as a gain (credit) or loss (debit).
+pub struct DisposeFixedAsset;
+
+impl DisposeFixedAsset {
+    #[instrument(name = "ledger.dispose_fixed_asset.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), FixedAssetError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("params.description")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'DISPOSE_FIXED_ASSET_COST_CR'")
+                .currency("'USD'")
+                .account_id("params.asset_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.cost")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DISPOSE_FIXED_ASSET_ACCUMULATED_DEPRECIATION_DR'")
+                .currency("'USD'")
+                .account_id("params.accumulated_depreciation_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.accumulated_depreciation")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DISPOSE_FIXED_ASSET_PROCEEDS_DR'")
+                .currency("'USD'")
+                .account_id("params.proceeds_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.proceeds")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'DISPOSE_FIXED_ASSET_GAIN_OR_LOSS'")
+                .currency("'USD'")
+                .account_id("params.gain_or_loss_account_id")
+                .direction("params.gain_or_loss_direction")
+                .layer("SETTLED")
+                .units("params.gain_or_loss")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = DisposeFixedAssetParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(DISPOSE_FIXED_ASSET_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
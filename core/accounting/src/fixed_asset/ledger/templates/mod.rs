@@ -0,0 +1,7 @@
+mod acquire_fixed_asset;
+mod depreciate_fixed_asset;
+mod dispose_fixed_asset;
+
+pub use acquire_fixed_asset::*;
+pub use depreciate_fixed_asset::*;
+pub use dispose_fixed_asset::*;
@@ -0,0 +1,389 @@
+mod depreciation;
+mod entity;
+pub mod error;
+mod job;
+mod ledger;
+mod register;
+mod repo;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::{CalaLedger, JournalId};
+
+use crate::{
+    Chart,
+    accounting_period::AccountingPeriodLock,
+    primitives::{
+        AccountCode, CalaTxId, CoreAccountingAction, CoreAccountingObject, FixedAssetId, UsdCents,
+    },
+};
+
+pub use depreciation::DepreciationMethod;
+#[cfg(feature = "json-schema")]
+pub use entity::FixedAssetEvent;
+pub(super) use entity::*;
+pub use entity::{
+    FixedAsset, FixedAssetAccountIds, FixedAssetDepreciation, FixedAssetDisposal, FixedAssetStatus,
+};
+use error::*;
+pub use job::{FixedAssetDepreciationJobConfig, FixedAssetDepreciationJobInitializer};
+use ledger::FixedAssetLedger;
+pub use register::{FixedAssetRegister, FixedAssetRegisterLine};
+pub use repo::fixed_asset_cursor::FixedAssetsByCreatedAtCursor;
+use repo::*;
+
+/// Chart nodes an asset is acquired against. The asset gets its own cost,
+/// accumulated depreciation and depreciation expense accounts below the
+/// first three; the acquisition is credited to `funding`.
+#[derive(Debug, Clone)]
+pub struct FixedAssetAccountCodes {
+    pub asset: AccountCode,
+    pub accumulated_depreciation: AccountCode,
+    pub depreciation_expense: AccountCode,
+    pub funding: AccountCode,
+}
+
+#[derive(Clone)]
+pub struct FixedAssets<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: FixedAssetRepo,
+    ledger: FixedAssetLedger,
+    authz: Perms,
+    period_lock: AccountingPeriodLock,
+}
+
+impl<Perms> FixedAssets<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub async fn init(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        period_lock: &AccountingPeriodLock,
+    ) -> Result<Self, FixedAssetError> {
+        Ok(Self {
+            repo: FixedAssetRepo::new(pool),
+            ledger: FixedAssetLedger::init(cala, journal_id).await?,
+            authz: authz.clone(),
+            period_lock: period_lock.clone(),
+        })
+    }
+
+    /// Books the asset at `cost` on `acquired_on`, debiting its own cost
+    /// account and crediting the funding node.
+    #[instrument(name = "core_accounting.fixed_asset.acquire", skip(self, chart), err)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn acquire(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        name: String,
+        description: String,
+        cost: UsdCents,
+        salvage_value: UsdCents,
+        useful_life_months: u32,
+        depreciation_method: DepreciationMethod,
+        acquired_on: NaiveDate,
+        account_codes: FixedAssetAccountCodes,
+    ) -> Result<FixedAsset, FixedAssetError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fixed_assets(),
+                CoreAccountingAction::FIXED_ASSET_ACQUIRE,
+            )
+            .await?;
+
+        validate_terms(cost, salvage_value, useful_life_months)
+            .map_err(FixedAssetError::InvalidAsset)?;
+
+        let id = FixedAssetId::new();
+        let account_ids = FixedAssetAccountIds {
+            asset_account_id: self
+                .ledger
+                .account_below_node(
+                    chart,
+                    &account_codes.asset,
+                    format!("fixed-asset:{id}:cost"),
+                    format!("{name} Cost"),
+                )
+                .await?,
+            accumulated_depreciation_account_id: self
+                .ledger
+                .account_below_node(
+                    chart,
+                    &account_codes.accumulated_depreciation,
+                    format!("fixed-asset:{id}:accumulated-depreciation"),
+                    format!("{name} Accumulated Depreciation"),
+                )
+                .await?,
+            depreciation_expense_account_id: self
+                .ledger
+                .account_below_node(
+                    chart,
+                    &account_codes.depreciation_expense,
+                    format!("fixed-asset:{id}:depreciation-expense"),
+                    format!("{name} Depreciation Expense"),
+                )
+                .await?,
+        };
+        let funding_account_id = self.shared_account(chart, &account_codes.funding).await?;
+
+        let new_asset = NewFixedAsset::builder()
+            .id(id)
+            .chart_id(chart.id)
+            .name(name)
+            .description(description)
+            .cost(cost)
+            .salvage_value(salvage_value)
+            .useful_life_months(useful_life_months)
+            .depreciation_method(depreciation_method)
+            .acquired_on(acquired_on)
+            .account_ids(account_ids)
+            .acquisition_tx_id(CalaTxId::new())
+            .audit_info(audit_info)
+            .build()
+            .map_err(|e| FixedAssetError::InvalidAsset(e.to_string()))?;
+
+        let mut db = self.repo.begin_op().await?;
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut db, acquired_on)
+            .await?;
+        let asset = self.repo.create_in_op(&mut db, new_asset).await?;
+        self.ledger
+            .acquire(
+                db,
+                asset.acquisition_tx_id,
+                format!("Acquisition of {}", asset.name),
+                asset.cost,
+                asset.account_ids.asset_account_id,
+                funding_account_id,
+                asset.acquired_on,
+            )
+            .await?;
+
+        Ok(asset)
+    }
+
+    /// Takes the asset off the books on `disposed_on`. The difference between
+    /// `proceeds` and the book value is posted to the gain or loss node.
+    #[instrument(name = "core_accounting.fixed_asset.dispose", skip(self, chart), err)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn dispose(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        id: impl Into<FixedAssetId> + std::fmt::Debug,
+        disposed_on: NaiveDate,
+        proceeds: UsdCents,
+        proceeds_account_code: AccountCode,
+        gain_or_loss_account_code: AccountCode,
+    ) -> Result<FixedAsset, FixedAssetError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::fixed_asset(id),
+                CoreAccountingAction::FIXED_ASSET_DISPOSE,
+            )
+            .await?;
+
+        let mut asset = self.repo.find_by_id(id).await?;
+        let proceeds_account_id = self.shared_account(chart, &proceeds_account_code).await?;
+        let gain_or_loss_account_id = self
+            .shared_account(chart, &gain_or_loss_account_code)
+            .await?;
+
+        let mut db = self.repo.begin_op().await?;
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut db, disposed_on)
+            .await?;
+        let disposal = asset.dispose(disposed_on, proceeds, audit_info)?;
+        self.repo.update_in_op(&mut db, &mut asset).await?;
+        self.ledger
+            .dispose(
+                db,
+                disposal.ledger_tx_id,
+                format!("Disposal of {}", asset.name),
+                asset.cost,
+                disposal.accumulated_depreciation,
+                disposal.proceeds,
+                disposal.gain_or_loss,
+                asset.account_ids.asset_account_id,
+                asset.account_ids.accumulated_depreciation_account_id,
+                proceeds_account_id,
+                gain_or_loss_account_id,
+                disposal.disposed_on,
+            )
+            .await?;
+
+        Ok(asset)
+    }
+
+    /// Posts every month of depreciation that has ended before `today` and
+    /// has not been posted yet.
+    pub(crate) async fn post_due_depreciation(
+        &self,
+        asset: &mut FixedAsset,
+        today: NaiveDate,
+    ) -> Result<(), FixedAssetError> {
+        while let Some((period_end, amount)) = asset.next_depreciation(today) {
+            let mut db = self.repo.begin_op().await?;
+            self.period_lock
+                .ensure_accepts_module_posting_in_op(&mut db, period_end)
+                .await?;
+            let audit_info = self
+                .authz
+                .audit()
+                .record_system_entry_in_tx(
+                    db.tx(),
+                    CoreAccountingObject::fixed_asset(asset.id),
+                    CoreAccountingAction::FIXED_ASSET_DEPRECIATE,
+                )
+                .await?;
+
+            let ledger_tx_id = CalaTxId::new();
+            if !asset
+                .record_depreciation(period_end, amount, ledger_tx_id, audit_info)
+                .did_execute()
+            {
+                break;
+            }
+            self.repo.update_in_op(&mut db, asset).await?;
+            self.ledger
+                .depreciate(
+                    db,
+                    ledger_tx_id,
+                    format!("Depreciation of {} for {}", asset.name, period_end),
+                    amount,
+                    asset.account_ids.depreciation_expense_account_id,
+                    asset.account_ids.accumulated_depreciation_account_id,
+                    period_end,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "core_accounting.fixed_asset.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<FixedAssetId> + std::fmt::Debug,
+    ) -> Result<Option<FixedAsset>, FixedAssetError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::fixed_asset(id),
+                CoreAccountingAction::FIXED_ASSET_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(asset) => Ok(Some(asset)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.fixed_asset.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<FixedAssetsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<FixedAsset, FixedAssetsByCreatedAtCursor>,
+        FixedAssetError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fixed_assets(),
+                CoreAccountingAction::FIXED_ASSET_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    /// Assets held on `as_of` with their cost, accumulated depreciation and
+    /// book value as of that date.
+    #[instrument(name = "core_accounting.fixed_asset.register", skip(self), err)]
+    pub async fn register(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        as_of: NaiveDate,
+    ) -> Result<FixedAssetRegister, FixedAssetError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fixed_assets(),
+                CoreAccountingAction::FIXED_ASSET_LIST,
+            )
+            .await?;
+
+        Ok(FixedAssetRegister::new(as_of, self.all_assets().await?))
+    }
+
+    #[instrument(name = "core_accounting.fixed_asset.find_all", skip(self), err)]
+    pub async fn find_all<T: From<FixedAsset>>(
+        &self,
+        ids: &[FixedAssetId],
+    ) -> Result<HashMap<FixedAssetId, T>, FixedAssetError> {
+        self.repo.find_all(ids).await
+    }
+
+    pub(crate) async fn all_assets(&self) -> Result<Vec<FixedAsset>, FixedAssetError> {
+        let mut assets = Vec::new();
+        let mut has_next_page = true;
+        let mut after: Option<FixedAssetsByCreatedAtCursor> = None;
+        while has_next_page {
+            let page = self
+                .repo
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<FixedAssetsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (page.end_cursor, page.has_next_page);
+            assets.extend(page.entities);
+        }
+        Ok(assets)
+    }
+
+    /// Account below `code` shared by all assets, e.g. the bank account
+    /// acquisitions are paid from.
+    async fn shared_account(
+        &self,
+        chart: &Chart,
+        code: &AccountCode,
+    ) -> Result<crate::primitives::CalaAccountId, FixedAssetError> {
+        self.ledger
+            .account_below_node(
+                chart,
+                code,
+                format!("fixed-assets:{}:{}", chart.id, code),
+                format!("{code} Fixed Assets"),
+            )
+            .await
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+
+use crate::primitives::{FixedAssetId, UsdCents};
+
+use super::{depreciation::DepreciationMethod, entity::FixedAsset};
+
+/// The assets held on `as_of` together with their carrying amounts.
+#[derive(Debug, Clone)]
+pub struct FixedAssetRegister {
+    pub as_of: NaiveDate,
+    pub lines: Vec<FixedAssetRegisterLine>,
+}
+
+impl FixedAssetRegister {
+    pub(super) fn new(as_of: NaiveDate, assets: impl IntoIterator<Item = FixedAsset>) -> Self {
+        Self {
+            as_of,
+            lines: assets
+                .into_iter()
+                .filter_map(|asset| FixedAssetRegisterLine::for_asset(&asset, as_of))
+                .collect(),
+        }
+    }
+
+    pub fn total_cost(&self) -> UsdCents {
+        self.lines
+            .iter()
+            .fold(UsdCents::ZERO, |total, line| total + line.cost)
+    }
+
+    pub fn total_accumulated_depreciation(&self) -> UsdCents {
+        self.lines.iter().fold(UsdCents::ZERO, |total, line| {
+            total + line.accumulated_depreciation
+        })
+    }
+
+    pub fn total_book_value(&self) -> UsdCents {
+        self.lines
+            .iter()
+            .fold(UsdCents::ZERO, |total, line| total + line.book_value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedAssetRegisterLine {
+    pub fixed_asset_id: FixedAssetId,
+    pub name: String,
+    pub acquired_on: NaiveDate,
+    pub depreciation_method: DepreciationMethod,
+    pub useful_life_months: u32,
+    pub cost: UsdCents,
+    pub accumulated_depreciation: UsdCents,
+    pub book_value: UsdCents,
+}
+
+impl FixedAssetRegisterLine {
+    /// `None` for assets not yet acquired or already disposed of on `as_of`.
+    fn for_asset(asset: &FixedAsset, as_of: NaiveDate) -> Option<Self> {
+        if asset.acquired_on > as_of {
+            return None;
+        }
+        if asset
+            .disposal()
+            .is_some_and(|disposal| disposal.disposed_on <= as_of)
+        {
+            return None;
+        }
+
+        let accumulated_depreciation = asset.accumulated_depreciation_as_of(as_of);
+        Some(Self {
+            fixed_asset_id: asset.id,
+            name: asset.name.clone(),
+            acquired_on: asset.acquired_on,
+            depreciation_method: asset.depreciation_method,
+            useful_life_months: asset.useful_life_months,
+            cost: asset.cost,
+            accumulated_depreciation,
+            book_value: asset.cost - accumulated_depreciation,
+        })
+    }
+}
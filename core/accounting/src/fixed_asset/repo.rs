@@ -0,0 +1,19 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::FixedAssetId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(entity = "FixedAsset", err = "FixedAssetError", tbl_prefix = "core")]
+pub struct FixedAssetRepo {
+    pool: PgPool,
+}
+
+impl FixedAssetRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
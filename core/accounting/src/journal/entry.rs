@@ -1,6 +1,7 @@
 use cala_ledger::entry::Entry;
 use cala_ledger::{Currency, DebitOrCredit, EntryId, Layer};
 use core_money::{Satoshis, UsdCents};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use super::error::JournalError;
@@ -19,6 +20,35 @@ pub struct JournalEntry {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// A journal entry in any currency, dated by its transaction's effective date.
+#[derive(Clone)]
+pub struct EffectiveJournalEntry {
+    pub effective: chrono::NaiveDate,
+    pub ledger_account_id: LedgerAccountId,
+    pub ledger_transaction_id: LedgerTransactionId,
+    pub entry_type: String,
+    pub currency: Currency,
+    pub units: Decimal,
+    pub description: Option<String>,
+    pub direction: DebitOrCredit,
+}
+
+impl From<(chrono::NaiveDate, Entry)> for EffectiveJournalEntry {
+    fn from((effective, entry): (chrono::NaiveDate, Entry)) -> Self {
+        let values = entry.into_values();
+        Self {
+            effective,
+            ledger_account_id: values.account_id.into(),
+            ledger_transaction_id: values.transaction_id,
+            entry_type: values.entry_type,
+            currency: values.currency,
+            units: values.units,
+            description: values.description,
+            direction: values.direction,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum JournalEntryAmount {
     Usd(UsdCents),
@@ -58,6 +88,13 @@ impl JournalEntryAmount {
         }
     }
 
+    pub fn to_major_units(&self) -> Decimal {
+        match self {
+            JournalEntryAmount::Usd(cents) => cents.to_usd(),
+            JournalEntryAmount::Btc(sats) => sats.to_btc(),
+        }
+    }
+
     pub fn currency_code(&self) -> String {
         match self {
             JournalEntryAmount::Usd(_) => String::from("USD"),
@@ -1,6 +1,8 @@
 mod entry;
 pub mod error;
 
+use chrono::NaiveDate;
+use std::collections::HashMap;
 use tracing::instrument;
 
 use cala_ledger::CalaLedger;
@@ -13,6 +15,8 @@ use crate::primitives::{CalaJournalId, CoreAccountingAction, CoreAccountingObjec
 pub use entry::*;
 use error::*;
 
+const ENTRIES_BATCH_SIZE: usize = 1000;
+
 #[derive(Clone)]
 pub struct Journal<Perms>
 where
@@ -21,6 +25,7 @@ where
     authz: Perms,
     cala: CalaLedger,
     journal_id: CalaJournalId,
+    pool: sqlx::PgPool,
 }
 
 impl<Perms> Journal<Perms>
@@ -29,11 +34,17 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
-    pub fn new(authz: &Perms, cala: &CalaLedger, journal_id: CalaJournalId) -> Self {
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        cala: &CalaLedger,
+        journal_id: CalaJournalId,
+    ) -> Self {
         Self {
             authz: authz.clone(),
             cala: cala.clone(),
             journal_id,
+            pool: pool.clone(),
         }
     }
 
@@ -80,4 +91,42 @@ where
             end_cursor: ret.end_cursor.map(JournalEntryCursor::from),
         })
     }
+
+    /// All entries of transactions effective between `from` and `until`
+    /// (inclusive), in any currency, ordered by effective date.
+    #[instrument(name = "core_accounting.journal.entries_in_range", skip(self), err)]
+    pub(crate) async fn entries_in_range(
+        &self,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<EffectiveJournalEntry>, JournalError> {
+        let rows = sqlx::query!(
+            r#"SELECT e.id, t.effective
+            FROM cala_entries e
+            JOIN cala_transactions t ON t.id = e.transaction_id
+            WHERE t.journal_id = $1 AND t.effective >= $2 AND t.effective <= $3
+            ORDER BY t.effective, e.created_at, e.id"#,
+            uuid::Uuid::from(self.journal_id),
+            from,
+            until
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for chunk in rows.chunks(ENTRIES_BATCH_SIZE) {
+            let ids = chunk
+                .iter()
+                .map(|row| cala_ledger::EntryId::from(row.id))
+                .collect::<Vec<_>>();
+            let mut found: HashMap<_, cala_ledger::entry::Entry> =
+                self.cala.entries().find_all(&ids).await?;
+            for (row, id) in chunk.iter().zip(ids) {
+                if let Some(entry) = found.remove(&id) {
+                    entries.push(EffectiveJournalEntry::from((row.effective, entry)));
+                }
+            }
+        }
+        Ok(entries)
+    }
 }
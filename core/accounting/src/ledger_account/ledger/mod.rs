@@ -9,6 +9,7 @@ use cala_ledger::{
         AccountSet, AccountSetId, AccountSetMemberByExternalId, AccountSetMemberId,
         AccountSetMembersByExternalIdCursor,
     },
+    balance::BalanceRange,
 };
 
 use crate::{AccountCode, LedgerAccount, LedgerAccountId, journal_error::JournalError};
@@ -361,6 +362,33 @@ impl LedgerAccountLedger {
         })
     }
 
+    pub async fn balance_ranges_in_currencies(
+        &self,
+        ids: &[LedgerAccountId],
+        currencies: &[Currency],
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<HashMap<(LedgerAccountId, Currency), BalanceRange>, LedgerAccountLedgerError> {
+        let balance_ids = ids
+            .iter()
+            .flat_map(|id| {
+                currencies
+                    .iter()
+                    .map(move |currency| (self.journal_id, (*id).into(), *currency))
+            })
+            .collect::<Vec<_>>();
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, Some(until))
+            .await?;
+        Ok(balances
+            .into_iter()
+            .map(|((_, account_id, currency), range)| ((account_id.into(), currency), range))
+            .collect())
+    }
+
     async fn get_member_account_sets<U>(
         &self,
         account_set_id: AccountSetId,
@@ -14,7 +14,8 @@ use crate::journal::{JournalEntry, JournalEntryCursor};
 use crate::{
     chart_of_accounts::Chart,
     primitives::{
-        AccountCode, CalaJournalId, CoreAccountingAction, CoreAccountingObject, LedgerAccountId,
+        AccountCode, CalaBalanceRange, CalaCurrency, CalaJournalId, CoreAccountingAction,
+        CoreAccountingObject, LedgerAccountId,
     },
 };
 
@@ -238,6 +239,87 @@ where
         }
     }
 
+    /// Name and chart code of each account. Accounts that are not chart nodes
+    /// take the code of their closest ancestor in the chart.
+    #[instrument(
+        name = "core_accounting.ledger_account.find_names_and_codes",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn find_names_and_codes(
+        &self,
+        ids: &[LedgerAccountId],
+    ) -> Result<HashMap<LedgerAccountId, (String, Option<AccountCode>)>, LedgerAccountError> {
+        let accounts = self.ledger.load_ledger_accounts(ids).await?;
+        let mut res = HashMap::new();
+        for (id, account) in accounts.into_iter() {
+            let code = match account.code.clone() {
+                Some(code) => Some(code),
+                None => self
+                    .ledger
+                    .find_parent_with_account_code(account.account_set_member_id(), 1)
+                    .await?
+                    .map(|(_, code)| code),
+            };
+            res.insert(id, (account.name, code));
+        }
+        Ok(res)
+    }
+
+    /// Balance ranges over `from..=until` of each account in each of
+    /// `currencies`. Accounts without balance in a currency are left out.
+    #[instrument(
+        name = "core_accounting.ledger_account.balance_ranges_in_currencies",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn balance_ranges_in_currencies(
+        &self,
+        ids: &[LedgerAccountId],
+        currencies: &[CalaCurrency],
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<HashMap<(LedgerAccountId, CalaCurrency), CalaBalanceRange>, LedgerAccountError>
+    {
+        Ok(self
+            .ledger
+            .balance_ranges_in_currencies(ids, currencies, from, until)
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.ledger_account.complete_children",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn complete_children(
+        &self,
+        id: cala_ledger::AccountSetId,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<LedgerAccount>, LedgerAccountError> {
+        let mut all_children = Vec::new();
+        let mut cursor: Option<LedgerAccountChildrenCursor> = None;
+        loop {
+            let query_args = es_entity::PaginatedQueryArgs {
+                first: 100,
+                after: cursor,
+            };
+            let result = self
+                .ledger
+                .list_children(id, query_args, from, Some(until))
+                .await?;
+            all_children.extend(result.entities);
+
+            if !result.has_next_page {
+                break;
+            }
+            cursor = result.end_cursor;
+        }
+
+        Ok(all_children)
+    }
+
     /// Pushes into `account`'s `ancestor_ids` ancestors from the chart of account. The ancestors
     /// are pushed in ascending order, the root of the chart of accounts is pushed last. `account`
     /// itself is not pushed.
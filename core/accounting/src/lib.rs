@@ -1,16 +1,21 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
+pub mod accounting_period;
 pub mod balance_sheet;
+pub mod cash_flow;
 pub mod chart_of_accounts;
+pub mod comparative_statement;
 pub mod csv;
 pub mod error;
+pub mod fixed_asset;
 pub mod journal;
 pub mod ledger_account;
 pub mod ledger_transaction;
 pub mod manual_transaction;
 mod primitives;
 pub mod profit_and_loss;
+pub mod recurring_entry;
 pub mod transaction_templates;
 pub mod trial_balance;
 
@@ -20,105 +25,223 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use cloud_storage::Storage;
+use fixed_asset::{FixedAssetDepreciationJobConfig, FixedAssetDepreciationJobInitializer};
+use governance::{Governance, GovernanceEvent};
 use job::Jobs;
-use manual_transaction::ManualTransactions;
+use manual_transaction::{
+    ManualTransactionApprovalJobConfig, ManualTransactionApprovalJobInitializer, ManualTransactions,
+};
+use outbox::{Outbox, OutboxEventMarker};
+use recurring_entry::{RecurringEntryJobConfig, RecurringEntryJobInitializer};
 use tracing::instrument;
 
+pub use accounting_period::{
+    AccountingPeriod, AccountingPeriodLock, AccountingPeriodStatus, AccountingPeriodType,
+    AccountingPeriods, AccountingPeriodsByCreatedAtCursor, error as accounting_period_error,
+};
 pub use balance_sheet::{BalanceSheet, BalanceSheets};
-pub use chart_of_accounts::{Chart, ChartOfAccounts, error as chart_of_accounts_error, tree};
+pub use cash_flow::{CashFlowStatement, CashFlowStatements};
+pub use chart_of_accounts::{
+    Chart, ChartExport, ChartExportFormat, ChartExportNode, ChartOfAccounts,
+    error as chart_of_accounts_error, tree,
+};
+pub use comparative_statement::{
+    ComparativeAmount, ComparativeStatement, ComparativeStatementLine, StatementPeriod,
+    error as comparative_statement_error,
+};
 pub use csv::AccountingCsvs;
 use error::CoreAccountingError;
+pub use fixed_asset::{
+    DepreciationMethod, FixedAsset, FixedAssetAccountCodes, FixedAssetRegister, FixedAssetStatus,
+    FixedAssets, FixedAssetsByCreatedAtCursor,
+};
 pub use journal::{Journal, error as journal_error};
 pub use ledger_account::{LedgerAccount, LedgerAccountChildrenCursor, LedgerAccounts};
 pub use ledger_transaction::{LedgerTransaction, LedgerTransactions};
-pub use manual_transaction::ManualEntryInput;
+pub use manual_transaction::{
+    APPROVE_MANUAL_TRANSACTION_PROCESS, ManualEntryInput, ManualTransaction,
+};
 pub use primitives::*;
 pub use profit_and_loss::{ProfitAndLossStatement, ProfitAndLossStatements};
+pub use recurring_entry::{
+    RecurringEntryFrequency, RecurringEntryRun, RecurringEntryTemplate,
+    RecurringEntryTemplateStatus, RecurringEntryTemplates,
+    RecurringEntryTemplatesByCreatedAtCursor,
+};
 pub use transaction_templates::TransactionTemplates;
 pub use trial_balance::{TrialBalanceRoot, TrialBalances};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
+    pub use crate::accounting_period::AccountingPeriodEvent;
     pub use crate::chart_of_accounts::ChartEvent;
     pub use crate::csv::AccountingCsvEvent;
+    pub use crate::fixed_asset::FixedAssetEvent;
     pub use crate::manual_transaction::ManualTransactionEvent;
+    pub use crate::recurring_entry::RecurringEntryTemplateEvent;
 }
 
-pub struct CoreAccounting<Perms>
+pub struct CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     authz: Perms,
+    accounting_periods: AccountingPeriods<Perms>,
     chart_of_accounts: ChartOfAccounts<Perms>,
     journal: Journal<Perms>,
     ledger_accounts: LedgerAccounts<Perms>,
     ledger_transactions: LedgerTransactions<Perms>,
-    manual_transactions: ManualTransactions<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    recurring_entry_templates: RecurringEntryTemplates<Perms, E>,
+    fixed_assets: FixedAssets<Perms>,
     profit_and_loss: ProfitAndLossStatements<Perms>,
     transaction_templates: TransactionTemplates<Perms>,
     balance_sheets: BalanceSheets<Perms>,
+    cash_flows: CashFlowStatements<Perms>,
     csvs: AccountingCsvs<Perms>,
     trial_balances: TrialBalances<Perms>,
 }
 
-impl<Perms> Clone for CoreAccounting<Perms>
+impl<Perms, E> Clone for CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
             authz: self.authz.clone(),
+            accounting_periods: self.accounting_periods.clone(),
             chart_of_accounts: self.chart_of_accounts.clone(),
             journal: self.journal.clone(),
             ledger_accounts: self.ledger_accounts.clone(),
             manual_transactions: self.manual_transactions.clone(),
+            recurring_entry_templates: self.recurring_entry_templates.clone(),
+            fixed_assets: self.fixed_assets.clone(),
             ledger_transactions: self.ledger_transactions.clone(),
             profit_and_loss: self.profit_and_loss.clone(),
             transaction_templates: self.transaction_templates.clone(),
             balance_sheets: self.balance_sheets.clone(),
+            cash_flows: self.cash_flows.clone(),
             csvs: self.csvs.clone(),
             trial_balances: self.trial_balances.clone(),
         }
     }
 }
 
-impl<Perms> CoreAccounting<Perms>
+impl<Perms, E> CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Perms,
         cala: &CalaLedger,
         journal_id: CalaJournalId,
         storage: &Storage,
         jobs: &Jobs,
-    ) -> Self {
+        governance: &Governance<Perms, E>,
+        outbox: &Outbox<E>,
+    ) -> Result<Self, CoreAccountingError> {
         let chart_of_accounts = ChartOfAccounts::new(pool, authz, cala, journal_id);
-        let journal = Journal::new(authz, cala, journal_id);
+        let journal = Journal::new(pool, authz, cala, journal_id);
         let ledger_accounts = LedgerAccounts::new(authz, cala, journal_id);
-        let manual_transactions = ManualTransactions::new(pool, authz, cala, journal_id);
+        let accounting_periods = AccountingPeriods::new(pool, authz, cala, journal_id);
+        let manual_transactions = ManualTransactions::new(
+            pool,
+            authz,
+            cala,
+            journal_id,
+            accounting_periods.lock(),
+            governance,
+        );
+
+        jobs.add_initializer_and_spawn_unique(
+            ManualTransactionApprovalJobInitializer::new(
+                outbox,
+                manual_transactions.approve_manual_transaction(),
+            ),
+            ManualTransactionApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        match governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        let recurring_entry_templates =
+            RecurringEntryTemplates::new(pool, authz, &manual_transactions);
+        jobs.add_initializer_and_spawn_unique(
+            RecurringEntryJobInitializer::new(
+                &recurring_entry_templates,
+                &manual_transactions,
+                &chart_of_accounts,
+                authz.audit(),
+            ),
+            RecurringEntryJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let fixed_assets =
+            FixedAssets::init(pool, authz, cala, journal_id, accounting_periods.lock()).await?;
+        jobs.add_initializer_and_spawn_unique(
+            FixedAssetDepreciationJobInitializer::new(&fixed_assets),
+            FixedAssetDepreciationJobConfig::<Perms>::new(),
+        )
+        .await?;
+
         let ledger_transactions = LedgerTransactions::new(authz, cala);
         let profit_and_loss = ProfitAndLossStatements::new(pool, authz, cala, journal_id);
         let transaction_templates = TransactionTemplates::new(authz, cala);
         let balance_sheets = BalanceSheets::new(pool, authz, cala, journal_id);
-        let csvs = AccountingCsvs::new(pool, authz, jobs, storage, &ledger_accounts);
+        let cash_flows = CashFlowStatements::new(pool, authz, cala, journal_id);
         let trial_balances = TrialBalances::new(pool, authz, cala, journal_id);
-        Self {
+        let csvs = AccountingCsvs::new(
+            pool,
+            authz,
+            jobs,
+            storage,
+            &ledger_accounts,
+            &balance_sheets,
+            &profit_and_loss,
+            &cash_flows,
+            &journal,
+            &trial_balances,
+        );
+        Ok(Self {
             authz: authz.clone(),
+            accounting_periods,
             chart_of_accounts,
             journal,
             ledger_accounts,
             ledger_transactions,
             manual_transactions,
+            recurring_entry_templates,
+            fixed_assets,
             profit_and_loss,
             transaction_templates,
             balance_sheets,
+            cash_flows,
             csvs,
             trial_balances,
-        }
+        })
+    }
+
+    pub fn accounting_periods(&self) -> &AccountingPeriods<Perms> {
+        &self.accounting_periods
     }
 
     pub fn chart_of_accounts(&self) -> &ChartOfAccounts<Perms> {
@@ -137,10 +260,18 @@ where
         &self.ledger_transactions
     }
 
-    pub fn manual_transactions(&self) -> &ManualTransactions<Perms> {
+    pub fn manual_transactions(&self) -> &ManualTransactions<Perms, E> {
         &self.manual_transactions
     }
 
+    pub fn recurring_entry_templates(&self) -> &RecurringEntryTemplates<Perms, E> {
+        &self.recurring_entry_templates
+    }
+
+    pub fn fixed_assets(&self) -> &FixedAssets<Perms> {
+        &self.fixed_assets
+    }
+
     pub fn profit_and_loss(&self) -> &ProfitAndLossStatements<Perms> {
         &self.profit_and_loss
     }
@@ -157,6 +288,10 @@ where
         &self.balance_sheets
     }
 
+    pub fn cash_flows(&self) -> &CashFlowStatements<Perms> {
+        &self.cash_flows
+    }
+
     pub fn trial_balances(&self) -> &TrialBalances<Perms> {
         &self.trial_balances
     }
@@ -255,7 +390,7 @@ where
         description: String,
         effective: Option<chrono::NaiveDate>,
         entries: Vec<ManualEntryInput>,
-    ) -> Result<LedgerTransaction, CoreAccountingError> {
+    ) -> Result<ManualTransaction, CoreAccountingError> {
         let chart = self
             .chart_of_accounts
             .find_by_reference(chart_ref)
@@ -264,7 +399,7 @@ where
                 CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
             })?;
 
-        let tx = self
+        Ok(self
             .manual_transactions
             .execute(
                 sub,
@@ -274,13 +409,326 @@ where
                 effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
                 entries,
             )
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.create_recurring_entry_template",
+        skip(self, entries),
+        err
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_recurring_entry_template(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        name: String,
+        description: String,
+        frequency: RecurringEntryFrequency,
+        start_date: chrono::NaiveDate,
+        end_date: Option<chrono::NaiveDate>,
+        auto_reverse: bool,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<RecurringEntryTemplate, CoreAccountingError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+
+        Ok(self
+            .recurring_entry_templates
+            .create(
+                sub,
+                &chart,
+                name,
+                description,
+                frequency,
+                start_date,
+                end_date,
+                auto_reverse,
+                entries,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_accounting.acquire_fixed_asset", skip(self), err)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn acquire_fixed_asset(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        name: String,
+        description: String,
+        cost: UsdCents,
+        salvage_value: UsdCents,
+        useful_life_months: u32,
+        depreciation_method: DepreciationMethod,
+        acquired_on: chrono::NaiveDate,
+        account_codes: FixedAssetAccountCodes,
+    ) -> Result<FixedAsset, CoreAccountingError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+
+        Ok(self
+            .fixed_assets
+            .acquire(
+                sub,
+                &chart,
+                name,
+                description,
+                cost,
+                salvage_value,
+                useful_life_months,
+                depreciation_method,
+                acquired_on,
+                account_codes,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_accounting.dispose_fixed_asset", skip(self), err)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn dispose_fixed_asset(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<FixedAssetId> + std::fmt::Debug,
+        disposed_on: chrono::NaiveDate,
+        proceeds: UsdCents,
+        proceeds_account_code: AccountCode,
+        gain_or_loss_account_code: AccountCode,
+    ) -> Result<FixedAsset, CoreAccountingError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+
+        Ok(self
+            .fixed_assets
+            .dispose(
+                sub,
+                &chart,
+                id,
+                disposed_on,
+                proceeds,
+                proceeds_account_code,
+                gain_or_loss_account_code,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_accounting.reverse_manual_transaction", skip(self), err)]
+    pub async fn reverse_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        effective: Option<chrono::NaiveDate>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .reverse(
+                sub,
+                id,
+                effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
+            )
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.correct_manual_transaction",
+        skip(self, entries),
+        err
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn correct_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        reference: Option<String>,
+        description: String,
+        effective: Option<chrono::NaiveDate>,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+
+        Ok(self
+            .manual_transactions
+            .correct(
+                sub,
+                &chart,
+                id,
+                reference,
+                description,
+                effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
+                entries,
+            )
+            .await?)
+    }
+
+    /// Closes a period that is in `Closing`. Closing an annual period first moves
+    /// the year's P&L balances into the retained earnings account.
+    #[instrument(name = "core_accounting.close_accounting_period", skip(self), err)]
+    pub async fn close_accounting_period(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        pl_statement_ref: &str,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+        retained_earnings_code: Option<AccountCode>,
+    ) -> Result<AccountingPeriod, CoreAccountingError> {
+        let id = id.into();
+        let period = self
+            .accounting_periods
+            .find_by_id(sub, id)
+            .await?
+            .ok_or(CoreAccountingError::AccountingPeriodNotFound(id))?;
+
+        let closing_transaction_id = if period.is_year_end()
+            && period.status() == AccountingPeriodStatus::Closing
+        {
+            let retained_earnings_code = retained_earnings_code.ok_or(
+                accounting_period_error::AccountingPeriodError::RetainedEarningsAccountRequired(id),
+            )?;
+            self.post_year_end_close(
+                sub,
+                chart_ref,
+                pl_statement_ref,
+                &period,
+                retained_earnings_code,
+            )
+            .await?
+        } else {
+            None
+        };
+
+        Ok(self
+            .accounting_periods
+            .close(sub, id, closing_transaction_id)
+            .await?)
+    }
+
+    async fn post_year_end_close(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        pl_statement_ref: &str,
+        period: &AccountingPeriod,
+        retained_earnings_code: AccountCode,
+    ) -> Result<Option<ManualTransactionId>, CoreAccountingError> {
+        let reference = accounting_period::year_end_close_reference(period);
+        if let Some(existing) = self
+            .manual_transactions
+            .find_by_reference(reference.clone())
+            .await?
+        {
+            return Ok(Some(existing.id));
+        }
+
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+        let pl_account_set_ids = self
+            .profit_and_loss
+            .category_account_set_ids(pl_statement_ref.to_string())
+            .await?;
+        let entries = self
+            .accounting_periods
+            .year_end_closing_entries(period, pl_account_set_ids, &retained_earnings_code)
             .await?;
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let tx = self
+            .manual_transactions
+            .execute_pre_approved(
+                sub,
+                &chart,
+                Some(reference),
+                accounting_period::year_end_close_description(period),
+                period.period_end,
+                entries,
+            )
+            .await?;
+        Ok(Some(tx.id))
+    }
+
+    #[instrument(name = "core_accounting.deactivate_chart_node", skip(self), err)]
+    pub async fn deactivate_chart_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_id: ChartId,
+        code: AccountCode,
+        balance_sheet_ref: &str,
+        pl_statement_ref: &str,
+        cash_flow_ref: &str,
+    ) -> Result<Chart, CoreAccountingError> {
+        let mut referenced_codes = Vec::new();
+        if let Some(config) = self
+            .balance_sheets()
+            .get_chart_of_accounts_integration_config(sub, balance_sheet_ref.to_string())
+            .await?
+            .filter(|config| config.chart_of_accounts_id == chart_id)
+        {
+            referenced_codes.extend([
+                config.chart_of_accounts_assets_code,
+                config.chart_of_accounts_liabilities_code,
+                config.chart_of_accounts_equity_code,
+                config.chart_of_accounts_revenue_code,
+                config.chart_of_accounts_cost_of_revenue_code,
+                config.chart_of_accounts_expenses_code,
+            ]);
+        }
+        if let Some(config) = self
+            .profit_and_loss()
+            .get_chart_of_accounts_integration_config(sub, pl_statement_ref.to_string())
+            .await?
+            .filter(|config| config.chart_of_accounts_id == chart_id)
+        {
+            referenced_codes.extend([
+                config.chart_of_accounts_revenue_code,
+                config.chart_of_accounts_cost_of_revenue_code,
+                config.chart_of_accounts_expenses_code,
+            ]);
+        }
+        if let Some(config) = self
+            .cash_flows()
+            .get_chart_of_accounts_integration_config(sub, cash_flow_ref.to_string())
+            .await?
+            .filter(|config| config.chart_of_accounts_id == chart_id)
+        {
+            referenced_codes.extend(config.chart_of_accounts_cash_codes);
+            referenced_codes.extend(config.chart_of_accounts_operating_codes);
+            referenced_codes.extend(config.chart_of_accounts_investing_codes);
+            referenced_codes.extend(config.chart_of_accounts_financing_codes);
+        }
 
-        let ledger_tx_id = tx.ledger_transaction_id;
-        let mut txs = self.ledger_transactions.find_all(&[ledger_tx_id]).await?;
-        Ok(txs
-            .remove(&ledger_tx_id)
-            .expect("Could not find LedgerTransaction"))
+        Ok(self
+            .chart_of_accounts()
+            .deactivate_node(sub, chart_id, &code, &referenced_codes)
+            .await?)
     }
 
     #[instrument(name = "core_accounting.import_csv", skip(self), err)]
@@ -303,4 +751,25 @@ where
 
         Ok(true)
     }
+
+    #[instrument(name = "core_accounting.import_json", skip(self, data), err)]
+    pub async fn import_json(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_id: ChartId,
+        data: String,
+        trial_balance_ref: &str,
+    ) -> Result<bool, CoreAccountingError> {
+        if let Some(new_account_set_ids) = self
+            .chart_of_accounts()
+            .import_from_json(sub, chart_id, data)
+            .await?
+        {
+            self.trial_balances()
+                .add_new_chart_accounts_to_trial_balance(trial_balance_ref, new_account_set_ids)
+                .await?;
+        }
+
+        Ok(true)
+    }
 }
@@ -0,0 +1,260 @@
+mod job;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use cala_ledger::JournalId;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, Governance, GovernanceAction,
+    GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    accounting_period::{AccountingPeriodLock, error::AccountingPeriodError},
+    primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+};
+
+use super::{
+    entity::{ManualTransaction, NewManualTransaction},
+    error::ManualTransactionError,
+    ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams},
+    repo::ManualTransactionRepo,
+    reversal_description, reversal_reference,
+};
+
+pub use job::*;
+
+pub const APPROVE_MANUAL_TRANSACTION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction");
+
+pub struct ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    repo: ManualTransactionRepo,
+    ledger: ManualTransactionLedger,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+    journal_id: JournalId,
+    period_lock: AccountingPeriodLock,
+}
+
+impl<Perms, E> Clone for ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+            journal_id: self.journal_id,
+            period_lock: self.period_lock.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub(super) fn new(
+        repo: &ManualTransactionRepo,
+        ledger: &ManualTransactionLedger,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+        journal_id: JournalId,
+        period_lock: &AccountingPeriodLock,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+            journal_id,
+            period_lock: period_lock.clone(),
+        }
+    }
+
+    pub async fn execute_from_svc(
+        &self,
+        manual_transaction: &ManualTransaction,
+    ) -> Result<Option<ManualTransaction>, ManualTransactionError> {
+        let Some(approval_process_id) = manual_transaction.approval_process_id else {
+            return Ok(None);
+        };
+        if manual_transaction.is_approved_or_denied().is_some() {
+            return Ok(None);
+        }
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[approval_process_id])
+            .await?
+            .remove(&approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => {
+                Some(self.execute(manual_transaction.id, true).await?)
+            }
+            ApprovalProcessStatus::Denied => {
+                Some(self.execute(manual_transaction.id, false).await?)
+            }
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<ManualTransactionId>,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        if manual_transaction.is_approved_or_denied().is_some() {
+            return Ok(manual_transaction);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+        if manual_transaction
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(manual_transaction);
+        }
+
+        if approved {
+            if let Some(reason) = self.rejection_reason(&mut db, &manual_transaction).await? {
+                let _ = manual_transaction.reject(reason, audit_info);
+            } else {
+                let _ = manual_transaction.posted(audit_info.clone());
+                self.post_in_op(db, &mut manual_transaction, audit_info)
+                    .await?;
+                return Ok(manual_transaction);
+            }
+        }
+
+        self.repo
+            .update_in_op(&mut db, &mut manual_transaction)
+            .await?;
+        db.commit().await?;
+        Ok(manual_transaction)
+    }
+
+    /// Why an approved transaction can no longer be posted, if anything
+    /// changed since it was submitted.
+    async fn rejection_reason(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        manual_transaction: &ManualTransaction,
+    ) -> Result<Option<String>, ManualTransactionError> {
+        let effective = effective_date(manual_transaction);
+        match self
+            .period_lock
+            .ensure_accepts_manual_posting_in_op(db, effective)
+            .await
+        {
+            Err(e @ AccountingPeriodError::PeriodLocked(..)) => return Ok(Some(e.to_string())),
+            Err(e) => return Err(e.into()),
+            Ok(()) => (),
+        }
+
+        if let Some(original_id) = manual_transaction.reverses.or(manual_transaction.corrects) {
+            let original = self.repo.find_by_id(original_id).await?;
+            if let Err(e) = original.ensure_reversible() {
+                return Ok(Some(e.to_string()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Posts the entries of an approved transaction, linking it to the
+    /// transaction it reverses or corrects, and commits `db`.
+    pub(super) async fn post_in_op(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        manual_transaction: &mut ManualTransaction,
+        audit_info: AuditInfo,
+    ) -> Result<(), ManualTransactionError> {
+        let effective = effective_date(manual_transaction);
+        let mut transactions = Vec::new();
+
+        if let Some(original_id) = manual_transaction.corrects {
+            let mut original = self.repo.find_by_id(original_id).await?;
+            let reversal_entries = self
+                .ledger
+                .reversal_entry_params(original.ledger_transaction_id)
+                .await?;
+            let new_reversal = NewManualTransaction::builder()
+                .id(ManualTransactionId::new())
+                .ledger_transaction_id(CalaTxId::new())
+                .description(reversal_description(&original))
+                .reference(Some(reversal_reference(&original)))
+                .reverses(Some(original.id))
+                .effective(effective)
+                .entries(reversal_entries.into_iter().map(Into::into).collect())
+                .audit_info(audit_info.clone())
+                .build()
+                .expect("Couldn't build reversal manual transaction");
+            original.correct(new_reversal.id, manual_transaction.id, audit_info)?;
+
+            let reversal = self.repo.create_in_op(&mut db, new_reversal).await?;
+            self.repo.update_in_op(&mut db, &mut original).await?;
+            transactions.push(self.params_for(&reversal, effective));
+        } else if let Some(original_id) = manual_transaction.reverses {
+            let mut original = self.repo.find_by_id(original_id).await?;
+            original.reverse(manual_transaction.id, audit_info)?;
+            self.repo.update_in_op(&mut db, &mut original).await?;
+        }
+
+        self.repo.update_in_op(&mut db, manual_transaction).await?;
+        transactions.push(self.params_for(manual_transaction, effective));
+
+        self.ledger.execute_all(db, transactions).await
+    }
+
+    fn params_for(
+        &self,
+        manual_transaction: &ManualTransaction,
+        effective: chrono::NaiveDate,
+    ) -> (CalaTxId, ManualTransactionParams) {
+        (
+            manual_transaction.ledger_transaction_id,
+            ManualTransactionParams {
+                journal_id: self.journal_id,
+                description: manual_transaction.description.clone(),
+                entry_params: manual_transaction
+                    .entries
+                    .iter()
+                    .map(EntryParams::from)
+                    .collect(),
+                effective,
+            },
+        )
+    }
+}
+
+fn effective_date(manual_transaction: &ManualTransaction) -> chrono::NaiveDate {
+    manual_transaction
+        .effective
+        .unwrap_or_else(|| manual_transaction.created_at().date_naive())
+}
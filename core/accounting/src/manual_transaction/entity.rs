@@ -5,8 +5,14 @@ use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
 use es_entity::*;
+use governance::ApprovalProcessId;
 
-use crate::primitives::{CalaTxId, ManualTransactionId};
+use crate::primitives::{CalaTxId, ManualTransactionId, RecurringEntryTemplateId};
+
+use super::{
+    error::ManualTransactionError,
+    primitives::{ManualTransactionEntry, ManualTransactionStatus},
+};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -18,6 +24,38 @@ pub enum ManualTransactionEvent {
         ledger_transaction_id: CalaTxId,
         description: String,
         reference: String,
+        #[serde(default)]
+        reverses: Option<ManualTransactionId>,
+        #[serde(default)]
+        corrects: Option<ManualTransactionId>,
+        #[serde(default)]
+        effective: Option<chrono::NaiveDate>,
+        #[serde(default)]
+        entries: Vec<ManualTransactionEntry>,
+        #[serde(default)]
+        approval_process_id: Option<ApprovalProcessId>,
+        #[serde(default)]
+        recurring_entry_template_id: Option<RecurringEntryTemplateId>,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Posted {
+        audit_info: AuditInfo,
+    },
+    Rejected {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Reversed {
+        reversal_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    },
+    Corrected {
+        correction_transaction_id: ManualTransactionId,
         audit_info: AuditInfo,
     },
 }
@@ -29,6 +67,22 @@ pub struct ManualTransaction {
     pub reference: String,
     pub description: String,
     pub ledger_transaction_id: CalaTxId,
+    /// Original transaction this one is the mirror image of.
+    #[builder(default)]
+    pub reverses: Option<ManualTransactionId>,
+    /// Original transaction whose entries this one re-posts with edits.
+    #[builder(default)]
+    pub corrects: Option<ManualTransactionId>,
+    #[builder(default)]
+    pub effective: Option<chrono::NaiveDate>,
+    #[builder(default)]
+    pub entries: Vec<ManualTransactionEntry>,
+    /// `None` for transactions that were posted without going through approval.
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    /// Recurring entry template this transaction was posted from.
+    #[builder(default)]
+    pub recurring_entry_template_id: Option<RecurringEntryTemplateId>,
     events: EntityEvents<ManualTransactionEvent>,
 }
 
@@ -38,6 +92,158 @@ impl ManualTransaction {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn status(&self) -> ManualTransactionStatus {
+        let initial = if self.approval_process_id.is_some() {
+            ManualTransactionStatus::PendingApproval
+        } else {
+            ManualTransactionStatus::Posted
+        };
+        self.events
+            .iter_all()
+            .fold(initial, |status, event| match event {
+                ManualTransactionEvent::ApprovalProcessConcluded {
+                    approved: false, ..
+                } => ManualTransactionStatus::Denied,
+                ManualTransactionEvent::Posted { .. } => ManualTransactionStatus::Posted,
+                ManualTransactionEvent::Rejected { .. } => ManualTransactionStatus::Rejected,
+                _ => status,
+            })
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|event| match event {
+            ManualTransactionEvent::ApprovalProcessConcluded { approved, .. } => Some(*approved),
+            _ => None,
+        })
+    }
+
+    pub fn rejection_reason(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|event| match event {
+            ManualTransactionEvent::Rejected { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub(super) fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        let Some(approval_process_id) = self.approval_process_id else {
+            return Idempotent::Ignored;
+        };
+        idempotency_guard!(
+            self.events.iter_all(),
+            ManualTransactionEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events
+            .push(ManualTransactionEvent::ApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn posted(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        if self.status() != ManualTransactionStatus::PendingApproval
+            || self.is_approved_or_denied() != Some(true)
+        {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(ManualTransactionEvent::Posted { audit_info });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn reject(&mut self, reason: String, audit_info: AuditInfo) -> Idempotent<()> {
+        if self.status() != ManualTransactionStatus::PendingApproval {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(ManualTransactionEvent::Rejected { reason, audit_info });
+        Idempotent::Executed(())
+    }
+
+    /// Checks that a reversal or correction of this transaction may be submitted.
+    pub(super) fn ensure_reversible(&self) -> Result<(), ManualTransactionError> {
+        if self.status() != ManualTransactionStatus::Posted {
+            return Err(ManualTransactionError::NotPosted(self.id));
+        }
+        if self.is_reversed() {
+            return Err(ManualTransactionError::AlreadyReversed(self.id));
+        }
+        if self.reverses.is_some() {
+            return Err(ManualTransactionError::CannotReverseReversal(self.id));
+        }
+        Ok(())
+    }
+
+    pub fn reversed_by(&self) -> Option<ManualTransactionId> {
+        self.events.iter_all().find_map(|event| match event {
+            ManualTransactionEvent::Reversed {
+                reversal_transaction_id,
+                ..
+            } => Some(*reversal_transaction_id),
+            _ => None,
+        })
+    }
+
+    pub fn corrected_by(&self) -> Option<ManualTransactionId> {
+        self.events.iter_all().find_map(|event| match event {
+            ManualTransactionEvent::Corrected {
+                correction_transaction_id,
+                ..
+            } => Some(*correction_transaction_id),
+            _ => None,
+        })
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed_by().is_some()
+    }
+
+    pub(super) fn reverse(
+        &mut self,
+        reversal_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ManualTransactionError> {
+        match self.reversed_by() {
+            Some(id) if id == reversal_transaction_id => return Ok(Idempotent::Ignored),
+            Some(_) => return Err(ManualTransactionError::AlreadyReversed(self.id)),
+            None => (),
+        }
+        if self.reverses.is_some() {
+            return Err(ManualTransactionError::CannotReverseReversal(self.id));
+        }
+        self.events.push(ManualTransactionEvent::Reversed {
+            reversal_transaction_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Reverses the transaction and links the transaction re-posting the
+    /// corrected entries.
+    pub(super) fn correct(
+        &mut self,
+        reversal_transaction_id: ManualTransactionId,
+        correction_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ManualTransactionError> {
+        if self
+            .reverse(reversal_transaction_id, audit_info.clone())?
+            .was_ignored()
+        {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events.push(ManualTransactionEvent::Corrected {
+            correction_transaction_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
 }
 
 impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
@@ -52,6 +258,12 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                     reference,
                     description,
                     ledger_transaction_id,
+                    reverses,
+                    corrects,
+                    effective,
+                    entries,
+                    approval_process_id,
+                    recurring_entry_template_id,
                     ..
                 } => {
                     builder = builder
@@ -59,7 +271,18 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                         .reference(reference.clone())
                         .description(description.clone())
                         .ledger_transaction_id(*ledger_transaction_id)
+                        .reverses(*reverses)
+                        .corrects(*corrects)
+                        .effective(*effective)
+                        .entries(entries.clone())
+                        .approval_process_id(*approval_process_id)
+                        .recurring_entry_template_id(*recurring_entry_template_id)
                 }
+                ManualTransactionEvent::ApprovalProcessConcluded { .. } => (),
+                ManualTransactionEvent::Posted { .. } => (),
+                ManualTransactionEvent::Rejected { .. } => (),
+                ManualTransactionEvent::Reversed { .. } => (),
+                ManualTransactionEvent::Corrected { .. } => (),
             }
         }
         builder.events(events).build()
@@ -73,6 +296,17 @@ pub struct NewManualTransaction {
     reference: Option<String>,
     pub(super) ledger_transaction_id: CalaTxId,
     description: String,
+    #[builder(default)]
+    reverses: Option<ManualTransactionId>,
+    #[builder(default)]
+    corrects: Option<ManualTransactionId>,
+    pub(super) effective: chrono::NaiveDate,
+    #[builder(default)]
+    pub(super) entries: Vec<ManualTransactionEntry>,
+    #[builder(default)]
+    approval_process_id: Option<ApprovalProcessId>,
+    #[builder(default)]
+    recurring_entry_template_id: Option<RecurringEntryTemplateId>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -100,8 +334,150 @@ impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
                 id: self.id,
                 ledger_transaction_id: self.ledger_transaction_id,
                 description: self.description,
+                reverses: self.reverses,
+                corrects: self.corrects,
+                effective: Some(self.effective),
+                entries: self.entries,
+                approval_process_id: self.approval_process_id,
+                recurring_entry_template_id: self.recurring_entry_template_id,
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_transaction(
+        reverses: Option<ManualTransactionId>,
+        requires_approval: bool,
+    ) -> ManualTransaction {
+        let id = ManualTransactionId::new();
+        let new_tx = NewManualTransaction::builder()
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description("description".to_string())
+            .reference(None)
+            .reverses(reverses)
+            .effective(chrono::Utc::now().date_naive())
+            .approval_process_id(requires_approval.then(|| id.into()))
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    fn transaction(reverses: Option<ManualTransactionId>) -> ManualTransaction {
+        new_transaction(reverses, false)
+    }
+
+    #[test]
+    fn pending_until_approval_concludes() {
+        let mut tx = new_transaction(None, true);
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+        assert!(tx.posted(dummy_audit_info()).was_ignored());
+
+        assert!(
+            tx.approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(
+            tx.approval_process_concluded(true, dummy_audit_info())
+                .was_ignored()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+
+        assert!(tx.posted(dummy_audit_info()).did_execute());
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+    }
+
+    #[test]
+    fn denied_transaction_is_not_posted() {
+        let mut tx = new_transaction(None, true);
+        let _ = tx.approval_process_concluded(false, dummy_audit_info());
+
+        assert_eq!(tx.status(), ManualTransactionStatus::Denied);
+        assert!(tx.posted(dummy_audit_info()).was_ignored());
+        assert!(matches!(
+            tx.ensure_reversible(),
+            Err(ManualTransactionError::NotPosted(_))
+        ));
+    }
+
+    #[test]
+    fn approved_transaction_can_be_rejected() {
+        let mut tx = new_transaction(None, true);
+        let _ = tx.approval_process_concluded(true, dummy_audit_info());
+
+        assert!(
+            tx.reject("period closed".to_string(), dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Rejected);
+        assert_eq!(tx.rejection_reason(), Some("period closed"));
+    }
+
+    #[test]
+    fn auto_approved_transaction_is_posted() {
+        let tx = transaction(None);
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+        assert!(tx.ensure_reversible().is_ok());
+    }
+
+    #[test]
+    fn can_only_be_reversed_once() {
+        let mut tx = transaction(None);
+        let reversal_id = ManualTransactionId::new();
+
+        assert!(
+            tx.reverse(reversal_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(tx.reversed_by(), Some(reversal_id));
+        assert!(
+            tx.reverse(reversal_id, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(matches!(
+            tx.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::AlreadyReversed(_))
+        ));
+    }
+
+    #[test]
+    fn reversal_cannot_be_reversed() {
+        let mut reversal = transaction(Some(ManualTransactionId::new()));
+        assert!(matches!(
+            reversal.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::CannotReverseReversal(_))
+        ));
+    }
+
+    #[test]
+    fn correction_records_reversal_and_correction() {
+        let mut tx = transaction(None);
+        let reversal_id = ManualTransactionId::new();
+        let correction_id = ManualTransactionId::new();
+
+        assert!(
+            tx.correct(reversal_id, correction_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(tx.reversed_by(), Some(reversal_id));
+        assert_eq!(tx.corrected_by(), Some(correction_id));
+    }
+}
@@ -18,8 +18,42 @@ pub enum ManualTransactionError {
     TxTemplateError(#[from] cala_ledger::tx_template::error::TxTemplateError),
     #[error("ManualTransactionError - AuthorizationError: {0}")]
     AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ManualTransactionError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] crate::accounting_period::error::AccountingPeriodError),
+    #[error("ManualTransactionError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("ManualTransactionError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("ManualTransactionError - ConversionError: {0}")]
+    ConversionError(#[from] core_money::ConversionError),
+    #[error("ManualTransactionError - AmountTiersRequireUsd: {0}")]
+    AmountTiersRequireUsd(cala_ledger::Currency),
     #[error("ManualTransactionError - Unknown account code: {0}")]
     UnknownAccountCode(String),
+    #[error("ManualTransactionError - AlreadyReversed: {0}")]
+    AlreadyReversed(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - CannotReverseReversal: {0}")]
+    CannotReverseReversal(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - NotPosted: {0}")]
+    NotPosted(crate::primitives::ManualTransactionId),
+    #[error("ManualTransactionError - CalaEntryError: {0}")]
+    EntryError(#[from] cala_ledger::entry::error::EntryError),
+}
+
+impl ManualTransactionError {
+    /// Whether the transaction was refused for its content rather than because
+    /// of the infrastructure recording it, so submitting it again cannot succeed.
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self,
+            Self::UnknownAccountCode(_)
+                | Self::AmountTiersRequireUsd(_)
+                | Self::ConversionError(_)
+                | Self::AccountingPeriodError(
+                    crate::accounting_period::error::AccountingPeriodError::PeriodLocked(..)
+                )
+        )
+    }
 }
 
 es_entity::from_es_entity_error!(ManualTransactionError);
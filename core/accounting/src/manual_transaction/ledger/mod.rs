@@ -1,17 +1,44 @@
 mod template;
 
-use cala_ledger::{AccountId, AccountSetId, CalaLedger, account::NewAccount};
+use cala_ledger::{AccountId, AccountSetId, CalaLedger, DebitOrCredit, account::NewAccount};
 
 use crate::{
     Chart,
     primitives::{AccountCode, CalaTxId},
 };
 
-use super::{error::ManualTransactionError, primitives::AccountIdOrCode};
+use super::{
+    error::ManualTransactionError,
+    primitives::{AccountIdOrCode, ManualTransactionEntry},
+};
 
 use template::*;
 pub use template::{EntryParams, ManualTransactionParams};
 
+impl From<&ManualTransactionEntry> for EntryParams {
+    fn from(entry: &ManualTransactionEntry) -> Self {
+        Self {
+            account_id: entry.account_id.into(),
+            currency: entry.currency,
+            amount: entry.amount,
+            description: entry.description.clone(),
+            direction: entry.direction,
+        }
+    }
+}
+
+impl From<EntryParams> for ManualTransactionEntry {
+    fn from(params: EntryParams) -> Self {
+        Self {
+            account_id: params.account_id.into(),
+            currency: params.currency,
+            amount: params.amount,
+            description: params.description,
+            direction: params.direction,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ManualTransactionLedger {
     cala: CalaLedger,
@@ -27,21 +54,57 @@ impl ManualTransactionLedger {
         op: es_entity::DbOp<'_>,
         tx_id: CalaTxId,
         params: ManualTransactionParams,
+    ) -> Result<(), ManualTransactionError> {
+        self.execute_all(op, vec![(tx_id, params)]).await
+    }
+
+    /// Posts all transactions in the same operation so that a correction's
+    /// reversal and re-posting either both land or neither does.
+    pub async fn execute_all(
+        &self,
+        op: es_entity::DbOp<'_>,
+        transactions: Vec<(CalaTxId, ManualTransactionParams)>,
     ) -> Result<(), ManualTransactionError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
-        let template =
-            ManualTransactionTemplate::init(&self.cala, params.entry_params.len()).await?;
+        for (tx_id, params) in transactions {
+            let template =
+                ManualTransactionTemplate::init(&self.cala, params.entry_params.len()).await?;
 
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, &template.code(), params)
-            .await?;
+            self.cala
+                .post_transaction_in_op(&mut op, tx_id, &template.code(), params)
+                .await?;
+        }
 
         op.commit().await?;
 
         Ok(())
     }
 
+    /// Mirror image of the entries posted by `tx_id`.
+    pub async fn reversal_entry_params(
+        &self,
+        tx_id: CalaTxId,
+    ) -> Result<Vec<EntryParams>, ManualTransactionError> {
+        let entries = self.cala.entries().list_for_transaction_id(tx_id).await?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let values = entry.into_values();
+                EntryParams {
+                    account_id: values.account_id,
+                    currency: values.currency,
+                    amount: values.units,
+                    description: format!("Reversal: {}", values.description.unwrap_or_default()),
+                    direction: match values.direction {
+                        DebitOrCredit::Debit => DebitOrCredit::Credit,
+                        DebitOrCredit::Credit => DebitOrCredit::Debit,
+                    },
+                }
+            })
+            .collect())
+    }
+
     pub async fn resolve_account_id(
         &self,
         chart: &Chart,
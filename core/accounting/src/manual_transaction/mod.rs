@@ -1,3 +1,4 @@
+mod approval;
 mod entity;
 pub mod error;
 mod ledger;
@@ -10,15 +11,27 @@ use std::collections::HashMap;
 
 use audit::AuditSvc;
 use authz::PermissionCheck;
-use cala_ledger::{CalaLedger, JournalId};
-use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
+use cala_ledger::{CalaLedger, Currency, DebitOrCredit, JournalId};
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use ledger::ManualTransactionLedger;
+use outbox::OutboxEventMarker;
+use rust_decimal::Decimal;
 
 use crate::{
     Chart,
-    primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+    accounting_period::AccountingPeriodLock,
+    primitives::{
+        ApprovalProcessId, CalaTxId, CoreAccountingAction, CoreAccountingObject,
+        ManualTransactionId, RecurringEntryTemplateId, UsdCents,
+    },
 };
 use error::*;
 
+pub use approval::{
+    APPROVE_MANUAL_TRANSACTION_PROCESS, ApproveManualTransaction,
+    ManualTransactionApprovalJobConfig, ManualTransactionApprovalJobInitializer,
+};
+
 pub use entity::ManualTransaction;
 #[cfg(feature = "json-schema")]
 pub use entity::ManualTransactionEvent;
@@ -27,38 +40,77 @@ pub use primitives::*;
 pub use repo::manual_transaction_cursor::ManualTransactionsByCreatedAtCursor;
 use repo::*;
 
-#[derive(Clone)]
-pub struct ManualTransactions<Perms>
+pub struct ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     ledger: ManualTransactionLedger,
     authz: Perms,
-    journal_id: JournalId,
     repo: ManualTransactionRepo,
+    period_lock: AccountingPeriodLock,
+    governance: Governance<Perms, E>,
+    approve_manual_transaction: ApproveManualTransaction<Perms, E>,
+}
+
+impl<Perms, E> Clone for ManualTransactions<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            period_lock: self.period_lock.clone(),
+            governance: self.governance.clone(),
+            approve_manual_transaction: self.approve_manual_transaction.clone(),
+        }
+    }
 }
 
-impl<Perms> ManualTransactions<Perms>
+impl<Perms, E> ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     pub fn new(
         pool: &sqlx::PgPool,
         authz: &Perms,
         cala: &CalaLedger,
         journal_id: JournalId,
+        period_lock: &AccountingPeriodLock,
+        governance: &Governance<Perms, E>,
     ) -> Self {
         let repo = ManualTransactionRepo::new(pool);
+        let ledger = ManualTransactionLedger::new(cala);
+        let approve_manual_transaction = ApproveManualTransaction::new(
+            &repo,
+            &ledger,
+            authz.audit(),
+            governance,
+            journal_id,
+            period_lock,
+        );
         Self {
-            ledger: ManualTransactionLedger::new(cala),
+            ledger,
             authz: authz.clone(),
-            journal_id,
             repo,
+            period_lock: period_lock.clone(),
+            governance: governance.clone(),
+            approve_manual_transaction,
         }
     }
 
+    pub fn approve_manual_transaction(&self) -> &ApproveManualTransaction<Perms, E> {
+        &self.approve_manual_transaction
+    }
+
     #[instrument(
         name = "core_accounting.manual_transaction.find_by_id",
         skip(self),
@@ -115,6 +167,24 @@ where
         self.repo.find_all(ids).await
     }
 
+    pub(crate) async fn find_by_reference(
+        &self,
+        reference: String,
+    ) -> Result<Option<ManualTransaction>, ManualTransactionError> {
+        match self.repo.find_by_reference(reference).await {
+            Ok(tx) => Ok(Some(tx)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Submits a manual transaction for approval. It is posted once the
+    /// approval process concludes, or right away if it is auto-approved.
+    #[instrument(
+        name = "core_accounting.manual_transaction.execute",
+        skip(self, chart, entries),
+        err
+    )]
     pub async fn execute(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -133,49 +203,409 @@ where
             )
             .await?;
 
-        let ledger_tx_id = CalaTxId::new();
-        let manual_tx_id = ManualTransactionId::new();
+        let entries = self.resolve_entries(chart, entries).await?;
+        let id = ManualTransactionId::new();
+        let new_tx = NewManualTransaction::builder()
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
+            .reference(reference)
+            .effective(effective)
+            .approval_process_id(Some(id.into()))
+            .entries(entries)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build new manual transaction");
+
+        self.submit(new_tx).await
+    }
+
+    /// Posts a system generated transaction, such as a year-end close,
+    /// without going through approval.
+    pub(crate) async fn execute_pre_approved(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.submit_entries(
+            sub,
+            chart,
+            reference,
+            description,
+            effective,
+            entries,
+            None,
+            false,
+        )
+        .await
+    }
+
+    /// Submits a run of a recurring entry template for approval like any
+    /// other manual transaction. The run is posted once approved.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn execute_recurring(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        template_id: RecurringEntryTemplateId,
+        reference: String,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.submit_entries(
+            sub,
+            chart,
+            Some(reference),
+            description,
+            effective,
+            entries,
+            Some(template_id),
+            true,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn submit_entries(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+        recurring_entry_template_id: Option<RecurringEntryTemplateId>,
+        requires_approval: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_CREATE,
+            )
+            .await?;
 
+        let id = ManualTransactionId::new();
         let new_tx = NewManualTransaction::builder()
-            .id(manual_tx_id)
-            .ledger_transaction_id(ledger_tx_id)
-            .description(description.clone())
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
             .reference(reference)
+            .effective(effective)
+            .approval_process_id(requires_approval.then(|| id.into()))
+            .entries(self.resolve_entries(chart, entries).await?)
+            .recurring_entry_template_id(recurring_entry_template_id)
             .audit_info(audit_info)
             .build()
             .expect("Couldn't build new manual transaction");
 
+        self.submit(new_tx).await
+    }
+
+    /// Submits the mirror image of a posted transaction for approval. The two
+    /// are linked once the reversal is posted.
+    #[instrument(name = "core_accounting.manual_transaction.reverse", skip(self), err)]
+    pub async fn reverse(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        effective: chrono::NaiveDate,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CREATE,
+            )
+            .await?;
+
+        let original = self.repo.find_by_id(id).await?;
+        original.ensure_reversible()?;
+
+        let new_reversal = self
+            .new_reversal(&original, None, effective, audit_info, true)
+            .await?;
+
+        self.submit(new_reversal).await
+    }
+
+    /// Posts the mirror image of a system generated transaction, such as the
+    /// auto-reversal of a recurring accrual, without going through approval.
+    pub(crate) async fn reverse_pre_approved(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: ManualTransactionId,
+        effective: chrono::NaiveDate,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CREATE,
+            )
+            .await?;
+
+        let original = self.repo.find_by_id(id).await?;
+        original.ensure_reversible()?;
+
+        let new_reversal = self
+            .new_reversal(
+                &original,
+                Some(reversal_reference(&original)),
+                effective,
+                audit_info,
+                false,
+            )
+            .await?;
+
+        self.submit(new_reversal).await
+    }
+
+    async fn new_reversal(
+        &self,
+        original: &ManualTransaction,
+        reference: Option<String>,
+        effective: chrono::NaiveDate,
+        audit_info: audit::AuditInfo,
+        requires_approval: bool,
+    ) -> Result<NewManualTransaction, ManualTransactionError> {
+        let entries: Vec<ManualTransactionEntry> = self
+            .ledger
+            .reversal_entry_params(original.ledger_transaction_id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let reversal_id = ManualTransactionId::new();
+        let approval_process_id: Option<ApprovalProcessId> =
+            requires_approval.then(|| reversal_id.into());
+        Ok(NewManualTransaction::builder()
+            .id(reversal_id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(reversal_description(original))
+            .reference(reference)
+            .reverses(Some(original.id))
+            .effective(effective)
+            .approval_process_id(approval_process_id)
+            .entries(entries)
+            .recurring_entry_template_id(original.recurring_entry_template_id)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build reversal manual transaction"))
+    }
+
+    /// Submits edited entries replacing a posted transaction for approval.
+    /// Once approved the original is reversed and the edited entries are
+    /// posted in one step.
+    #[instrument(
+        name = "core_accounting.manual_transaction.correct",
+        skip(self, chart, entries),
+        err
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn correct(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CREATE,
+            )
+            .await?;
+
+        let original = self.repo.find_by_id(id).await?;
+        original.ensure_reversible()?;
+
+        let entries = self.resolve_entries(chart, entries).await?;
+        let correction_id = ManualTransactionId::new();
+        let new_correction = NewManualTransaction::builder()
+            .id(correction_id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
+            .reference(reference)
+            .corrects(Some(original.id))
+            .effective(effective)
+            .approval_process_id(Some(correction_id.into()))
+            .entries(entries)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build correction manual transaction");
+
+        self.submit(new_correction).await
+    }
+
+    /// Reversal and correction links of the manual transaction that posted
+    /// `ledger_transaction_id`, resolved to ledger transactions.
+    #[instrument(
+        name = "core_accounting.manual_transaction.links_for_ledger_transaction",
+        skip(self),
+        err
+    )]
+    pub async fn links_for_ledger_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_transaction_id: CalaTxId,
+    ) -> Result<Option<ManualTransactionLinks>, ManualTransactionError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_LIST,
+            )
+            .await?;
+
+        let tx = match self
+            .repo
+            .find_by_ledger_transaction_id(ledger_transaction_id)
+            .await
+        {
+            Ok(tx) => tx,
+            Err(e) if e.was_not_found() => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let linked_ids: Vec<_> = [
+            tx.reverses,
+            tx.corrects,
+            tx.reversed_by(),
+            tx.corrected_by(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let linked: HashMap<ManualTransactionId, ManualTransaction> =
+            self.repo.find_all(&linked_ids).await?;
+        let ledger_tx_id = |id: Option<ManualTransactionId>| {
+            id.and_then(|id| linked.get(&id))
+                .map(|linked| linked.ledger_transaction_id)
+        };
+
+        Ok(Some(ManualTransactionLinks {
+            manual_transaction_id: tx.id,
+            reversal_of: ledger_tx_id(tx.reverses),
+            correction_of: ledger_tx_id(tx.corrects),
+            reversed_by: ledger_tx_id(tx.reversed_by()),
+            corrected_by: ledger_tx_id(tx.corrected_by()),
+        }))
+    }
+
+    async fn submit(
+        &self,
+        new_tx: NewManualTransaction,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let audit_info = new_tx.audit_info.clone();
         let mut db = self.repo.begin_op().await?;
-        let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+        self.period_lock
+            .ensure_accepts_manual_posting_in_op(&mut db, new_tx.effective)
+            .await?;
+        let mut manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+
+        match manual_transaction.approval_process_id {
+            Some(approval_process_id) => {
+                let amount = self.policy_amount(&manual_transaction.entries).await?;
+                self.governance
+                    .start_process(
+                        &mut db,
+                        approval_process_id,
+                        manual_transaction.id.to_string(),
+                        APPROVE_MANUAL_TRANSACTION_PROCESS,
+                        amount,
+                        Some(&audit_info),
+                    )
+                    .await?;
+                db.commit().await?;
+
+                if let Some(concluded) = self
+                    .approve_manual_transaction
+                    .execute_from_svc(&manual_transaction)
+                    .await?
+                {
+                    manual_transaction = concluded;
+                }
+            }
+            None => {
+                self.approve_manual_transaction
+                    .post_in_op(db, &mut manual_transaction, audit_info)
+                    .await?;
+            }
+        }
 
-        let mut entry_params = vec![];
+        Ok(manual_transaction)
+    }
+
+    /// Total debits matched against the approval policy's amount tiers.
+    /// Tiers are denominated in USD so entries in other currencies are only
+    /// accepted when the policy does not use them.
+    async fn policy_amount(
+        &self,
+        entries: &[ManualTransactionEntry],
+    ) -> Result<Option<UsdCents>, ManualTransactionError> {
+        if let Some(entry) = entries.iter().find(|e| e.currency != Currency::USD) {
+            if self
+                .governance
+                .policy_has_amount_tiers(APPROVE_MANUAL_TRANSACTION_PROCESS)
+                .await?
+            {
+                return Err(ManualTransactionError::AmountTiersRequireUsd(
+                    entry.currency,
+                ));
+            }
+            return Ok(None);
+        }
+        let debits: Decimal = entries
+            .iter()
+            .filter(|e| e.direction == DebitOrCredit::Debit)
+            .map(|e| e.amount)
+            .sum();
+        Ok(Some(UsdCents::try_from_usd(debits)?))
+    }
+
+    pub(crate) async fn resolve_entries(
+        &self,
+        chart: &Chart,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<Vec<ManualTransactionEntry>, ManualTransactionError> {
+        let mut resolved = vec![];
         for e in entries {
             let account_id = self
                 .ledger
                 .resolve_account_id(chart, &e.account_id_or_code)
                 .await?;
-            entry_params.push(EntryParams {
-                account_id,
+            resolved.push(ManualTransactionEntry {
+                account_id: account_id.into(),
                 amount: e.amount,
                 currency: e.currency,
                 direction: e.direction,
                 description: e.description,
             });
         }
+        Ok(resolved)
+    }
+}
 
-        self.ledger
-            .execute(
-                db,
-                ledger_tx_id,
-                ManualTransactionParams {
-                    journal_id: self.journal_id,
-                    description,
-                    entry_params,
-                    effective,
-                },
-            )
-            .await?;
+fn reversal_description(original: &ManualTransaction) -> String {
+    format!("Reversal of {}", original.description)
+}
 
-        Ok(manual_transaction)
-    }
+fn reversal_reference(original: &ManualTransaction) -> String {
+    format!("reversal:{}", original.id)
 }
@@ -1,9 +1,12 @@
 use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use cala_ledger::{Currency, DebitOrCredit};
 use rust_decimal::Decimal;
 
-use crate::primitives::{AccountCode, LedgerAccountId};
+use crate::primitives::{AccountCode, LedgerAccountId, LedgerTransactionId, ManualTransactionId};
 
 pub use cala_ledger::TransactionId as CalaTransactionId;
 
@@ -40,3 +43,50 @@ impl ManualEntryInput {
         ManualEntryInputBuilder::default()
     }
 }
+
+impl From<ManualTransactionEntry> for ManualEntryInput {
+    fn from(entry: ManualTransactionEntry) -> Self {
+        Self {
+            account_id_or_code: AccountIdOrCode::Id(entry.account_id),
+            amount: entry.amount,
+            currency: entry.currency,
+            description: entry.description,
+            direction: entry.direction,
+        }
+    }
+}
+
+/// Entry of a manual transaction with its account resolved, as submitted for
+/// approval and later posted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ManualTransactionEntry {
+    pub account_id: LedgerAccountId,
+    pub amount: Decimal,
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub currency: Currency,
+    pub direction: DebitOrCredit,
+    pub description: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ManualTransactionStatus {
+    PendingApproval,
+    Posted,
+    Denied,
+    /// Approved, but could not be posted, e.g. because its period was closed
+    /// in the meantime.
+    Rejected,
+}
+
+/// How a manual transaction relates to the transactions it reversed or
+/// corrected, expressed as the ledger transactions they posted.
+#[derive(Clone, Debug)]
+pub struct ManualTransactionLinks {
+    pub manual_transaction_id: ManualTransactionId,
+    pub reversal_of: Option<LedgerTransactionId>,
+    pub correction_of: Option<LedgerTransactionId>,
+    pub reversed_by: Option<LedgerTransactionId>,
+    pub corrected_by: Option<LedgerTransactionId>,
+}
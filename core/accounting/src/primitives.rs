@@ -17,17 +17,22 @@ pub use cala_ledger::{
     },
 };
 
-pub use core_money::{Satoshis, UsdCents};
+pub use core_money::{CurrencyCode, Satoshis, SignedUsdCents, UsdCents};
+pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 
 es_entity::entity_id! {
     ChartId,
     ManualTransactionId,
     LedgerAccountId,
-    AccountingCsvId;
+    AccountingCsvId,
+    AccountingPeriodId,
+    RecurringEntryTemplateId,
+    FixedAssetId;
 
     LedgerAccountId => CalaAccountId,
     LedgerAccountId => CalaAccountSetId,
     AccountingCsvId => job::JobId,
+    ManualTransactionId => governance::ApprovalProcessId,
 }
 
 impl From<cala_ledger::account_set::AccountSetMemberId> for LedgerAccountId {
@@ -280,7 +285,12 @@ pub type ProfitAndLossAllOrOne = AllOrOne<LedgerAccountId>;
 pub type ProfitAndLossConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
+pub type CashFlowAllOrOne = AllOrOne<LedgerAccountId>;
+pub type CashFlowConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type AccountingCsvAllOrOne = AllOrOne<AccountingCsvId>;
+pub type AccountingPeriodAllOrOne = AllOrOne<AccountingPeriodId>;
+pub type RecurringEntryTemplateAllOrOne = AllOrOne<RecurringEntryTemplateId>;
+pub type FixedAssetAllOrOne = AllOrOne<FixedAssetId>;
 pub type TrialBalanceAllOrOne = AllOrOne<LedgerAccountId>; // what to do if there is only All
 // option
 
@@ -301,8 +311,13 @@ pub enum CoreAccountingAction {
     ProfitAndLossConfiguration(ProfitAndLossConfigurationAction),
     BalanceSheet(BalanceSheetAction),
     BalanceSheetConfiguration(BalanceSheetConfigurationAction),
+    CashFlow(CashFlowAction),
+    CashFlowConfiguration(CashFlowConfigurationAction),
     AccountingCsv(AccountingCsvAction),
     TrialBalance(TrialBalanceAction),
+    AccountingPeriod(AccountingPeriodAction),
+    RecurringEntryTemplate(RecurringEntryTemplateAction),
+    FixedAsset(FixedAssetAction),
 }
 
 impl CoreAccountingAction {
@@ -326,8 +341,13 @@ impl CoreAccountingAction {
                 D::ProfitAndLossConfiguration => ProfitAndLossConfigurationAction::describe(),
                 D::BalanceSheet => BalanceSheetAction::describe(),
                 D::BalanceSheetConfiguration => BalanceSheetConfigurationAction::describe(),
+                D::CashFlow => CashFlowAction::describe(),
+                D::CashFlowConfiguration => CashFlowConfigurationAction::describe(),
                 D::AccountingCsv => AccountingCsvAction::describe(),
                 D::TrialBalance => TrialBalanceAction::describe(),
+                D::AccountingPeriod => AccountingPeriodAction::describe(),
+                D::RecurringEntryTemplate => RecurringEntryTemplateAction::describe(),
+                D::FixedAsset => FixedAssetAction::describe(),
             };
 
             result.push((*entity, actions));
@@ -350,8 +370,13 @@ pub enum CoreAccountingObject {
     ProfitAndLossConfiguration(ProfitAndLossConfigurationAllOrOne),
     BalanceSheet(BalanceSheetAllOrOne),
     BalanceSheetConfiguration(BalanceSheetConfigurationAllOrOne),
+    CashFlow(CashFlowAllOrOne),
+    CashFlowConfiguration(CashFlowConfigurationAllOrOne),
     AccountingCsv(AccountingCsvAllOrOne),
     TrialBalance(TrialBalanceAllOrOne),
+    AccountingPeriod(AccountingPeriodAllOrOne),
+    RecurringEntryTemplate(RecurringEntryTemplateAllOrOne),
+    FixedAsset(FixedAssetAllOrOne),
 }
 
 impl CoreAccountingObject {
@@ -422,6 +447,14 @@ impl CoreAccountingObject {
     pub fn all_balance_sheet_configuration() -> Self {
         CoreAccountingObject::BalanceSheetConfiguration(AllOrOne::All)
     }
+
+    pub fn all_cash_flow() -> Self {
+        CoreAccountingObject::CashFlow(AllOrOne::All)
+    }
+
+    pub fn all_cash_flow_configuration() -> Self {
+        CoreAccountingObject::CashFlowConfiguration(AllOrOne::All)
+    }
     pub fn accounting_csv(id: AccountingCsvId) -> Self {
         CoreAccountingObject::AccountingCsv(AllOrOne::ById(id))
     }
@@ -433,6 +466,30 @@ impl CoreAccountingObject {
     pub fn all_trial_balance() -> Self {
         CoreAccountingObject::TrialBalance(AllOrOne::All)
     }
+
+    pub fn accounting_period(id: AccountingPeriodId) -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::ById(id))
+    }
+
+    pub fn all_accounting_periods() -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::All)
+    }
+
+    pub fn recurring_entry_template(id: RecurringEntryTemplateId) -> Self {
+        CoreAccountingObject::RecurringEntryTemplate(AllOrOne::ById(id))
+    }
+
+    pub fn all_recurring_entry_templates() -> Self {
+        CoreAccountingObject::RecurringEntryTemplate(AllOrOne::All)
+    }
+
+    pub fn fixed_asset(id: FixedAssetId) -> Self {
+        CoreAccountingObject::FixedAsset(AllOrOne::ById(id))
+    }
+
+    pub fn all_fixed_assets() -> Self {
+        CoreAccountingObject::FixedAsset(AllOrOne::All)
+    }
 }
 
 impl Display for CoreAccountingObject {
@@ -450,8 +507,13 @@ impl Display for CoreAccountingObject {
             ProfitAndLossConfiguration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             BalanceSheet(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             BalanceSheetConfiguration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            CashFlow(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            CashFlowConfiguration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             AccountingCsv(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             TrialBalance(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            AccountingPeriod(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            RecurringEntryTemplate(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            FixedAsset(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -513,6 +575,16 @@ impl FromStr for CoreAccountingObject {
                     .map_err(|_| "could not parse BalanceSheetConfiguration")?;
                 CoreAccountingObject::BalanceSheetConfiguration(obj_ref)
             }
+            CashFlow => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CashFlow")?;
+                CoreAccountingObject::CashFlow(obj_ref)
+            }
+            CashFlowConfiguration => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CashFlowConfiguration")?;
+                CoreAccountingObject::CashFlowConfiguration(obj_ref)
+            }
             AccountingCsv => {
                 let obj_ref = id.parse().map_err(|_| "could not parse AccountingCsv")?;
                 CoreAccountingObject::AccountingCsv(obj_ref)
@@ -521,6 +593,20 @@ impl FromStr for CoreAccountingObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse TrialBalance")?;
                 CoreAccountingObject::TrialBalance(obj_ref)
             }
+            AccountingPeriod => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AccountingPeriod")?;
+                CoreAccountingObject::AccountingPeriod(obj_ref)
+            }
+            RecurringEntryTemplate => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse RecurringEntryTemplate")?;
+                CoreAccountingObject::RecurringEntryTemplate(obj_ref)
+            }
+            FixedAsset => {
+                let obj_ref = id.parse().map_err(|_| "could not parse FixedAsset")?;
+                CoreAccountingObject::FixedAsset(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -531,6 +617,9 @@ impl CoreAccountingAction {
     pub const CHART_LIST: Self = CoreAccountingAction::Chart(ChartAction::List);
     pub const CHART_IMPORT_ACCOUNTS: Self =
         CoreAccountingAction::Chart(ChartAction::ImportAccounts);
+    pub const CHART_EDIT_ACCOUNTS: Self = CoreAccountingAction::Chart(ChartAction::EditAccounts);
+    pub const CHART_EXPORT_ACCOUNTS: Self =
+        CoreAccountingAction::Chart(ChartAction::ExportAccounts);
 
     pub const JOURNAL_READ_ENTRIES: Self =
         CoreAccountingAction::Journal(JournalAction::ReadEntries);
@@ -556,6 +645,8 @@ impl CoreAccountingAction {
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::Create);
     pub const MANUAL_TRANSACTION_LIST: Self =
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::List);
+    pub const MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::ConcludeApprovalProcess);
     pub const PROFIT_AND_LOSS_READ: Self =
         CoreAccountingAction::ProfitAndLoss(ProfitAndLossAction::Read);
     pub const PROFIT_AND_LOSS_CREATE: Self =
@@ -575,6 +666,12 @@ impl CoreAccountingAction {
         CoreAccountingAction::BalanceSheetConfiguration(BalanceSheetConfigurationAction::Read);
     pub const BALANCE_SHEET_CONFIGURATION_UPDATE: Self =
         CoreAccountingAction::BalanceSheetConfiguration(BalanceSheetConfigurationAction::Update);
+    pub const CASH_FLOW_READ: Self = CoreAccountingAction::CashFlow(CashFlowAction::Read);
+    pub const CASH_FLOW_CREATE: Self = CoreAccountingAction::CashFlow(CashFlowAction::Create);
+    pub const CASH_FLOW_CONFIGURATION_READ: Self =
+        CoreAccountingAction::CashFlowConfiguration(CashFlowConfigurationAction::Read);
+    pub const CASH_FLOW_CONFIGURATION_UPDATE: Self =
+        CoreAccountingAction::CashFlowConfiguration(CashFlowConfigurationAction::Update);
 
     pub const ACCOUNTING_CSV_CREATE: Self =
         CoreAccountingAction::AccountingCsv(AccountingCsvAction::Create);
@@ -593,6 +690,35 @@ impl CoreAccountingAction {
         CoreAccountingAction::TrialBalance(TrialBalanceAction::Create);
     pub const TRIAL_BALANCE_UPDATE: Self =
         CoreAccountingAction::TrialBalance(TrialBalanceAction::Update);
+
+    pub const ACCOUNTING_PERIOD_CREATE: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Create);
+    pub const ACCOUNTING_PERIOD_READ: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Read);
+    pub const ACCOUNTING_PERIOD_LIST: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::List);
+    pub const ACCOUNTING_PERIOD_CLOSE: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Close);
+
+    pub const RECURRING_ENTRY_TEMPLATE_CREATE: Self =
+        CoreAccountingAction::RecurringEntryTemplate(RecurringEntryTemplateAction::Create);
+    pub const RECURRING_ENTRY_TEMPLATE_READ: Self =
+        CoreAccountingAction::RecurringEntryTemplate(RecurringEntryTemplateAction::Read);
+    pub const RECURRING_ENTRY_TEMPLATE_LIST: Self =
+        CoreAccountingAction::RecurringEntryTemplate(RecurringEntryTemplateAction::List);
+    pub const RECURRING_ENTRY_TEMPLATE_DEACTIVATE: Self =
+        CoreAccountingAction::RecurringEntryTemplate(RecurringEntryTemplateAction::Deactivate);
+    pub const RECURRING_ENTRY_TEMPLATE_POST: Self =
+        CoreAccountingAction::RecurringEntryTemplate(RecurringEntryTemplateAction::Post);
+
+    pub const FIXED_ASSET_ACQUIRE: Self =
+        CoreAccountingAction::FixedAsset(FixedAssetAction::Acquire);
+    pub const FIXED_ASSET_READ: Self = CoreAccountingAction::FixedAsset(FixedAssetAction::Read);
+    pub const FIXED_ASSET_LIST: Self = CoreAccountingAction::FixedAsset(FixedAssetAction::List);
+    pub const FIXED_ASSET_DEPRECIATE: Self =
+        CoreAccountingAction::FixedAsset(FixedAssetAction::Depreciate);
+    pub const FIXED_ASSET_DISPOSE: Self =
+        CoreAccountingAction::FixedAsset(FixedAssetAction::Dispose);
 }
 
 impl Display for CoreAccountingAction {
@@ -610,8 +736,13 @@ impl Display for CoreAccountingAction {
             ProfitAndLossConfiguration(action) => action.fmt(f),
             BalanceSheet(action) => action.fmt(f),
             BalanceSheetConfiguration(action) => action.fmt(f),
+            CashFlow(action) => action.fmt(f),
+            CashFlowConfiguration(action) => action.fmt(f),
             AccountingCsv(action) => action.fmt(f),
             TrialBalance(action) => action.fmt(f),
+            AccountingPeriod(action) => action.fmt(f),
+            RecurringEntryTemplate(action) => action.fmt(f),
+            FixedAsset(action) => action.fmt(f),
         }
     }
 }
@@ -652,12 +783,27 @@ impl FromStr for CoreAccountingAction {
             CoreAccountingActionDiscriminants::BalanceSheetConfiguration => {
                 CoreAccountingAction::from(action.parse::<BalanceSheetConfigurationAction>()?)
             }
+            CoreAccountingActionDiscriminants::CashFlow => {
+                CoreAccountingAction::from(action.parse::<CashFlowAction>()?)
+            }
+            CoreAccountingActionDiscriminants::CashFlowConfiguration => {
+                CoreAccountingAction::from(action.parse::<CashFlowConfigurationAction>()?)
+            }
             CoreAccountingActionDiscriminants::AccountingCsv => {
                 CoreAccountingAction::from(action.parse::<AccountingCsvAction>()?)
             }
             CoreAccountingActionDiscriminants::TrialBalance => {
                 CoreAccountingAction::from(action.parse::<TrialBalanceAction>()?)
             }
+            CoreAccountingActionDiscriminants::AccountingPeriod => {
+                CoreAccountingAction::from(action.parse::<AccountingPeriodAction>()?)
+            }
+            CoreAccountingActionDiscriminants::RecurringEntryTemplate => {
+                CoreAccountingAction::from(action.parse::<RecurringEntryTemplateAction>()?)
+            }
+            CoreAccountingActionDiscriminants::FixedAsset => {
+                CoreAccountingAction::from(action.parse::<FixedAssetAction>()?)
+            }
         };
         Ok(res)
     }
@@ -669,6 +815,8 @@ pub enum ChartAction {
     Create,
     List,
     ImportAccounts,
+    EditAccounts,
+    ExportAccounts,
 }
 
 impl ChartAction {
@@ -680,14 +828,14 @@ impl ChartAction {
                 Self::Create => {
                     ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
                 }
-                Self::List => ActionDescription::new(
+                Self::List | Self::ExportAccounts => ActionDescription::new(
                     variant,
                     &[
                         PERMISSION_SET_ACCOUNTING_VIEWER,
                         PERMISSION_SET_ACCOUNTING_WRITER,
                     ],
                 ),
-                Self::ImportAccounts => {
+                Self::ImportAccounts | Self::EditAccounts => {
                     ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
                 }
             };
@@ -855,6 +1003,7 @@ pub enum ManualTransactionAction {
     Read,
     Create,
     List,
+    ConcludeApprovalProcess,
 }
 
 impl ManualTransactionAction {
@@ -880,6 +1029,9 @@ impl ManualTransactionAction {
                         PERMISSION_SET_ACCOUNTING_WRITER,
                     ],
                 ),
+                Self::ConcludeApprovalProcess => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -1046,6 +1198,80 @@ impl From<BalanceSheetConfigurationAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CashFlowAction {
+    Read,
+    Create,
+}
+
+impl CashFlowAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<CashFlowAction> for CoreAccountingAction {
+    fn from(action: CashFlowAction) -> Self {
+        CoreAccountingAction::CashFlow(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CashFlowConfigurationAction {
+    Read,
+    Update,
+}
+
+impl CashFlowConfigurationAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let set = match variant {
+                Self::Update => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+            };
+            res.push(set);
+        }
+
+        res
+    }
+}
+
+impl From<CashFlowConfigurationAction> for CoreAccountingAction {
+    fn from(action: CashFlowConfigurationAction) -> Self {
+        CoreAccountingAction::CashFlowConfiguration(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum AccountingCsvAction {
@@ -1140,6 +1366,157 @@ impl From<TrialBalanceAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountingPeriodAction {
+    Create,
+    Read,
+    List,
+    Close,
+}
+
+impl AccountingPeriodAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::Close => ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER]),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<AccountingPeriodAction> for CoreAccountingAction {
+    fn from(action: AccountingPeriodAction) -> Self {
+        CoreAccountingAction::AccountingPeriod(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RecurringEntryTemplateAction {
+    Create,
+    Read,
+    List,
+    Deactivate,
+    Post,
+}
+
+impl RecurringEntryTemplateAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::Deactivate => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Post => ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER]),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<RecurringEntryTemplateAction> for CoreAccountingAction {
+    fn from(action: RecurringEntryTemplateAction) -> Self {
+        CoreAccountingAction::RecurringEntryTemplate(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum FixedAssetAction {
+    Acquire,
+    Read,
+    List,
+    Depreciate,
+    Dispose,
+}
+
+impl FixedAssetAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Acquire => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[
+                        PERMISSION_SET_ACCOUNTING_VIEWER,
+                        PERMISSION_SET_ACCOUNTING_WRITER,
+                    ],
+                ),
+                Self::Depreciate => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+                Self::Dispose => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_ACCOUNTING_WRITER])
+                }
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<FixedAssetAction> for CoreAccountingAction {
+    fn from(action: FixedAssetAction) -> Self {
+        CoreAccountingAction::FixedAsset(action)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BalanceRange {
     pub open: Option<CalaAccountBalance>,
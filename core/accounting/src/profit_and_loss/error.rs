@@ -12,6 +12,10 @@ pub enum ProfitAndLossStatementError {
     ProfitAndLossStatementLedgerError(
         #[from] super::ledger::error::ProfitAndLossStatementLedgerError,
     ),
+    #[error("ProfitAndLossStatementError - ComparativeStatementError: {0}")]
+    ComparativeStatementError(
+        #[from] crate::comparative_statement::error::ComparativeStatementError,
+    ),
     #[error("ProfitAndLossStatementError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
     #[error("ProfitAndLossStatementError - ProfitAndLossStatementConfigAlreadyExists")]
@@ -12,6 +12,9 @@ use cala_ledger::CalaLedger;
 use crate::{
     LedgerAccountId,
     chart_of_accounts::Chart,
+    comparative_statement::{
+        ComparativeStatement, ComparativeStatementLedger, StatementBalance, StatementPeriod,
+    },
     primitives::{BalanceRange, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject},
 };
 
@@ -57,6 +60,7 @@ where
     pool: sqlx::PgPool,
     authz: Perms,
     pl_statement_ledger: ProfitAndLossStatementLedger,
+    comparative_statement_ledger: ComparativeStatementLedger,
 }
 
 impl<Perms> ProfitAndLossStatements<Perms>
@@ -72,10 +76,12 @@ where
         journal_id: cala_ledger::JournalId,
     ) -> Self {
         let pl_statement_ledger = ProfitAndLossStatementLedger::new(cala, journal_id);
+        let comparative_statement_ledger = ComparativeStatementLedger::new(cala, journal_id);
 
         Self {
             pool: pool.clone(),
             pl_statement_ledger,
+            comparative_statement_ledger,
             authz: authz.clone(),
         }
     }
@@ -204,6 +210,61 @@ where
             .get_pl_statement(reference, from, until)
             .await?)
     }
+
+    #[instrument(
+        name = "core_accounting.profit_and_loss.comparative_pl_statement",
+        skip(self),
+        err
+    )]
+    pub async fn comparative_pl_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<ComparativeStatement, ProfitAndLossStatementError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_profit_and_loss(),
+                CoreAccountingAction::PROFIT_AND_LOSS_READ,
+            )
+            .await?;
+
+        self.load_comparative_pl_statement(reference, periods).await
+    }
+
+    pub(crate) async fn load_comparative_pl_statement(
+        &self,
+        reference: String,
+        periods: Vec<StatementPeriod>,
+    ) -> Result<ComparativeStatement, ProfitAndLossStatementError> {
+        let ids = self
+            .pl_statement_ledger
+            .get_ids_from_reference(reference)
+            .await?;
+
+        Ok(self
+            .comparative_statement_ledger
+            .load(
+                ids.id,
+                &[ids.revenue, ids.cost_of_revenue, ids.expenses],
+                periods,
+                StatementBalance::PeriodActivity,
+            )
+            .await?)
+    }
+
+    /// Revenue, cost of revenue and expenses account sets of the statement.
+    pub(crate) async fn category_account_set_ids(
+        &self,
+        reference: String,
+    ) -> Result<Vec<CalaAccountSetId>, ProfitAndLossStatementError> {
+        Ok(self
+            .pl_statement_ledger
+            .get_ids_from_reference(reference)
+            .await?
+            .internal_ids())
+    }
 }
 
 #[derive(Clone)]
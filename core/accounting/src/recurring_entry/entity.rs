@@ -0,0 +1,537 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use audit::AuditInfo;
+use cala_ledger::{Currency, DebitOrCredit};
+use es_entity::*;
+use rust_decimal::Decimal;
+
+use crate::{
+    manual_transaction::ManualTransactionEntry,
+    primitives::{ChartId, ManualTransactionId, RecurringEntryTemplateId},
+};
+
+use super::{
+    error::RecurringEntryTemplateError,
+    schedule::{RecurringEntryFrequency, start_of_next_period},
+};
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum RecurringEntryTemplateStatus {
+    Active,
+    Completed,
+    Deactivated,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "RecurringEntryTemplateId")]
+pub enum RecurringEntryTemplateEvent {
+    Initialized {
+        id: RecurringEntryTemplateId,
+        chart_id: ChartId,
+        name: String,
+        description: String,
+        entries: Vec<ManualTransactionEntry>,
+        frequency: RecurringEntryFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        auto_reverse: bool,
+        audit_info: AuditInfo,
+    },
+    RunPosted {
+        scheduled_for: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    },
+    RunFailed {
+        scheduled_for: NaiveDate,
+        error: String,
+        audit_info: AuditInfo,
+    },
+    RunReversed {
+        scheduled_for: NaiveDate,
+        reversal_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    },
+    Deactivated {
+        audit_info: AuditInfo,
+    },
+}
+
+/// Outcome of a single run of the template.
+#[derive(Debug, Clone)]
+pub struct RecurringEntryRun {
+    pub scheduled_for: NaiveDate,
+    pub manual_transaction_id: Option<ManualTransactionId>,
+    pub error: Option<String>,
+    /// Date the run is reversed on, for templates that auto-reverse.
+    pub reversal_date: Option<NaiveDate>,
+    pub reversal_transaction_id: Option<ManualTransactionId>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct RecurringEntryTemplate {
+    pub id: RecurringEntryTemplateId,
+    pub chart_id: ChartId,
+    pub name: String,
+    pub description: String,
+    pub entries: Vec<ManualTransactionEntry>,
+    pub frequency: RecurringEntryFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub auto_reverse: bool,
+
+    events: EntityEvents<RecurringEntryTemplateEvent>,
+}
+
+impl RecurringEntryTemplate {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for recurring entry template")
+    }
+
+    pub fn runs(&self) -> Vec<RecurringEntryRun> {
+        let mut runs: Vec<RecurringEntryRun> = Vec::new();
+        for event in self.events.iter_all() {
+            match event {
+                RecurringEntryTemplateEvent::RunPosted {
+                    scheduled_for,
+                    manual_transaction_id,
+                    ..
+                } => runs.push(RecurringEntryRun {
+                    scheduled_for: *scheduled_for,
+                    manual_transaction_id: Some(*manual_transaction_id),
+                    error: None,
+                    reversal_date: self
+                        .auto_reverse
+                        .then(|| start_of_next_period(*scheduled_for)),
+                    reversal_transaction_id: None,
+                }),
+                RecurringEntryTemplateEvent::RunFailed {
+                    scheduled_for,
+                    error,
+                    ..
+                } => runs.push(RecurringEntryRun {
+                    scheduled_for: *scheduled_for,
+                    manual_transaction_id: None,
+                    error: Some(error.clone()),
+                    reversal_date: None,
+                    reversal_transaction_id: None,
+                }),
+                RecurringEntryTemplateEvent::RunReversed {
+                    scheduled_for,
+                    reversal_transaction_id,
+                    ..
+                } => {
+                    if let Some(run) = runs
+                        .iter_mut()
+                        .find(|run| run.scheduled_for == *scheduled_for)
+                    {
+                        run.reversal_transaction_id = Some(*reversal_transaction_id);
+                    }
+                }
+                _ => (),
+            }
+        }
+        runs
+    }
+
+    fn is_deactivated(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, RecurringEntryTemplateEvent::Deactivated { .. }))
+    }
+
+    fn run_count(&self) -> u32 {
+        self.events
+            .iter_all()
+            .filter(|e| {
+                matches!(
+                    e,
+                    RecurringEntryTemplateEvent::RunPosted { .. }
+                        | RecurringEntryTemplateEvent::RunFailed { .. }
+                )
+            })
+            .count() as u32
+    }
+
+    pub fn next_run_date(&self) -> Option<NaiveDate> {
+        if self.is_deactivated() {
+            return None;
+        }
+        self.frequency
+            .occurrence(self.start_date, self.run_count())
+            .filter(|date| self.end_date.is_none_or(|end| *date <= end))
+    }
+
+    /// The next run date if it is on or before `today`.
+    pub fn due_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        self.next_run_date().filter(|date| *date <= today)
+    }
+
+    /// Posted runs whose auto-reversal is due on or before `today` and has not
+    /// been posted yet. Reversals are still posted after the template is
+    /// deactivated, so no accrual is left standing.
+    pub fn due_reversals(&self, today: NaiveDate) -> Vec<(NaiveDate, ManualTransactionId)> {
+        self.runs()
+            .into_iter()
+            .filter(|run| run.reversal_transaction_id.is_none())
+            .filter(|run| run.reversal_date.is_some_and(|date| date <= today))
+            .filter_map(|run| Some((run.scheduled_for, run.manual_transaction_id?)))
+            .collect()
+    }
+
+    pub fn status(&self) -> RecurringEntryTemplateStatus {
+        if self.is_deactivated() {
+            RecurringEntryTemplateStatus::Deactivated
+        } else if self.next_run_date().is_none() {
+            RecurringEntryTemplateStatus::Completed
+        } else {
+            RecurringEntryTemplateStatus::Active
+        }
+    }
+
+    /// Reference of the manual transaction posted for the run on
+    /// `scheduled_for`. Manual transaction references are unique, so a run
+    /// can never be posted twice.
+    pub fn transaction_reference(&self, scheduled_for: NaiveDate) -> String {
+        format!("recurring:{}:{}", self.id, scheduled_for)
+    }
+
+    fn has_run(&self, scheduled_for: NaiveDate) -> bool {
+        self.runs()
+            .iter()
+            .any(|run| run.scheduled_for == scheduled_for)
+    }
+
+    pub(super) fn record_run_posted(
+        &mut self,
+        scheduled_for: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.has_run(scheduled_for) {
+            return Idempotent::Ignored;
+        }
+        self.events.push(RecurringEntryTemplateEvent::RunPosted {
+            scheduled_for,
+            manual_transaction_id,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_run_failed(
+        &mut self,
+        scheduled_for: NaiveDate,
+        error: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.has_run(scheduled_for) {
+            return Idempotent::Ignored;
+        }
+        self.events.push(RecurringEntryTemplateEvent::RunFailed {
+            scheduled_for,
+            error,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_run_reversed(
+        &mut self,
+        scheduled_for: NaiveDate,
+        reversal_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if !self
+            .due_reversals(NaiveDate::MAX)
+            .iter()
+            .any(|(date, _)| *date == scheduled_for)
+        {
+            return Idempotent::Ignored;
+        }
+        self.events.push(RecurringEntryTemplateEvent::RunReversed {
+            scheduled_for,
+            reversal_transaction_id,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn deactivate(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, RecurringEntryTemplateError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            RecurringEntryTemplateEvent::Deactivated { .. }
+        );
+        if self.status() == RecurringEntryTemplateStatus::Completed {
+            return Err(RecurringEntryTemplateError::AlreadyCompleted(self.id));
+        }
+        self.events
+            .push(RecurringEntryTemplateEvent::Deactivated { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<RecurringEntryTemplateEvent> for RecurringEntryTemplate {
+    fn try_from_events(
+        events: EntityEvents<RecurringEntryTemplateEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = RecurringEntryTemplateBuilder::default();
+        for event in events.iter_all() {
+            if let RecurringEntryTemplateEvent::Initialized {
+                id,
+                chart_id,
+                name,
+                description,
+                entries,
+                frequency,
+                start_date,
+                end_date,
+                auto_reverse,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .chart_id(*chart_id)
+                    .name(name.clone())
+                    .description(description.clone())
+                    .entries(entries.clone())
+                    .frequency(*frequency)
+                    .start_date(*start_date)
+                    .end_date(*end_date)
+                    .auto_reverse(*auto_reverse)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewRecurringEntryTemplate {
+    #[builder(setter(into))]
+    pub(super) id: RecurringEntryTemplateId,
+    pub(super) chart_id: ChartId,
+    #[builder(setter(into))]
+    name: String,
+    #[builder(setter(into))]
+    description: String,
+    entries: Vec<ManualTransactionEntry>,
+    frequency: RecurringEntryFrequency,
+    start_date: NaiveDate,
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    #[builder(default)]
+    auto_reverse: bool,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewRecurringEntryTemplate {
+    pub fn builder() -> NewRecurringEntryTemplateBuilder {
+        NewRecurringEntryTemplateBuilder::default()
+    }
+}
+
+impl NewRecurringEntryTemplateBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(entries) = self.entries.as_ref() {
+            if entries.is_empty() {
+                return Err("Recurring entry template needs at least one entry".to_string());
+            }
+            let mut net_by_currency: HashMap<Currency, Decimal> = HashMap::new();
+            for entry in entries {
+                let net = net_by_currency.entry(entry.currency).or_default();
+                match entry.direction {
+                    DebitOrCredit::Debit => *net += entry.amount,
+                    DebitOrCredit::Credit => *net -= entry.amount,
+                }
+            }
+            if let Some((currency, _)) = net_by_currency.iter().find(|(_, net)| !net.is_zero()) {
+                return Err(format!(
+                    "Recurring entry template debits and credits do not balance in {currency}"
+                ));
+            }
+        }
+        if let (Some(start), Some(Some(end))) = (self.start_date, self.end_date)
+            && end < start
+        {
+            return Err("Recurring entry template cannot end before it starts".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<RecurringEntryTemplateEvent> for NewRecurringEntryTemplate {
+    fn into_events(self) -> EntityEvents<RecurringEntryTemplateEvent> {
+        EntityEvents::init(
+            self.id,
+            [RecurringEntryTemplateEvent::Initialized {
+                id: self.id,
+                chart_id: self.chart_id,
+                name: self.name,
+                description: self.description,
+                entries: self.entries,
+                frequency: self.frequency,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                auto_reverse: self.auto_reverse,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use crate::primitives::LedgerAccountId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn entry(direction: DebitOrCredit, amount: Decimal) -> ManualTransactionEntry {
+        ManualTransactionEntry {
+            account_id: LedgerAccountId::new(),
+            amount,
+            currency: Currency::USD,
+            direction,
+            description: "rent accrual".to_string(),
+        }
+    }
+
+    fn new_template(
+        entries: Vec<ManualTransactionEntry>,
+        auto_reverse: bool,
+        end_date: Option<NaiveDate>,
+    ) -> Result<NewRecurringEntryTemplate, NewRecurringEntryTemplateBuilderError> {
+        NewRecurringEntryTemplate::builder()
+            .id(RecurringEntryTemplateId::new())
+            .chart_id(ChartId::new())
+            .name("Rent")
+            .description("Monthly rent accrual")
+            .entries(entries)
+            .frequency(RecurringEntryFrequency::Monthly)
+            .start_date(date(1, 31))
+            .end_date(end_date)
+            .auto_reverse(auto_reverse)
+            .audit_info(dummy_audit_info())
+            .build()
+    }
+
+    fn template(auto_reverse: bool, end_date: Option<NaiveDate>) -> RecurringEntryTemplate {
+        let new_template = new_template(
+            vec![
+                entry(DebitOrCredit::Debit, dec!(100)),
+                entry(DebitOrCredit::Credit, dec!(100)),
+            ],
+            auto_reverse,
+            end_date,
+        )
+        .unwrap();
+        RecurringEntryTemplate::try_from_events(new_template.into_events()).unwrap()
+    }
+
+    #[test]
+    fn rejects_unbalanced_entries() {
+        let res = new_template(
+            vec![
+                entry(DebitOrCredit::Debit, dec!(100)),
+                entry(DebitOrCredit::Credit, dec!(90)),
+            ],
+            false,
+            None,
+        );
+        assert!(res.is_err());
+        assert!(new_template(vec![], false, None).is_err());
+    }
+
+    #[test]
+    fn runs_advance_the_schedule() {
+        let mut template = template(false, None);
+        assert_eq!(template.due_date(date(1, 30)), None);
+        assert_eq!(template.due_date(date(2, 5)), Some(date(1, 31)));
+
+        let _ =
+            template.record_run_posted(date(1, 31), ManualTransactionId::new(), dummy_audit_info());
+        assert_eq!(template.next_run_date(), Some(date(2, 28)));
+        assert!(
+            template
+                .record_run_failed(date(1, 31), "duplicate".to_string(), dummy_audit_info())
+                .was_ignored()
+        );
+
+        let _ = template.record_run_failed(
+            date(2, 28),
+            "period closed".to_string(),
+            dummy_audit_info(),
+        );
+        assert_eq!(template.next_run_date(), Some(date(3, 31)));
+        assert_eq!(template.runs()[1].error.as_deref(), Some("period closed"));
+    }
+
+    #[test]
+    fn auto_reversal_is_due_on_first_day_of_next_period() {
+        let mut template = template(true, None);
+        let tx_id = ManualTransactionId::new();
+        let _ = template.record_run_posted(date(1, 31), tx_id, dummy_audit_info());
+
+        assert!(template.due_reversals(date(1, 31)).is_empty());
+        assert_eq!(
+            template.due_reversals(date(2, 1)),
+            vec![(date(1, 31), tx_id)]
+        );
+
+        let reversal_id = ManualTransactionId::new();
+        assert!(
+            template
+                .record_run_reversed(date(1, 31), reversal_id, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(template.due_reversals(date(2, 1)).is_empty());
+        assert_eq!(
+            template.runs()[0].reversal_transaction_id,
+            Some(reversal_id)
+        );
+    }
+
+    #[test]
+    fn completes_after_end_date_and_keeps_reversing() {
+        let mut template = template(true, Some(date(2, 15)));
+        let tx_id = ManualTransactionId::new();
+        let _ = template.record_run_posted(date(1, 31), tx_id, dummy_audit_info());
+
+        assert_eq!(template.status(), RecurringEntryTemplateStatus::Completed);
+        assert!(template.deactivate(dummy_audit_info()).is_err());
+        assert_eq!(
+            template.due_reversals(date(2, 1)),
+            vec![(date(1, 31), tx_id)]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::primitives::RecurringEntryTemplateId;
+
+#[derive(Error, Debug)]
+pub enum RecurringEntryTemplateError {
+    #[error("RecurringEntryTemplateError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("RecurringEntryTemplateError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("RecurringEntryTemplateError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("RecurringEntryTemplateError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("RecurringEntryTemplateError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("RecurringEntryTemplateError - ChartOfAccountsError: {0}")]
+    ChartOfAccountsError(#[from] crate::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("RecurringEntryTemplateError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] crate::manual_transaction::error::ManualTransactionError),
+    #[error("RecurringEntryTemplateError - InvalidTemplate: {0}")]
+    InvalidTemplate(String),
+    #[error("RecurringEntryTemplateError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(RecurringEntryTemplateId),
+}
+
+es_entity::from_es_entity_error!(RecurringEntryTemplateError);
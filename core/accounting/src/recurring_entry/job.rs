@@ -0,0 +1,317 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    chart_of_accounts::ChartOfAccounts,
+    manual_transaction::{ManualTransactionStatus, ManualTransactions},
+    primitives::{CoreAccountingAction, CoreAccountingObject},
+};
+
+use super::{
+    RecurringEntryTemplates, entity::RecurringEntryTemplate, error::RecurringEntryTemplateError,
+    repo::recurring_entry_template_cursor::RecurringEntryTemplatesByCreatedAtCursor,
+    schedule::start_of_next_period,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RecurringEntryJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> RecurringEntryJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> JobConfig for RecurringEntryJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = RecurringEntryJobInitializer<Perms, E>;
+}
+
+pub struct RecurringEntryJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    templates: RecurringEntryTemplates<Perms, E>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> RecurringEntryJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        templates: &RecurringEntryTemplates<Perms, E>,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        chart_of_accounts: &ChartOfAccounts<Perms>,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            templates: templates.clone(),
+            manual_transactions: manual_transactions.clone(),
+            chart_of_accounts: chart_of_accounts.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const POST_RECURRING_ENTRIES_JOB: JobType = JobType::new("post-recurring-entries");
+impl<Perms, E> JobInitializer for RecurringEntryJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        POST_RECURRING_ENTRIES_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(RecurringEntryJobRunner::<Perms, E> {
+            templates: self.templates.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct RecurringEntryJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    templates: RecurringEntryTemplates<Perms, E>,
+    manual_transactions: ManualTransactions<Perms, E>,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> RecurringEntryJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    async fn post_due_runs(
+        &self,
+        template: &mut RecurringEntryTemplate,
+        today: chrono::NaiveDate,
+    ) -> Result<(), RecurringEntryTemplateError> {
+        if template.due_date(today).is_none() {
+            return Ok(());
+        }
+        let system = <<Perms::Audit as AuditSvc>::Subject as SystemSubject>::system();
+        let chart = self.chart_of_accounts.find_by_id(template.chart_id).await?;
+
+        // Runs missed while the job was not running are caught up one by one.
+        while let Some(scheduled_for) = template.due_date(today) {
+            let reference = template.transaction_reference(scheduled_for);
+            let res = match self
+                .manual_transactions
+                .find_by_reference(reference.clone())
+                .await?
+            {
+                Some(existing) => Ok(existing),
+                None => {
+                    self.manual_transactions
+                        .execute_recurring(
+                            &system,
+                            &chart,
+                            template.id,
+                            reference,
+                            template.description.clone(),
+                            scheduled_for,
+                            template.entries.iter().cloned().map(Into::into).collect(),
+                        )
+                        .await
+                }
+            };
+            let res = match res {
+                Err(e) if !e.is_rejected() => return Err(e.into()),
+                res => res,
+            };
+
+            let mut db = self.templates.repo().begin_op().await?;
+            let audit_info = self
+                .audit
+                .record_system_entry_in_tx(
+                    db.tx(),
+                    CoreAccountingObject::recurring_entry_template(template.id),
+                    CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_POST,
+                )
+                .await?;
+            let _ = match res {
+                Ok(tx) => template.record_run_posted(scheduled_for, tx.id, audit_info),
+                Err(e) => {
+                    tracing::warn!(
+                        recurring_entry_template_id = %template.id,
+                        error = %e,
+                        "recurring entry run failed"
+                    );
+                    template.record_run_failed(scheduled_for, e.to_string(), audit_info)
+                }
+            };
+            self.templates
+                .repo()
+                .update_in_op(&mut db, template)
+                .await?;
+            db.commit().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs are only reversed once they have been approved and posted.
+    /// Reversals that fail, e.g. because their period is locked, are retried
+    /// on the next run of the job.
+    async fn post_due_reversals(
+        &self,
+        template: &mut RecurringEntryTemplate,
+        today: chrono::NaiveDate,
+    ) -> Result<(), RecurringEntryTemplateError> {
+        let system = <<Perms::Audit as AuditSvc>::Subject as SystemSubject>::system();
+
+        for (scheduled_for, manual_transaction_id) in template.due_reversals(today) {
+            let Some(original) = self
+                .manual_transactions
+                .find_all::<crate::ManualTransaction>(&[manual_transaction_id])
+                .await?
+                .remove(&manual_transaction_id)
+            else {
+                continue;
+            };
+            if original.status() != ManualTransactionStatus::Posted {
+                continue;
+            }
+            let reversal_id = match original.reversed_by() {
+                Some(reversal_id) => reversal_id,
+                None => match self
+                    .manual_transactions
+                    .reverse_pre_approved(
+                        &system,
+                        manual_transaction_id,
+                        start_of_next_period(scheduled_for),
+                    )
+                    .await
+                {
+                    Ok(reversal) => reversal.id,
+                    Err(e) => {
+                        tracing::warn!(
+                            recurring_entry_template_id = %template.id,
+                            error = %e,
+                            "recurring entry reversal failed"
+                        );
+                        continue;
+                    }
+                },
+            };
+
+            let mut db = self.templates.repo().begin_op().await?;
+            let audit_info = self
+                .audit
+                .record_system_entry_in_tx(
+                    db.tx(),
+                    CoreAccountingObject::recurring_entry_template(template.id),
+                    CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_POST,
+                )
+                .await?;
+            if template
+                .record_run_reversed(scheduled_for, reversal_id, audit_info)
+                .did_execute()
+            {
+                self.templates
+                    .repo()
+                    .update_in_op(&mut db, template)
+                    .await?;
+            }
+            db.commit().await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for RecurringEntryJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = Utc::now();
+        let today = now.date_naive();
+
+        let mut has_next_page = true;
+        let mut after: Option<RecurringEntryTemplatesByCreatedAtCursor> = None;
+        while has_next_page {
+            let templates = self
+                .templates
+                .repo()
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<RecurringEntryTemplatesByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (templates.end_cursor, templates.has_next_page);
+
+            for mut template in templates.entities {
+                self.post_due_runs(&mut template, today).await?;
+                self.post_due_reversals(&mut template, today).await?;
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(start_of_next_day(now)))
+    }
+}
+
+fn start_of_next_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    (now.date_naive() + Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+}
@@ -0,0 +1,226 @@
+mod entity;
+pub mod error;
+mod job;
+mod repo;
+mod schedule;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    Chart,
+    manual_transaction::{ManualEntryInput, ManualTransactions},
+    primitives::{CoreAccountingAction, CoreAccountingObject, RecurringEntryTemplateId},
+};
+
+#[cfg(feature = "json-schema")]
+pub use entity::RecurringEntryTemplateEvent;
+pub(super) use entity::*;
+pub use entity::{RecurringEntryRun, RecurringEntryTemplate, RecurringEntryTemplateStatus};
+use error::*;
+pub use job::{RecurringEntryJobConfig, RecurringEntryJobInitializer};
+pub use repo::recurring_entry_template_cursor::RecurringEntryTemplatesByCreatedAtCursor;
+use repo::*;
+pub use schedule::RecurringEntryFrequency;
+
+pub struct RecurringEntryTemplates<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    repo: RecurringEntryTemplateRepo,
+    authz: Perms,
+    manual_transactions: ManualTransactions<Perms, E>,
+}
+
+impl<Perms, E> Clone for RecurringEntryTemplates<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+        }
+    }
+}
+
+impl<Perms, E> RecurringEntryTemplates<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        manual_transactions: &ManualTransactions<Perms, E>,
+    ) -> Self {
+        Self {
+            repo: RecurringEntryTemplateRepo::new(pool),
+            authz: authz.clone(),
+            manual_transactions: manual_transactions.clone(),
+        }
+    }
+
+    pub(crate) fn repo(&self) -> &RecurringEntryTemplateRepo {
+        &self.repo
+    }
+
+    /// Saves `entries` to be submitted for approval on every run of the
+    /// schedule, starting on `start_date`. With `auto_reverse` each approved
+    /// run is reversed on the first day of the following month.
+    #[instrument(
+        name = "core_accounting.recurring_entry_template.create",
+        skip(self, chart, entries),
+        err
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        name: String,
+        description: String,
+        frequency: RecurringEntryFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        auto_reverse: bool,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<RecurringEntryTemplate, RecurringEntryTemplateError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_entry_templates(),
+                CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_CREATE,
+            )
+            .await?;
+
+        let entries = self
+            .manual_transactions
+            .resolve_entries(chart, entries)
+            .await?;
+        let new_template = NewRecurringEntryTemplate::builder()
+            .id(RecurringEntryTemplateId::new())
+            .chart_id(chart.id)
+            .name(name)
+            .description(description)
+            .entries(entries)
+            .frequency(frequency)
+            .start_date(start_date)
+            .end_date(end_date)
+            .auto_reverse(auto_reverse)
+            .audit_info(audit_info)
+            .build()
+            .map_err(|e| RecurringEntryTemplateError::InvalidTemplate(e.to_string()))?;
+
+        self.repo.create(new_template).await
+    }
+
+    /// Stops future runs. Runs already posted are still auto-reversed.
+    #[instrument(
+        name = "core_accounting.recurring_entry_template.deactivate",
+        skip(self),
+        err
+    )]
+    pub async fn deactivate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringEntryTemplateId> + std::fmt::Debug,
+    ) -> Result<RecurringEntryTemplate, RecurringEntryTemplateError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_entry_template(id),
+                CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_DEACTIVATE,
+            )
+            .await?;
+
+        let mut template = self.repo.find_by_id(id).await?;
+        if template.deactivate(audit_info)?.did_execute() {
+            self.repo.update(&mut template).await?;
+        }
+        Ok(template)
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_entry_template.find_by_id",
+        skip(self),
+        err
+    )]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringEntryTemplateId> + std::fmt::Debug,
+    ) -> Result<Option<RecurringEntryTemplate>, RecurringEntryTemplateError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_entry_template(id),
+                CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(template) => Ok(Some(template)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_entry_template.list",
+        skip(self),
+        err
+    )]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<RecurringEntryTemplatesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            RecurringEntryTemplate,
+            RecurringEntryTemplatesByCreatedAtCursor,
+        >,
+        RecurringEntryTemplateError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_entry_templates(),
+                CoreAccountingAction::RECURRING_ENTRY_TEMPLATE_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_entry_template.find_all",
+        skip(self),
+        err
+    )]
+    pub async fn find_all<T: From<RecurringEntryTemplate>>(
+        &self,
+        ids: &[RecurringEntryTemplateId],
+    ) -> Result<HashMap<RecurringEntryTemplateId, T>, RecurringEntryTemplateError> {
+        self.repo.find_all(ids).await
+    }
+}
@@ -0,0 +1,23 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::RecurringEntryTemplateId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "RecurringEntryTemplate",
+    err = "RecurringEntryTemplateError",
+    tbl_prefix = "core"
+)]
+pub struct RecurringEntryTemplateRepo {
+    pool: PgPool,
+}
+
+impl RecurringEntryTemplateRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
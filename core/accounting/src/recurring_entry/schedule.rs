@@ -0,0 +1,71 @@
+use chrono::{Datelike, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum RecurringEntryFrequency {
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+impl RecurringEntryFrequency {
+    fn months(&self) -> u32 {
+        match self {
+            Self::Monthly => 1,
+            Self::Quarterly => 3,
+            Self::Annually => 12,
+        }
+    }
+
+    /// Date of the `n`-th run where the 0th is `start` itself. Runs falling on
+    /// a day the month does not have are moved to the last day of that month,
+    /// so a template starting on the 31st posts on every month end.
+    pub fn occurrence(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        let months = n.checked_mul(self.months())?;
+        start.checked_add_months(Months::new(months))
+    }
+}
+
+/// First day of the month after the one containing `date`, which is when an
+/// accrual posted on `date` is reversed.
+pub fn start_of_next_period(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("first day of month") + Months::new(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn monthly_runs_are_clamped_to_month_end() {
+        let start = date(2025, 1, 31);
+        let frequency = RecurringEntryFrequency::Monthly;
+        assert_eq!(frequency.occurrence(start, 0), Some(start));
+        assert_eq!(frequency.occurrence(start, 1), Some(date(2025, 2, 28)));
+        assert_eq!(frequency.occurrence(start, 2), Some(date(2025, 3, 31)));
+    }
+
+    #[test]
+    fn quarterly_runs_skip_two_months() {
+        assert_eq!(
+            RecurringEntryFrequency::Quarterly.occurrence(date(2025, 3, 31), 1),
+            Some(date(2025, 6, 30))
+        );
+    }
+
+    #[test]
+    fn reversal_is_on_first_day_of_next_month() {
+        assert_eq!(start_of_next_period(date(2025, 1, 31)), date(2025, 2, 1));
+        assert_eq!(start_of_next_period(date(2025, 12, 15)), date(2026, 1, 1));
+    }
+}
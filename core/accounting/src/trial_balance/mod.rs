@@ -113,6 +113,15 @@ where
             )
             .await?;
 
+        self.load_trial_balance(name, from, until).await
+    }
+
+    pub(crate) async fn load_trial_balance(
+        &self,
+        name: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<TrialBalanceRoot, TrialBalanceError> {
         Ok(self
             .trial_balance_ledger
             .get_trial_balance(name, from, Some(until))
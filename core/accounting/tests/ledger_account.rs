@@ -8,7 +8,7 @@ use cala_ledger::{
 };
 use cloud_storage::{Storage, config::StorageConfig};
 use core_accounting::CoreAccounting;
-use helpers::{action, object};
+use helpers::{action, event, object};
 use job::{JobExecutorConfig, Jobs};
 
 #[tokio::test]
@@ -24,7 +24,10 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &cala, journal_id, &storage, &jobs, &governance, &outbox).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
@@ -88,7 +91,10 @@ async fn ledger_account_children() -> anyhow::Result<()> {
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &cala, journal_id, &storage, &jobs, &governance, &outbox).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
@@ -141,10 +147,10 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id ,
-        &storage,
-        &jobs,
-    );
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &cala, journal_id, &storage, &jobs, &governance, &outbox).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
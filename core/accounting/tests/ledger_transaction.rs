@@ -5,8 +5,11 @@ use cloud_storage::{Storage, config::StorageConfig};
 use job::{JobExecutorConfig, Jobs};
 
 use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency, DebitOrCredit};
-use core_accounting::{CoreAccounting, ManualEntryInput, manual_transaction::AccountIdOrCode};
-use helpers::{action, object};
+use core_accounting::{
+    CoreAccounting, ManualEntryInput,
+    manual_transaction::{AccountIdOrCode, ManualTransactionStatus},
+};
+use helpers::{action, event, object};
 use rust_decimal_macros::dec;
 
 #[tokio::test]
@@ -21,7 +24,8 @@ async fn manual_transaction() -> anyhow::Result<()> {
         ManualEntryInput::builder().account_id_or_code(to.clone()).amount(dec!(100)).currency(Currency::USD).direction(DebitOrCredit::Debit).description("test 1 debit").build().unwrap(),
         ManualEntryInput::builder().account_id_or_code(from.clone()).amount(dec!(100)).currency(Currency::USD).direction(DebitOrCredit::Credit).description("test 1 credit").build().unwrap(),
     ];
-    accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
+    let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
+    assert_eq!(manual_tx.status(), ManualTransactionStatus::Posted);
 
     let account = accounting.find_ledger_account_by_code(&DummySubject, &chart_ref, "2".to_string()).await?.unwrap();
     assert_eq!(account.usd_balance_range.expect("should have balance").close.expect("balance missing").settled(), dec!(100));
@@ -45,13 +49,13 @@ async fn ledger_transactions_by_template_code() -> anyhow::Result<()> {
     let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
 
     let template_txs = accounting.ledger_transactions().list_for_template_code(&DummySubject, "MANUAL_TRANSACTION_2", Default::default()).await?.entities;
-    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.id));
+    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.ledger_transaction_id));
 
     Ok(())
 }
 
 async fn prepare_test() -> anyhow::Result<(
-    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>>,
+    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, event::DummyEvent>,
     String,
 )> {
     use rand::Rng;
@@ -67,7 +71,20 @@ async fn prepare_test() -> anyhow::Result<(
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
+
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -173,6 +173,7 @@ impl CreditFacility {
                     tx_ref: format!("{}-create", self.id),
                     credit_facility_account_ids: *account_ids,
                     facility_amount: *amount,
+                    effective: self.created_at().date_naive(),
                 }),
                 _ => None,
             })
@@ -301,6 +302,7 @@ impl CreditFacility {
             debit_account_id: self.disbursal_credit_account_id,
             facility_amount: self.amount,
             structuring_fee_amount: self.structuring_fee(),
+            effective: activated_at.date_naive(),
         };
 
         Ok(Idempotent::Executed((activation, periods.accrual)))
@@ -655,7 +657,7 @@ pub struct NewCreditFacility {
     #[builder(setter(into))]
     pub(super) collateral_id: CollateralId,
     terms: TermValues,
-    amount: UsdCents,
+    pub(super) amount: UsdCents,
     #[builder(setter(skip), default)]
     pub(super) status: CreditFacilityStatus,
     #[builder(setter(skip), default)]
@@ -130,6 +130,8 @@ where
                 new_credit_facility.id,
                 new_credit_facility.id.to_string(),
                 crate::APPROVE_CREDIT_FACILITY_PROCESS,
+                Some(new_credit_facility.amount),
+                Some(&new_credit_facility.audit_info),
             )
             .await?;
         self.repo.create_in_op(db, new_credit_facility).await
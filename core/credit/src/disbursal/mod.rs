@@ -96,6 +96,8 @@ where
                 new_disbursal.approval_process_id,
                 new_disbursal.approval_process_id.to_string(),
                 crate::APPROVE_DISBURSAL_PROCESS,
+                Some(new_disbursal.amount),
+                Some(&new_disbursal.audit_info),
             )
             .await?;
         let disbursal = self.repo.create_in_op(db, new_disbursal).await?;
@@ -59,6 +59,7 @@ pub struct CreditFacilityCreation {
     pub tx_ref: String,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
     pub facility_amount: UsdCents,
+    pub effective: chrono::NaiveDate,
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +70,7 @@ pub struct CreditFacilityActivation {
     pub debit_account_id: CalaAccountId,
     pub facility_amount: UsdCents,
     pub structuring_fee_amount: UsdCents,
+    pub effective: chrono::NaiveDate,
 }
 
 #[derive(Debug, Clone)]
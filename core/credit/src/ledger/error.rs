@@ -18,6 +18,8 @@ pub enum CreditLedgerError {
     ConversionError(#[from] core_money::ConversionError),
     #[error("CreditLedgerError - CalaVelocityError: {0}")]
     CalaVelocity(#[from] cala_ledger::velocity::error::VelocityError),
+    #[error("CreditLedgerError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] core_accounting::accounting_period_error::AccountingPeriodError),
     #[error("CreditLedgerError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
     #[error(
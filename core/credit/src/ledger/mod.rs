@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
+use core_accounting::AccountingPeriodLock;
 
 mod balance;
 mod constants;
@@ -169,12 +170,17 @@ pub struct CreditLedger {
     collateral_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
     credit_facility_control_id: VelocityControlId,
+    period_lock: AccountingPeriodLock,
     usd: Currency,
     btc: Currency,
 }
 
 impl CreditLedger {
-    pub async fn init(cala: &CalaLedger, journal_id: JournalId) -> Result<Self, CreditLedgerError> {
+    pub async fn init(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        period_lock: &AccountingPeriodLock,
+    ) -> Result<Self, CreditLedgerError> {
         templates::AddCollateral::init(cala).await?;
         templates::CreateCreditFacility::init(cala).await?;
         templates::ActivateCreditFacility::init(cala).await?;
@@ -789,6 +795,7 @@ impl CreditLedger {
             collateral_omnibus_account_ids,
             internal_account_sets,
             credit_facility_control_id,
+            period_lock: period_lock.clone(),
             usd: Currency::USD,
             btc: Currency::BTC,
         })
@@ -1072,7 +1079,7 @@ impl CreditLedger {
 
     pub async fn update_credit_facility_collateral(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CollateralUpdate {
             tx_id,
             abs_diff,
@@ -1081,6 +1088,9 @@ impl CreditLedger {
         }: CollateralUpdate,
         credit_facility_account_ids: CreditFacilityAccountIds,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         match action {
             CollateralAction::Add => {
@@ -1163,9 +1173,14 @@ impl CreditLedger {
 
     pub async fn record_obligation_repayments(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         payments: Vec<PaymentAllocation>,
     ) -> Result<(), CreditLedgerError> {
+        for payment in payments.iter() {
+            self.period_lock
+                .ensure_accepts_module_posting_in_op(&mut op, payment.effective)
+                .await?;
+        }
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         for payment in payments {
@@ -1179,7 +1194,7 @@ impl CreditLedger {
 
     pub async fn record_obligation_due(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         ObligationDueReallocationData {
             tx_id,
             amount: outstanding_amount,
@@ -1189,6 +1204,9 @@ impl CreditLedger {
             ..
         }: ObligationDueReallocationData,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1210,7 +1228,7 @@ impl CreditLedger {
 
     pub async fn record_obligation_overdue(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         ObligationOverdueReallocationData {
             tx_id,
             amount: outstanding_amount,
@@ -1220,6 +1238,9 @@ impl CreditLedger {
             ..
         }: ObligationOverdueReallocationData,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1241,7 +1262,7 @@ impl CreditLedger {
 
     pub async fn record_obligation_defaulted(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         ObligationDefaultedReallocationData {
             tx_id,
             amount: outstanding_amount,
@@ -1251,6 +1272,9 @@ impl CreditLedger {
             ..
         }: ObligationDefaultedReallocationData,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1272,13 +1296,17 @@ impl CreditLedger {
 
     pub async fn complete_credit_facility(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CreditFacilityCompletion {
             tx_id,
             collateral,
             credit_facility_account_ids,
         }: CreditFacilityCompletion,
     ) -> Result<(), CreditLedgerError> {
+        let effective = crate::time::now().date_naive();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1291,7 +1319,7 @@ impl CreditLedger {
                     amount: collateral.to_btc(),
                     collateral_account_id: credit_facility_account_ids.collateral_account_id,
                     bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
-                    effective: crate::time::now().date_naive(),
+                    effective,
                 },
             )
             .await?;
@@ -1307,6 +1335,7 @@ impl CreditLedger {
             tx_ref,
             credit_facility_account_ids,
             facility_amount,
+            effective,
         }: CreditFacilityCreation,
     ) -> Result<(), CreditLedgerError> {
         self.cala
@@ -1321,6 +1350,7 @@ impl CreditLedger {
                     facility_amount: facility_amount.to_usd(),
                     currency: self.usd,
                     external_id: tx_ref,
+                    effective,
                 },
             )
             .await?;
@@ -1330,7 +1360,7 @@ impl CreditLedger {
 
     pub async fn activate_credit_facility(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CreditFacilityActivation {
             tx_id,
             tx_ref,
@@ -1338,8 +1368,12 @@ impl CreditLedger {
             debit_account_id,
             facility_amount,
             structuring_fee_amount,
+            effective,
         }: CreditFacilityActivation,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1358,6 +1392,7 @@ impl CreditLedger {
                     structuring_fee_amount: structuring_fee_amount.to_usd(),
                     currency: self.usd,
                     external_id: tx_ref,
+                    effective,
                 },
             )
             .await?;
@@ -1367,7 +1402,7 @@ impl CreditLedger {
 
     pub async fn record_interest_accrual(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CreditFacilityInterestAccrual {
             tx_id,
             tx_ref,
@@ -1376,6 +1411,10 @@ impl CreditLedger {
             credit_facility_account_ids,
         }: CreditFacilityInterestAccrual,
     ) -> Result<(), CreditLedgerError> {
+        let effective = period.end.date_naive();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1391,7 +1430,7 @@ impl CreditLedger {
                         .interest_income_account_id,
                     interest_amount: interest.to_usd(),
                     external_id: tx_ref,
-                    effective: period.end.date_naive(),
+                    effective,
                 },
             )
             .await?;
@@ -1401,7 +1440,7 @@ impl CreditLedger {
 
     pub async fn record_interest_accrual_cycle(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         obligation: Obligation,
     ) -> Result<(), CreditLedgerError> {
         let interest_receivable_account_id =
@@ -1416,6 +1455,9 @@ impl CreditLedger {
             ..
         } = obligation;
 
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1439,11 +1481,15 @@ impl CreditLedger {
 
     pub async fn initiate_disbursal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
         facility_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1455,6 +1501,7 @@ impl CreditLedger {
                     credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
                     credit_facility_account: facility_account_id,
                     disbursed_amount: amount.to_usd(),
+                    effective,
                 },
             )
             .await?;
@@ -1464,11 +1511,15 @@ impl CreditLedger {
 
     pub async fn cancel_disbursal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: LedgerTxId,
         amount: UsdCents,
         facility_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
     ) -> Result<(), CreditLedgerError> {
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1480,6 +1531,7 @@ impl CreditLedger {
                     credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
                     credit_facility_account: facility_account_id,
                     disbursed_amount: amount.to_usd(),
+                    effective,
                 },
             )
             .await?;
@@ -1489,7 +1541,7 @@ impl CreditLedger {
 
     pub async fn settle_disbursal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         obligation: Obligation,
         facility_account_id: CalaAccountId,
     ) -> Result<(), CreditLedgerError> {
@@ -1500,9 +1552,13 @@ impl CreditLedger {
             tx_id,
             reference: external_id,
             initial_amount: amount,
+            effective,
             ..
         } = obligation;
 
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1517,6 +1573,7 @@ impl CreditLedger {
                     account_to_be_credited_id,
                     disbursed_amount: amount.to_usd(),
                     external_id,
+                    effective,
                 },
             )
             .await?;
@@ -1655,11 +1712,15 @@ impl CreditLedger {
 
     pub(super) async fn handle_facility_create(
         &self,
-        db: es_entity::DbOp<'_>,
+        mut db: es_entity::DbOp<'_>,
         credit_facility: &crate::CreditFacility,
         customer_type: CustomerType,
         duration_type: FacilityDurationType,
     ) -> Result<(), CreditLedgerError> {
+        let creation = credit_facility.creation_data();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut db, creation.effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(db);
 
         self.create_accounts_for_credit_facility(
@@ -1677,8 +1738,7 @@ impl CreditLedger {
         )
         .await?;
 
-        self.create_credit_facility(op, credit_facility.creation_data())
-            .await?;
+        self.create_credit_facility(op, creation).await?;
 
         Ok(())
     }
@@ -22,6 +22,7 @@ pub struct ActivateCreditFacilityParams {
     pub structuring_fee_amount: Decimal,
     pub currency: Currency,
     pub external_id: String,
+    pub effective: chrono::NaiveDate,
 }
 
 impl ActivateCreditFacilityParams {
@@ -99,6 +100,7 @@ impl From<ActivateCreditFacilityParams> for Params {
             structuring_fee_amount,
             currency,
             external_id,
+            effective,
         }: ActivateCreditFacilityParams,
     ) -> Self {
         let mut params = Self::default();
@@ -115,7 +117,7 @@ impl From<ActivateCreditFacilityParams> for Params {
         params.insert("structuring_fee_amount", structuring_fee_amount);
         params.insert("currency", currency);
         params.insert("external_id", external_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
         params
     }
 }
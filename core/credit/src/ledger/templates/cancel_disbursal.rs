@@ -15,6 +15,7 @@ pub struct CancelDisbursalParams {
     pub credit_omnibus_account: CalaAccountId,
     pub credit_facility_account: CalaAccountId,
     pub disbursed_amount: Decimal,
+    pub effective: chrono::NaiveDate,
 }
 
 impl CancelDisbursalParams {
@@ -56,6 +57,7 @@ impl From<CancelDisbursalParams> for Params {
             credit_omnibus_account,
             credit_facility_account,
             disbursed_amount,
+            effective,
         }: CancelDisbursalParams,
     ) -> Self {
         let mut params = Self::default();
@@ -63,7 +65,7 @@ impl From<CancelDisbursalParams> for Params {
         params.insert("credit_omnibus_account", credit_omnibus_account);
         params.insert("credit_facility_account", credit_facility_account);
         params.insert("disbursed_amount", disbursed_amount);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
         params
     }
 }
@@ -18,6 +18,7 @@ pub struct ConfirmDisbursalParams {
     pub account_to_be_credited_id: CalaAccountId,
     pub disbursed_amount: Decimal,
     pub external_id: String,
+    pub effective: chrono::NaiveDate,
 }
 
 impl ConfirmDisbursalParams {
@@ -77,6 +78,7 @@ impl From<ConfirmDisbursalParams> for Params {
             account_to_be_credited_id,
             disbursed_amount,
             external_id,
+            effective,
         }: ConfirmDisbursalParams,
     ) -> Self {
         let mut params = Self::default();
@@ -90,7 +92,7 @@ impl From<ConfirmDisbursalParams> for Params {
         params.insert("account_to_be_credited_id", account_to_be_credited_id);
         params.insert("disbursed_amount", disbursed_amount);
         params.insert("external_id", external_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
         params
     }
 }
@@ -18,6 +18,7 @@ pub struct CreateCreditFacilityParams {
     pub facility_amount: Decimal,
     pub currency: Currency,
     pub external_id: String,
+    pub effective: chrono::NaiveDate,
 }
 
 impl CreateCreditFacilityParams {
@@ -71,6 +72,7 @@ impl From<CreateCreditFacilityParams> for Params {
             facility_amount,
             currency,
             external_id,
+            effective,
         }: CreateCreditFacilityParams,
     ) -> Self {
         let mut params = Self::default();
@@ -80,7 +82,7 @@ impl From<CreateCreditFacilityParams> for Params {
         params.insert("facility_amount", facility_amount);
         params.insert("currency", currency);
         params.insert("external_id", external_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
         params
     }
 }
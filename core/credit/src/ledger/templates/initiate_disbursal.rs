@@ -15,6 +15,7 @@ pub struct InitiateDisbursalParams {
     pub credit_omnibus_account: CalaAccountId,
     pub credit_facility_account: CalaAccountId,
     pub disbursed_amount: Decimal,
+    pub effective: chrono::NaiveDate,
 }
 
 impl InitiateDisbursalParams {
@@ -57,6 +58,7 @@ impl From<InitiateDisbursalParams> for Params {
             credit_facility_account,
             disbursed_amount,
             credit_omnibus_account,
+            effective,
         }: InitiateDisbursalParams,
     ) -> Self {
         let mut params = Self::default();
@@ -64,7 +66,7 @@ impl From<InitiateDisbursalParams> for Params {
         params.insert("credit_omnibus_account", credit_omnibus_account);
         params.insert("credit_facility_account", credit_facility_account);
         params.insert("disbursed_amount", disbursed_amount);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
         params
     }
 }
@@ -23,8 +23,9 @@ mod terms_template;
 mod time;
 
 use audit::{AuditInfo, AuditSvc};
-use authz::PermissionCheck;
+use authz::{Attributes, PermissionCheck};
 use cala_ledger::CalaLedger;
+use core_accounting::AccountingPeriodLock;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
 use core_price::Price;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
@@ -153,7 +154,7 @@ where
         journal_id: cala_ledger::JournalId,
     ) -> Result<Self, CoreCreditError> {
         let publisher = CreditFacilityPublisher::new(outbox);
-        let ledger = CreditLedger::init(cala, journal_id).await?;
+        let ledger = CreditLedger::init(cala, journal_id, &AccountingPeriodLock::new(pool)).await?;
         let obligations = Obligations::new(pool, authz, cala, jobs, &publisher);
         let credit_facilities = CreditFacilities::new(
             pool,
@@ -376,17 +377,24 @@ where
         amount: UsdCents,
         terms: TermValues,
     ) -> Result<CreditFacility, CoreCreditError> {
-        let audit_info = self
-            .subject_can_create(sub, true)
-            .await?
-            .expect("audit info missing");
-
         let customer = self
             .customer
             .find_by_id(sub, customer_id)
             .await?
             .ok_or(CoreCreditError::CustomerNotFound)?;
 
+        let audit_info = self
+            .authz
+            .enforce_permission_with_attributes(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_CREATE,
+                &Attributes::new()
+                    .with(ATTRIBUTE_AMOUNT_USD, amount.to_usd())
+                    .with(ATTRIBUTE_CUSTOMER_TYPE, customer.customer_type.to_string()),
+            )
+            .await?;
+
         if self.config.customer_active_check_enabled && customer.status.is_inactive() {
             return Err(CoreCreditError::CustomerNotActive);
         }
@@ -496,9 +504,14 @@ where
         amount: UsdCents,
     ) -> Result<Disbursal, CoreCreditError> {
         let audit_info = self
-            .subject_can_initiate_disbursal(sub, true)
-            .await?
-            .expect("audit info missing");
+            .authz
+            .enforce_permission_with_attributes(
+                sub,
+                CoreCreditObject::all_disbursals(),
+                CoreCreditAction::DISBURSAL_INITIATE,
+                &Attributes::new().with(ATTRIBUTE_AMOUNT_USD, amount.to_usd()),
+            )
+            .await?;
 
         let facility = self
             .facilities
@@ -565,6 +578,7 @@ where
                 disbursal.id,
                 disbursal.amount,
                 disbursal.account_ids.facility_account_id,
+                disbursal.created_at().date_naive(),
             )
             .await?;
 
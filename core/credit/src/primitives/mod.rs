@@ -121,6 +121,9 @@ pub type TermsTemplateAllOrOne = AllOrOne<TermsTemplateId>;
 pub const PERMISSION_SET_CREDIT_WRITER: &str = "credit_writer";
 pub const PERMISSION_SET_CREDIT_VIEWER: &str = "credit_viewer";
 
+pub const ATTRIBUTE_AMOUNT_USD: &str = "amount_usd";
+pub const ATTRIBUTE_CUSTOMER_TYPE: &str = "customer_type";
+
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
 #[strum_discriminants(derive(strum::Display, strum::EnumString))]
 #[strum_discriminants(strum(serialize_all = "kebab-case"))]
@@ -146,6 +146,7 @@ where
                         tx_id,
                         disbursal.amount,
                         credit_facility.account_ids.facility_account_id,
+                        crate::time::now().date_naive(),
                     )
                     .await?;
                 disbursal
@@ -20,7 +20,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let storage = Storage::new(&StorageConfig::default());
     let document_storage = DocumentStorage::new(&pool, &storage);
 
-    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
     let customers = core_customer::Customers::new(&pool, &authz, &outbox, document_storage);
     let price = core_price::Price::new();
 
@@ -29,7 +30,6 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         .exec_migrations(false)
         .build()?;
     let cala = CalaLedger::init(cala_config).await?;
-    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
 
     let journal_id = helpers::init_journal(&cala).await?;
 
@@ -47,7 +47,17 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     )
     .await?;
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -9,6 +9,8 @@ use audit::AuditInfo;
 
 use crate::primitives::*;
 
+use super::error::DepositAccountError;
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -22,6 +24,8 @@ pub enum DepositAccountEvent {
         name: String,
         description: String,
         status: AccountStatus,
+        #[serde(default = "default_currencies")]
+        currencies: Vec<CurrencyCode>,
         audit_info: AuditInfo,
     },
     AccountStatusUpdated {
@@ -39,6 +43,7 @@ pub struct DepositAccount {
     pub name: String,
     pub description: String,
     pub status: AccountStatus,
+    pub currencies: Vec<CurrencyCode>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -50,6 +55,17 @@ impl DepositAccount {
             .expect("Deposit Account has never been persisted")
     }
 
+    pub fn supports_currency(&self, currency: CurrencyCode) -> bool {
+        self.currencies.contains(&currency)
+    }
+
+    pub fn ensure_currency(&self, currency: CurrencyCode) -> Result<(), DepositAccountError> {
+        if !self.supports_currency(currency) {
+            return Err(DepositAccountError::CurrencyNotSupported(currency));
+        }
+        Ok(())
+    }
+
     pub fn update_account_status(
         &mut self,
         status: AccountStatus,
@@ -78,6 +94,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                     name,
                     description,
                     status,
+                    currencies,
                     ..
                 } => {
                     builder = builder
@@ -87,6 +104,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                         .name(name.to_string())
                         .description(description.to_string())
                         .status(*status)
+                        .currencies(currencies.clone())
                 }
                 DepositAccountEvent::AccountStatusUpdated { status, .. } => {
                     builder = builder.status(*status);
@@ -98,6 +116,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
 }
 
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct NewDepositAccount {
     #[builder(setter(into))]
     pub(super) id: DepositAccountId,
@@ -107,10 +126,23 @@ pub struct NewDepositAccount {
     pub(super) name: String,
     pub(super) description: String,
     pub(super) active: bool,
+    #[builder(default = "default_currencies()")]
+    pub(super) currencies: Vec<CurrencyCode>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
 
+impl NewDepositAccountBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.currencies {
+            Some(ref currencies) if currencies.is_empty() => {
+                Err("Deposit account must hold at least one currency".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl NewDepositAccount {
     pub fn builder() -> NewDepositAccountBuilder {
         NewDepositAccountBuilder::default()
@@ -133,8 +165,13 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
                 } else {
                     AccountStatus::Inactive
                 },
+                currencies: self.currencies,
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+fn default_currencies() -> Vec<CurrencyCode> {
+    vec![CurrencyCode::Usd]
+}
@@ -8,6 +8,8 @@ pub enum DepositAccountError {
     EsEntityError(es_entity::EsEntityError),
     #[error("CommitteeError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositAccountError - CurrencyNotSupported: {0}")]
+    CurrencyNotSupported(crate::primitives::CurrencyCode),
 }
 
 es_entity::from_es_entity_error!(DepositAccountError);
@@ -0,0 +1,335 @@
+use cala_ledger::{DebitOrCredit, Layer};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::primitives::{CalaTransactionId, CurrencyCode, DepositAccountId, Money};
+
+use super::error::AccountStatementError;
+
+/// A single ledger entry posted to a deposit account in the statement currency.
+/// Only entries on the settled and pending layers are relevant for statements.
+#[derive(Debug, Clone)]
+pub struct AccountStatementLedgerEntry {
+    pub tx_id: CalaTransactionId,
+    pub recorded_at: DateTime<Utc>,
+    /// Booking date of the transaction, which is before `recorded_at` for
+    /// back-dated postings
+    pub effective: NaiveDate,
+    pub entry_type: String,
+    pub description: Option<String>,
+    pub direction: DebitOrCredit,
+    pub layer: Layer,
+    pub amount: Money,
+}
+
+impl AccountStatementLedgerEntry {
+    fn signed_minor_units(&self) -> i128 {
+        let units = i128::from(self.amount.minor_units());
+        match self.direction {
+            DebitOrCredit::Credit => units,
+            DebitOrCredit::Debit => -units,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum AccountStatementLineKind {
+    Deposit,
+    Withdrawal,
+    #[strum(serialize = "Cancelled Withdrawal")]
+    CancelledWithdrawal,
+    Disbursal,
+    Payment,
+    Other,
+}
+
+impl AccountStatementLineKind {
+    fn from_entry_type(entry_type: &str) -> Self {
+        if entry_type.starts_with("RECORD_DEPOSIT") {
+            Self::Deposit
+        } else if entry_type.starts_with("INITIATE_WITHDRAW")
+            || entry_type.starts_with("CONFIRM_WITHDRAW")
+        {
+            Self::Withdrawal
+        } else if entry_type.starts_with("CANCEL_WITHDRAW") {
+            Self::CancelledWithdrawal
+        } else if entry_type.starts_with("CONFIRM_DISBURSAL") {
+            Self::Disbursal
+        } else if entry_type.starts_with("RECORD_PAYMENT") {
+            Self::Payment
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountStatementLine {
+    pub effective: NaiveDate,
+    pub tx_id: CalaTransactionId,
+    pub kind: AccountStatementLineKind,
+    pub description: String,
+    pub credit: Option<Money>,
+    pub debit: Option<Money>,
+    pub balance: Option<Money>,
+}
+
+/// Everything that goes into a rendered statement for one account, currency
+/// and period. Settled entries make up the transactions while entries on the
+/// pending layer (e.g. withdrawals awaiting confirmation) are listed as
+/// accrued items.
+#[derive(Debug, Clone)]
+pub struct AccountStatementContent {
+    pub deposit_account_id: DepositAccountId,
+    pub account_name: String,
+    pub currency: CurrencyCode,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub generated_at: DateTime<Utc>,
+    pub opening_balance: Money,
+    pub closing_balance: Money,
+    pub total_credits: Money,
+    pub total_debits: Money,
+    pub accrued_balance: Money,
+    pub transactions: Vec<AccountStatementLine>,
+    pub accrued_items: Vec<AccountStatementLine>,
+}
+
+pub struct AccountStatementContentHeader {
+    pub deposit_account_id: DepositAccountId,
+    pub account_name: String,
+    pub currency: CurrencyCode,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl AccountStatementContent {
+    /// Rolls the current balances back through `entries` to derive the
+    /// balances at the start and end of the period.
+    ///
+    /// Entries are assigned to the period by their effective date. `entries`
+    /// must contain every entry effective since the start of the period
+    /// (entries before it are ignored) in any order.
+    pub fn build(
+        header: AccountStatementContentHeader,
+        current_settled: Money,
+        current_pending: Money,
+        mut entries: Vec<AccountStatementLedgerEntry>,
+    ) -> Result<Self, AccountStatementError> {
+        if header.period_start > header.period_end {
+            return Err(AccountStatementError::InvalidPeriod(
+                header.period_start,
+                header.period_end,
+            ));
+        }
+        let currency = header.currency;
+        let period_start = header.period_start;
+        let period_end = header.period_end;
+
+        entries.retain(|e| e.amount.currency() == currency && e.effective >= period_start);
+        entries.sort_by_key(|e| (e.effective, e.recorded_at));
+
+        let net_after_period = |layer: Layer| -> i128 {
+            entries
+                .iter()
+                .filter(|e| e.layer == layer && e.effective > period_end)
+                .map(AccountStatementLedgerEntry::signed_minor_units)
+                .sum()
+        };
+        let closing_settled =
+            i128::from(current_settled.minor_units()) - net_after_period(Layer::Settled);
+        let closing_pending =
+            i128::from(current_pending.minor_units()) - net_after_period(Layer::Pending);
+
+        let in_period = entries
+            .iter()
+            .filter(|e| e.effective <= period_end)
+            .collect::<Vec<_>>();
+
+        let (mut total_credits, mut total_debits) = (0i128, 0i128);
+        for entry in in_period.iter().filter(|e| e.layer == Layer::Settled) {
+            match entry.direction {
+                DebitOrCredit::Credit => total_credits += i128::from(entry.amount.minor_units()),
+                DebitOrCredit::Debit => total_debits += i128::from(entry.amount.minor_units()),
+            }
+        }
+        let opening_settled = closing_settled - total_credits + total_debits;
+
+        let mut running = opening_settled;
+        let mut transactions = Vec::new();
+        let mut accrued_items = Vec::new();
+        for entry in in_period {
+            let mut line = AccountStatementLine {
+                effective: entry.effective,
+                tx_id: entry.tx_id,
+                kind: AccountStatementLineKind::from_entry_type(&entry.entry_type),
+                description: entry.description.clone().unwrap_or_default(),
+                credit: None,
+                debit: None,
+                balance: None,
+            };
+            if line.description.is_empty() {
+                line.description = line.kind.to_string();
+            }
+            match entry.direction {
+                DebitOrCredit::Credit => line.credit = Some(entry.amount),
+                DebitOrCredit::Debit => line.debit = Some(entry.amount),
+            }
+            if entry.layer == Layer::Settled {
+                running += entry.signed_minor_units();
+                line.balance = Some(to_money(running, currency, "running balance")?);
+                transactions.push(line);
+            } else {
+                accrued_items.push(line);
+            }
+        }
+
+        Ok(Self {
+            deposit_account_id: header.deposit_account_id,
+            account_name: header.account_name,
+            currency,
+            period_start: header.period_start,
+            period_end: header.period_end,
+            generated_at: header.generated_at,
+            opening_balance: to_money(opening_settled, currency, "opening balance")?,
+            closing_balance: to_money(closing_settled, currency, "closing balance")?,
+            total_credits: to_money(total_credits, currency, "total credits")?,
+            total_debits: to_money(total_debits, currency, "total debits")?,
+            accrued_balance: to_money(closing_pending, currency, "accrued balance")?,
+            transactions,
+            accrued_items,
+        })
+    }
+}
+
+pub(super) fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+}
+
+fn to_money(
+    minor_units: i128,
+    currency: CurrencyCode,
+    what: &'static str,
+) -> Result<Money, AccountStatementError> {
+    u64::try_from(minor_units)
+        .map(|units| Money::new(units, currency))
+        .map_err(|_| AccountStatementError::NegativeBalance(what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn entry(
+        day: u32,
+        entry_type: &str,
+        direction: DebitOrCredit,
+        layer: Layer,
+        units: u64,
+    ) -> AccountStatementLedgerEntry {
+        AccountStatementLedgerEntry {
+            tx_id: CalaTransactionId::new(),
+            recorded_at: start_of_day(date(day)),
+            effective: date(day),
+            entry_type: entry_type.to_string(),
+            description: None,
+            direction,
+            layer,
+            amount: Money::new(units, CurrencyCode::Usd),
+        }
+    }
+
+    fn header() -> AccountStatementContentHeader {
+        AccountStatementContentHeader {
+            deposit_account_id: DepositAccountId::new(),
+            account_name: "Deposit Account".to_string(),
+            currency: CurrencyCode::Usd,
+            period_start: date(10),
+            period_end: date(20),
+            generated_at: start_of_day(date(31)),
+        }
+    }
+
+    #[test]
+    fn derives_opening_and_closing_balances() {
+        use DebitOrCredit::*;
+        use Layer::*;
+
+        let entries = vec![
+            entry(5, "RECORD_DEPOSIT_CR", Credit, Settled, 1_000),
+            entry(12, "RECORD_DEPOSIT_CR", Credit, Settled, 500),
+            entry(15, "INITIATE_WITHDRAW_SETTLED_DR", Debit, Settled, 200),
+            entry(15, "INITIATE_WITHDRAW_PENDING_CR", Credit, Pending, 200),
+            entry(25, "RECORD_DEPOSIT_CR", Credit, Settled, 300),
+        ];
+        let content = AccountStatementContent::build(
+            header(),
+            Money::new(1_600, CurrencyCode::Usd),
+            Money::new(200, CurrencyCode::Usd),
+            entries,
+        )
+        .unwrap();
+
+        assert_eq!(content.opening_balance.minor_units(), 1_000);
+        assert_eq!(content.closing_balance.minor_units(), 1_300);
+        assert_eq!(content.total_credits.minor_units(), 500);
+        assert_eq!(content.total_debits.minor_units(), 200);
+        assert_eq!(content.accrued_balance.minor_units(), 200);
+        assert_eq!(content.transactions.len(), 2);
+        assert_eq!(content.accrued_items.len(), 1);
+        assert_eq!(
+            content.transactions[1].kind,
+            AccountStatementLineKind::Withdrawal
+        );
+        assert_eq!(
+            content.transactions[1].balance,
+            Some(Money::new(1_300, CurrencyCode::Usd))
+        );
+    }
+
+    #[test]
+    fn assigns_back_dated_entries_by_effective_date() {
+        use DebitOrCredit::*;
+        use Layer::*;
+
+        let mut back_dated = entry(25, "RECORD_DEPOSIT_CR", Credit, Settled, 300);
+        back_dated.effective = date(15);
+        let entries = vec![
+            entry(5, "RECORD_DEPOSIT_CR", Credit, Settled, 1_000),
+            back_dated,
+        ];
+        let content = AccountStatementContent::build(
+            header(),
+            Money::new(1_300, CurrencyCode::Usd),
+            Money::zero(CurrencyCode::Usd),
+            entries,
+        )
+        .unwrap();
+
+        assert_eq!(content.opening_balance.minor_units(), 1_000);
+        assert_eq!(content.closing_balance.minor_units(), 1_300);
+        assert_eq!(content.transactions.len(), 1);
+        assert_eq!(content.transactions[0].effective, date(15));
+    }
+
+    #[test]
+    fn rejects_inverted_period() {
+        let mut header = header();
+        header.period_end = date(1);
+        assert!(matches!(
+            AccountStatementContent::build(
+                header,
+                Money::zero(CurrencyCode::Usd),
+                Money::zero(CurrencyCode::Usd),
+                vec![],
+            ),
+            Err(AccountStatementError::InvalidPeriod(..))
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+use ::csv::Writer;
+
+use crate::primitives::Money;
+
+use super::{content::*, error::AccountStatementError};
+
+pub const ACCOUNT_STATEMENT_CSV_CONTENT_TYPE: &str = "text/csv";
+
+pub fn render(content: &AccountStatementContent) -> Result<Vec<u8>, AccountStatementError> {
+    let mut wtr = Writer::from_writer(vec![]);
+    let precision = content.currency.precision() as usize;
+    let amount = |money: Option<Money>| -> Result<String, AccountStatementError> {
+        Ok(match money {
+            Some(m) => format!("{:.*}", precision, m.to_major()?),
+            None => String::new(),
+        })
+    };
+
+    wtr.write_record([
+        "Section",
+        "Booking Date",
+        "Transaction Id",
+        "Type",
+        "Description",
+        "Currency",
+        "Debit Amount",
+        "Credit Amount",
+        "Balance",
+    ])
+    .map_err(|e| AccountStatementError::CsvError(e.to_string()))?;
+
+    let summary = |label: &str, value: Money| -> Result<[String; 9], AccountStatementError> {
+        Ok([
+            "Summary".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            label.to_string(),
+            content.currency.to_string(),
+            String::new(),
+            String::new(),
+            amount(Some(value))?,
+        ])
+    };
+
+    wtr.write_record(summary(
+        &format!("Opening balance {}", content.period_start),
+        content.opening_balance,
+    )?)
+    .map_err(|e| AccountStatementError::CsvError(e.to_string()))?;
+
+    for (section, lines) in [
+        ("Transaction", &content.transactions),
+        ("Accrued", &content.accrued_items),
+    ] {
+        for line in lines {
+            wtr.write_record([
+                section.to_string(),
+                line.effective.to_string(),
+                line.tx_id.to_string(),
+                line.kind.to_string(),
+                line.description.clone(),
+                content.currency.to_string(),
+                amount(line.debit)?,
+                amount(line.credit)?,
+                amount(line.balance)?,
+            ])
+            .map_err(|e| AccountStatementError::CsvError(e.to_string()))?;
+        }
+    }
+
+    for (label, value) in [
+        ("Total credits".to_string(), content.total_credits),
+        ("Total debits".to_string(), content.total_debits),
+        (
+            format!("Closing balance {}", content.period_end),
+            content.closing_balance,
+        ),
+        ("Accrued items".to_string(), content.accrued_balance),
+    ] {
+        wtr.write_record(summary(&label, value)?)
+            .map_err(|e| AccountStatementError::CsvError(e.to_string()))?;
+    }
+
+    wtr.into_inner()
+        .map_err(|e| AccountStatementError::CsvError(e.to_string()))
+}
@@ -0,0 +1,176 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{AccountStatementId, CurrencyCode, DepositAccountId, DocumentId, Money};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum AccountStatementFormat {
+    Csv,
+    Pdf,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AccountStatementId")]
+pub enum AccountStatementEvent {
+    Initialized {
+        id: AccountStatementId,
+        deposit_account_id: DepositAccountId,
+        currency: CurrencyCode,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        opening_balance: Money,
+        closing_balance: Money,
+        total_credits: Money,
+        total_debits: Money,
+        accrued_balance: Money,
+        csv_document_id: DocumentId,
+        pdf_document_id: DocumentId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct AccountStatement {
+    pub id: AccountStatementId,
+    pub deposit_account_id: DepositAccountId,
+    pub currency: CurrencyCode,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub opening_balance: Money,
+    pub closing_balance: Money,
+    pub total_credits: Money,
+    pub total_debits: Money,
+    pub accrued_balance: Money,
+    pub csv_document_id: DocumentId,
+    pub pdf_document_id: DocumentId,
+
+    events: EntityEvents<AccountStatementEvent>,
+}
+
+impl AccountStatement {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for account statement")
+    }
+
+    pub fn document_id(&self, format: AccountStatementFormat) -> DocumentId {
+        match format {
+            AccountStatementFormat::Csv => self.csv_document_id,
+            AccountStatementFormat::Pdf => self.pdf_document_id,
+        }
+    }
+
+    pub fn covers(
+        &self,
+        currency: CurrencyCode,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> bool {
+        self.currency == currency
+            && self.period_start == period_start
+            && self.period_end == period_end
+    }
+}
+
+impl TryFromEvents<AccountStatementEvent> for AccountStatement {
+    fn try_from_events(events: EntityEvents<AccountStatementEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = AccountStatementBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                AccountStatementEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    currency,
+                    period_start,
+                    period_end,
+                    opening_balance,
+                    closing_balance,
+                    total_credits,
+                    total_debits,
+                    accrued_balance,
+                    csv_document_id,
+                    pdf_document_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .currency(*currency)
+                        .period_start(*period_start)
+                        .period_end(*period_end)
+                        .opening_balance(*opening_balance)
+                        .closing_balance(*closing_balance)
+                        .total_credits(*total_credits)
+                        .total_debits(*total_debits)
+                        .accrued_balance(*accrued_balance)
+                        .csv_document_id(*csv_document_id)
+                        .pdf_document_id(*pdf_document_id);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewAccountStatement {
+    #[builder(setter(into))]
+    pub(super) id: AccountStatementId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) currency: CurrencyCode,
+    pub(super) period_start: NaiveDate,
+    pub(super) period_end: NaiveDate,
+    pub(super) opening_balance: Money,
+    pub(super) closing_balance: Money,
+    pub(super) total_credits: Money,
+    pub(super) total_debits: Money,
+    pub(super) accrued_balance: Money,
+    #[builder(setter(into))]
+    pub(super) csv_document_id: DocumentId,
+    #[builder(setter(into))]
+    pub(super) pdf_document_id: DocumentId,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewAccountStatement {
+    pub fn builder() -> NewAccountStatementBuilder {
+        NewAccountStatementBuilder::default()
+    }
+}
+
+impl IntoEvents<AccountStatementEvent> for NewAccountStatement {
+    fn into_events(self) -> EntityEvents<AccountStatementEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountStatementEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                currency: self.currency,
+                period_start: self.period_start,
+                period_end: self.period_end,
+                opening_balance: self.opening_balance,
+                closing_balance: self.closing_balance,
+                total_credits: self.total_credits,
+                total_debits: self.total_debits,
+                accrued_balance: self.accrued_balance,
+                csv_document_id: self.csv_document_id,
+                pdf_document_id: self.pdf_document_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
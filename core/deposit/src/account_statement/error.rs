@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AccountStatementError {
+    #[error("AccountStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountStatementError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("AccountStatementError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("AccountStatementError - ConversionError: {0}")]
+    ConversionError(#[from] core_money::ConversionError),
+    #[error("AccountStatementError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
+    #[error("AccountStatementError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("AccountStatementError - CsvError: {0}")]
+    CsvError(String),
+    #[error("AccountStatementError - InvalidPeriod: {0} is after {1}")]
+    InvalidPeriod(chrono::NaiveDate, chrono::NaiveDate),
+    #[error("AccountStatementError - NegativeBalance: {0}")]
+    NegativeBalance(&'static str),
+}
+
+es_entity::from_es_entity_error!(AccountStatementError);
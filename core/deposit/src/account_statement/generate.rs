@@ -0,0 +1,159 @@
+use chrono::NaiveDate;
+
+use audit::AuditInfo;
+use document_storage::DocumentStorage;
+
+use crate::{
+    DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT,
+    account::DepositAccount,
+    ledger::DepositLedger,
+    primitives::{AccountStatementId, CurrencyCode},
+    time,
+};
+
+use super::{
+    content::*,
+    csv,
+    entity::*,
+    error::AccountStatementError,
+    pdf,
+    repo::{account_statement_cursor::AccountStatementsByCreatedAtCursor, *},
+};
+
+#[derive(Clone)]
+pub struct AccountStatementGenerator {
+    repo: AccountStatementRepo,
+    ledger: DepositLedger,
+    document_storage: DocumentStorage,
+}
+
+impl AccountStatementGenerator {
+    pub fn new(
+        repo: &AccountStatementRepo,
+        ledger: &DepositLedger,
+        document_storage: &DocumentStorage,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            ledger: ledger.clone(),
+            document_storage: document_storage.clone(),
+        }
+    }
+
+    /// Renders the statement for the given period as CSV and PDF, stores both
+    /// documents and records the statement summary.
+    pub async fn generate(
+        &self,
+        account: &DepositAccount,
+        currency: CurrencyCode,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<AccountStatement, AccountStatementError> {
+        let balance = self
+            .ledger
+            .balances(account.id, &[currency])
+            .await?
+            .pop()
+            .expect("one balance per requested currency");
+        let entries = self
+            .ledger
+            .account_statement_entries(account.id, currency, start_of_day(period_start))
+            .await?;
+        let content = AccountStatementContent::build(
+            AccountStatementContentHeader {
+                deposit_account_id: account.id,
+                account_name: account.name.clone(),
+                currency,
+                period_start,
+                period_end,
+                generated_at: time::now(),
+            },
+            balance.settled,
+            balance.pending,
+            entries,
+        )?;
+
+        let id = AccountStatementId::new();
+        let filename = format!(
+            "statement-{}-{}-{}-{}",
+            account.id, currency, period_start, period_end
+        );
+        let csv_document = self
+            .document_storage
+            .create_and_upload(
+                audit_info.clone(),
+                csv::render(&content)?,
+                format!("{}.csv", filename),
+                csv::ACCOUNT_STATEMENT_CSV_CONTENT_TYPE,
+                id,
+                DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT,
+            )
+            .await?;
+        let pdf_document = self
+            .document_storage
+            .create_and_upload(
+                audit_info.clone(),
+                pdf::render(&content)?,
+                format!("{}.pdf", filename),
+                pdf::ACCOUNT_STATEMENT_PDF_CONTENT_TYPE,
+                id,
+                DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT,
+            )
+            .await?;
+
+        let new_statement = NewAccountStatement::builder()
+            .id(id)
+            .deposit_account_id(account.id)
+            .currency(currency)
+            .period_start(period_start)
+            .period_end(period_end)
+            .opening_balance(content.opening_balance)
+            .closing_balance(content.closing_balance)
+            .total_credits(content.total_credits)
+            .total_debits(content.total_debits)
+            .accrued_balance(content.accrued_balance)
+            .csv_document_id(csv_document.id)
+            .pdf_document_id(pdf_document.id)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new account statement");
+
+        self.repo.create(new_statement).await
+    }
+
+    /// Returns the statement previously generated for exactly this period, if any.
+    pub async fn find_existing(
+        &self,
+        account: &DepositAccount,
+        currency: CurrencyCode,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Option<AccountStatement>, AccountStatementError> {
+        let mut has_next_page = true;
+        let mut after: Option<AccountStatementsByCreatedAtCursor> = None;
+        while has_next_page {
+            let statements = self
+                .repo
+                .list_for_deposit_account_id_by_created_at(
+                    account.id,
+                    es_entity::PaginatedQueryArgs::<AccountStatementsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            (after, has_next_page) = (statements.end_cursor, statements.has_next_page);
+
+            if let Some(statement) = statements
+                .entities
+                .into_iter()
+                .find(|s| s.covers(currency, period_start, period_end))
+            {
+                return Ok(Some(statement));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositEvent, CoreDepositObject,
+    account::{DepositAccountRepo, deposit_account_cursor::DepositAccountsByCreatedAtCursor},
+    time,
+};
+
+use super::{content::start_of_day, generate::AccountStatementGenerator, repo::*};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccountStatementJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> AccountStatementJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> JobConfig for AccountStatementJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = AccountStatementJobInitializer<Perms, E>;
+}
+
+pub struct AccountStatementJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    repo: AccountStatementRepo,
+    generator: AccountStatementGenerator,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> AccountStatementJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        repo: &AccountStatementRepo,
+        generator: &AccountStatementGenerator,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            repo: repo.clone(),
+            generator: generator.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const GENERATE_ACCOUNT_STATEMENTS_JOB: JobType = JobType::new("generate-account-statements");
+impl<Perms, E> JobInitializer for AccountStatementJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        GENERATE_ACCOUNT_STATEMENTS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(AccountStatementJobRunner::<Perms, E> {
+            accounts: self.accounts.clone(),
+            repo: self.repo.clone(),
+            generator: self.generator.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct AccountStatementJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    repo: AccountStatementRepo,
+    generator: AccountStatementGenerator,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for AccountStatementJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = time::now();
+        let (period_start, period_end) = previous_month(now.date_naive());
+        let period_close = start_of_day(period_end) + Duration::days(1);
+
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities {
+                if account.created_at() >= period_close {
+                    continue;
+                }
+                for currency in account.currencies.iter().copied() {
+                    if self
+                        .generator
+                        .find_existing(&account, currency, period_start, period_end)
+                        .await?
+                        .is_some()
+                    {
+                        continue;
+                    }
+
+                    let mut db = self.repo.begin_op().await?;
+                    let audit_info = self
+                        .audit
+                        .record_system_entry_in_tx(
+                            db.tx(),
+                            CoreDepositObject::all_account_statements(),
+                            CoreDepositAction::ACCOUNT_STATEMENT_GENERATE,
+                        )
+                        .await?;
+                    db.commit().await?;
+
+                    self.generator
+                        .generate(&account, currency, period_start, period_end, audit_info)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(start_of_next_month(now)))
+    }
+}
+
+fn previous_month(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let first_of_month = today.with_day(1).expect("first day of month");
+    let period_end = first_of_month - Duration::days(1);
+    let period_start = period_end.with_day(1).expect("first day of month");
+    (period_start, period_end)
+}
+
+fn start_of_next_month(now: DateTime<Utc>) -> DateTime<Utc> {
+    let first_of_month = now.date_naive().with_day(1).expect("first day of month");
+    start_of_day(
+        first_of_month
+            .checked_add_months(chrono::Months::new(1))
+            .expect("next month out of range"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_period_is_previous_calendar_month() {
+        let (start, end) = previous_month(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap());
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 2, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+
+        let (start, end) = previous_month(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        assert_eq!(start, NaiveDate::from_ymd_opt(2024, 12, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn reschedules_at_start_of_next_month() {
+        let now = start_of_day(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+        assert_eq!(
+            start_of_next_month(now),
+            start_of_day(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+        );
+    }
+}
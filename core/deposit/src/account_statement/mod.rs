@@ -0,0 +1,17 @@
+mod content;
+mod csv;
+mod entity;
+pub mod error;
+mod generate;
+mod job;
+mod pdf;
+mod repo;
+
+pub(crate) use content::AccountStatementLedgerEntry;
+#[cfg(feature = "json-schema")]
+pub use entity::AccountStatementEvent;
+pub use entity::{AccountStatement, AccountStatementFormat};
+pub(super) use generate::*;
+pub(super) use job::*;
+pub use repo::account_statement_cursor::AccountStatementsByCreatedAtCursor;
+pub(super) use repo::*;
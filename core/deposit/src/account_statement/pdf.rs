@@ -0,0 +1,235 @@
+use std::fmt::Write as _;
+
+use crate::primitives::Money;
+
+use super::{content::*, error::AccountStatementError};
+
+pub const ACCOUNT_STATEMENT_PDF_CONTENT_TYPE: &str = "application/pdf";
+
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 50;
+const FONT_SIZE: u32 = 8;
+const LEADING: u32 = 11;
+const LINES_PER_PAGE: usize = ((PAGE_HEIGHT - 2 * MARGIN) / LEADING) as usize;
+
+pub fn render(content: &AccountStatementContent) -> Result<Vec<u8>, AccountStatementError> {
+    let lines = text_lines(content)?;
+    let pages = lines
+        .chunks(LINES_PER_PAGE)
+        .map(|chunk| chunk.to_vec())
+        .collect::<Vec<_>>();
+    Ok(write_document(&pages))
+}
+
+fn text_lines(content: &AccountStatementContent) -> Result<Vec<String>, AccountStatementError> {
+    let precision = content.currency.precision() as usize;
+    let amount = |money: Option<Money>| -> Result<String, AccountStatementError> {
+        Ok(match money {
+            Some(m) => format!("{:.*}", precision, m.to_major()?),
+            None => String::new(),
+        })
+    };
+    let row = |line: &AccountStatementLine,
+               with_balance: bool|
+     -> Result<String, AccountStatementError> {
+        let description = if line.description == line.kind.to_string() {
+            line.description.clone()
+        } else {
+            format!("{}: {}", line.kind, line.description)
+        };
+        let description = description.chars().take(38).collect::<String>();
+        Ok(format!(
+            "{:<10}  {:<38}  {:>14}  {:>14}  {:>14}",
+            line.effective.format("%Y-%m-%d"),
+            description,
+            amount(line.debit)?,
+            amount(line.credit)?,
+            if with_balance {
+                amount(line.balance)?
+            } else {
+                String::new()
+            },
+        ))
+    };
+    let header = format!(
+        "{:<10}  {:<38}  {:>14}  {:>14}  {:>14}",
+        "Date", "Description", "Debit", "Credit", "Balance"
+    );
+
+    let mut lines = vec![
+        "Deposit Account Statement".to_string(),
+        String::new(),
+        format!("Account:   {}", content.account_name),
+        format!("Account Id: {}", content.deposit_account_id),
+        format!("Currency:  {}", content.currency),
+        format!(
+            "Period:    {} to {}",
+            content.period_start, content.period_end
+        ),
+        format!(
+            "Generated: {}",
+            content.generated_at.format("%Y-%m-%d %H:%M UTC")
+        ),
+        String::new(),
+        format!(
+            "Opening balance ({}): {}",
+            content.period_start,
+            amount(Some(content.opening_balance))?
+        ),
+        String::new(),
+        "Transactions".to_string(),
+        header.clone(),
+    ];
+    if content.transactions.is_empty() {
+        lines.push("No transactions in this period".to_string());
+    }
+    for line in &content.transactions {
+        lines.push(row(line, true)?);
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "Total credits: {}",
+        amount(Some(content.total_credits))?
+    ));
+    lines.push(format!(
+        "Total debits:  {}",
+        amount(Some(content.total_debits))?
+    ));
+    lines.push(format!(
+        "Closing balance ({}): {}",
+        content.period_end,
+        amount(Some(content.closing_balance))?
+    ));
+    lines.push(String::new());
+    lines.push("Accrued items".to_string());
+    if content.accrued_items.is_empty() {
+        lines.push("No accrued items in this period".to_string());
+    } else {
+        lines.push(header);
+        for line in &content.accrued_items {
+            lines.push(row(line, false)?);
+        }
+    }
+    lines.push(format!(
+        "Accrued balance ({}): {}",
+        content.period_end,
+        amount(Some(content.accrued_balance))?
+    ));
+    Ok(lines)
+}
+
+/// Writes a minimal PDF 1.4 document with one monospaced text page per chunk of lines.
+fn write_document(pages: &[Vec<String>]) -> Vec<u8> {
+    const FIRST_PAGE_OBJ: usize = 4;
+
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len())
+                .map(|i| format!("{} 0 R", FIRST_PAGE_OBJ + 2 * i))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Courier >>".to_string(),
+    ];
+    for (idx, lines) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            FIRST_PAGE_OBJ + 2 * idx + 1
+        ));
+        let stream = page_stream(lines);
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            stream.len(),
+            stream
+        ));
+    }
+
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (idx, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        let _ = write!(out, "{} 0 obj\n{}\nendobj\n", idx + 1, object);
+    }
+    let xref_offset = out.len();
+    let _ = writeln!(out, "xref\n0 {}", objects.len() + 1);
+    out.push_str("0000000000 65535 f \n");
+    for offset in offsets {
+        let _ = writeln!(out, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(
+        out,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    );
+    out.into_bytes()
+}
+
+fn page_stream(lines: &[String]) -> String {
+    let mut stream = format!(
+        "BT\n/F1 {} Tf\n{} TL\n{} {} Td\n",
+        FONT_SIZE,
+        LEADING,
+        MARGIN,
+        PAGE_HEIGHT - MARGIN
+    );
+    for line in lines {
+        let _ = writeln!(stream, "({}) Tj T*", escape(line));
+    }
+    stream.push_str("ET");
+    stream
+}
+
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '(' | ')' | '\\' => format!("\\{}", c),
+            c if c.is_ascii() && !c.is_ascii_control() => c.to_string(),
+            _ => "?".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_valid_xref_offsets() {
+        let pages = vec![
+            vec!["Deposit Account Statement (USD)".to_string()],
+            vec!["second page".to_string()],
+        ];
+        let pdf = String::from_utf8(write_document(&pages)).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.contains("(Deposit Account Statement \\(USD\\)) Tj"));
+
+        let startxref = pdf
+            .lines()
+            .skip_while(|l| *l != "startxref")
+            .nth(1)
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        assert!(pdf[startxref..].starts_with("xref"));
+
+        let first_offset = pdf[startxref..]
+            .lines()
+            .nth(3)
+            .unwrap()
+            .split(' ')
+            .next()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        assert!(pdf[first_offset..].starts_with("1 0 obj"));
+    }
+}
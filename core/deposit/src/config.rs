@@ -0,0 +1,55 @@
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::CurrencyCode;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositConfig {
+    #[serde(default)]
+    pub payment_file: PaymentFileConfig,
+    #[serde(default)]
+    pub account_statement: AccountStatementConfig,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PaymentFileConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_job_interval_secs")]
+    pub job_interval_secs: u64,
+    #[serde(default)]
+    pub debtor_name: String,
+    #[serde(default)]
+    pub debtor_iban: String,
+    #[serde(default)]
+    pub debtor_bic: Option<String>,
+    #[serde(default)]
+    pub currency: CurrencyCode,
+}
+
+impl Default for PaymentFileConfig {
+    fn default() -> Self {
+        PaymentFileConfig {
+            enabled: false,
+            job_interval_secs: default_job_interval_secs(),
+            debtor_name: String::new(),
+            debtor_iban: String::new(),
+            debtor_bic: None,
+            currency: CurrencyCode::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct AccountStatementConfig {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+fn default_job_interval_secs() -> u64 {
+    60 * 60
+}
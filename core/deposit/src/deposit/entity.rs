@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
-use core_money::UsdCents;
+use core_money::{CurrencyCode, Money, UsdCents};
 use es_entity::*;
 
 use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId};
@@ -19,6 +19,8 @@ pub enum DepositEvent {
         ledger_transaction_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default)]
+        currency: CurrencyCode,
         reference: String,
         audit_info: AuditInfo,
     },
@@ -29,7 +31,7 @@ pub enum DepositEvent {
 pub struct Deposit {
     pub id: DepositId,
     pub deposit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub reference: String,
     events: EntityEvents<DepositEvent>,
 }
@@ -52,12 +54,13 @@ impl TryFromEvents<DepositEvent> for Deposit {
                     reference,
                     deposit_account_id,
                     amount,
+                    currency,
                     ..
                 } => {
                     builder = builder
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
-                        .amount(*amount)
+                        .amount(Money::new(amount.into_inner(), *currency))
                         .reference(reference.clone());
                 }
             }
@@ -76,7 +79,7 @@ pub struct NewDeposit {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: Money,
     reference: Option<String>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
@@ -114,7 +117,8 @@ impl IntoEvents<DepositEvent> for NewDeposit {
                 id: self.id,
                 ledger_transaction_id: self.ledger_transaction_id,
                 deposit_account_id: self.deposit_account_id,
-                amount: self.amount,
+                amount: UsdCents::from(self.amount.minor_units()),
+                currency: self.amount.currency(),
                 audit_info: self.audit_info,
             }],
         )
@@ -1,4 +1,4 @@
-use core_money::UsdCents;
+use core_money::{CurrencyCode, Money, UsdCents};
 
 pub struct DepositAccountBalance {
     pub settled: UsdCents,
@@ -11,3 +11,19 @@ impl DepositAccountBalance {
         pending: UsdCents::ZERO,
     };
 }
+
+pub struct DepositAccountCurrencyBalance {
+    pub currency: CurrencyCode,
+    pub settled: Money,
+    pub pending: Money,
+}
+
+impl DepositAccountCurrencyBalance {
+    pub fn zero(currency: CurrencyCode) -> Self {
+        DepositAccountCurrencyBalance {
+            currency,
+            settled: Money::zero(currency),
+            pending: Money::zero(currency),
+        }
+    }
+}
@@ -24,6 +24,20 @@ pub enum CoreDepositError {
     ),
     #[error("CoreDepositError - JobError: {0}")]
     JobError(#[from] job::error::JobError),
+    #[error("CoreDepositError - StatementImportError: {0}")]
+    StatementImportError(#[from] crate::statement_import::error::StatementImportError),
+    #[error("CoreDepositError - PaymentFileError: {0}")]
+    PaymentFileError(#[from] crate::payment_file::error::PaymentFileError),
+    #[error("CoreDepositError - AccountStatementError: {0}")]
+    AccountStatementError(#[from] crate::account_statement::error::AccountStatementError),
+    #[error("CoreDepositError - ScheduledWithdrawalError: {0}")]
+    ScheduledWithdrawalError(#[from] crate::scheduled_withdrawal::error::ScheduledWithdrawalError),
+    #[error("CoreDepositError - StorageError: {0}")]
+    StorageError(#[from] cloud_storage::error::StorageError),
+    #[error("CoreDepositError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("CoreDepositError - ConversionError: {0}")]
+    ConversionError(#[from] core_money::ConversionError),
     #[error("CoreDepositError - ProcessError: {0}")]
     ProcessError(#[from] crate::processes::error::ProcessError),
     #[error("CoreDepositError - SubjectIsNotDepositAccountHolder")]
@@ -38,8 +52,18 @@ pub enum CoreDepositError {
     DepositAccountNotActive,
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
+    #[error("CoreDepositError - DepositAccountBuilderError: {0}")]
+    DepositAccountBuilderError(#[from] super::NewDepositAccountBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
+    #[error("CoreDepositError - StatementImportBuilderError: {0}")]
+    StatementImportBuilderError(#[from] super::NewStatementImportBuilderError),
+    #[error("CoreDepositError - ScheduledWithdrawalBuilderError: {0}")]
+    ScheduledWithdrawalBuilderError(#[from] super::NewScheduledWithdrawalBuilderError),
+    #[error("CoreDepositError - UnsupportedStatementCurrency: {0}")]
+    UnsupportedStatementCurrency(String),
+    #[error("CoreDepositError - AmountTiersRequireUsd: {0}")]
+    AmountTiersRequireUsd(core_money::CurrencyCode),
 }
 
 impl CoreDepositError {
@@ -51,4 +75,16 @@ impl CoreDepositError {
             ))
         )
     }
+
+    /// Whether recording a deposit failed because of the deposit itself rather
+    /// than the infrastructure recording it.
+    pub fn is_deposit_rejected(&self) -> bool {
+        matches!(
+            self,
+            Self::DepositAccountNotActive
+                | Self::DepositAccountError(
+                    crate::account::error::DepositAccountError::CurrencyNotSupported(_)
+                )
+        )
+    }
 }
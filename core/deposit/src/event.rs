@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::primitives::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
-use core_money::UsdCents;
+use core_money::{CurrencyCode, UsdCents};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -14,10 +14,14 @@ pub enum CoreDepositEvent {
         id: DepositId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default)]
+        currency: CurrencyCode,
     },
     WithdrawalConfirmed {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default)]
+        currency: CurrencyCode,
     },
 }
@@ -1,10 +1,12 @@
-use audit::AuditSvc;
+use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
+use document_storage::{DocumentStorage, GeneratedDocumentDownloadLink};
 use outbox::OutboxEventMarker;
 use tracing::instrument;
 
 use crate::{
     account::*,
+    account_statement::*,
     deposit::*,
     deposit_account_balance::*,
     deposit_account_cursor::DepositAccountsByCreatedAtCursor,
@@ -26,7 +28,9 @@ where
     accounts: &'a DepositAccountRepo<E>,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
+    account_statements: &'a AccountStatementRepo,
     ledger: &'a DepositLedger,
+    document_storage: &'a DocumentStorage,
     authz: &'a Perms,
 }
 
@@ -45,7 +49,9 @@ where
         accounts: &'a DepositAccountRepo<E>,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
+        account_statements: &'a AccountStatementRepo,
         ledger: &'a DepositLedger,
+        document_storage: &'a DocumentStorage,
         authz: &'a Perms,
     ) -> Self {
         Self {
@@ -54,7 +60,9 @@ where
             accounts,
             deposits,
             withdrawals,
+            account_statements,
             ledger,
+            document_storage,
             authz,
         }
     }
@@ -105,6 +113,28 @@ where
         Ok(balance)
     }
 
+    #[instrument(name = "deposit.for_subject.account_balances", skip(self), err)]
+    pub async fn account_balances(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountCurrencyBalance>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::deposit_account(account_id),
+            CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+        )
+        .await?;
+
+        let account = self.accounts.find_by_id(account_id).await?;
+        let balances = self
+            .ledger
+            .balances(account_id, &account.currencies)
+            .await?;
+        Ok(balances)
+    }
+
     #[instrument(name = "deposit.for_subject.account_history", skip(self), err)]
     pub async fn account_history(
         &self,
@@ -234,12 +264,64 @@ where
         Ok(withdrawal)
     }
 
+    pub async fn list_account_statements_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<AccountStatementsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<AccountStatement, AccountStatementsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_account_statements(),
+            CoreDepositAction::ACCOUNT_STATEMENT_LIST,
+        )
+        .await?;
+
+        Ok(self
+            .account_statements
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    pub async fn generate_account_statement_download_link(
+        &self,
+        account_statement_id: impl Into<AccountStatementId> + std::fmt::Debug,
+        format: AccountStatementFormat,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let account_statement_id = account_statement_id.into();
+        let statement = self
+            .account_statements
+            .find_by_id(account_statement_id)
+            .await?;
+
+        let audit_info = self
+            .ensure_account_access(
+                statement.deposit_account_id,
+                CoreDepositObject::account_statement(account_statement_id),
+                CoreDepositAction::ACCOUNT_STATEMENT_READ,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, statement.document_id(format))
+            .await?)
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
         object: CoreDepositObject,
         action: CoreDepositAction,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<AuditInfo, CoreDepositError> {
         let account = self.accounts.find_by_id(account_id).await?;
 
         if account.account_holder_id != self.account_holder_id {
@@ -249,11 +331,12 @@ where
                 .await?;
             return Err(CoreDepositError::DepositAccountNotFound);
         }
-        self.authz
+        let audit_info = self
+            .authz
             .audit()
             .record_entry(self.sub, object, action, true)
             .await?;
 
-        Ok(())
+        Ok(audit_info)
     }
 }
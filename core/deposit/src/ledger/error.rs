@@ -34,4 +34,8 @@ pub enum DepositLedgerError {
     NonAccountMemberFoundInAccountSet(String),
     #[error("DepositLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error("DepositLedgerError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(crate::primitives::CurrencyCode),
+    #[error("DepositLedgerError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] core_accounting::accounting_period_error::AccountingPeriodError),
 }
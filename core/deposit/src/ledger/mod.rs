@@ -2,13 +2,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use audit::AuditInfo;
+use core_accounting::AccountingPeriodLock;
 
 pub mod error;
 mod templates;
 mod velocity;
 
 use cala_ledger::{
-    CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
+    CalaLedger, Currency, DebitOrCredit, JournalId, Layer, LedgerOperation, TransactionId,
     account::*,
     account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
     tx_template::Params,
@@ -16,9 +17,12 @@ use cala_ledger::{
 };
 
 use crate::{
-    DepositAccountBalance, LedgerOmnibusAccountIds,
+    DepositAccountBalance, DepositAccountCurrencyBalance, LedgerOmnibusAccountIds,
+    account_statement::AccountStatementLedgerEntry,
     chart_of_accounts_integration::ChartOfAccountsIntegrationConfig,
-    primitives::{CalaAccountId, CalaAccountSetId, DepositAccountType, UsdCents},
+    primitives::{
+        CalaAccountId, CalaAccountSetId, CurrencyCode, DepositAccountType, Money, UsdCents,
+    },
 };
 
 use error::*;
@@ -87,14 +91,15 @@ pub struct DepositLedger {
     journal_id: JournalId,
     deposits_account_set: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
-    usd: Currency,
     deposit_control_id: VelocityControlId,
+    period_lock: AccountingPeriodLock,
 }
 
 impl DepositLedger {
     pub async fn init(
         cala: &CalaLedger,
         journal_id: JournalId,
+        period_lock: &AccountingPeriodLock,
     ) -> Result<Self, DepositLedgerError> {
         templates::RecordDeposit::init(cala).await?;
         templates::InitiateWithdraw::init(cala).await?;
@@ -212,7 +217,7 @@ impl DepositLedger {
             },
             deposit_omnibus_account_ids,
             deposit_control_id,
-            usd: Currency::USD,
+            period_lock: period_lock.clone(),
         })
     }
 
@@ -364,22 +369,97 @@ impl DepositLedger {
         })
     }
 
+    /// Entries recorded since `since`. As postings can only be back-dated,
+    /// this covers every entry effective on or after `since`.
+    pub async fn account_statement_entries(
+        &self,
+        id: impl Into<AccountId>,
+        currency: CurrencyCode,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<AccountStatementLedgerEntry>, DepositLedgerError> {
+        let id = id.into();
+        let cala_currency = cala_currency(currency)?;
+
+        let mut entries = Vec::new();
+        let mut after = None;
+        loop {
+            let ret = self
+                .cala
+                .entries()
+                .list_for_account_id(
+                    id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            let mut reached_since = false;
+            for entry in ret.entities {
+                if entry.created_at() < since {
+                    reached_since = true;
+                    break;
+                }
+                let values = entry.values();
+                if values.currency != cala_currency || values.layer == Layer::Encumbrance {
+                    continue;
+                }
+                entries.push(entry);
+            }
+            if reached_since || !ret.has_next_page {
+                break;
+            }
+            after = ret.end_cursor;
+        }
+
+        let tx_ids = entries
+            .iter()
+            .map(|entry| entry.values().transaction_id)
+            .collect::<Vec<_>>();
+        let transactions: HashMap<_, cala_ledger::transaction::Transaction> =
+            self.cala.transactions().find_all(&tx_ids).await?;
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                let values = entry.values();
+                let effective = transactions
+                    .get(&values.transaction_id)
+                    .map(|tx| tx.values().effective)
+                    .unwrap_or_else(|| entry.created_at().date_naive());
+                Ok(AccountStatementLedgerEntry {
+                    tx_id: values.transaction_id,
+                    recorded_at: entry.created_at(),
+                    effective,
+                    entry_type: values.entry_type.clone(),
+                    description: values.description.clone(),
+                    direction: values.direction,
+                    layer: values.layer,
+                    amount: Money::try_from_major(values.units, currency)?,
+                })
+            })
+            .collect()
+    }
+
     pub async fn record_deposit(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
+        effective: chrono::NaiveDate,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::RecordDepositParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.to_major()?,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
+            effective,
         };
         self.cala
             .post_transaction_in_op(&mut op, tx_id, templates::RECORD_DEPOSIT_CODE, params)
@@ -391,20 +471,25 @@ impl DepositLedger {
 
     pub async fn initiate_withdrawal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
+        effective: chrono::NaiveDate,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::InitiateWithdrawParams {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
-            amount: amount.to_usd(),
-            currency: self.usd,
+            amount: amount.to_major()?,
+            currency: cala_currency(amount.currency())?,
+            effective,
         };
 
         self.cala
@@ -417,24 +502,29 @@ impl DepositLedger {
 
     pub async fn confirm_withdrawal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         correlation_id: String,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
         external_id: String,
+        effective: chrono::NaiveDate,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::ConfirmWithdrawParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.to_major()?,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
             correlation_id,
             external_id,
+            effective,
         };
 
         self.cala
@@ -446,20 +536,25 @@ impl DepositLedger {
 
     pub async fn cancel_withdrawal(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
+        effective: chrono::NaiveDate,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        self.period_lock
+            .ensure_accepts_module_posting_in_op(&mut op, effective)
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::CancelWithdrawParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.to_major()?,
             credit_account_id: credit_account_id.into(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            effective,
         };
 
         self.cala
@@ -476,7 +571,7 @@ impl DepositLedger {
         match self
             .cala
             .balances()
-            .find(self.journal_id, account_id.into(), self.usd)
+            .find(self.journal_id, account_id.into(), Currency::USD)
             .await
         {
             Ok(balances) => Ok(DepositAccountBalance {
@@ -490,6 +585,33 @@ impl DepositLedger {
         }
     }
 
+    pub async fn balances(
+        &self,
+        account_id: impl Into<AccountId>,
+        currencies: &[CurrencyCode],
+    ) -> Result<Vec<DepositAccountCurrencyBalance>, DepositLedgerError> {
+        let account_id = account_id.into();
+        let balance_ids = currencies
+            .iter()
+            .map(|currency| Ok((self.journal_id, account_id, cala_currency(*currency)?)))
+            .collect::<Result<Vec<_>, DepositLedgerError>>()?;
+        let mut balances = self.cala.balances().find_all(&balance_ids).await?;
+
+        let mut res = Vec::with_capacity(currencies.len());
+        for (currency, balance_id) in currencies.iter().zip(balance_ids.iter()) {
+            let balance = match balances.remove(balance_id) {
+                Some(balance) => DepositAccountCurrencyBalance {
+                    currency: *currency,
+                    settled: Money::try_from_major(balance.settled(), *currency)?,
+                    pending: Money::try_from_major(balance.pending(), *currency)?,
+                },
+                None => DepositAccountCurrencyBalance::zero(*currency),
+            };
+            res.push(balance);
+        }
+        Ok(res)
+    }
+
     pub async fn create_deposit_account(
         &self,
         op: es_entity::DbOp<'_>,
@@ -790,3 +912,10 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub financial_institution_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub non_domiciled_individual_deposit_accounts_parent_account_set_id: CalaAccountSetId,
 }
+
+fn cala_currency(currency: CurrencyCode) -> Result<Currency, DepositLedgerError> {
+    currency
+        .code()
+        .parse()
+        .map_err(|_| DepositLedgerError::UnsupportedCurrency(currency))
+}
@@ -17,6 +17,7 @@ pub struct CancelWithdrawParams {
     pub amount: Decimal,
     pub deposit_omnibus_account_id: AccountId,
     pub credit_account_id: AccountId,
+    pub effective: chrono::NaiveDate,
 }
 
 impl CancelWithdrawParams {
@@ -64,6 +65,7 @@ impl From<CancelWithdrawParams> for Params {
             amount,
             deposit_omnibus_account_id,
             credit_account_id,
+            effective,
         }: CancelWithdrawParams,
     ) -> Self {
         let mut params = Self::default();
@@ -72,7 +74,7 @@ impl From<CancelWithdrawParams> for Params {
         params.insert("amount", amount);
         params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
         params.insert("credit_account_id", credit_account_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
 
         params
     }
@@ -20,6 +20,7 @@ pub struct ConfirmWithdrawParams {
     pub credit_account_id: CalaAccountId,
     pub correlation_id: String,
     pub external_id: String,
+    pub effective: chrono::NaiveDate,
 }
 
 impl ConfirmWithdrawParams {
@@ -79,6 +80,7 @@ impl From<ConfirmWithdrawParams> for Params {
             correlation_id,
             external_id,
             credit_account_id,
+            effective,
         }: ConfirmWithdrawParams,
     ) -> Self {
         let mut params = Self::default();
@@ -89,7 +91,7 @@ impl From<ConfirmWithdrawParams> for Params {
         params.insert("credit_account_id", credit_account_id);
         params.insert("correlation_id", correlation_id);
         params.insert("external_id", external_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
 
         params
     }
@@ -17,6 +17,7 @@ pub struct InitiateWithdrawParams {
     pub credit_account_id: CalaAccountId,
     pub amount: Decimal,
     pub currency: Currency,
+    pub effective: chrono::NaiveDate,
 }
 
 impl InitiateWithdrawParams {
@@ -64,6 +65,7 @@ impl From<InitiateWithdrawParams> for Params {
             credit_account_id,
             amount,
             currency,
+            effective,
         }: InitiateWithdrawParams,
     ) -> Self {
         let mut params = Self::default();
@@ -73,7 +75,7 @@ impl From<InitiateWithdrawParams> for Params {
         params.insert("amount", amount);
         params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
         params.insert("credit_account_id", credit_account_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
 
         params
     }
@@ -17,6 +17,7 @@ pub struct RecordDepositParams {
     pub amount: Decimal,
     pub deposit_omnibus_account_id: CalaAccountId,
     pub credit_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
 }
 
 impl RecordDepositParams {
@@ -64,6 +65,7 @@ impl From<RecordDepositParams> for Params {
             amount,
             deposit_omnibus_account_id,
             credit_account_id,
+            effective,
         }: RecordDepositParams,
     ) -> Self {
         let mut params = Self::default();
@@ -72,7 +74,7 @@ impl From<RecordDepositParams> for Params {
         params.insert("amount", amount);
         params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
         params.insert("credit_account_id", credit_account_id);
-        params.insert("effective", crate::time::now().date_naive());
+        params.insert("effective", effective);
 
         params
     }
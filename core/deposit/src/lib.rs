@@ -2,7 +2,9 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
 mod account;
+pub mod account_statement;
 mod chart_of_accounts_integration;
+mod config;
 mod deposit;
 mod deposit_account_balance;
 pub mod error;
@@ -10,9 +12,12 @@ mod event;
 mod for_subject;
 mod history;
 mod ledger;
+pub mod payment_file;
 mod primitives;
 mod processes;
 mod publisher;
+pub mod scheduled_withdrawal;
+pub mod statement_import;
 mod time;
 mod withdrawal;
 
@@ -22,40 +27,75 @@ use tracing::instrument;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
-use core_accounting::Chart;
+use cloud_storage::Storage;
+use core_accounting::{AccountingPeriodLock, Chart};
+use document_storage::{DocumentStorage, DocumentType, GeneratedDocumentDownloadLink};
 use governance::{Governance, GovernanceEvent};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
+use sha2::{Digest, Sha256};
 
 pub use account::DepositAccount;
 use account::*;
+use account_statement::*;
+pub use account_statement::{
+    AccountStatement, AccountStatementFormat, AccountStatementsByCreatedAtCursor,
+};
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, ChartOfAccountsIntegrationConfigBuilderError,
 };
+pub use config::{AccountStatementConfig, DepositConfig, PaymentFileConfig};
 use deposit::*;
 pub use deposit::{Deposit, DepositsByCreatedAtCursor};
-pub use deposit_account_balance::DepositAccountBalance;
+pub use deposit_account_balance::{DepositAccountBalance, DepositAccountCurrencyBalance};
 use error::*;
 pub use event::*;
 pub use for_subject::DepositsForSubject;
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
 use ledger::*;
+pub use payment_file::{
+    PaymentFile, PaymentFileEntry, PaymentFileEntryStatus, PaymentFileStatus,
+    PaymentFilesByCreatedAtCursor,
+};
+use payment_file::{error::PaymentFileError, iso20022::*, *};
 pub use primitives::*;
 pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
 use processes::approval::{
     ApproveWithdrawal, WithdrawApprovalJobConfig, WithdrawApprovalJobInitializer,
 };
 use publisher::DepositPublisher;
+use scheduled_withdrawal::*;
+pub use scheduled_withdrawal::{
+    ScheduledWithdrawal, ScheduledWithdrawalExecution, ScheduledWithdrawalStatus,
+    ScheduledWithdrawalsByCreatedAtCursor, WithdrawalFrequency, WithdrawalRecurrence,
+};
+use statement_import::*;
+pub use statement_import::{
+    ImportedStatementLine, StatementImport, StatementImportStatus,
+    StatementImportsByCreatedAtCursor, StatementLineStatus,
+    parser::{StatementEntryDirection, StatementFormat, StatementLine},
+};
 use withdrawal::*;
-pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
+pub use withdrawal::{
+    Withdrawal, WithdrawalBeneficiary, WithdrawalStatus, WithdrawalsByCreatedAtCursor,
+};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
+    pub use crate::account_statement::AccountStatementEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::payment_file::PaymentFileEvent;
+    pub use crate::scheduled_withdrawal::ScheduledWithdrawalEvent;
+    pub use crate::statement_import::StatementImportEvent;
     pub use crate::withdrawal::WithdrawalEvent;
 }
 
+pub const DEPOSIT_STATEMENT_IMPORT_DOCUMENT: DocumentType =
+    DocumentType::new("deposit_statement_import");
+pub const DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT: DocumentType =
+    DocumentType::new("deposit_account_statement");
+
 pub struct CoreDeposit<Perms, E>
 where
     Perms: PermissionCheck,
@@ -64,12 +104,20 @@ where
     accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    statement_imports: StatementImportRepo,
+    payment_files: PaymentFileRepo,
+    payment_file_generator: PaymentFileGenerator<E>,
+    account_statements: AccountStatementRepo,
+    account_statement_generator: AccountStatementGenerator,
+    scheduled_withdrawals: ScheduledWithdrawalRepo,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
     ledger: DepositLedger,
     cala: CalaLedger,
     authz: Perms,
     governance: Governance<Perms, E>,
     outbox: Outbox<E>,
+    document_storage: DocumentStorage,
+    storage: Storage,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -82,12 +130,20 @@ where
             accounts: self.accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            statement_imports: self.statement_imports.clone(),
+            payment_files: self.payment_files.clone(),
+            payment_file_generator: self.payment_file_generator.clone(),
+            account_statements: self.account_statements.clone(),
+            account_statement_generator: self.account_statement_generator.clone(),
+            scheduled_withdrawals: self.scheduled_withdrawals.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
             outbox: self.outbox.clone(),
+            document_storage: self.document_storage.clone(),
+            storage: self.storage.clone(),
         }
     }
 }
@@ -110,12 +166,20 @@ where
         jobs: &Jobs,
         cala: &CalaLedger,
         journal_id: CalaJournalId,
+        document_storage: &DocumentStorage,
+        storage: &Storage,
+        config: DepositConfig,
     ) -> Result<Self, CoreDepositError> {
         let publisher = DepositPublisher::new(outbox);
         let accounts = DepositAccountRepo::new(pool, &publisher);
         let deposits = DepositRepo::new(pool, &publisher);
         let withdrawals = WithdrawalRepo::new(pool, &publisher);
-        let ledger = DepositLedger::init(cala, journal_id).await?;
+        let statement_imports = StatementImportRepo::new(pool);
+        let payment_files = PaymentFileRepo::new(pool);
+        let account_statements = AccountStatementRepo::new(pool);
+        let scheduled_withdrawals = ScheduledWithdrawalRepo::new(pool);
+        let ledger =
+            DepositLedger::init(cala, journal_id, &AccountingPeriodLock::new(pool)).await?;
 
         let approve_withdrawal = ApproveWithdrawal::new(&withdrawals, authz.audit(), governance);
 
@@ -125,6 +189,35 @@ where
         )
         .await?;
 
+        let payment_file_generator =
+            PaymentFileGenerator::new(&withdrawals, &payment_files, storage, &config.payment_file);
+        if config.payment_file.enabled {
+            jobs.add_initializer_and_spawn_unique(
+                PaymentFileJobInitializer::<Perms, E>::new(
+                    &payment_files,
+                    &payment_file_generator,
+                    authz.audit(),
+                ),
+                PaymentFileJobConfig::<Perms, E>::new(config.payment_file.job_interval_secs),
+            )
+            .await?;
+        }
+
+        let account_statement_generator =
+            AccountStatementGenerator::new(&account_statements, &ledger, document_storage);
+        if config.account_statement.enabled {
+            jobs.add_initializer_and_spawn_unique(
+                AccountStatementJobInitializer::<Perms, E>::new(
+                    &accounts,
+                    &account_statements,
+                    &account_statement_generator,
+                    authz.audit(),
+                ),
+                AccountStatementJobConfig::<Perms, E>::new(),
+            )
+            .await?;
+        }
+
         match governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await {
             Err(governance::error::GovernanceError::PolicyError(
                 governance::policy_error::PolicyError::DuplicateApprovalProcessType,
@@ -137,13 +230,28 @@ where
             accounts,
             deposits,
             withdrawals,
+            statement_imports,
+            payment_files,
+            payment_file_generator,
+            account_statements,
+            account_statement_generator,
+            scheduled_withdrawals,
             authz: authz.clone(),
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
             ledger,
+            document_storage: document_storage.clone(),
+            storage: storage.clone(),
         };
+
+        jobs.add_initializer_and_spawn_unique(
+            ScheduledWithdrawalJobInitializer::new(&res, &res.scheduled_withdrawals, authz.audit()),
+            ScheduledWithdrawalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
         Ok(res)
     }
 
@@ -163,7 +271,9 @@ where
             &self.accounts,
             &self.deposits,
             &self.withdrawals,
+            &self.account_statements,
             &self.ledger,
+            &self.document_storage,
             &self.authz,
         ))
     }
@@ -175,6 +285,7 @@ where
         holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
         active: bool,
         deposit_account_type: impl Into<DepositAccountType>,
+        currencies: Vec<CurrencyCode>,
     ) -> Result<DepositAccount, CoreDepositError> {
         let holder_id = holder_id.into();
 
@@ -197,9 +308,9 @@ where
             .name(name.to_string())
             .description(name.to_string())
             .active(active)
+            .currencies(currencies)
             .audit_info(audit_info.clone())
-            .build()
-            .expect("Could not build new account");
+            .build()?;
 
         let mut op = self.accounts.begin_op().await?;
         let account = self.accounts.create_in_op(&mut op, new_account).await?;
@@ -306,19 +417,40 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        amount: impl Into<Money> + std::fmt::Debug,
         reference: Option<String>,
     ) -> Result<Deposit, CoreDepositError> {
-        let deposit_account_id = deposit_account_id.into();
+        self.record_deposit_effective(
+            sub,
+            deposit_account_id.into(),
+            amount.into(),
+            reference,
+            crate::time::now().date_naive(),
+        )
+        .await
+    }
+
+    /// Records a deposit booked on `effective`, e.g. the booking date of a bank statement line.
+    async fn record_deposit_effective(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: DepositAccountId,
+        amount: Money,
+        reference: Option<String>,
+        effective: chrono::NaiveDate,
+    ) -> Result<Deposit, CoreDepositError> {
         let audit_info = self
             .authz
-            .enforce_permission(
+            .enforce_permission_with_attributes(
                 sub,
                 CoreDepositObject::all_deposits(),
                 CoreDepositAction::DEPOSIT_CREATE,
+                &amount_attributes(amount),
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        self.check_account_active(deposit_account_id)
+            .await?
+            .ensure_currency(amount.currency())?;
         let deposit_id = DepositId::new();
         let new_deposit = NewDeposit::builder()
             .id(deposit_id)
@@ -332,7 +464,7 @@ where
         let mut op = self.deposits.begin_op().await?;
         let deposit = self.deposits.create_in_op(&mut op, new_deposit).await?;
         self.ledger
-            .record_deposit(op, deposit_id, amount, deposit_account_id)
+            .record_deposit(op, deposit_id, amount, deposit_account_id, effective)
             .await?;
         Ok(deposit)
     }
@@ -342,19 +474,24 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        amount: impl Into<Money> + std::fmt::Debug,
         reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
     ) -> Result<Withdrawal, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
         let audit_info = self
             .authz
-            .enforce_permission(
+            .enforce_permission_with_attributes(
                 sub,
                 CoreDepositObject::all_withdrawals(),
                 CoreDepositAction::WITHDRAWAL_INITIATE,
+                &amount_attributes(amount),
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        self.check_account_active(deposit_account_id)
+            .await?
+            .ensure_currency(amount.currency())?;
         let withdrawal_id = WithdrawalId::new();
         let new_withdrawal = NewWithdrawal::builder()
             .id(withdrawal_id)
@@ -362,9 +499,26 @@ where
             .amount(amount)
             .approval_process_id(withdrawal_id)
             .reference(reference)
-            .audit_info(audit_info)
+            .beneficiary(beneficiary)
+            .audit_info(audit_info.clone())
             .build()?;
 
+        // Policy tiers are denominated in USD and there is no exchange rate
+        // to convert other currencies, so those are only accepted while the
+        // policy applies the same rules to every amount.
+        let policy_amount = match UsdCents::try_from(amount) {
+            Ok(usd) => Some(usd),
+            Err(_)
+                if self
+                    .governance
+                    .policy_has_amount_tiers(APPROVE_WITHDRAWAL_PROCESS)
+                    .await? =>
+            {
+                return Err(CoreDepositError::AmountTiersRequireUsd(amount.currency()));
+            }
+            Err(_) => None,
+        };
+
         let mut op = self.withdrawals.begin_op().await?;
         self.governance
             .start_process(
@@ -372,6 +526,8 @@ where
                 withdrawal_id,
                 withdrawal_id.to_string(),
                 APPROVE_WITHDRAWAL_PROCESS,
+                policy_amount,
+                Some(&audit_info),
             )
             .await?;
         let withdrawal = self
@@ -380,7 +536,13 @@ where
             .await?;
 
         self.ledger
-            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id)
+            .initiate_withdrawal(
+                op,
+                withdrawal_id,
+                amount,
+                deposit_account_id,
+                crate::time::now().date_naive(),
+            )
             .await?;
         Ok(withdrawal)
     }
@@ -401,6 +563,9 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
+        if withdrawal.initiated_by() == audit_info.sub {
+            return Err(withdrawal::error::WithdrawalError::ConfirmedByInitiator(id).into());
+        }
         self.check_account_active(withdrawal.deposit_account_id)
             .await?;
         let mut op = self.withdrawals.begin_op().await?;
@@ -417,6 +582,7 @@ where
                 withdrawal.amount,
                 withdrawal.deposit_account_id,
                 format!("lana:withdraw:{}:confirm", withdrawal.id),
+                crate::time::now().date_naive(),
             )
             .await?;
 
@@ -447,7 +613,13 @@ where
             .update_in_op(&mut op, &mut withdrawal)
             .await?;
         self.ledger
-            .cancel_withdrawal(op, tx_id, withdrawal.amount, withdrawal.deposit_account_id)
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                crate::time::now().date_naive(),
+            )
             .await?;
         Ok(withdrawal)
     }
@@ -472,6 +644,30 @@ where
         Ok(balance)
     }
 
+    #[instrument(name = "deposit.account_balances", skip(self), err)]
+    pub async fn account_balances(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountCurrencyBalance>, CoreDepositError> {
+        let account_id = account_id.into();
+        let _ = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
+
+        let account = self.accounts.find_by_id(account_id).await?;
+        let balances = self
+            .ledger
+            .balances(account_id, &account.currencies)
+            .await?;
+        Ok(balances)
+    }
+
     #[instrument(name = "deposit.find_deposit_by_id", skip(self), err)]
     pub async fn find_deposit_by_id(
         &self,
@@ -516,6 +712,17 @@ where
         }
     }
 
+    pub(crate) async fn find_withdrawal_by_reference(
+        &self,
+        reference: &String,
+    ) -> Result<Option<Withdrawal>, CoreDepositError> {
+        match self.withdrawals.find_by_reference(reference).await {
+            Ok(withdrawal) => Ok(Some(withdrawal)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     #[instrument(name = "deposit.find_withdrawal_by_cancelled_tx_id", skip(self), err)]
     pub async fn find_withdrawal_by_cancelled_tx_id(
         &self,
@@ -562,6 +769,14 @@ where
         Ok(self.accounts.find_all(ids).await?)
     }
 
+    #[instrument(name = "deposit.find_all_statement_imports", skip(self), err)]
+    pub async fn find_all_statement_imports<T: From<StatementImport>>(
+        &self,
+        ids: &[StatementImportId],
+    ) -> Result<std::collections::HashMap<StatementImportId, T>, CoreDepositError> {
+        Ok(self.statement_imports.find_all(ids).await?)
+    }
+
     #[instrument(name = "deposit.list_withdrawals", skip(self), err)]
     pub async fn list_withdrawals(
         &self,
@@ -781,14 +996,887 @@ where
         Ok(config)
     }
 
+    #[instrument(name = "deposit.import_deposit_statement", skip(self, content), err)]
+    pub async fn import_deposit_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        format: StatementFormat,
+        filename: impl Into<String> + std::fmt::Debug,
+        content: Vec<u8>,
+    ) -> Result<StatementImport, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_statement_imports(),
+                CoreDepositAction::STATEMENT_IMPORT_CREATE,
+            )
+            .await?;
+
+        let lines = format
+            .parse(&content)
+            .map_err(statement_import::error::StatementImportError::from)?;
+
+        let content_hash = hex::encode(Sha256::digest(&content));
+        match self
+            .statement_imports
+            .find_by_content_hash(&content_hash)
+            .await
+        {
+            Ok(existing) => {
+                return Err(
+                    statement_import::error::StatementImportError::AlreadyImported(existing.id)
+                        .into(),
+                );
+            }
+            Err(e) if e.was_not_found() => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let filename = filename.into();
+        let statement_import_id = StatementImportId::new();
+        let document = self
+            .document_storage
+            .create_and_upload(
+                audit_info.clone(),
+                content,
+                filename.clone(),
+                format.content_type(),
+                statement_import_id,
+                DEPOSIT_STATEMENT_IMPORT_DOCUMENT,
+            )
+            .await?;
+
+        let new_statement_import = NewStatementImport::builder()
+            .id(statement_import_id)
+            .format(format)
+            .filename(filename)
+            .content_hash(content_hash)
+            .document_id(document.id)
+            .lines(lines)
+            .audit_info(audit_info.clone())
+            .build()?;
+        let mut statement_import = self.statement_imports.create(new_statement_import).await?;
+
+        for (line_idx, line) in statement_import
+            .lines_to_match()
+            .map(|l| (l.idx, l.line.clone()))
+            .collect::<Vec<_>>()
+        {
+            let reference = statement_line_reference(statement_import_id, line_idx, &line);
+            match self.deposits.find_by_reference(&reference).await {
+                Ok(deposit) => {
+                    statement_import.line_skipped_as_duplicate(
+                        line_idx,
+                        deposit.deposit_account_id,
+                        deposit.id,
+                        audit_info.clone(),
+                    )?;
+                    continue;
+                }
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            let (account, amount) = match self.match_statement_line(&line).await? {
+                Ok(matched) => matched,
+                Err(reason) => {
+                    statement_import.flag_line_for_review(line_idx, reason, audit_info.clone())?;
+                    continue;
+                }
+            };
+            let deposit = match self
+                .record_deposit_effective(
+                    sub,
+                    account.id,
+                    amount,
+                    Some(reference),
+                    line.booking_date,
+                )
+                .await
+            {
+                Ok(deposit) => deposit,
+                Err(e) if e.is_deposit_rejected() => {
+                    statement_import.flag_line_for_review(
+                        line_idx,
+                        e.to_string(),
+                        audit_info.clone(),
+                    )?;
+                    continue;
+                }
+                Err(e) => {
+                    // Lines not reached yet stay open and can be resolved later
+                    self.statement_imports.update(&mut statement_import).await?;
+                    return Err(e);
+                }
+            };
+            statement_import.line_deposit_recorded(
+                line_idx,
+                account.id,
+                deposit.id,
+                audit_info.clone(),
+            )?;
+            self.statement_imports.update(&mut statement_import).await?;
+        }
+        self.statement_imports.update(&mut statement_import).await?;
+
+        Ok(statement_import)
+    }
+
+    #[instrument(name = "deposit.resolve_statement_line", skip(self), err)]
+    pub async fn resolve_statement_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_import_id: impl Into<StatementImportId> + std::fmt::Debug,
+        line_idx: usize,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<StatementImport, CoreDepositError> {
+        let statement_import_id = statement_import_id.into();
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement_import(statement_import_id),
+                CoreDepositAction::STATEMENT_IMPORT_RESOLVE_LINE,
+            )
+            .await?;
+
+        let mut statement_import = self
+            .statement_imports
+            .find_by_id(statement_import_id)
+            .await?;
+        statement_import.ensure_line_open(line_idx)?;
+        let line = statement_import.line(line_idx)?.line.clone();
+        let currency = line
+            .currency
+            .parse::<CurrencyCode>()
+            .map_err(|_| CoreDepositError::UnsupportedStatementCurrency(line.currency.clone()))?;
+
+        let deposit = self
+            .record_deposit_effective(
+                sub,
+                deposit_account_id,
+                Money::try_from_major(line.amount, currency)?,
+                Some(statement_line_reference(
+                    statement_import_id,
+                    line_idx,
+                    &line,
+                )),
+                line.booking_date,
+            )
+            .await?;
+        if statement_import
+            .line_deposit_recorded(line_idx, deposit_account_id, deposit.id, audit_info)?
+            .did_execute()
+        {
+            self.statement_imports.update(&mut statement_import).await?;
+        }
+
+        Ok(statement_import)
+    }
+
+    #[instrument(name = "deposit.dismiss_statement_line", skip(self), err)]
+    pub async fn dismiss_statement_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_import_id: impl Into<StatementImportId> + std::fmt::Debug,
+        line_idx: usize,
+    ) -> Result<StatementImport, CoreDepositError> {
+        let statement_import_id = statement_import_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement_import(statement_import_id),
+                CoreDepositAction::STATEMENT_IMPORT_RESOLVE_LINE,
+            )
+            .await?;
+
+        let mut statement_import = self
+            .statement_imports
+            .find_by_id(statement_import_id)
+            .await?;
+        if statement_import
+            .dismiss_line(line_idx, audit_info)?
+            .did_execute()
+        {
+            self.statement_imports.update(&mut statement_import).await?;
+        }
+
+        Ok(statement_import)
+    }
+
+    #[instrument(name = "deposit.find_statement_import_by_id", skip(self), err)]
+    pub async fn find_statement_import_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<StatementImportId> + std::fmt::Debug,
+    ) -> Result<Option<StatementImport>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement_import(id),
+                CoreDepositAction::STATEMENT_IMPORT_READ,
+            )
+            .await?;
+
+        match self.statement_imports.find_by_id(id).await {
+            Ok(statement_import) => Ok(Some(statement_import)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_statement_imports", skip(self), err)]
+    pub async fn list_statement_imports(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<StatementImportsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<StatementImport, StatementImportsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_statement_imports(),
+                CoreDepositAction::STATEMENT_IMPORT_LIST,
+            )
+            .await?;
+        Ok(self
+            .statement_imports
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(
+        name = "deposit.list_statement_imports_pending_review",
+        skip(self),
+        err
+    )]
+    pub async fn list_statement_imports_pending_review(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<StatementImportsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<StatementImport, StatementImportsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_statement_imports(),
+                CoreDepositAction::STATEMENT_IMPORT_LIST,
+            )
+            .await?;
+        Ok(self
+            .statement_imports
+            .list_for_status_by_created_at(
+                StatementImportStatus::PendingReview,
+                query,
+                es_entity::ListDirection::Ascending,
+            )
+            .await?)
+    }
+
+    #[instrument(
+        name = "deposit.generate_statement_import_download_link",
+        skip(self),
+        err
+    )]
+    pub async fn generate_statement_import_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<StatementImportId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement_import(id),
+                CoreDepositAction::STATEMENT_IMPORT_READ,
+            )
+            .await?;
+        let statement_import = self.statement_imports.find_by_id(id).await?;
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, statement_import.document_id)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.generate_payment_file", skip(self), err)]
+    pub async fn generate_payment_file(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Option<PaymentFile>, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_GENERATE,
+            )
+            .await?;
+
+        let mut op = self.payment_files.begin_op().await?;
+        let payment_file = self
+            .payment_file_generator
+            .generate_in_op(&mut op, audit_info.clone())
+            .await?;
+        op.commit().await?;
+
+        let Some(mut payment_file) = payment_file else {
+            return Ok(None);
+        };
+        self.payment_file_generator
+            .upload(&mut payment_file, audit_info)
+            .await?;
+        Ok(Some(payment_file))
+    }
+
+    #[instrument(
+        name = "deposit.import_payment_status_report",
+        skip(self, content),
+        err
+    )]
+    pub async fn import_payment_status_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        content: Vec<u8>,
+    ) -> Result<PaymentFile, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_IMPORT_STATUS,
+            )
+            .await?;
+
+        let report = PaymentStatusReport::parse(&content).map_err(PaymentFileError::from)?;
+        let mut payment_file = match self
+            .payment_files
+            .find_by_message_id(report.original_message_id.clone())
+            .await
+        {
+            Ok(payment_file) => payment_file,
+            Err(e) if e.was_not_found() => {
+                return Err(
+                    PaymentFileError::UnknownOriginalMessage(report.original_message_id).into(),
+                );
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        for (withdrawal_id, outcome, reason) in payment_file.entry_outcomes(&report)? {
+            match outcome {
+                PaymentStatusOutcome::Accepted => {
+                    if payment_file
+                        .entry_accepted(withdrawal_id, audit_info.clone())?
+                        .was_ignored()
+                    {
+                        continue;
+                    }
+                    self.settle_sent_withdrawal(
+                        &mut payment_file,
+                        withdrawal_id,
+                        audit_info.clone(),
+                    )
+                    .await?;
+                }
+                PaymentStatusOutcome::Rejected => {
+                    if payment_file
+                        .entry_rejected(withdrawal_id, reason.clone(), audit_info.clone())?
+                        .was_ignored()
+                    {
+                        continue;
+                    }
+                    self.reverse_rejected_withdrawal(
+                        &mut payment_file,
+                        withdrawal_id,
+                        reason,
+                        audit_info.clone(),
+                    )
+                    .await?;
+                }
+                PaymentStatusOutcome::Pending => continue,
+            }
+        }
+
+        Ok(payment_file)
+    }
+
+    #[instrument(name = "deposit.find_payment_file_by_id", skip(self), err)]
+    pub async fn find_payment_file_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentFileId> + std::fmt::Debug,
+    ) -> Result<Option<PaymentFile>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_file(id),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+
+        match self.payment_files.find_by_id(id).await {
+            Ok(payment_file) => Ok(Some(payment_file)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_payment_files", skip(self), err)]
+    pub async fn find_all_payment_files<T: From<PaymentFile>>(
+        &self,
+        ids: &[PaymentFileId],
+    ) -> Result<std::collections::HashMap<PaymentFileId, T>, CoreDepositError> {
+        Ok(self.payment_files.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_payment_files", skip(self), err)]
+    pub async fn list_payment_files(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<PaymentFilesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<PaymentFile, PaymentFilesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_LIST,
+            )
+            .await?;
+        Ok(self
+            .payment_files
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.generate_payment_file_download_link", skip(self), err)]
+    pub async fn generate_payment_file_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentFileId> + std::fmt::Debug,
+    ) -> Result<String, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_file(id),
+                CoreDepositAction::PAYMENT_FILE_READ,
+            )
+            .await?;
+        let payment_file = self.payment_files.find_by_id(id).await?;
+        Ok(self
+            .storage
+            .generate_download_link(payment_file.location_in_storage()?)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.generate_account_statement", skip(self), err)]
+    pub async fn generate_account_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        currency: CurrencyCode,
+        period_start: chrono::NaiveDate,
+        period_end: chrono::NaiveDate,
+    ) -> Result<AccountStatement, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_account_statements(),
+                CoreDepositAction::ACCOUNT_STATEMENT_GENERATE,
+            )
+            .await?;
+
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        account.ensure_currency(currency)?;
+
+        Ok(self
+            .account_statement_generator
+            .generate(&account, currency, period_start, period_end, audit_info)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.find_account_statement_by_id", skip(self), err)]
+    pub async fn find_account_statement_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountStatementId> + std::fmt::Debug,
+    ) -> Result<Option<AccountStatement>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::account_statement(id),
+                CoreDepositAction::ACCOUNT_STATEMENT_READ,
+            )
+            .await?;
+
+        match self.account_statements.find_by_id(id).await {
+            Ok(statement) => Ok(Some(statement)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_account_statements", skip(self), err)]
+    pub async fn find_all_account_statements<T: From<AccountStatement>>(
+        &self,
+        ids: &[AccountStatementId],
+    ) -> Result<std::collections::HashMap<AccountStatementId, T>, CoreDepositError> {
+        Ok(self.account_statements.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_account_statements_for_account", skip(self), err)]
+    pub async fn list_account_statements_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<AccountStatementsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<AccountStatement, AccountStatementsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_account_statements(),
+                CoreDepositAction::ACCOUNT_STATEMENT_LIST,
+            )
+            .await?;
+        Ok(self
+            .account_statements
+            .list_for_deposit_account_id_by_created_at(
+                deposit_account_id.into(),
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    #[instrument(
+        name = "deposit.generate_account_statement_download_link",
+        skip(self),
+        err
+    )]
+    pub async fn generate_account_statement_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountStatementId> + std::fmt::Debug,
+        format: AccountStatementFormat,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::account_statement(id),
+                CoreDepositAction::ACCOUNT_STATEMENT_READ,
+            )
+            .await?;
+        let statement = self.account_statements.find_by_id(id).await?;
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, statement.document_id(format))
+            .await?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(name = "deposit.create_scheduled_withdrawal", skip(self), err)]
+    pub async fn create_scheduled_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+        recurrence: WithdrawalRecurrence,
+        start_date: chrono::NaiveDate,
+        end_date: Option<chrono::NaiveDate>,
+    ) -> Result<ScheduledWithdrawal, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission_with_attributes(
+                sub,
+                CoreDepositObject::all_scheduled_withdrawals(),
+                CoreDepositAction::SCHEDULED_WITHDRAWAL_CREATE,
+                &amount_attributes(amount),
+            )
+            .await?;
+        if start_date < crate::time::now().date_naive() {
+            return Err(
+                scheduled_withdrawal::error::ScheduledWithdrawalError::StartDateInPast(start_date)
+                    .into(),
+            );
+        }
+        self.check_account_active(deposit_account_id)
+            .await?
+            .ensure_currency(amount.currency())?;
+
+        let new_schedule = NewScheduledWithdrawal::builder()
+            .id(ScheduledWithdrawalId::new())
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .reference(reference)
+            .beneficiary(beneficiary)
+            .recurrence(recurrence)
+            .start_date(start_date)
+            .end_date(end_date)
+            .audit_info(audit_info)
+            .build()?;
+        Ok(self.scheduled_withdrawals.create(new_schedule).await?)
+    }
+
+    #[instrument(name = "deposit.cancel_scheduled_withdrawal", skip(self), err)]
+    pub async fn cancel_scheduled_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ScheduledWithdrawalId> + std::fmt::Debug,
+    ) -> Result<ScheduledWithdrawal, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::scheduled_withdrawal(id),
+                CoreDepositAction::SCHEDULED_WITHDRAWAL_CANCEL,
+            )
+            .await?;
+        let mut schedule = self.scheduled_withdrawals.find_by_id(id).await?;
+        if schedule.cancel(audit_info)?.did_execute() {
+            self.scheduled_withdrawals.update(&mut schedule).await?;
+        }
+        Ok(schedule)
+    }
+
+    #[instrument(name = "deposit.find_scheduled_withdrawal_by_id", skip(self), err)]
+    pub async fn find_scheduled_withdrawal_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ScheduledWithdrawalId> + std::fmt::Debug,
+    ) -> Result<Option<ScheduledWithdrawal>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::scheduled_withdrawal(id),
+                CoreDepositAction::SCHEDULED_WITHDRAWAL_READ,
+            )
+            .await?;
+
+        match self.scheduled_withdrawals.find_by_id(id).await {
+            Ok(schedule) => Ok(Some(schedule)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_scheduled_withdrawals", skip(self), err)]
+    pub async fn find_all_scheduled_withdrawals<T: From<ScheduledWithdrawal>>(
+        &self,
+        ids: &[ScheduledWithdrawalId],
+    ) -> Result<std::collections::HashMap<ScheduledWithdrawalId, T>, CoreDepositError> {
+        Ok(self.scheduled_withdrawals.find_all(ids).await?)
+    }
+
+    #[instrument(
+        name = "deposit.list_scheduled_withdrawals_for_account",
+        skip(self),
+        err
+    )]
+    pub async fn list_scheduled_withdrawals_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<ScheduledWithdrawal>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_scheduled_withdrawals(),
+                CoreDepositAction::SCHEDULED_WITHDRAWAL_LIST,
+            )
+            .await?;
+        Ok(self
+            .scheduled_withdrawals
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    /// Settles the withdrawal in the same transaction that records the entry
+    /// outcome on the payment file, so an interrupted import can be re-run.
+    async fn settle_sent_withdrawal(
+        &self,
+        payment_file: &mut PaymentFile,
+        id: WithdrawalId,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(), CoreDepositError> {
+        let mut withdrawal = self.withdrawals.find_by_id(id).await?;
+        let mut op = self.withdrawals.begin_op().await?;
+        self.payment_files
+            .update_in_op(&mut op, payment_file)
+            .await?;
+        let es_entity::Idempotent::Executed(tx_id) = withdrawal.settle_payment(audit_info)? else {
+            op.commit().await?;
+            return Ok(());
+        };
+        self.withdrawals
+            .update_in_op(&mut op, &mut withdrawal)
+            .await?;
+
+        self.ledger
+            .confirm_withdrawal(
+                op,
+                tx_id,
+                withdrawal.id.to_string(),
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                format!("lana:withdraw:{}:confirm", withdrawal.id),
+                crate::time::now().date_naive(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn reverse_rejected_withdrawal(
+        &self,
+        payment_file: &mut PaymentFile,
+        id: WithdrawalId,
+        reason: Option<String>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(), CoreDepositError> {
+        let mut withdrawal = self.withdrawals.find_by_id(id).await?;
+        let mut op = self.withdrawals.begin_op().await?;
+        self.payment_files
+            .update_in_op(&mut op, payment_file)
+            .await?;
+        let es_entity::Idempotent::Executed(tx_id) =
+            withdrawal.reject_payment(reason, audit_info)?
+        else {
+            op.commit().await?;
+            return Ok(());
+        };
+        self.withdrawals
+            .update_in_op(&mut op, &mut withdrawal)
+            .await?;
+        self.ledger
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                crate::time::now().date_naive(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn match_statement_line(
+        &self,
+        line: &StatementLine,
+    ) -> Result<Result<(DepositAccount, Money), &'static str>, CoreDepositError> {
+        let Ok(currency) = line.currency.parse::<CurrencyCode>() else {
+            return Ok(Err("unsupported currency"));
+        };
+        if line.amount.is_zero() {
+            return Ok(Err("zero amount"));
+        }
+        let Ok(amount) = Money::try_from_major(line.amount, currency) else {
+            return Ok(Err("amount not representable in currency"));
+        };
+
+        let candidates = line
+            .matching_candidates()
+            .flat_map(uuid_candidates)
+            .collect::<Vec<_>>();
+        for candidate in candidates {
+            let account = match self
+                .accounts
+                .find_by_id(DepositAccountId::from(candidate))
+                .await
+            {
+                Ok(account) => Some(account),
+                Err(e) if e.was_not_found() => {
+                    let mut holder_accounts = self
+                        .accounts
+                        .list_for_account_holder_id_by_created_at(
+                            DepositAccountHolderId::from(candidate),
+                            Default::default(),
+                            es_entity::ListDirection::Ascending,
+                        )
+                        .await?
+                        .entities;
+                    if holder_accounts.len() == 1 {
+                        holder_accounts.pop()
+                    } else {
+                        None
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            };
+            if let Some(account) = account {
+                if account.status.is_inactive() {
+                    return Ok(Err("deposit account is not active"));
+                }
+                if !account.supports_currency(currency) {
+                    return Ok(Err("currency not held by deposit account"));
+                }
+                return Ok(Ok((account, amount)));
+            }
+        }
+
+        Ok(Err("no matching deposit account"))
+    }
+
     async fn check_account_active(
         &self,
         deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account = self.accounts.find_by_id(deposit_account_id).await?;
         if account.status.is_inactive() {
             return Err(CoreDepositError::DepositAccountNotActive);
         }
-        Ok(())
+        Ok(account)
+    }
+}
+
+/// Deposits booked from a statement line carry the bank's transaction reference when
+/// available so that the same transaction appearing in a later statement is recognized.
+/// The bank reference is only unique per statement account, so it is only used
+/// together with the account.
+fn statement_line_reference(
+    statement_import_id: StatementImportId,
+    line_idx: usize,
+    line: &StatementLine,
+) -> String {
+    match (
+        line.statement_account.as_deref(),
+        line.bank_reference.as_deref(),
+    ) {
+        (Some(account), Some(bank_reference)) => format!(
+            "bank-transaction:{}:{}:{}",
+            account, line.currency, bank_reference
+        ),
+        _ => format!("statement-import:{}:{}", statement_import_id, line_idx),
     }
 }
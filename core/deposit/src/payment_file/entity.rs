@@ -0,0 +1,526 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use cloud_storage::LocationInStorage;
+use es_entity::*;
+
+use crate::{
+    primitives::{CurrencyCode, Money, PaymentFileId, WithdrawalId},
+    withdrawal::WithdrawalBeneficiary,
+};
+
+use super::{
+    error::PaymentFileError,
+    iso20022::{PaymentStatusOutcome, PaymentStatusReport},
+};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum PaymentFileStatus {
+    Pending,
+    Uploaded,
+    UploadFailed,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum PaymentFileEntryStatus {
+    Sent,
+    Accepted,
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PaymentFileEntry {
+    pub withdrawal_id: WithdrawalId,
+    pub end_to_end_id: String,
+    pub amount: Money,
+    pub reference: String,
+    pub beneficiary: WithdrawalBeneficiary,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "PaymentFileId")]
+pub enum PaymentFileEvent {
+    Initialized {
+        id: PaymentFileId,
+        message_id: String,
+        entries: Vec<PaymentFileEntry>,
+        path_in_bucket: String,
+        audit_info: AuditInfo,
+    },
+    FileUploaded {
+        bucket: String,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    UploadFailed {
+        error: String,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    EntryAccepted {
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    },
+    EntryRejected {
+        withdrawal_id: WithdrawalId,
+        reason: Option<String>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct PaymentFile {
+    pub id: PaymentFileId,
+    pub message_id: String,
+    pub entries: Vec<PaymentFileEntry>,
+    pub(super) path_in_storage: String,
+    events: EntityEvents<PaymentFileEvent>,
+}
+
+impl PaymentFile {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn status(&self) -> PaymentFileStatus {
+        if self
+            .entries
+            .iter()
+            .all(|e| self.entry_status(e.withdrawal_id) != PaymentFileEntryStatus::Sent)
+        {
+            return PaymentFileStatus::Completed;
+        }
+        for e in self.events.iter_all().rev() {
+            match e {
+                PaymentFileEvent::FileUploaded { .. } => return PaymentFileStatus::Uploaded,
+                PaymentFileEvent::UploadFailed { .. } => return PaymentFileStatus::UploadFailed,
+                _ => {}
+            }
+        }
+        PaymentFileStatus::Pending
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.entries
+            .first()
+            .expect("payment file without entries")
+            .amount
+            .currency()
+    }
+
+    pub fn total_amount(&self) -> Money {
+        Money::new(
+            self.entries.iter().map(|e| e.amount.minor_units()).sum(),
+            self.currency(),
+        )
+    }
+
+    pub fn entry_status(&self, withdrawal_id: WithdrawalId) -> PaymentFileEntryStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                PaymentFileEvent::EntryAccepted {
+                    withdrawal_id: id, ..
+                } if *id == withdrawal_id => Some(PaymentFileEntryStatus::Accepted),
+                PaymentFileEvent::EntryRejected {
+                    withdrawal_id: id, ..
+                } if *id == withdrawal_id => Some(PaymentFileEntryStatus::Rejected),
+                _ => None,
+            })
+            .unwrap_or(PaymentFileEntryStatus::Sent)
+    }
+
+    pub fn entry_rejection_reason(&self, withdrawal_id: WithdrawalId) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            PaymentFileEvent::EntryRejected {
+                withdrawal_id: id,
+                reason,
+                ..
+            } if *id == withdrawal_id => reason.as_deref(),
+            _ => None,
+        })
+    }
+
+    pub fn entry_by_end_to_end_id(&self, end_to_end_id: &str) -> Option<&PaymentFileEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.end_to_end_id.eq_ignore_ascii_case(end_to_end_id))
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        for e in self.events.iter_all().rev() {
+            if let PaymentFileEvent::UploadFailed { error, .. } = e {
+                return Some(error);
+            }
+        }
+        None
+    }
+
+    pub fn is_uploaded(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, PaymentFileEvent::FileUploaded { .. }))
+    }
+
+    pub fn file_uploaded(&mut self, bucket: String, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            PaymentFileEvent::FileUploaded { .. }
+        );
+
+        self.events.push(PaymentFileEvent::FileUploaded {
+            bucket,
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+        Idempotent::Executed(())
+    }
+
+    pub fn upload_failed(&mut self, error: String, audit_info: AuditInfo) {
+        self.events.push(PaymentFileEvent::UploadFailed {
+            error,
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+    }
+
+    pub fn entry_accepted(
+        &mut self,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PaymentFileError> {
+        if self.ensure_entry_sent(withdrawal_id)?.was_ignored() {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events.push(PaymentFileEvent::EntryAccepted {
+            withdrawal_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn entry_rejected(
+        &mut self,
+        withdrawal_id: WithdrawalId,
+        reason: Option<String>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PaymentFileError> {
+        if self.ensure_entry_sent(withdrawal_id)?.was_ignored() {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events.push(PaymentFileEvent::EntryRejected {
+            withdrawal_id,
+            reason,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    fn ensure_entry_sent(
+        &self,
+        withdrawal_id: WithdrawalId,
+    ) -> Result<Idempotent<()>, PaymentFileError> {
+        if !self.is_uploaded() {
+            return Err(PaymentFileError::NotUploaded(self.id));
+        }
+        if !self
+            .entries
+            .iter()
+            .any(|e| e.withdrawal_id == withdrawal_id)
+        {
+            return Err(PaymentFileError::EntryNotFound(withdrawal_id));
+        }
+        match self.entry_status(withdrawal_id) {
+            PaymentFileEntryStatus::Sent => Ok(Idempotent::Executed(())),
+            _ => Ok(Idempotent::Ignored),
+        }
+    }
+
+    /// Maps the transaction statuses of a pain.002 report onto the entries of
+    /// this file. A report without transaction details applies its group
+    /// status to every entry.
+    pub fn entry_outcomes(
+        &self,
+        report: &PaymentStatusReport,
+    ) -> Result<Vec<(WithdrawalId, PaymentStatusOutcome, Option<String>)>, PaymentFileError> {
+        if !report
+            .original_message_id
+            .eq_ignore_ascii_case(&self.message_id)
+        {
+            return Err(PaymentFileError::UnknownOriginalMessage(
+                report.original_message_id.clone(),
+            ));
+        }
+
+        if report.transactions.is_empty() {
+            let outcome = report
+                .group_outcome
+                .unwrap_or(PaymentStatusOutcome::Pending);
+            return Ok(self
+                .entries
+                .iter()
+                .map(|e| (e.withdrawal_id, outcome, report.group_reason.clone()))
+                .collect());
+        }
+
+        report
+            .transactions
+            .iter()
+            .map(|tx| {
+                let entry = self
+                    .entry_by_end_to_end_id(&tx.original_end_to_end_id)
+                    .ok_or_else(|| {
+                        PaymentFileError::UnknownEndToEndId(tx.original_end_to_end_id.clone())
+                    })?;
+                Ok((entry.withdrawal_id, tx.outcome, tx.reason.clone()))
+            })
+            .collect()
+    }
+
+    pub fn path_in_bucket(&self) -> &str {
+        &self.path_in_storage
+    }
+
+    pub fn location_in_storage(&self) -> Result<LocationInStorage, PaymentFileError> {
+        if !self.is_uploaded() {
+            return Err(PaymentFileError::NotUploaded(self.id));
+        }
+        Ok(LocationInStorage {
+            path_in_storage: &self.path_in_storage,
+        })
+    }
+}
+
+impl TryFromEvents<PaymentFileEvent> for PaymentFile {
+    fn try_from_events(events: EntityEvents<PaymentFileEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = PaymentFileBuilder::default();
+
+        for event in events.iter_all() {
+            if let PaymentFileEvent::Initialized {
+                id,
+                message_id,
+                entries,
+                path_in_bucket,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .message_id(message_id.clone())
+                    .entries(entries.clone())
+                    .path_in_storage(path_in_bucket.clone());
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewPaymentFile {
+    #[builder(setter(into))]
+    pub(super) id: PaymentFileId,
+    pub(super) entries: Vec<PaymentFileEntry>,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewPaymentFile {
+    pub fn builder() -> NewPaymentFileBuilder {
+        NewPaymentFileBuilder::default()
+    }
+
+    pub(super) fn status(&self) -> PaymentFileStatus {
+        PaymentFileStatus::Pending
+    }
+
+    // ISO 20022 limits identifiers to 35 characters
+    pub(super) fn message_id(&self) -> String {
+        uuid::Uuid::from(self.id).simple().to_string()
+    }
+
+    pub(super) fn path_in_bucket(&self) -> String {
+        format!("payment_files/{}.xml", self.id)
+    }
+}
+
+impl NewPaymentFileBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.entries.as_ref() {
+            Some(entries) if entries.is_empty() => {
+                Err("Payment file must contain at least one entry".to_string())
+            }
+            Some(entries)
+                if entries
+                    .iter()
+                    .any(|e| e.amount.currency() != entries[0].amount.currency()) =>
+            {
+                Err("Payment file entries must share one currency".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<PaymentFileEvent> for NewPaymentFile {
+    fn into_events(self) -> EntityEvents<PaymentFileEvent> {
+        EntityEvents::init(
+            self.id,
+            [PaymentFileEvent::Initialized {
+                id: self.id,
+                message_id: self.message_id(),
+                path_in_bucket: self.path_in_bucket(),
+                entries: self.entries,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn entry() -> PaymentFileEntry {
+        let withdrawal_id = WithdrawalId::new();
+        PaymentFileEntry {
+            withdrawal_id,
+            end_to_end_id: uuid::Uuid::from(withdrawal_id).simple().to_string(),
+            amount: Money::new(1000, CurrencyCode::Usd),
+            reference: "ref".to_string(),
+            beneficiary: WithdrawalBeneficiary::new(
+                "Jane Doe",
+                "GB82WEST12345698765432",
+                None::<&str>,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn payment_file(entries: Vec<PaymentFileEntry>) -> PaymentFile {
+        let new_file = NewPaymentFile::builder()
+            .id(PaymentFileId::new())
+            .entries(entries)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        PaymentFile::try_from_events(new_file.into_events()).unwrap()
+    }
+
+    #[test]
+    fn requires_entries() {
+        let res = NewPaymentFile::builder()
+            .id(PaymentFileId::new())
+            .entries(vec![])
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(matches!(
+            res,
+            Err(NewPaymentFileBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn requires_single_currency() {
+        let mut eur = entry();
+        eur.amount = Money::new(1000, CurrencyCode::Eur);
+        let res = NewPaymentFile::builder()
+            .id(PaymentFileId::new())
+            .entries(vec![entry(), eur])
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(matches!(
+            res,
+            Err(NewPaymentFileBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn entry_outcomes_require_upload() {
+        let first = entry();
+        let mut file = payment_file(vec![first.clone()]);
+        assert!(matches!(
+            file.entry_accepted(first.withdrawal_id, dummy_audit_info()),
+            Err(PaymentFileError::NotUploaded(_))
+        ));
+    }
+
+    #[test]
+    fn completes_once_all_entries_have_outcome() {
+        let (first, second) = (entry(), entry());
+        let mut file = payment_file(vec![first.clone(), second.clone()]);
+        let _ = file.file_uploaded("bucket".to_string(), dummy_audit_info());
+        assert_eq!(file.status(), PaymentFileStatus::Uploaded);
+        assert_eq!(file.total_amount(), Money::new(2000, CurrencyCode::Usd));
+
+        assert!(
+            file.entry_accepted(first.withdrawal_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            file.entry_rejected(first.withdrawal_id, None, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert_eq!(file.status(), PaymentFileStatus::Uploaded);
+
+        file.entry_rejected(
+            second.withdrawal_id,
+            Some("AC04".to_string()),
+            dummy_audit_info(),
+        )
+        .unwrap();
+        assert_eq!(file.status(), PaymentFileStatus::Completed);
+        assert_eq!(
+            file.entry_rejection_reason(second.withdrawal_id),
+            Some("AC04")
+        );
+    }
+
+    #[test]
+    fn group_status_applies_to_all_entries() {
+        let (first, second) = (entry(), entry());
+        let file = payment_file(vec![first.clone(), second.clone()]);
+        let report = PaymentStatusReport {
+            original_message_id: file.message_id.clone(),
+            group_outcome: Some(PaymentStatusOutcome::Rejected),
+            group_reason: Some("AM04".to_string()),
+            transactions: vec![],
+        };
+
+        let outcomes = file.entry_outcomes(&report).unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|(_, outcome, reason)| {
+            *outcome == PaymentStatusOutcome::Rejected && reason.as_deref() == Some("AM04")
+        }));
+        assert_eq!(outcomes[1].0, second.withdrawal_id);
+    }
+}
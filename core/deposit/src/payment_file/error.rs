@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+use crate::primitives::{PaymentFileId, WithdrawalId};
+
+use super::iso20022::error::Iso20022Error;
+
+#[derive(Error, Debug)]
+pub enum PaymentFileError {
+    #[error("PaymentFileError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PaymentFileError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("PaymentFileError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("PaymentFileError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("PaymentFileError - StorageError: {0}")]
+    StorageError(#[from] cloud_storage::error::StorageError),
+    #[error("PaymentFileError - WithdrawalError: {0}")]
+    WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("PaymentFileError - ConversionError: {0}")]
+    ConversionError(#[from] core_money::ConversionError),
+    #[error("PaymentFileError - Iso20022Error: {0}")]
+    Iso20022Error(#[from] Iso20022Error),
+    #[error("PaymentFileError - NotUploaded: {0}")]
+    NotUploaded(PaymentFileId),
+    #[error("PaymentFileError - EntryNotFound: {0}")]
+    EntryNotFound(WithdrawalId),
+    #[error("PaymentFileError - UnknownOriginalMessage: {0}")]
+    UnknownOriginalMessage(String),
+    #[error("PaymentFileError - UnknownEndToEndId: {0}")]
+    UnknownEndToEndId(String),
+    #[error("PaymentFileError - DebtorNotConfigured")]
+    DebtorNotConfigured,
+}
+
+es_entity::from_es_entity_error!(PaymentFileError);
@@ -0,0 +1,229 @@
+use audit::AuditInfo;
+use cloud_storage::Storage;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    config::PaymentFileConfig,
+    event::CoreDepositEvent,
+    primitives::PaymentFileId,
+    withdrawal::{Withdrawal, WithdrawalRepo, WithdrawalStatus},
+};
+
+use super::{
+    entity::*,
+    error::PaymentFileError,
+    iso20022::*,
+    repo::{PaymentFileRepo, payment_file_cursor::PaymentFilesByCreatedAtCursor},
+};
+
+const PAYMENT_FILE_CONTENT_TYPE: &str = "application/xml";
+
+pub struct PaymentFileGenerator<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    withdrawals: WithdrawalRepo<E>,
+    payment_files: PaymentFileRepo,
+    storage: Storage,
+    config: PaymentFileConfig,
+}
+
+impl<E> Clone for PaymentFileGenerator<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            withdrawals: self.withdrawals.clone(),
+            payment_files: self.payment_files.clone(),
+            storage: self.storage.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+
+impl<E> PaymentFileGenerator<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        withdrawals: &WithdrawalRepo<E>,
+        payment_files: &PaymentFileRepo,
+        storage: &Storage,
+        config: &PaymentFileConfig,
+    ) -> Self {
+        Self {
+            withdrawals: withdrawals.clone(),
+            payment_files: payment_files.clone(),
+            storage: storage.clone(),
+            config: config.clone(),
+        }
+    }
+
+    /// Collects all withdrawals in the configured currency that await confirmation
+    /// and carry beneficiary details into a new payment file and marks them as sent.
+    /// The withdrawals stay locked until `db` completes. The file itself is only
+    /// uploaded via [`Self::upload`] once `db` has been committed.
+    pub async fn generate_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        audit_info: AuditInfo,
+    ) -> Result<Option<PaymentFile>, PaymentFileError> {
+        if self.config.debtor_iban.is_empty() {
+            return Err(PaymentFileError::DebtorNotConfigured);
+        }
+
+        let mut withdrawals = self.withdrawals_to_send(db).await?;
+        if withdrawals.is_empty() {
+            return Ok(None);
+        }
+
+        let entries = withdrawals
+            .iter()
+            .map(|w| PaymentFileEntry {
+                withdrawal_id: w.id,
+                end_to_end_id: w.end_to_end_id(),
+                amount: w.amount,
+                reference: w.reference.clone(),
+                beneficiary: w.beneficiary.clone().expect("filtered on beneficiary"),
+            })
+            .collect();
+        let new_payment_file = NewPaymentFile::builder()
+            .id(PaymentFileId::new())
+            .entries(entries)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new payment file");
+        let payment_file = self
+            .payment_files
+            .create_in_op(db, new_payment_file)
+            .await?;
+
+        for withdrawal in withdrawals.iter_mut() {
+            if withdrawal
+                .mark_payment_sent(payment_file.id, audit_info.clone())?
+                .did_execute()
+            {
+                self.withdrawals.update_in_op(db, withdrawal).await?;
+            }
+        }
+
+        Ok(Some(payment_file))
+    }
+
+    /// Uploads every payment file that has not reached the bank yet, including
+    /// files whose previous upload attempt failed.
+    pub async fn upload_pending(&self, audit_info: AuditInfo) -> Result<(), PaymentFileError> {
+        for status in [PaymentFileStatus::Pending, PaymentFileStatus::UploadFailed] {
+            let mut has_next_page = true;
+            let mut after = None;
+            while has_next_page {
+                let payment_files = self
+                    .payment_files
+                    .list_for_status_by_created_at(
+                        status,
+                        es_entity::PaginatedQueryArgs::<PaymentFilesByCreatedAtCursor> {
+                            first: 100,
+                            after,
+                        },
+                        es_entity::ListDirection::Ascending,
+                    )
+                    .await?;
+                (after, has_next_page) = (payment_files.end_cursor, payment_files.has_next_page);
+
+                for mut payment_file in payment_files.entities {
+                    self.upload(&mut payment_file, audit_info.clone()).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Uploads the rendered file to storage. Must only be called after the
+    /// transaction that created the file has been committed.
+    pub async fn upload(
+        &self,
+        payment_file: &mut PaymentFile,
+        audit_info: AuditInfo,
+    ) -> Result<(), PaymentFileError> {
+        if payment_file.is_uploaded() {
+            return Ok(());
+        }
+
+        let upload_result = match self.render(payment_file) {
+            Ok(xml) => self
+                .storage
+                .upload(
+                    xml,
+                    payment_file.path_in_bucket(),
+                    PAYMENT_FILE_CONTENT_TYPE,
+                )
+                .await
+                .map_err(PaymentFileError::from),
+            Err(e) => Err(e),
+        };
+
+        match upload_result {
+            Ok(_) => {
+                let _ =
+                    payment_file.file_uploaded(self.storage.bucket_name().to_string(), audit_info);
+            }
+            Err(e) => {
+                payment_file.upload_failed(e.to_string(), audit_info);
+            }
+        }
+        self.payment_files.update(payment_file).await?;
+
+        Ok(())
+    }
+
+    async fn withdrawals_to_send(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+    ) -> Result<Vec<Withdrawal>, PaymentFileError> {
+        let ids = self.withdrawals.lock_pending_confirmation_in_op(db).await?;
+        let mut withdrawals: Vec<Withdrawal> = self
+            .withdrawals
+            .find_all::<Withdrawal>(&ids)
+            .await?
+            .into_values()
+            .filter(|w| {
+                w.status() == WithdrawalStatus::PendingConfirmation
+                    && w.beneficiary.is_some()
+                    && w.amount.currency() == self.config.currency
+            })
+            .collect();
+        withdrawals.sort_by_key(|w| w.created_at());
+        Ok(withdrawals)
+    }
+
+    fn render(&self, payment_file: &PaymentFile) -> Result<Vec<u8>, PaymentFileError> {
+        let transfers = payment_file
+            .entries
+            .iter()
+            .map(|entry| {
+                Ok(CreditTransfer {
+                    end_to_end_id: &entry.end_to_end_id,
+                    amount: entry.amount.to_major()?,
+                    currency: entry.amount.currency().code(),
+                    creditor_name: &entry.beneficiary.name,
+                    creditor_iban: &entry.beneficiary.iban,
+                    creditor_bic: entry.beneficiary.bic.as_deref(),
+                    remittance_info: &entry.reference,
+                })
+            })
+            .collect::<Result<_, PaymentFileError>>()?;
+
+        let initiation = CustomerCreditTransferInitiation {
+            message_id: &payment_file.message_id,
+            created_at: payment_file.created_at(),
+            debtor: PaymentDebtor {
+                name: &self.config.debtor_name,
+                iban: &self.config.debtor_iban,
+                bic: self.config.debtor_bic.as_deref(),
+            },
+            transfers,
+        };
+        Ok(initiation.to_xml()?)
+    }
+}
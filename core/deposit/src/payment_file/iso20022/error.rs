@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Iso20022Error {
+    #[error("Iso20022Error - Xml: {0}")]
+    Xml(#[from] quick_xml::Error),
+    #[error("Iso20022Error - Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Iso20022Error - Utf8: {0}")]
+    Utf8(#[from] std::str::Utf8Error),
+    #[error("Iso20022Error - MissingField: {0}")]
+    MissingField(&'static str),
+}
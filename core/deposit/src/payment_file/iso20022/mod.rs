@@ -0,0 +1,6 @@
+pub mod error;
+mod pain001;
+mod pain002;
+
+pub use pain001::*;
+pub use pain002::*;
@@ -0,0 +1,210 @@
+use chrono::{DateTime, Utc};
+use quick_xml::{
+    Writer,
+    events::{BytesDecl, BytesText, Event},
+};
+use rust_decimal::Decimal;
+
+use super::error::Iso20022Error;
+
+const PAIN_001_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.03";
+
+pub struct PaymentDebtor<'a> {
+    pub name: &'a str,
+    pub iban: &'a str,
+    pub bic: Option<&'a str>,
+}
+
+pub struct CreditTransfer<'a> {
+    pub end_to_end_id: &'a str,
+    pub amount: Decimal,
+    pub currency: &'a str,
+    pub creditor_name: &'a str,
+    pub creditor_iban: &'a str,
+    pub creditor_bic: Option<&'a str>,
+    pub remittance_info: &'a str,
+}
+
+/// Customer credit transfer initiation (pain.001.001.03) with a single
+/// payment information block holding all transfers.
+pub struct CustomerCreditTransferInitiation<'a> {
+    pub message_id: &'a str,
+    pub created_at: DateTime<Utc>,
+    pub debtor: PaymentDebtor<'a>,
+    pub transfers: Vec<CreditTransfer<'a>>,
+}
+
+impl CustomerCreditTransferInitiation<'_> {
+    pub fn to_xml(&self) -> Result<Vec<u8>, Iso20022Error> {
+        let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        let number_of_txs = self.transfers.len().to_string();
+        let control_sum = format_amount(self.transfers.iter().map(|t| t.amount).sum());
+
+        writer
+            .create_element("Document")
+            .with_attribute(("xmlns", PAIN_001_NAMESPACE))
+            .write_inner_content(|w| {
+                w.create_element("CstmrCdtTrfInitn")
+                    .write_inner_content(|w| {
+                        w.create_element("GrpHdr").write_inner_content(|w| {
+                            text(w, "MsgId", self.message_id)?;
+                            text(
+                                w,
+                                "CreDtTm",
+                                &self.created_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                            )?;
+                            text(w, "NbOfTxs", &number_of_txs)?;
+                            text(w, "CtrlSum", &control_sum)?;
+                            w.create_element("InitgPty")
+                                .write_inner_content(|w| text(w, "Nm", self.debtor.name))?;
+                            Ok(())
+                        })?;
+                        self.write_payment_information(w, &number_of_txs, &control_sum)
+                    })?;
+                Ok(())
+            })?;
+
+        Ok(writer.into_inner())
+    }
+
+    fn write_payment_information(
+        &self,
+        w: &mut Writer<Vec<u8>>,
+        number_of_txs: &str,
+        control_sum: &str,
+    ) -> std::io::Result<()> {
+        w.create_element("PmtInf").write_inner_content(|w| {
+            text(w, "PmtInfId", self.message_id)?;
+            text(w, "PmtMtd", "TRF")?;
+            text(w, "NbOfTxs", number_of_txs)?;
+            text(w, "CtrlSum", control_sum)?;
+            text(
+                w,
+                "ReqdExctnDt",
+                &self.created_at.date_naive().format("%Y-%m-%d").to_string(),
+            )?;
+            w.create_element("Dbtr")
+                .write_inner_content(|w| text(w, "Nm", self.debtor.name))?;
+            account(w, "DbtrAcct", self.debtor.iban)?;
+            agent(w, "DbtrAgt", self.debtor.bic)?;
+            text(w, "ChrgBr", "SLEV")?;
+            for transfer in self.transfers.iter() {
+                w.create_element("CdtTrfTxInf").write_inner_content(|w| {
+                    w.create_element("PmtId")
+                        .write_inner_content(|w| text(w, "EndToEndId", transfer.end_to_end_id))?;
+                    w.create_element("Amt").write_inner_content(|w| {
+                        w.create_element("InstdAmt")
+                            .with_attribute(("Ccy", transfer.currency))
+                            .write_text_content(BytesText::new(&format_amount(transfer.amount)))?;
+                        Ok(())
+                    })?;
+                    if transfer.creditor_bic.is_some() {
+                        agent(w, "CdtrAgt", transfer.creditor_bic)?;
+                    }
+                    w.create_element("Cdtr")
+                        .write_inner_content(|w| text(w, "Nm", transfer.creditor_name))?;
+                    account(w, "CdtrAcct", transfer.creditor_iban)?;
+                    w.create_element("RmtInf")
+                        .write_inner_content(|w| text(w, "Ustrd", transfer.remittance_info))?;
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+}
+
+fn text(w: &mut Writer<Vec<u8>>, tag: &str, value: &str) -> std::io::Result<()> {
+    w.create_element(tag)
+        .write_text_content(BytesText::new(value))?;
+    Ok(())
+}
+
+fn account(w: &mut Writer<Vec<u8>>, tag: &str, iban: &str) -> std::io::Result<()> {
+    w.create_element(tag).write_inner_content(|w| {
+        w.create_element("Id")
+            .write_inner_content(|w| text(w, "IBAN", iban))?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+// Without a BIC the agent is left for the bank to derive from the IBAN
+fn agent(w: &mut Writer<Vec<u8>>, tag: &str, bic: Option<&str>) -> std::io::Result<()> {
+    w.create_element(tag).write_inner_content(|w| {
+        w.create_element("FinInstnId")
+            .write_inner_content(|w| match bic {
+                Some(bic) => text(w, "BIC", bic),
+                None => w
+                    .create_element("Othr")
+                    .write_inner_content(|w| text(w, "Id", "NOTPROVIDED"))
+                    .map(|_| ()),
+            })?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn format_amount(amount: Decimal) -> String {
+    format!("{:.2}", amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn initiation() -> CustomerCreditTransferInitiation<'static> {
+        CustomerCreditTransferInitiation {
+            message_id: "3f2b0c6a8d8b4e0b9b7f0e3c1a2d4e5f",
+            created_at: Utc.with_ymd_and_hms(2025, 2, 3, 9, 30, 0).unwrap(),
+            debtor: PaymentDebtor {
+                name: "Lana Bank",
+                iban: "DE89370400440532013000",
+                bic: Some("COBADEFFXXX"),
+            },
+            transfers: vec![
+                CreditTransfer {
+                    end_to_end_id: "e2e1",
+                    amount: dec!(1500),
+                    currency: "EUR",
+                    creditor_name: "Jane & Co",
+                    creditor_iban: "GB82WEST12345698765432",
+                    creditor_bic: None,
+                    remittance_info: "withdrawal-1",
+                },
+                CreditTransfer {
+                    end_to_end_id: "e2e2",
+                    amount: dec!(20.5),
+                    currency: "EUR",
+                    creditor_name: "John Doe",
+                    creditor_iban: "GB82WEST12345698765432",
+                    creditor_bic: Some("WESTGB2L"),
+                    remittance_info: "withdrawal-2",
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn writes_group_header_and_transfers() {
+        let xml = String::from_utf8(initiation().to_xml().unwrap()).unwrap();
+
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(xml.contains(&format!(r#"<Document xmlns="{PAIN_001_NAMESPACE}">"#)));
+        assert!(xml.contains("<MsgId>3f2b0c6a8d8b4e0b9b7f0e3c1a2d4e5f</MsgId>"));
+        assert!(xml.contains("<CreDtTm>2025-02-03T09:30:00</CreDtTm>"));
+        assert!(xml.contains("<ReqdExctnDt>2025-02-03</ReqdExctnDt>"));
+        assert_eq!(xml.matches("<NbOfTxs>2</NbOfTxs>").count(), 2);
+        assert_eq!(xml.matches("<CtrlSum>1520.50</CtrlSum>").count(), 2);
+        assert!(xml.contains(r#"<InstdAmt Ccy="EUR">20.50</InstdAmt>"#));
+        assert!(xml.contains("<Nm>Jane &amp; Co</Nm>"));
+        assert_eq!(xml.matches("<CdtTrfTxInf>").count(), 2);
+        assert_eq!(xml.matches("<CdtrAgt>").count(), 1);
+    }
+}
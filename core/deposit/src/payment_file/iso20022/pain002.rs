@@ -0,0 +1,208 @@
+use quick_xml::{Reader, events::Event};
+
+use super::error::Iso20022Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentStatusOutcome {
+    Accepted,
+    Rejected,
+    Pending,
+}
+
+impl PaymentStatusOutcome {
+    fn from_code(code: &str) -> Self {
+        match code {
+            // Settlement completed on the debtor / creditor side
+            "ACSC" | "ACCC" | "ACSP" | "ACWC" => PaymentStatusOutcome::Accepted,
+            "RJCT" => PaymentStatusOutcome::Rejected,
+            _ => PaymentStatusOutcome::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionStatus {
+    pub original_end_to_end_id: String,
+    pub outcome: PaymentStatusOutcome,
+    pub reason: Option<String>,
+}
+
+/// Customer payment status report (pain.002) reduced to what is needed
+/// to settle the transfers of the original payment file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentStatusReport {
+    pub original_message_id: String,
+    pub group_outcome: Option<PaymentStatusOutcome>,
+    pub group_reason: Option<String>,
+    pub transactions: Vec<TransactionStatus>,
+}
+
+#[derive(Default)]
+struct TransactionBuilder {
+    end_to_end_id: Option<String>,
+    status: Option<String>,
+    reason: Option<String>,
+}
+
+impl PaymentStatusReport {
+    pub fn parse(content: &[u8]) -> Result<Self, Iso20022Error> {
+        let mut reader = Reader::from_reader(content);
+        reader.config_mut().trim_text(true);
+
+        let mut path: Vec<String> = Vec::new();
+        let mut buf = Vec::new();
+        let mut original_message_id = None;
+        let mut group_status = None;
+        let mut group_reason = None;
+        let mut transactions = Vec::new();
+        let mut tx: Option<TransactionBuilder> = None;
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => {
+                    let name = std::str::from_utf8(e.local_name().as_ref())?.to_string();
+                    if name == "TxInfAndSts" {
+                        tx = Some(TransactionBuilder::default());
+                    }
+                    path.push(name);
+                }
+                Event::End(_) => {
+                    if path.pop().as_deref() == Some("TxInfAndSts")
+                        && let Some(tx) = tx.take()
+                    {
+                        let original_end_to_end_id = tx
+                            .end_to_end_id
+                            .ok_or(Iso20022Error::MissingField("OrgnlEndToEndId"))?;
+                        let status = tx.status.ok_or(Iso20022Error::MissingField("TxSts"))?;
+                        transactions.push(TransactionStatus {
+                            original_end_to_end_id,
+                            outcome: PaymentStatusOutcome::from_code(&status),
+                            reason: tx.reason,
+                        });
+                    }
+                }
+                Event::Text(text) => {
+                    let value = text.unescape()?.trim().to_string();
+                    let rel: Vec<&str> = path
+                        .iter()
+                        .rev()
+                        .take(3)
+                        .rev()
+                        .map(String::as_str)
+                        .collect();
+                    match (tx.as_mut(), rel.as_slice()) {
+                        (Some(tx), [.., "TxInfAndSts", "OrgnlEndToEndId"]) => {
+                            tx.end_to_end_id = Some(value)
+                        }
+                        (Some(tx), [.., "TxInfAndSts", "TxSts"]) => tx.status = Some(value),
+                        (Some(tx), [.., "StsRsnInf", "Rsn", "Cd"]) if tx.reason.is_none() => {
+                            tx.reason = Some(value)
+                        }
+                        (None, [.., "OrgnlGrpInfAndSts", "OrgnlMsgId"]) => {
+                            original_message_id = Some(value)
+                        }
+                        (None, [.., "OrgnlGrpInfAndSts", "GrpSts"]) => group_status = Some(value),
+                        (None, [.., "StsRsnInf", "Rsn", "Cd"]) if group_reason.is_none() => {
+                            group_reason = Some(value)
+                        }
+                        _ => (),
+                    }
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(PaymentStatusReport {
+            original_message_id: original_message_id
+                .ok_or(Iso20022Error::MissingField("OrgnlMsgId"))?,
+            group_outcome: group_status.as_deref().map(PaymentStatusOutcome::from_code),
+            group_reason,
+            transactions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pain.002.001.03">
+  <CstmrPmtStsRpt>
+    <GrpHdr><MsgId>STS-1</MsgId><CreDtTm>2025-02-04T08:00:00</CreDtTm></GrpHdr>
+    <OrgnlGrpInfAndSts>
+      <OrgnlMsgId>3f2b0c6a8d8b4e0b9b7f0e3c1a2d4e5f</OrgnlMsgId>
+      <OrgnlMsgNmId>pain.001.001.03</OrgnlMsgNmId>
+      <GrpSts>PART</GrpSts>
+    </OrgnlGrpInfAndSts>
+    <OrgnlPmtInfAndSts>
+      <OrgnlPmtInfId>3f2b0c6a8d8b4e0b9b7f0e3c1a2d4e5f</OrgnlPmtInfId>
+      <TxInfAndSts>
+        <OrgnlEndToEndId>e2e1</OrgnlEndToEndId>
+        <TxSts>ACSC</TxSts>
+      </TxInfAndSts>
+      <TxInfAndSts>
+        <OrgnlEndToEndId>e2e2</OrgnlEndToEndId>
+        <TxSts>RJCT</TxSts>
+        <StsRsnInf><Rsn><Cd>AC04</Cd></Rsn><AddtlInf>Closed account</AddtlInf></StsRsnInf>
+      </TxInfAndSts>
+      <TxInfAndSts>
+        <OrgnlEndToEndId>e2e3</OrgnlEndToEndId>
+        <TxSts>PDNG</TxSts>
+      </TxInfAndSts>
+    </OrgnlPmtInfAndSts>
+  </CstmrPmtStsRpt>
+</Document>"#;
+
+    #[test]
+    fn parses_transaction_statuses() {
+        let report = PaymentStatusReport::parse(REPORT.as_bytes()).unwrap();
+        assert_eq!(
+            report.original_message_id,
+            "3f2b0c6a8d8b4e0b9b7f0e3c1a2d4e5f"
+        );
+        assert_eq!(report.group_outcome, Some(PaymentStatusOutcome::Pending));
+        assert_eq!(
+            report.transactions,
+            vec![
+                TransactionStatus {
+                    original_end_to_end_id: "e2e1".to_string(),
+                    outcome: PaymentStatusOutcome::Accepted,
+                    reason: None,
+                },
+                TransactionStatus {
+                    original_end_to_end_id: "e2e2".to_string(),
+                    outcome: PaymentStatusOutcome::Rejected,
+                    reason: Some("AC04".to_string()),
+                },
+                TransactionStatus {
+                    original_end_to_end_id: "e2e3".to_string(),
+                    outcome: PaymentStatusOutcome::Pending,
+                    reason: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_group_rejection() {
+        let content = r#"<Document><CstmrPmtStsRpt><OrgnlGrpInfAndSts>
+            <OrgnlMsgId>MSG</OrgnlMsgId><GrpSts>RJCT</GrpSts>
+            <StsRsnInf><Rsn><Cd>FF01</Cd></Rsn></StsRsnInf>
+        </OrgnlGrpInfAndSts></CstmrPmtStsRpt></Document>"#;
+        let report = PaymentStatusReport::parse(content.as_bytes()).unwrap();
+        assert_eq!(report.group_outcome, Some(PaymentStatusOutcome::Rejected));
+        assert_eq!(report.group_reason.as_deref(), Some("FF01"));
+        assert!(report.transactions.is_empty());
+    }
+
+    #[test]
+    fn requires_original_message_id() {
+        assert!(matches!(
+            PaymentStatusReport::parse(b"<Document></Document>"),
+            Err(Iso20022Error::MissingField("OrgnlMsgId"))
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use std::time::Duration;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{CoreDepositAction, CoreDepositEvent, CoreDepositObject};
+
+use super::{generate::PaymentFileGenerator, repo::PaymentFileRepo};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PaymentFileJobConfig<Perms, E> {
+    pub job_interval_secs: u64,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> PaymentFileJobConfig<Perms, E> {
+    pub fn new(job_interval_secs: u64) -> Self {
+        Self {
+            job_interval_secs,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> JobConfig for PaymentFileJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = PaymentFileJobInitializer<Perms, E>;
+}
+
+pub struct PaymentFileJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: PaymentFileRepo,
+    generator: PaymentFileGenerator<E>,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> PaymentFileJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        repo: &PaymentFileRepo,
+        generator: &PaymentFileGenerator<E>,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            generator: generator.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const GENERATE_PAYMENT_FILE_JOB: JobType = JobType::new("generate-payment-file");
+impl<Perms, E> JobInitializer for PaymentFileJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        GENERATE_PAYMENT_FILE_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(PaymentFileJobRunner::<Perms, E> {
+            config: job.config()?,
+            repo: self.repo.clone(),
+            generator: self.generator.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct PaymentFileJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: PaymentFileJobConfig<Perms, E>,
+    repo: PaymentFileRepo,
+    generator: PaymentFileGenerator<E>,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for PaymentFileJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::all_payment_files(),
+                CoreDepositAction::PAYMENT_FILE_GENERATE,
+            )
+            .await?;
+
+        self.generator
+            .generate_in_op(&mut db, audit_info.clone())
+            .await?;
+        db.commit().await?;
+
+        self.generator.upload_pending(audit_info).await?;
+
+        Ok(JobCompletion::RescheduleIn(Duration::from_secs(
+            self.config.job_interval_secs,
+        )))
+    }
+}
@@ -0,0 +1,15 @@
+mod entity;
+pub mod error;
+mod generate;
+pub mod iso20022;
+mod job;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::PaymentFileEvent;
+pub(super) use entity::*;
+pub use entity::{PaymentFile, PaymentFileEntry, PaymentFileEntryStatus, PaymentFileStatus};
+pub(super) use generate::*;
+pub(super) use job::*;
+pub use repo::payment_file_cursor::PaymentFilesByCreatedAtCursor;
+pub(super) use repo::*;
@@ -0,0 +1,70 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::PaymentFileId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "PaymentFile",
+    err = "PaymentFileError",
+    columns(
+        message_id(ty = "String", update(persist = false)),
+        status(
+            ty = "PaymentFileStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct PaymentFileRepo {
+    pool: PgPool,
+}
+
+impl PaymentFileRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+mod payment_file_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::PaymentFileStatus;
+
+    impl Type<Postgres> for PaymentFileStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for PaymentFileStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for PaymentFileStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for PaymentFileStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
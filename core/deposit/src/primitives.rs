@@ -1,9 +1,10 @@
 use std::{fmt::Display, str::FromStr};
 
-use authz::{AllOrOne, action_description::*};
+use authz::{AllOrOne, Attributes, action_description::*};
 
 pub use core_accounting::ChartId;
 pub use core_customer::CustomerType;
+pub use document_storage::DocumentId;
 pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 
 pub use cala_ledger::primitives::{
@@ -16,26 +17,50 @@ es_entity::entity_id! {
     DepositAccountId,
     WithdrawalId,
     ChartOfAccountsIntegrationConfigId,
-    DepositId;
+    DepositId,
+    StatementImportId,
+    PaymentFileId,
+    AccountStatementId,
+    ScheduledWithdrawalId;
 
     DepositAccountHolderId => core_customer::CustomerId,
     DepositAccountId => CalaAccountId,
     DepositId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    StatementImportId => document_storage::ReferenceId,
+    AccountStatementId => document_storage::ReferenceId
 }
 
 pub use core_customer::AccountStatus;
-pub use core_money::UsdCents;
+pub use core_money::{CurrencyCode, Money, UsdCents};
 
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type StatementImportAllOrOne = AllOrOne<StatementImportId>;
+pub type PaymentFileAllOrOne = AllOrOne<PaymentFileId>;
+pub type AccountStatementAllOrOne = AllOrOne<AccountStatementId>;
+pub type ScheduledWithdrawalAllOrOne = AllOrOne<ScheduledWithdrawalId>;
 
 pub const PERMISSION_SET_DEPOSIT_VIEWER: &str = "deposit_viewer";
 pub const PERMISSION_SET_DEPOSIT_WRITER: &str = "deposit_writer";
+pub const PERMISSION_SET_DEPOSIT_APPROVER: &str = "deposit_approver";
+
+pub const ATTRIBUTE_AMOUNT_USD: &str = "amount_usd";
+pub const ATTRIBUTE_CURRENCY: &str = "currency";
+
+/// Amounts in other currencies carry no `amount_usd`, so USD limits on a role
+/// deny them.
+pub(crate) fn amount_attributes(amount: Money) -> Attributes {
+    let attributes = Attributes::new().with(ATTRIBUTE_CURRENCY, amount.currency().to_string());
+    match UsdCents::try_from(amount) {
+        Ok(usd) => attributes.with(ATTRIBUTE_AMOUNT_USD, usd.to_usd()),
+        Err(_) => attributes,
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct LedgerOmnibusAccountIds {
@@ -51,6 +76,10 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    StatementImport(StatementImportAllOrOne),
+    PaymentFile(PaymentFileAllOrOne),
+    AccountStatement(AccountStatementAllOrOne),
+    ScheduledWithdrawal(ScheduledWithdrawalAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -81,6 +110,38 @@ impl CoreDepositObject {
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegrationConfig(AllOrOne::All)
     }
+
+    pub fn all_statement_imports() -> Self {
+        CoreDepositObject::StatementImport(AllOrOne::All)
+    }
+
+    pub fn statement_import(id: StatementImportId) -> Self {
+        CoreDepositObject::StatementImport(AllOrOne::ById(id))
+    }
+
+    pub fn all_payment_files() -> Self {
+        CoreDepositObject::PaymentFile(AllOrOne::All)
+    }
+
+    pub fn payment_file(id: PaymentFileId) -> Self {
+        CoreDepositObject::PaymentFile(AllOrOne::ById(id))
+    }
+
+    pub fn all_account_statements() -> Self {
+        CoreDepositObject::AccountStatement(AllOrOne::All)
+    }
+
+    pub fn account_statement(id: AccountStatementId) -> Self {
+        CoreDepositObject::AccountStatement(AllOrOne::ById(id))
+    }
+
+    pub fn all_scheduled_withdrawals() -> Self {
+        CoreDepositObject::ScheduledWithdrawal(AllOrOne::All)
+    }
+
+    pub fn scheduled_withdrawal(id: ScheduledWithdrawalId) -> Self {
+        CoreDepositObject::ScheduledWithdrawal(AllOrOne::ById(id))
+    }
 }
 
 impl Display for CoreDepositObject {
@@ -92,6 +153,10 @@ impl Display for CoreDepositObject {
             Deposit(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             Withdrawal(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            StatementImport(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            PaymentFile(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            AccountStatement(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            ScheduledWithdrawal(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -127,6 +192,30 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::ChartOfAccountsIntegrationConfig(obj_ref)
             }
+            StatementImport => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::StatementImport(obj_ref)
+            }
+            PaymentFile => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::PaymentFile(obj_ref)
+            }
+            AccountStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::AccountStatement(obj_ref)
+            }
+            ScheduledWithdrawal => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::ScheduledWithdrawal(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -140,6 +229,10 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    StatementImport(StatementImportAction),
+    PaymentFile(PaymentFileAction),
+    AccountStatement(AccountStatementAction),
+    ScheduledWithdrawal(ScheduledWithdrawalAction),
 }
 
 impl CoreDepositAction {
@@ -175,6 +268,40 @@ impl CoreDepositAction {
     pub const WITHDRAWAL_READ: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Read);
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
 
+    pub const STATEMENT_IMPORT_CREATE: Self =
+        CoreDepositAction::StatementImport(StatementImportAction::Create);
+    pub const STATEMENT_IMPORT_READ: Self =
+        CoreDepositAction::StatementImport(StatementImportAction::Read);
+    pub const STATEMENT_IMPORT_LIST: Self =
+        CoreDepositAction::StatementImport(StatementImportAction::List);
+    pub const STATEMENT_IMPORT_RESOLVE_LINE: Self =
+        CoreDepositAction::StatementImport(StatementImportAction::ResolveLine);
+
+    pub const PAYMENT_FILE_GENERATE: Self =
+        CoreDepositAction::PaymentFile(PaymentFileAction::Generate);
+    pub const PAYMENT_FILE_READ: Self = CoreDepositAction::PaymentFile(PaymentFileAction::Read);
+    pub const PAYMENT_FILE_LIST: Self = CoreDepositAction::PaymentFile(PaymentFileAction::List);
+    pub const PAYMENT_FILE_IMPORT_STATUS: Self =
+        CoreDepositAction::PaymentFile(PaymentFileAction::ImportStatus);
+
+    pub const ACCOUNT_STATEMENT_GENERATE: Self =
+        CoreDepositAction::AccountStatement(AccountStatementAction::Generate);
+    pub const ACCOUNT_STATEMENT_READ: Self =
+        CoreDepositAction::AccountStatement(AccountStatementAction::Read);
+    pub const ACCOUNT_STATEMENT_LIST: Self =
+        CoreDepositAction::AccountStatement(AccountStatementAction::List);
+
+    pub const SCHEDULED_WITHDRAWAL_CREATE: Self =
+        CoreDepositAction::ScheduledWithdrawal(ScheduledWithdrawalAction::Create);
+    pub const SCHEDULED_WITHDRAWAL_CANCEL: Self =
+        CoreDepositAction::ScheduledWithdrawal(ScheduledWithdrawalAction::Cancel);
+    pub const SCHEDULED_WITHDRAWAL_EXECUTE: Self =
+        CoreDepositAction::ScheduledWithdrawal(ScheduledWithdrawalAction::Execute);
+    pub const SCHEDULED_WITHDRAWAL_READ: Self =
+        CoreDepositAction::ScheduledWithdrawal(ScheduledWithdrawalAction::Read);
+    pub const SCHEDULED_WITHDRAWAL_LIST: Self =
+        CoreDepositAction::ScheduledWithdrawal(ScheduledWithdrawalAction::List);
+
     pub fn entities() -> Vec<(
         CoreDepositActionDiscriminants,
         Vec<ActionDescription<NoPath>>,
@@ -191,6 +318,10 @@ impl CoreDepositAction {
                     ChartOfAccountsIntegrationConfigAction::describe()
                 }
                 Withdrawal => WithdrawalAction::describe(),
+                StatementImport => StatementImportAction::describe(),
+                PaymentFile => PaymentFileAction::describe(),
+                AccountStatement => AccountStatementAction::describe(),
+                ScheduledWithdrawal => ScheduledWithdrawalAction::describe(),
             };
 
             result.push((*entity, actions));
@@ -208,6 +339,10 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            StatementImport(action) => action.fmt(f),
+            PaymentFile(action) => action.fmt(f),
+            AccountStatement(action) => action.fmt(f),
+            ScheduledWithdrawal(action) => action.fmt(f),
         }
     }
 }
@@ -225,6 +360,12 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            StatementImport => CoreDepositAction::from(action.parse::<StatementImportAction>()?),
+            PaymentFile => CoreDepositAction::from(action.parse::<PaymentFileAction>()?),
+            AccountStatement => CoreDepositAction::from(action.parse::<AccountStatementAction>()?),
+            ScheduledWithdrawal => {
+                CoreDepositAction::from(action.parse::<ScheduledWithdrawalAction>()?)
+            }
         };
 
         Ok(res)
@@ -349,7 +490,9 @@ impl WithdrawalAction {
                 Self::ConcludeApprovalProcess => {
                     ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
                 }
-                Self::Confirm => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Confirm => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_APPROVER])
+                }
             };
             res.push(action_description);
         }
@@ -396,6 +539,166 @@ impl From<ChartOfAccountsIntegrationConfigAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StatementImportAction {
+    Create,
+    Read,
+    List,
+    ResolveLine,
+}
+
+impl StatementImportAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+                Self::ResolveLine => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<StatementImportAction> for CoreDepositAction {
+    fn from(action: StatementImportAction) -> Self {
+        CoreDepositAction::StatementImport(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PaymentFileAction {
+    Generate,
+    Read,
+    List,
+    ImportStatus,
+}
+
+impl PaymentFileAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Generate => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+                Self::ImportStatus => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<PaymentFileAction> for CoreDepositAction {
+    fn from(action: PaymentFileAction) -> Self {
+        CoreDepositAction::PaymentFile(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountStatementAction {
+    Generate,
+    Read,
+    List,
+}
+
+impl AccountStatementAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Generate => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<AccountStatementAction> for CoreDepositAction {
+    fn from(action: AccountStatementAction) -> Self {
+        CoreDepositAction::AccountStatement(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ScheduledWithdrawalAction {
+    Create,
+    Cancel,
+    Execute,
+    Read,
+    List,
+}
+
+impl ScheduledWithdrawalAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Cancel => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Execute => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<ScheduledWithdrawalAction> for CoreDepositAction {
+    fn from(action: ScheduledWithdrawalAction) -> Self {
+        CoreDepositAction::ScheduledWithdrawal(action)
+    }
+}
+
 pub enum DepositAccountType {
     Individual,
     GovernmentEntity,
@@ -4,6 +4,7 @@ use super::event::CoreDepositEvent;
 use crate::{
     account::{DepositAccount, DepositAccountEvent, error::DepositAccountError},
     deposit::{Deposit, DepositEvent, error::DepositError},
+    primitives::UsdCents,
     withdrawal::{Withdrawal, WithdrawalEvent, error::WithdrawalError},
 };
 
@@ -69,7 +70,8 @@ where
                 Confirmed { .. } => Some(CoreDepositEvent::WithdrawalConfirmed {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
-                    amount: entity.amount,
+                    amount: UsdCents::from(entity.amount.minor_units()),
+                    currency: entity.amount.currency(),
                 }),
                 _ => None,
             })
@@ -92,7 +94,8 @@ where
                 Initialized { .. } => CoreDepositEvent::DepositInitialized {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
-                    amount: entity.amount,
+                    amount: UsdCents::from(entity.amount.minor_units()),
+                    currency: entity.amount.currency(),
                 },
             })
             .collect::<Vec<_>>();
@@ -0,0 +1,507 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::{
+    primitives::{DepositAccountId, Money, ScheduledWithdrawalId, WithdrawalId},
+    withdrawal::WithdrawalBeneficiary,
+};
+
+use super::{error::ScheduledWithdrawalError, recurrence::WithdrawalRecurrence};
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ScheduledWithdrawalStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ScheduledWithdrawalId")]
+pub enum ScheduledWithdrawalEvent {
+    Initialized {
+        id: ScheduledWithdrawalId,
+        deposit_account_id: DepositAccountId,
+        amount: Money,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+        recurrence: WithdrawalRecurrence,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    WithdrawalInitiated {
+        scheduled_for: NaiveDate,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    },
+    WithdrawalFailed {
+        scheduled_for: NaiveDate,
+        error: String,
+        audit_info: AuditInfo,
+    },
+    RunSkipped {
+        scheduled_for: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
+}
+
+/// Outcome of a single run of the schedule.
+#[derive(Debug, Clone)]
+pub struct ScheduledWithdrawalExecution {
+    pub scheduled_for: NaiveDate,
+    pub withdrawal_id: Option<WithdrawalId>,
+    pub error: Option<String>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct ScheduledWithdrawal {
+    pub id: ScheduledWithdrawalId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: Money,
+    pub reference: Option<String>,
+    pub beneficiary: Option<WithdrawalBeneficiary>,
+    pub recurrence: WithdrawalRecurrence,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+
+    events: EntityEvents<ScheduledWithdrawalEvent>,
+}
+
+impl ScheduledWithdrawal {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for scheduled withdrawal")
+    }
+
+    pub fn executions(&self) -> Vec<ScheduledWithdrawalExecution> {
+        self.events
+            .iter_all()
+            .filter_map(|e| match e {
+                ScheduledWithdrawalEvent::WithdrawalInitiated {
+                    scheduled_for,
+                    withdrawal_id,
+                    ..
+                } => Some(ScheduledWithdrawalExecution {
+                    scheduled_for: *scheduled_for,
+                    withdrawal_id: Some(*withdrawal_id),
+                    error: None,
+                }),
+                ScheduledWithdrawalEvent::WithdrawalFailed {
+                    scheduled_for,
+                    error,
+                    ..
+                } => Some(ScheduledWithdrawalExecution {
+                    scheduled_for: *scheduled_for,
+                    withdrawal_id: None,
+                    error: Some(error.clone()),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Error of the most recent run if that run did not create a withdrawal.
+    pub fn last_error(&self) -> Option<String> {
+        self.executions().pop().and_then(|e| e.error)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, ScheduledWithdrawalEvent::Cancelled { .. }))
+    }
+
+    fn runs(&self) -> u32 {
+        self.events
+            .iter_all()
+            .filter(|e| {
+                matches!(
+                    e,
+                    ScheduledWithdrawalEvent::WithdrawalInitiated { .. }
+                        | ScheduledWithdrawalEvent::WithdrawalFailed { .. }
+                        | ScheduledWithdrawalEvent::RunSkipped { .. }
+                )
+            })
+            .count() as u32
+    }
+
+    fn occurrence(&self, n: u32) -> Option<NaiveDate> {
+        if self.is_cancelled() {
+            return None;
+        }
+        self.recurrence
+            .occurrence(self.start_date, n)
+            .filter(|date| self.end_date.is_none_or(|end| *date <= end))
+    }
+
+    pub fn next_run_date(&self) -> Option<NaiveDate> {
+        self.occurrence(self.runs())
+    }
+
+    /// The next run date if it is on or before `today`.
+    pub fn due_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        self.next_run_date().filter(|date| *date <= today)
+    }
+
+    /// The most recent run date on or before `today` that has not run yet.
+    /// Earlier pending dates were missed and are skipped rather than caught up.
+    pub fn latest_due_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let mut latest = None;
+        let mut n = self.runs();
+        while let Some(date) = self.occurrence(n).filter(|date| *date <= today) {
+            latest = Some(date);
+            n += 1;
+        }
+        latest
+    }
+
+    pub fn status(&self) -> ScheduledWithdrawalStatus {
+        if self.is_cancelled() {
+            ScheduledWithdrawalStatus::Cancelled
+        } else if self.next_run_date().is_none() {
+            ScheduledWithdrawalStatus::Completed
+        } else {
+            ScheduledWithdrawalStatus::Active
+        }
+    }
+
+    /// Reference of the withdrawal created for the run on `scheduled_for`.
+    /// Withdrawal references are unique, so a run can never create two withdrawals.
+    pub fn withdrawal_reference(&self, scheduled_for: NaiveDate) -> String {
+        match self.reference.as_deref() {
+            None | Some("") => format!("{}:{}", self.id, scheduled_for),
+            Some(reference) => format!("{}:{}", reference, scheduled_for),
+        }
+    }
+
+    fn has_run(&self, date: NaiveDate) -> bool {
+        self.events.iter_all().any(|e| match e {
+            ScheduledWithdrawalEvent::WithdrawalInitiated { scheduled_for, .. }
+            | ScheduledWithdrawalEvent::WithdrawalFailed { scheduled_for, .. }
+            | ScheduledWithdrawalEvent::RunSkipped { scheduled_for, .. } => *scheduled_for == date,
+            _ => false,
+        })
+    }
+
+    /// Records every pending run before `date` as skipped.
+    pub fn skip_runs_before(&mut self, date: NaiveDate, audit_info: AuditInfo) -> Idempotent<()> {
+        let mut skipped = false;
+        while let Some(scheduled_for) = self.next_run_date().filter(|next| *next < date) {
+            self.events.push(ScheduledWithdrawalEvent::RunSkipped {
+                scheduled_for,
+                audit_info: audit_info.clone(),
+            });
+            skipped = true;
+        }
+        if skipped {
+            Idempotent::Executed(())
+        } else {
+            Idempotent::Ignored
+        }
+    }
+
+    pub fn record_withdrawal_initiated(
+        &mut self,
+        scheduled_for: NaiveDate,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.has_run(scheduled_for) {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(ScheduledWithdrawalEvent::WithdrawalInitiated {
+                scheduled_for,
+                withdrawal_id,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub fn record_withdrawal_failed(
+        &mut self,
+        scheduled_for: NaiveDate,
+        error: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.has_run(scheduled_for) {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(ScheduledWithdrawalEvent::WithdrawalFailed {
+                scheduled_for,
+                error,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub fn cancel(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ScheduledWithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ScheduledWithdrawalEvent::Cancelled { .. }
+        );
+        if self.status() == ScheduledWithdrawalStatus::Completed {
+            return Err(ScheduledWithdrawalError::AlreadyCompleted(self.id));
+        }
+        self.events
+            .push(ScheduledWithdrawalEvent::Cancelled { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<ScheduledWithdrawalEvent> for ScheduledWithdrawal {
+    fn try_from_events(
+        events: EntityEvents<ScheduledWithdrawalEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = ScheduledWithdrawalBuilder::default();
+        for event in events.iter_all() {
+            if let ScheduledWithdrawalEvent::Initialized {
+                id,
+                deposit_account_id,
+                amount,
+                reference,
+                beneficiary,
+                recurrence,
+                start_date,
+                end_date,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .amount(*amount)
+                    .reference(reference.clone())
+                    .beneficiary(beneficiary.clone())
+                    .recurrence(*recurrence)
+                    .start_date(*start_date)
+                    .end_date(*end_date)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewScheduledWithdrawal {
+    #[builder(setter(into))]
+    pub(super) id: ScheduledWithdrawalId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: Money,
+    #[builder(default)]
+    reference: Option<String>,
+    #[builder(default)]
+    beneficiary: Option<WithdrawalBeneficiary>,
+    recurrence: WithdrawalRecurrence,
+    start_date: NaiveDate,
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewScheduledWithdrawal {
+    pub fn builder() -> NewScheduledWithdrawalBuilder {
+        NewScheduledWithdrawalBuilder::default()
+    }
+
+    pub(super) fn status(&self) -> ScheduledWithdrawalStatus {
+        ScheduledWithdrawalStatus::Active
+    }
+}
+
+impl NewScheduledWithdrawalBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount
+            && amount.is_zero()
+        {
+            return Err("Scheduled withdrawal amount cannot be zero".to_string());
+        }
+        if let Some(start) = self.start_date
+            && start < crate::time::now().date_naive()
+        {
+            return Err("Scheduled withdrawal cannot start in the past".to_string());
+        }
+        if let (Some(start), Some(Some(end))) = (self.start_date, self.end_date)
+            && end < start
+        {
+            return Err("Scheduled withdrawal cannot end before it starts".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<ScheduledWithdrawalEvent> for NewScheduledWithdrawal {
+    fn into_events(self) -> EntityEvents<ScheduledWithdrawalEvent> {
+        EntityEvents::init(
+            self.id,
+            [ScheduledWithdrawalEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                reference: self.reference,
+                beneficiary: self.beneficiary,
+                recurrence: self.recurrence,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use chrono::Datelike;
+
+    use crate::primitives::CurrencyCode;
+
+    use super::{super::recurrence::WithdrawalFrequency, *};
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(crate::time::now().year() + 1, 1, day).unwrap()
+    }
+
+    fn schedule(end_date: Option<NaiveDate>) -> ScheduledWithdrawal {
+        let new_schedule = NewScheduledWithdrawal::builder()
+            .id(ScheduledWithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(Money::new(100, CurrencyCode::Usd))
+            .recurrence(WithdrawalRecurrence::new(WithdrawalFrequency::Weekly, 1).unwrap())
+            .start_date(date(1))
+            .end_date(end_date)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        ScheduledWithdrawal::try_from_events(new_schedule.into_events()).unwrap()
+    }
+
+    #[test]
+    fn failed_runs_advance_the_schedule_and_stay_visible() {
+        let mut schedule = schedule(None);
+        assert_eq!(schedule.due_date(date(1)), Some(date(1)));
+
+        let _ = schedule.record_withdrawal_failed(
+            date(1),
+            "insufficient balance".to_string(),
+            dummy_audit_info(),
+        );
+        assert_eq!(schedule.due_date(date(1)), None);
+        assert_eq!(schedule.next_run_date(), Some(date(8)));
+        assert_eq!(
+            schedule.last_error().as_deref(),
+            Some("insufficient balance")
+        );
+
+        let _ =
+            schedule.record_withdrawal_initiated(date(8), WithdrawalId::new(), dummy_audit_info());
+        assert_eq!(schedule.last_error(), None);
+        assert_eq!(schedule.executions().len(), 2);
+    }
+
+    #[test]
+    fn completes_after_end_date() {
+        let mut schedule = schedule(Some(date(10)));
+        let _ =
+            schedule.record_withdrawal_initiated(date(1), WithdrawalId::new(), dummy_audit_info());
+        assert_eq!(schedule.status(), ScheduledWithdrawalStatus::Active);
+        let _ =
+            schedule.record_withdrawal_initiated(date(8), WithdrawalId::new(), dummy_audit_info());
+        assert_eq!(schedule.status(), ScheduledWithdrawalStatus::Completed);
+        assert!(schedule.cancel(dummy_audit_info()).is_err());
+    }
+
+    #[test]
+    fn run_is_recorded_once_per_date() {
+        let mut schedule = schedule(None);
+        assert!(
+            schedule
+                .record_withdrawal_initiated(date(1), WithdrawalId::new(), dummy_audit_info())
+                .did_execute()
+        );
+        assert!(
+            !schedule
+                .record_withdrawal_failed(date(1), "duplicate".to_string(), dummy_audit_info())
+                .did_execute()
+        );
+    }
+
+    #[test]
+    fn missed_runs_are_skipped() {
+        let mut schedule = schedule(None);
+        assert_eq!(schedule.latest_due_date(date(20)), Some(date(15)));
+
+        let _ = schedule.skip_runs_before(date(15), dummy_audit_info());
+        assert_eq!(schedule.due_date(date(20)), Some(date(15)));
+        let _ =
+            schedule.record_withdrawal_initiated(date(15), WithdrawalId::new(), dummy_audit_info());
+        assert_eq!(schedule.due_date(date(20)), None);
+        assert_eq!(schedule.next_run_date(), Some(date(22)));
+        assert_eq!(schedule.executions().len(), 1);
+    }
+
+    #[test]
+    fn errors_when_start_date_in_past() {
+        let res = NewScheduledWithdrawal::builder()
+            .id(ScheduledWithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(Money::new(100, CurrencyCode::Usd))
+            .recurrence(WithdrawalRecurrence::monthly())
+            .start_date(crate::time::now().date_naive() - chrono::Days::new(1))
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(matches!(
+            res,
+            Err(NewScheduledWithdrawalBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn errors_when_end_date_before_start_date() {
+        let res = NewScheduledWithdrawal::builder()
+            .id(ScheduledWithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(Money::new(100, CurrencyCode::Usd))
+            .recurrence(WithdrawalRecurrence::monthly())
+            .start_date(date(10))
+            .end_date(Some(date(1)))
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(matches!(
+            res,
+            Err(NewScheduledWithdrawalBuilderError::ValidationError(_))
+        ));
+    }
+}
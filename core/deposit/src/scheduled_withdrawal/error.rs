@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::primitives::ScheduledWithdrawalId;
+
+#[derive(Error, Debug)]
+pub enum ScheduledWithdrawalError {
+    #[error("ScheduledWithdrawalError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ScheduledWithdrawalError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ScheduledWithdrawalError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("ScheduledWithdrawalError - InvalidRecurrence: {0}")]
+    InvalidRecurrence(String),
+    #[error("ScheduledWithdrawalError - StartDateInPast: {0}")]
+    StartDateInPast(chrono::NaiveDate),
+    #[error("ScheduledWithdrawalError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(ScheduledWithdrawalId),
+}
+
+es_entity::from_es_entity_error!(ScheduledWithdrawalError);
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, time};
+
+use super::{
+    entity::ScheduledWithdrawalStatus,
+    repo::{scheduled_withdrawal_cursor::ScheduledWithdrawalsByCreatedAtCursor, *},
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScheduledWithdrawalJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> ScheduledWithdrawalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> JobConfig for ScheduledWithdrawalJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = ScheduledWithdrawalJobInitializer<Perms, E>;
+}
+
+pub struct ScheduledWithdrawalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    deposits: CoreDeposit<Perms, E>,
+    repo: ScheduledWithdrawalRepo,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> ScheduledWithdrawalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        deposits: &CoreDeposit<Perms, E>,
+        repo: &ScheduledWithdrawalRepo,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            deposits: deposits.clone(),
+            repo: repo.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const EXECUTE_SCHEDULED_WITHDRAWALS_JOB: JobType = JobType::new("execute-scheduled-withdrawals");
+impl<Perms, E> JobInitializer for ScheduledWithdrawalJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        EXECUTE_SCHEDULED_WITHDRAWALS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ScheduledWithdrawalJobRunner::<Perms, E> {
+            deposits: self.deposits.clone(),
+            repo: self.repo.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct ScheduledWithdrawalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    deposits: CoreDeposit<Perms, E>,
+    repo: ScheduledWithdrawalRepo,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ScheduledWithdrawalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = time::now();
+        let today = now.date_naive();
+        let system = <<Perms::Audit as AuditSvc>::Subject as SystemSubject>::system();
+
+        let mut has_next_page = true;
+        let mut after: Option<ScheduledWithdrawalsByCreatedAtCursor> = None;
+        while has_next_page {
+            let schedules = self
+                .repo
+                .list_for_status_by_created_at(
+                    ScheduledWithdrawalStatus::Active,
+                    es_entity::PaginatedQueryArgs::<ScheduledWithdrawalsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (schedules.end_cursor, schedules.has_next_page);
+
+            for mut schedule in schedules.entities {
+                // Only the most recent due run is executed, runs missed while the
+                // job was not running are recorded as skipped instead of caught up.
+                if let Some(scheduled_for) = schedule.latest_due_date(today) {
+                    // The withdrawal may have been initiated by a previous attempt
+                    // that failed before the schedule was updated.
+                    let reference = schedule.withdrawal_reference(scheduled_for);
+                    let res = match self
+                        .deposits
+                        .find_withdrawal_by_reference(&reference)
+                        .await?
+                    {
+                        Some(existing)
+                            if existing.deposit_account_id == schedule.deposit_account_id =>
+                        {
+                            Ok(existing)
+                        }
+                        _ => {
+                            self.deposits
+                                .initiate_withdrawal(
+                                    &system,
+                                    schedule.deposit_account_id,
+                                    schedule.amount,
+                                    Some(reference),
+                                    schedule.beneficiary.clone(),
+                                )
+                                .await
+                        }
+                    };
+
+                    let mut db = self.repo.begin_op().await?;
+                    let audit_info = self
+                        .audit
+                        .record_system_entry_in_tx(
+                            db.tx(),
+                            CoreDepositObject::scheduled_withdrawal(schedule.id),
+                            CoreDepositAction::SCHEDULED_WITHDRAWAL_EXECUTE,
+                        )
+                        .await?;
+                    let _ = schedule.skip_runs_before(scheduled_for, audit_info.clone());
+                    let _ = match res {
+                        Ok(withdrawal) => schedule.record_withdrawal_initiated(
+                            scheduled_for,
+                            withdrawal.id,
+                            audit_info,
+                        ),
+                        Err(e) => {
+                            tracing::warn!(
+                                scheduled_withdrawal_id = %schedule.id,
+                                error = %e,
+                                "scheduled withdrawal failed"
+                            );
+                            schedule.record_withdrawal_failed(
+                                scheduled_for,
+                                e.to_string(),
+                                audit_info,
+                            )
+                        }
+                    };
+                    self.repo.update_in_op(&mut db, &mut schedule).await?;
+                    db.commit().await?;
+                }
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(start_of_next_day(now)))
+    }
+}
+
+fn start_of_next_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    (now.date_naive() + Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+}
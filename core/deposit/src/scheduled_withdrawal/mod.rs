@@ -0,0 +1,14 @@
+mod entity;
+pub mod error;
+mod job;
+mod recurrence;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::ScheduledWithdrawalEvent;
+pub(super) use entity::*;
+pub use entity::{ScheduledWithdrawal, ScheduledWithdrawalExecution, ScheduledWithdrawalStatus};
+pub(super) use job::*;
+pub use recurrence::{WithdrawalFrequency, WithdrawalRecurrence};
+pub use repo::scheduled_withdrawal_cursor::ScheduledWithdrawalsByCreatedAtCursor;
+pub(super) use repo::*;
@@ -0,0 +1,93 @@
+use chrono::{Days, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::error::ScheduledWithdrawalError;
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum WithdrawalFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Repeats every `interval` days, weeks or months counted from the start date
+/// of the schedule. Monthly occurrences falling on a day the month does not
+/// have are moved to the last day of that month.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalRecurrence {
+    pub frequency: WithdrawalFrequency,
+    pub interval: u32,
+}
+
+impl WithdrawalRecurrence {
+    pub fn new(
+        frequency: WithdrawalFrequency,
+        interval: u32,
+    ) -> Result<Self, ScheduledWithdrawalError> {
+        if interval == 0 {
+            return Err(ScheduledWithdrawalError::InvalidRecurrence(
+                "interval must be at least 1".to_string(),
+            ));
+        }
+        Ok(Self {
+            frequency,
+            interval,
+        })
+    }
+
+    pub fn monthly() -> Self {
+        Self {
+            frequency: WithdrawalFrequency::Monthly,
+            interval: 1,
+        }
+    }
+
+    /// Date of the `n`-th occurrence where the 0th is `start` itself.
+    pub fn occurrence(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        let steps = n.checked_mul(self.interval)?;
+        match self.frequency {
+            WithdrawalFrequency::Daily => start.checked_add_days(Days::new(u64::from(steps))),
+            WithdrawalFrequency::Weekly => start.checked_add_days(Days::new(u64::from(steps) * 7)),
+            WithdrawalFrequency::Monthly => start.checked_add_months(Months::new(steps)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn monthly_occurrences_are_clamped_to_month_end() {
+        let recurrence = WithdrawalRecurrence::monthly();
+        let start = date(2025, 1, 31);
+        assert_eq!(recurrence.occurrence(start, 0), Some(start));
+        assert_eq!(recurrence.occurrence(start, 1), Some(date(2025, 2, 28)));
+        assert_eq!(recurrence.occurrence(start, 2), Some(date(2025, 3, 31)));
+    }
+
+    #[test]
+    fn weekly_occurrences_respect_interval() {
+        let recurrence = WithdrawalRecurrence::new(WithdrawalFrequency::Weekly, 2).unwrap();
+        assert_eq!(
+            recurrence.occurrence(date(2025, 1, 1), 2),
+            Some(date(2025, 1, 29))
+        );
+    }
+
+    #[test]
+    fn rejects_zero_interval() {
+        assert!(WithdrawalRecurrence::new(WithdrawalFrequency::Daily, 0).is_err());
+    }
+}
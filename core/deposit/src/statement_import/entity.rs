@@ -0,0 +1,509 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, DepositId, DocumentId, StatementImportId};
+
+use super::{
+    error::StatementImportError,
+    parser::{StatementFormat, StatementLine},
+};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StatementImportStatus {
+    Processing,
+    PendingReview,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StatementLineStatus {
+    Pending,
+    Recorded,
+    PendingReview,
+    Dismissed,
+    Ignored,
+    Duplicate,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "StatementImportId")]
+pub enum StatementImportEvent {
+    Initialized {
+        id: StatementImportId,
+        format: StatementFormat,
+        filename: String,
+        content_hash: String,
+        document_id: DocumentId,
+        lines: Vec<StatementLine>,
+        audit_info: AuditInfo,
+    },
+    LineDepositRecorded {
+        line_idx: usize,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    },
+    LineFlaggedForReview {
+        line_idx: usize,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    LineDismissed {
+        line_idx: usize,
+        audit_info: AuditInfo,
+    },
+    LineSkippedAsDuplicate {
+        line_idx: usize,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedStatementLine {
+    pub idx: usize,
+    pub line: StatementLine,
+    pub status: StatementLineStatus,
+    pub deposit_account_id: Option<DepositAccountId>,
+    pub deposit_id: Option<DepositId>,
+    pub review_reason: Option<String>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct StatementImport {
+    pub id: StatementImportId,
+    pub format: StatementFormat,
+    pub filename: String,
+    pub content_hash: String,
+    pub document_id: DocumentId,
+    pub lines: Vec<ImportedStatementLine>,
+
+    events: EntityEvents<StatementImportEvent>,
+}
+
+impl StatementImport {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for statement import")
+    }
+
+    pub fn status(&self) -> StatementImportStatus {
+        if self
+            .lines
+            .iter()
+            .any(|l| l.status == StatementLineStatus::Pending)
+        {
+            StatementImportStatus::Processing
+        } else if self
+            .lines
+            .iter()
+            .any(|l| l.status == StatementLineStatus::PendingReview)
+        {
+            StatementImportStatus::PendingReview
+        } else {
+            StatementImportStatus::Completed
+        }
+    }
+
+    pub fn lines_to_match(&self) -> impl Iterator<Item = &ImportedStatementLine> {
+        self.lines
+            .iter()
+            .filter(|l| l.status == StatementLineStatus::Pending)
+    }
+
+    pub fn lines_pending_review(&self) -> impl Iterator<Item = &ImportedStatementLine> {
+        self.lines
+            .iter()
+            .filter(|l| l.status == StatementLineStatus::PendingReview)
+    }
+
+    pub fn line(&self, line_idx: usize) -> Result<&ImportedStatementLine, StatementImportError> {
+        self.lines
+            .get(line_idx)
+            .ok_or(StatementImportError::LineNotFound(line_idx))
+    }
+
+    pub fn line_deposit_recorded(
+        &mut self,
+        line_idx: usize,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, StatementImportError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            StatementImportEvent::LineDepositRecorded { line_idx: idx, .. } if *idx == line_idx
+        );
+        self.ensure_line_open(line_idx)?;
+
+        self.events.push(StatementImportEvent::LineDepositRecorded {
+            line_idx,
+            deposit_account_id,
+            deposit_id,
+            audit_info,
+        });
+        let line = &mut self.lines[line_idx];
+        line.status = StatementLineStatus::Recorded;
+        line.deposit_account_id = Some(deposit_account_id);
+        line.deposit_id = Some(deposit_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Marks a line whose bank transaction was already recorded by an earlier import.
+    pub fn line_skipped_as_duplicate(
+        &mut self,
+        line_idx: usize,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, StatementImportError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            StatementImportEvent::LineSkippedAsDuplicate { line_idx: idx, .. } if *idx == line_idx
+        );
+        self.ensure_line_open(line_idx)?;
+
+        self.events
+            .push(StatementImportEvent::LineSkippedAsDuplicate {
+                line_idx,
+                deposit_account_id,
+                deposit_id,
+                audit_info,
+            });
+        let line = &mut self.lines[line_idx];
+        line.status = StatementLineStatus::Duplicate;
+        line.deposit_account_id = Some(deposit_account_id);
+        line.deposit_id = Some(deposit_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn flag_line_for_review(
+        &mut self,
+        line_idx: usize,
+        reason: impl Into<String>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, StatementImportError> {
+        if self.line(line_idx)?.status != StatementLineStatus::Pending {
+            return Ok(Idempotent::Ignored);
+        }
+
+        let reason = reason.into();
+        self.events
+            .push(StatementImportEvent::LineFlaggedForReview {
+                line_idx,
+                reason: reason.clone(),
+                audit_info,
+            });
+        let line = &mut self.lines[line_idx];
+        line.status = StatementLineStatus::PendingReview;
+        line.review_reason = Some(reason);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn dismiss_line(
+        &mut self,
+        line_idx: usize,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, StatementImportError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            StatementImportEvent::LineDismissed { line_idx: idx, .. } if *idx == line_idx
+        );
+        self.ensure_line_open(line_idx)?;
+
+        self.events.push(StatementImportEvent::LineDismissed {
+            line_idx,
+            audit_info,
+        });
+        self.lines[line_idx].status = StatementLineStatus::Dismissed;
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn ensure_line_open(&self, line_idx: usize) -> Result<(), StatementImportError> {
+        match self.line(line_idx)?.status {
+            StatementLineStatus::Pending | StatementLineStatus::PendingReview => Ok(()),
+            _ => Err(StatementImportError::LineAlreadyProcessed(line_idx)),
+        }
+    }
+}
+
+fn initial_line_status(line: &StatementLine) -> StatementLineStatus {
+    if line.is_credit() {
+        StatementLineStatus::Pending
+    } else {
+        StatementLineStatus::Ignored
+    }
+}
+
+impl TryFromEvents<StatementImportEvent> for StatementImport {
+    fn try_from_events(events: EntityEvents<StatementImportEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = StatementImportBuilder::default();
+        let mut lines = Vec::new();
+        for event in events.iter_all() {
+            match event {
+                StatementImportEvent::Initialized {
+                    id,
+                    format,
+                    filename,
+                    content_hash,
+                    document_id,
+                    lines: statement_lines,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .format(*format)
+                        .filename(filename.clone())
+                        .content_hash(content_hash.clone())
+                        .document_id(*document_id);
+                    lines = statement_lines
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, line)| ImportedStatementLine {
+                            idx,
+                            status: initial_line_status(line),
+                            line: line.clone(),
+                            deposit_account_id: None,
+                            deposit_id: None,
+                            review_reason: None,
+                        })
+                        .collect();
+                }
+                StatementImportEvent::LineDepositRecorded {
+                    line_idx,
+                    deposit_account_id,
+                    deposit_id,
+                    ..
+                } => {
+                    let line: &mut ImportedStatementLine = &mut lines[*line_idx];
+                    line.status = StatementLineStatus::Recorded;
+                    line.deposit_account_id = Some(*deposit_account_id);
+                    line.deposit_id = Some(*deposit_id);
+                }
+                StatementImportEvent::LineFlaggedForReview {
+                    line_idx, reason, ..
+                } => {
+                    let line: &mut ImportedStatementLine = &mut lines[*line_idx];
+                    line.status = StatementLineStatus::PendingReview;
+                    line.review_reason = Some(reason.clone());
+                }
+                StatementImportEvent::LineDismissed { line_idx, .. } => {
+                    lines[*line_idx].status = StatementLineStatus::Dismissed;
+                }
+                StatementImportEvent::LineSkippedAsDuplicate {
+                    line_idx,
+                    deposit_account_id,
+                    deposit_id,
+                    ..
+                } => {
+                    let line: &mut ImportedStatementLine = &mut lines[*line_idx];
+                    line.status = StatementLineStatus::Duplicate;
+                    line.deposit_account_id = Some(*deposit_account_id);
+                    line.deposit_id = Some(*deposit_id);
+                }
+            }
+        }
+        builder.lines(lines).events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewStatementImport {
+    #[builder(setter(into))]
+    pub(super) id: StatementImportId,
+    pub(super) format: StatementFormat,
+    #[builder(setter(into))]
+    pub(super) filename: String,
+    #[builder(setter(into))]
+    pub(super) content_hash: String,
+    #[builder(setter(into))]
+    pub(super) document_id: DocumentId,
+    pub(super) lines: Vec<StatementLine>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewStatementImport {
+    pub fn builder() -> NewStatementImportBuilder {
+        NewStatementImportBuilder::default()
+    }
+
+    pub(super) fn status(&self) -> StatementImportStatus {
+        if self.lines.iter().any(|l| l.is_credit()) {
+            StatementImportStatus::Processing
+        } else {
+            StatementImportStatus::Completed
+        }
+    }
+}
+
+impl IntoEvents<StatementImportEvent> for NewStatementImport {
+    fn into_events(self) -> EntityEvents<StatementImportEvent> {
+        EntityEvents::init(
+            self.id,
+            [StatementImportEvent::Initialized {
+                id: self.id,
+                format: self.format,
+                filename: self.filename,
+                content_hash: self.content_hash,
+                document_id: self.document_id,
+                lines: self.lines,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use crate::statement_import::parser::StatementEntryDirection;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn line(direction: StatementEntryDirection) -> StatementLine {
+        StatementLine {
+            booking_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            direction,
+            amount: dec!(100),
+            currency: "USD".to_string(),
+            reference: None,
+            bank_reference: None,
+            statement_account: None,
+            remittance_info: None,
+            counterparty: None,
+        }
+    }
+
+    fn statement_import() -> StatementImport {
+        let id = StatementImportId::new();
+        let new_import = NewStatementImport::builder()
+            .id(id)
+            .format(StatementFormat::Csv)
+            .filename("statement.csv")
+            .content_hash("hash")
+            .document_id(DocumentId::new())
+            .lines(vec![
+                line(StatementEntryDirection::Credit),
+                line(StatementEntryDirection::Credit),
+                line(StatementEntryDirection::Debit),
+            ])
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        StatementImport::try_from_events(new_import.into_events()).unwrap()
+    }
+
+    #[test]
+    fn debit_lines_are_ignored() {
+        let import = statement_import();
+        assert_eq!(import.lines_to_match().count(), 2);
+        assert_eq!(import.lines[2].status, StatementLineStatus::Ignored);
+        assert_eq!(import.status(), StatementImportStatus::Processing);
+    }
+
+    #[test]
+    fn status_follows_line_outcomes() {
+        let mut import = statement_import();
+        import
+            .line_deposit_recorded(
+                0,
+                DepositAccountId::new(),
+                DepositId::new(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        import
+            .flag_line_for_review(1, "no matching account", dummy_audit_info())
+            .unwrap();
+        assert_eq!(import.status(), StatementImportStatus::PendingReview);
+        assert_eq!(import.lines_pending_review().count(), 1);
+
+        import
+            .line_deposit_recorded(
+                1,
+                DepositAccountId::new(),
+                DepositId::new(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(import.status(), StatementImportStatus::Completed);
+    }
+
+    #[test]
+    fn recorded_line_cannot_be_dismissed() {
+        let mut import = statement_import();
+        import
+            .line_deposit_recorded(
+                0,
+                DepositAccountId::new(),
+                DepositId::new(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(matches!(
+            import.dismiss_line(0, dummy_audit_info()),
+            Err(StatementImportError::LineAlreadyProcessed(0))
+        ));
+    }
+
+    #[test]
+    fn duplicate_line_completes_import() {
+        let mut import = statement_import();
+        for idx in 0..2 {
+            import
+                .line_skipped_as_duplicate(
+                    idx,
+                    DepositAccountId::new(),
+                    DepositId::new(),
+                    dummy_audit_info(),
+                )
+                .unwrap();
+        }
+        assert_eq!(import.lines[0].status, StatementLineStatus::Duplicate);
+        assert_eq!(import.status(), StatementImportStatus::Completed);
+    }
+
+    #[test]
+    fn ignored_line_cannot_be_recorded() {
+        let mut import = statement_import();
+        assert!(matches!(
+            import.line_deposit_recorded(
+                2,
+                DepositAccountId::new(),
+                DepositId::new(),
+                dummy_audit_info()
+            ),
+            Err(StatementImportError::LineAlreadyProcessed(2))
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StatementImportError {
+    #[error("StatementImportError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("StatementImportError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("StatementImportError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("StatementImportError - StatementParseError: {0}")]
+    StatementParseError(#[from] super::parser::error::StatementParseError),
+    #[error("StatementImportError - LineNotFound: {0}")]
+    LineNotFound(usize),
+    #[error("StatementImportError - LineAlreadyProcessed: {0}")]
+    LineAlreadyProcessed(usize),
+    #[error("StatementImportError - AlreadyImported: {0}")]
+    AlreadyImported(crate::primitives::StatementImportId),
+}
+
+es_entity::from_es_entity_error!(StatementImportError);
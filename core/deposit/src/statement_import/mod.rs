@@ -0,0 +1,56 @@
+mod entity;
+pub mod error;
+pub mod parser;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::StatementImportEvent;
+pub(crate) use entity::*;
+pub use entity::{
+    ImportedStatementLine, StatementImport, StatementImportStatus, StatementLineStatus,
+};
+pub use repo::statement_import_cursor::StatementImportsByCreatedAtCursor;
+pub(crate) use repo::*;
+
+/// Extracts every hyphenated UUID embedded in the given text, in order of appearance.
+pub(crate) fn uuid_candidates(text: &str) -> Vec<uuid::Uuid> {
+    const LEN: usize = 36;
+    let bytes = text.as_bytes();
+    let mut res = Vec::new();
+    let mut idx = 0;
+    while idx + LEN <= bytes.len() {
+        match uuid::Uuid::try_parse_ascii(&bytes[idx..idx + LEN]) {
+            Ok(id) => {
+                if !res.contains(&id) {
+                    res.push(id);
+                }
+                idx += LEN;
+            }
+            Err(_) => idx += 1,
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_embedded_uuids() {
+        let text = "deposit-customer-account:c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11 / \
+                    REF-5e0b1bd8-0fd1-4c26-b9d4-7a3f0b7c2f90 \
+                    c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11";
+        let ids = uuid_candidates(text);
+        let expected: Vec<uuid::Uuid> = vec![
+            "c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11".parse().unwrap(),
+            "5e0b1bd8-0fd1-4c26-b9d4-7a3f0b7c2f90".parse().unwrap(),
+        ];
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn ignores_text_without_uuids() {
+        assert!(uuid_candidates("Salary January 2025").is_empty());
+    }
+}
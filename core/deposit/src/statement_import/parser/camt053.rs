@@ -0,0 +1,235 @@
+use chrono::NaiveDate;
+use quick_xml::{Reader, events::Event};
+
+use super::{
+    StatementEntryDirection, StatementLine, error::StatementParseError, non_empty, parse_amount,
+};
+
+#[derive(Default)]
+struct EntryBuilder {
+    amount: Option<String>,
+    currency: Option<String>,
+    direction: Option<String>,
+    booking_date: Option<String>,
+    account_servicer_reference: Option<String>,
+    end_to_end_id: Option<String>,
+    remittance_info: Vec<String>,
+    debtor_name: Option<String>,
+}
+
+impl EntryBuilder {
+    fn build(
+        self,
+        statement_account: Option<String>,
+    ) -> Result<StatementLine, StatementParseError> {
+        let amount = parse_amount(
+            &self
+                .amount
+                .ok_or(StatementParseError::MissingField("Ntry/Amt"))?,
+        )?;
+        let direction = match self
+            .direction
+            .ok_or(StatementParseError::MissingField("Ntry/CdtDbtInd"))?
+            .as_str()
+        {
+            "CRDT" => StatementEntryDirection::Credit,
+            "DBIT" => StatementEntryDirection::Debit,
+            other => return Err(StatementParseError::InvalidDirection(other.to_string())),
+        };
+        let date = self
+            .booking_date
+            .ok_or(StatementParseError::MissingField("Ntry/BookgDt"))?;
+        let booking_date = date
+            .get(0..10)
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .ok_or(StatementParseError::InvalidDate(date))?;
+
+        let reference = self
+            .end_to_end_id
+            .filter(|id| id != "NOTPROVIDED")
+            .or(self.account_servicer_reference.clone());
+
+        Ok(StatementLine {
+            booking_date,
+            direction,
+            amount,
+            currency: self
+                .currency
+                .ok_or(StatementParseError::MissingField("Ntry/Amt@Ccy"))?
+                .to_uppercase(),
+            reference,
+            bank_reference: self.account_servicer_reference,
+            statement_account,
+            remittance_info: non_empty(self.remittance_info.join(" ")),
+            counterparty: self.debtor_name,
+        })
+    }
+}
+
+pub(super) fn parse(content: &[u8]) -> Result<Vec<StatementLine>, StatementParseError> {
+    let mut reader = Reader::from_reader(content);
+    reader.config_mut().trim_text(true);
+
+    let mut lines = Vec::new();
+    let mut path: Vec<String> = Vec::new();
+    let mut entry: Option<EntryBuilder> = None;
+    let mut account: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => {
+                let name = std::str::from_utf8(e.local_name().as_ref())?.to_string();
+                if name == "Ntry" {
+                    entry = Some(EntryBuilder::default());
+                }
+                // Only the entry level amount is relevant, not the
+                // instructed / transaction amounts nested in NtryDtls
+                if let Some(entry) = entry.as_mut()
+                    && name == "Amt"
+                    && path.last().map(String::as_str) == Some("Ntry")
+                    && let Some(ccy) = e.try_get_attribute("Ccy")?
+                {
+                    entry.currency = Some(ccy.unescape_value()?.to_string());
+                }
+                path.push(name);
+            }
+            Event::End(_) => {
+                if path.pop().as_deref() == Some("Ntry")
+                    && let Some(entry) = entry.take()
+                {
+                    lines.push(entry.build(account.clone())?);
+                }
+            }
+            Event::Text(text) => {
+                if let Some(entry) = entry.as_mut() {
+                    let value = text.unescape()?.trim().to_string();
+                    apply_text(entry, &path, value);
+                } else if is_statement_account(&path) {
+                    account = non_empty(text.unescape()?.trim().to_string());
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(lines)
+}
+
+fn is_statement_account(path: &[String]) -> bool {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    matches!(
+        path.as_slice(),
+        [.., "Stmt", "Acct", "Id", "IBAN"] | [.., "Stmt", "Acct", "Id", "Othr", "Id"]
+    )
+}
+
+fn apply_text(entry: &mut EntryBuilder, path: &[String], value: String) {
+    let Some(ntry_idx) = path.iter().rposition(|p| p == "Ntry") else {
+        return;
+    };
+    let rel: Vec<&str> = path[ntry_idx + 1..].iter().map(String::as_str).collect();
+    match rel.as_slice() {
+        ["Amt"] => entry.amount = Some(value),
+        ["CdtDbtInd"] => entry.direction = Some(value),
+        ["BookgDt", "Dt"] | ["BookgDt", "DtTm"] => entry.booking_date = Some(value),
+        ["AcctSvcrRef"] => entry.account_servicer_reference = Some(value),
+        [.., "Refs", "EndToEndId"] if entry.end_to_end_id.is_none() => {
+            entry.end_to_end_id = Some(value)
+        }
+        [.., "RmtInf", "Ustrd"] | [.., "RmtInf", "Strd", "CdtrRefInf", "Ref"] => {
+            entry.remittance_info.push(value)
+        }
+        [.., "RltdPties", "Dbtr", "Nm"] | [.., "RltdPties", "Dbtr", "Pty", "Nm"]
+            if entry.debtor_name.is_none() =>
+        {
+            entry.debtor_name = Some(value)
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const STATEMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.02">
+  <BkToCstmrStmt>
+    <GrpHdr><MsgId>STMT-1</MsgId><CreDtTm>2025-02-01T08:00:00</CreDtTm></GrpHdr>
+    <Stmt>
+      <Id>STMT-1-1</Id>
+      <Acct><Id><IBAN>DE89370400440532013000</IBAN></Id></Acct>
+      <Ntry>
+        <Amt Ccy="USD">1500.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts>BOOK</Sts>
+        <BookgDt><Dt>2025-01-31</Dt></BookgDt>
+        <AcctSvcrRef>BANK-REF-1</AcctSvcrRef>
+        <NtryDtls>
+          <TxDtls>
+            <Refs><EndToEndId>E2E-1</EndToEndId></Refs>
+            <AmtDtls><InstdAmt><Amt Ccy="EUR">1400.00</Amt></InstdAmt></AmtDtls>
+            <RltdPties><Dbtr><Nm>Jane Doe</Nm></Dbtr></RltdPties>
+            <RmtInf><Ustrd>Savings c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11</Ustrd></RmtInf>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">20.50</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <BookgDt><DtTm>2025-02-01T10:00:00</DtTm></BookgDt>
+        <AcctSvcrRef>BANK-REF-2</AcctSvcrRef>
+        <NtryDtls><TxDtls><Refs><EndToEndId>NOTPROVIDED</EndToEndId></Refs></TxDtls></NtryDtls>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>"#;
+
+    #[test]
+    fn parses_entries() {
+        let lines = parse(STATEMENT.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 2);
+
+        let credit = &lines[0];
+        assert_eq!(credit.direction, StatementEntryDirection::Credit);
+        assert_eq!(credit.amount, dec!(1500.00));
+        assert_eq!(credit.currency, "USD");
+        assert_eq!(
+            credit.booking_date,
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+        );
+        assert_eq!(credit.reference.as_deref(), Some("E2E-1"));
+        assert_eq!(credit.bank_reference.as_deref(), Some("BANK-REF-1"));
+        assert_eq!(
+            credit.statement_account.as_deref(),
+            Some("DE89370400440532013000")
+        );
+        assert_eq!(credit.counterparty.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            credit.remittance_info.as_deref(),
+            Some("Savings c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11")
+        );
+
+        let debit = &lines[1];
+        assert_eq!(debit.direction, StatementEntryDirection::Debit);
+        assert_eq!(debit.reference.as_deref(), Some("BANK-REF-2"));
+        assert_eq!(
+            debit.booking_date,
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn errors_on_missing_direction() {
+        let content = r#"<Document><Ntry><Amt Ccy="USD">1.00</Amt><BookgDt><Dt>2025-01-31</Dt></BookgDt></Ntry></Document>"#;
+        assert!(matches!(
+            parse(content.as_bytes()),
+            Err(StatementParseError::MissingField("Ntry/CdtDbtInd"))
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use super::{
+    StatementEntryDirection, StatementLine, error::StatementParseError, non_empty, parse_amount,
+};
+
+// Expected layout:
+// date,amount,currency,reference,description,counterparty[,bank_reference[,account]]
+// 2025-01-31,1500.00,USD,<deposit account or customer id>,Salary,ACME Corp,TX-1,DE89...
+//
+// Negative amounts are treated as debits.
+#[derive(Debug, Deserialize)]
+struct CsvStatementRecord {
+    date: String,
+    amount: String,
+    #[serde(default)]
+    currency: String,
+    #[serde(default)]
+    reference: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    counterparty: String,
+    #[serde(default)]
+    bank_reference: String,
+    #[serde(default)]
+    account: String,
+}
+
+pub(super) fn parse(content: &[u8]) -> Result<Vec<StatementLine>, StatementParseError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .trim(::csv::Trim::All)
+        .from_reader(content);
+
+    let mut lines = Vec::new();
+    for record in reader.deserialize::<CsvStatementRecord>() {
+        let record = record?;
+        let booking_date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+            .map_err(|_| StatementParseError::InvalidDate(record.date.clone()))?;
+        let amount = parse_amount(&record.amount)?;
+        let direction = if amount.is_sign_negative() {
+            StatementEntryDirection::Debit
+        } else {
+            StatementEntryDirection::Credit
+        };
+        let currency = non_empty(record.currency).unwrap_or_else(|| "USD".to_string());
+
+        lines.push(StatementLine {
+            booking_date,
+            direction,
+            amount: amount.abs(),
+            currency: currency.to_uppercase(),
+            reference: non_empty(record.reference),
+            bank_reference: non_empty(record.bank_reference),
+            statement_account: non_empty(record.account),
+            remittance_info: non_empty(record.description),
+            counterparty: non_empty(record.counterparty),
+        });
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn parses_credits_and_debits() {
+        let content = "\
+date,amount,currency,reference,description,counterparty
+2025-01-31,1500.00,USD,REF-1,Salary,ACME Corp
+2025-02-01,-20.5,usd,,Fee,
+";
+        let lines = parse(content.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 2);
+
+        assert_eq!(lines[0].direction, StatementEntryDirection::Credit);
+        assert_eq!(lines[0].amount, dec!(1500.00));
+        assert_eq!(lines[0].reference.as_deref(), Some("REF-1"));
+        assert_eq!(lines[0].counterparty.as_deref(), Some("ACME Corp"));
+
+        assert_eq!(lines[1].direction, StatementEntryDirection::Debit);
+        assert_eq!(lines[1].amount, dec!(20.50));
+        assert_eq!(lines[1].currency, "USD");
+        assert_eq!(lines[1].reference, None);
+    }
+
+    #[test]
+    fn rejects_invalid_date() {
+        let content = "date,amount\n31/01/2025,10\n";
+        assert!(matches!(
+            parse(content.as_bytes()),
+            Err(StatementParseError::InvalidDate(_))
+        ));
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StatementParseError {
+    #[error("StatementParseError - Xml: {0}")]
+    Xml(#[from] quick_xml::Error),
+    #[error("StatementParseError - XmlAttribute: {0}")]
+    XmlAttribute(#[from] quick_xml::events::attributes::AttrError),
+    #[error("StatementParseError - Csv: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("StatementParseError - Utf8: {0}")]
+    Utf8(#[from] std::str::Utf8Error),
+    #[error("StatementParseError - InvalidAmount: {0}")]
+    InvalidAmount(String),
+    #[error("StatementParseError - InvalidDate: {0}")]
+    InvalidDate(String),
+    #[error("StatementParseError - InvalidDirection: {0}")]
+    InvalidDirection(String),
+    #[error("StatementParseError - MissingField: {0}")]
+    MissingField(&'static str),
+    #[error("StatementParseError - MalformedLine: {0}")]
+    MalformedLine(String),
+    #[error("StatementParseError - NoEntries")]
+    NoEntries,
+}
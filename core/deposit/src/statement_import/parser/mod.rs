@@ -0,0 +1,133 @@
+mod camt053;
+mod csv;
+pub mod error;
+mod mt940;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use error::StatementParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StatementFormat {
+    Camt053,
+    Mt940,
+    Csv,
+}
+
+impl StatementFormat {
+    pub fn parse(&self, content: &[u8]) -> Result<Vec<StatementLine>, StatementParseError> {
+        let lines = match self {
+            StatementFormat::Camt053 => camt053::parse(content)?,
+            StatementFormat::Mt940 => mt940::parse(content)?,
+            StatementFormat::Csv => csv::parse(content)?,
+        };
+        if lines.is_empty() {
+            return Err(StatementParseError::NoEntries);
+        }
+        Ok(lines)
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            StatementFormat::Camt053 => "application/xml",
+            StatementFormat::Mt940 => "text/plain",
+            StatementFormat::Csv => "text/csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StatementEntryDirection {
+    Credit,
+    Debit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StatementLine {
+    pub booking_date: NaiveDate,
+    pub direction: StatementEntryDirection,
+    pub amount: Decimal,
+    pub currency: String,
+    pub reference: Option<String>,
+    /// Reference assigned by the bank to the transaction, unique per statement account
+    pub bank_reference: Option<String>,
+    /// IBAN or other identifier of the account the statement was issued for
+    #[serde(default)]
+    pub statement_account: Option<String>,
+    pub remittance_info: Option<String>,
+    pub counterparty: Option<String>,
+}
+
+impl StatementLine {
+    pub fn is_credit(&self) -> bool {
+        self.direction == StatementEntryDirection::Credit
+    }
+
+    /// All free-text fields of the line that may carry an identifier
+    /// of the beneficiary deposit account or customer.
+    pub fn matching_candidates(&self) -> impl Iterator<Item = &str> {
+        [self.reference.as_deref(), self.remittance_info.as_deref()]
+            .into_iter()
+            .flatten()
+    }
+}
+
+fn parse_amount(raw: &str) -> Result<Decimal, StatementParseError> {
+    let normalized = raw.trim().replace(',', ".");
+    let normalized = normalized.trim_end_matches('.');
+    let amount: Decimal = normalized
+        .parse()
+        .map_err(|_| StatementParseError::InvalidAmount(raw.to_string()))?;
+    if amount.scale() > 2 && amount.round_dp(2) != amount {
+        return Err(StatementParseError::InvalidAmount(raw.to_string()));
+    }
+    Ok(amount.round_dp(2))
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn parse_amount_accepts_comma_decimal_separator() {
+        assert_eq!(parse_amount("1234,56").unwrap(), dec!(1234.56));
+        assert_eq!(parse_amount("10.5").unwrap(), dec!(10.50));
+    }
+
+    #[test]
+    fn parse_amount_rejects_sub_cent_precision() {
+        assert!(matches!(
+            parse_amount("1.001"),
+            Err(StatementParseError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn empty_statement_is_rejected() {
+        let content = "date,amount,currency,reference,description,counterparty\n";
+        assert!(matches!(
+            StatementFormat::Csv.parse(content.as_bytes()),
+            Err(StatementParseError::NoEntries)
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+use chrono::NaiveDate;
+
+use super::{
+    StatementEntryDirection, StatementLine, error::StatementParseError, non_empty, parse_amount,
+};
+
+pub(super) fn parse(content: &[u8]) -> Result<Vec<StatementLine>, StatementParseError> {
+    let content = std::str::from_utf8(content)?;
+
+    let mut lines = Vec::new();
+    let mut currency: Option<String> = None;
+    let mut account: Option<String> = None;
+    let mut pending: Option<StatementLine> = None;
+
+    for (tag, value) in fields(content) {
+        match tag.as_str() {
+            "25" => account = non_empty(value.trim().to_string()),
+            "60F" | "60M" => {
+                // C/D mark (1) + date (6) + currency (3) + amount
+                let ccy = value
+                    .get(7..10)
+                    .ok_or_else(|| StatementParseError::MalformedLine(value.clone()))?;
+                currency = Some(ccy.to_uppercase());
+            }
+            "61" => {
+                if let Some(line) = pending.take() {
+                    lines.push(line);
+                }
+                let currency = currency
+                    .clone()
+                    .ok_or(StatementParseError::MissingField("60F"))?;
+                pending = Some(parse_statement_line(&value, currency, account.clone())?);
+            }
+            "86" => {
+                if let Some(line) = pending.as_mut() {
+                    let info = value.replace('\n', " ");
+                    line.counterparty = counterparty_from_info(&info);
+                    line.remittance_info = non_empty(info);
+                }
+            }
+            "62F" | "62M" => {
+                if let Some(line) = pending.take() {
+                    lines.push(line);
+                }
+            }
+            _ => (),
+        }
+    }
+    if let Some(line) = pending.take() {
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
+/// Splits the raw message into `(tag, value)` pairs, joining continuation lines.
+fn fields(content: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if raw.trim() == "-" || raw.starts_with('{') {
+            continue;
+        }
+        if let Some(rest) = raw.strip_prefix(':')
+            && let Some((tag, value)) = rest.split_once(':')
+        {
+            fields.push((tag.to_string(), value.to_string()));
+            continue;
+        }
+        if let Some((_, value)) = fields.last_mut() {
+            value.push('\n');
+            value.push_str(raw);
+        }
+    }
+    fields
+}
+
+// :61:YYMMDD[MMDD]<C|D|RC|RD>[funds code]<amount>N<type><customer ref>[//<bank ref>]
+fn parse_statement_line(
+    value: &str,
+    currency: String,
+    statement_account: Option<String>,
+) -> Result<StatementLine, StatementParseError> {
+    let first_line = value.lines().next().unwrap_or_default();
+    let malformed = || StatementParseError::MalformedLine(value.to_string());
+
+    let date = first_line.get(0..6).ok_or_else(malformed)?;
+    let booking_date = NaiveDate::parse_from_str(date, "%y%m%d")
+        .map_err(|_| StatementParseError::InvalidDate(date.to_string()))?;
+
+    let mut rest = first_line.get(6..).ok_or_else(malformed)?;
+    if let Some(entry_date) = rest.get(..4)
+        && entry_date.chars().all(|c| c.is_ascii_digit())
+    {
+        rest = rest.get(4..).ok_or_else(malformed)?;
+    }
+
+    let (direction, rest) = if let Some(rest) = rest.strip_prefix("RC") {
+        (StatementEntryDirection::Debit, rest)
+    } else if let Some(rest) = rest.strip_prefix("RD") {
+        (StatementEntryDirection::Credit, rest)
+    } else if let Some(rest) = rest.strip_prefix('C') {
+        (StatementEntryDirection::Credit, rest)
+    } else if let Some(rest) = rest.strip_prefix('D') {
+        (StatementEntryDirection::Debit, rest)
+    } else {
+        return Err(StatementParseError::InvalidDirection(rest.to_string()));
+    };
+
+    // Optional third character of the currency code (funds code)
+    let rest = match rest.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => rest.get(1..).ok_or_else(malformed)?,
+        _ => rest,
+    };
+
+    let amount_len = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == ','))
+        .ok_or_else(malformed)?;
+    let amount = parse_amount(rest.get(..amount_len).ok_or_else(malformed)?)?;
+
+    // Transaction type identification code: 'N', 'F' or 'S' followed by 3 characters
+    let rest = rest.get(amount_len + 4..).ok_or_else(malformed)?;
+    let (customer_ref, bank_reference) = match rest.split_once("//") {
+        Some((customer_ref, bank_ref)) => (customer_ref, non_empty(bank_ref.to_string())),
+        None => (rest, None),
+    };
+    let reference = match customer_ref {
+        "NONREF" => bank_reference.clone(),
+        customer_ref => non_empty(customer_ref.to_string()),
+    };
+
+    Ok(StatementLine {
+        booking_date,
+        direction,
+        amount,
+        currency,
+        reference,
+        bank_reference,
+        statement_account,
+        remittance_info: None,
+        counterparty: None,
+    })
+}
+
+// Structured :86: fields use '?32'/'?33' for the counterparty name
+fn counterparty_from_info(info: &str) -> Option<String> {
+    let mut name = String::new();
+    for segment in info.split('?').skip(1) {
+        if let Some(value) = segment
+            .strip_prefix("32")
+            .or_else(|| segment.strip_prefix("33"))
+        {
+            name.push_str(value.trim());
+        }
+    }
+    non_empty(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const STATEMENT: &str = "\
+:20:STMT-2025-01
+:25:DE89370400440532013000
+:28C:1/1
+:60F:C250130USD1000,00
+:61:2501310131C1500,00NTRFREF-ONE//BANK-1
+:86:Deposit for account
+ c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11
+:61:250201D20,50NCHGNONREF//FEE-2
+:86:?00Fee?32ACME Bank
+:62F:C250201USD2479,50
+-";
+
+    #[test]
+    fn parses_statement_lines() {
+        let lines = parse(STATEMENT.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 2);
+
+        let credit = &lines[0];
+        assert_eq!(credit.direction, StatementEntryDirection::Credit);
+        assert_eq!(credit.amount, dec!(1500.00));
+        assert_eq!(credit.currency, "USD");
+        assert_eq!(
+            credit.booking_date,
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+        );
+        assert_eq!(credit.reference.as_deref(), Some("REF-ONE"));
+        assert_eq!(credit.bank_reference.as_deref(), Some("BANK-1"));
+        assert_eq!(
+            credit.statement_account.as_deref(),
+            Some("DE89370400440532013000")
+        );
+        assert_eq!(
+            credit.remittance_info.as_deref(),
+            Some("Deposit for account  c3a1d3c4-1b2e-4f3a-9a6f-0f6a5f5d1e11")
+        );
+
+        let debit = &lines[1];
+        assert_eq!(debit.direction, StatementEntryDirection::Debit);
+        assert_eq!(debit.amount, dec!(20.50));
+        assert_eq!(debit.reference.as_deref(), Some("FEE-2"));
+        assert_eq!(debit.counterparty.as_deref(), Some("ACME Bank"));
+    }
+
+    #[test]
+    fn requires_opening_balance() {
+        let content = ":20:X\n:61:250131C10,00NTRFREF\n";
+        assert!(matches!(
+            parse(content.as_bytes()),
+            Err(StatementParseError::MissingField("60F"))
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_and_non_ascii_lines() {
+        for line in ["25013", "250131ü", "250131000äC10,00", "250131C€10,00NTRF"] {
+            let content = format!(":60F:C250130USD0,00\n:61:{line}\n");
+            assert!(parse(content.as_bytes()).is_err(), "{line}");
+        }
+    }
+}
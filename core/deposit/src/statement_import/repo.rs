@@ -0,0 +1,70 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::StatementImportId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "StatementImport",
+    err = "StatementImportError",
+    columns(
+        status(
+            ty = "StatementImportStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        ),
+        content_hash(ty = "String", update(persist = false))
+    ),
+    tbl_prefix = "core"
+)]
+pub struct StatementImportRepo {
+    pool: PgPool,
+}
+
+impl StatementImportRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+mod statement_import_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::StatementImportStatus;
+
+    impl Type<Postgres> for StatementImportStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for StatementImportStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for StatementImportStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for StatementImportStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
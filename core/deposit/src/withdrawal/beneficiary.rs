@@ -0,0 +1,118 @@
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::error::WithdrawalError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalBeneficiary {
+    pub name: String,
+    pub iban: String,
+    pub bic: Option<String>,
+}
+
+impl WithdrawalBeneficiary {
+    pub fn new(
+        name: impl Into<String>,
+        iban: impl AsRef<str>,
+        bic: Option<impl AsRef<str>>,
+    ) -> Result<Self, WithdrawalError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(WithdrawalError::InvalidBeneficiary(
+                "name must not be empty".to_string(),
+            ));
+        }
+
+        let iban = normalize(iban.as_ref());
+        if !is_valid_iban(&iban) {
+            return Err(WithdrawalError::InvalidBeneficiary(format!(
+                "invalid IBAN '{iban}'"
+            )));
+        }
+
+        let bic = bic.map(|bic| normalize(bic.as_ref()));
+        if let Some(bic) = bic.as_ref()
+            && !is_valid_bic(bic)
+        {
+            return Err(WithdrawalError::InvalidBeneficiary(format!(
+                "invalid BIC '{bic}'"
+            )));
+        }
+
+        Ok(Self { name, iban, bic })
+    }
+}
+
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+// ISO 13616: move the first four characters to the end, convert letters
+// to numbers (A = 10 .. Z = 35) and check that the result mod 97 is 1
+fn is_valid_iban(iban: &str) -> bool {
+    if !(15..=34).contains(&iban.len()) || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (country, rest) = iban.split_at(2);
+    if !country.chars().all(|c| c.is_ascii_alphabetic())
+        || !rest[..2].chars().all(|c| c.is_ascii_digit())
+    {
+        return false;
+    }
+
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let remainder = rearranged.fold(0u32, |acc, c| {
+        let value = c.to_digit(36).expect("alphanumeric");
+        if value >= 10 {
+            (acc * 100 + value) % 97
+        } else {
+            (acc * 10 + value) % 97
+        }
+    });
+    remainder == 1
+}
+
+fn is_valid_bic(bic: &str) -> bool {
+    (bic.len() == 8 || bic.len() == 11)
+        && bic[..6].chars().all(|c| c.is_ascii_alphabetic())
+        && bic[6..].chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalizes_and_accepts_valid_details() {
+        let beneficiary = WithdrawalBeneficiary::new(
+            "Jane Doe",
+            "de89 3704 0044 0532 0130 00",
+            Some("cobadeffxxx"),
+        )
+        .unwrap();
+        assert_eq!(beneficiary.iban, "DE89370400440532013000");
+        assert_eq!(beneficiary.bic.as_deref(), Some("COBADEFFXXX"));
+    }
+
+    #[test]
+    fn rejects_iban_with_wrong_checksum() {
+        assert!(matches!(
+            WithdrawalBeneficiary::new("Jane Doe", "DE88370400440532013000", None::<&str>),
+            Err(WithdrawalError::InvalidBeneficiary(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_bic() {
+        assert!(matches!(
+            WithdrawalBeneficiary::new("Jane Doe", "GB82WEST12345698765432", Some("WEST12")),
+            Err(WithdrawalError::InvalidBeneficiary(_))
+        ));
+    }
+}
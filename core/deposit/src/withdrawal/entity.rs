@@ -6,18 +6,22 @@ use serde::{Deserialize, Serialize};
 use es_entity::*;
 
 use crate::primitives::{
-    ApprovalProcessId, CalaTransactionId, DepositAccountId, UsdCents, WithdrawalId,
+    ApprovalProcessId, CalaTransactionId, CurrencyCode, DepositAccountId, Money, PaymentFileId,
+    UsdCents, WithdrawalId,
 };
 use audit::AuditInfo;
 
-use super::error::WithdrawalError;
+use super::{beneficiary::WithdrawalBeneficiary, error::WithdrawalError};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum WithdrawalStatus {
     PendingApproval,
     PendingConfirmation,
+    PaymentSent,
     Confirmed,
     Denied,
     Cancelled,
@@ -32,8 +36,11 @@ pub enum WithdrawalEvent {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default)]
+        currency: CurrencyCode,
         reference: String,
         approval_process_id: ApprovalProcessId,
+        beneficiary: Option<WithdrawalBeneficiary>,
         audit_info: AuditInfo,
     },
     ApprovalProcessConcluded {
@@ -41,6 +48,15 @@ pub enum WithdrawalEvent {
         approved: bool,
         audit_info: AuditInfo,
     },
+    PaymentSent {
+        payment_file_id: PaymentFileId,
+        audit_info: AuditInfo,
+    },
+    PaymentRejected {
+        payment_file_id: PaymentFileId,
+        reason: Option<String>,
+        audit_info: AuditInfo,
+    },
     Confirmed {
         ledger_tx_id: CalaTransactionId,
         audit_info: AuditInfo,
@@ -57,10 +73,14 @@ pub struct Withdrawal {
     pub id: WithdrawalId,
     pub deposit_account_id: DepositAccountId,
     pub reference: String,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub approval_process_id: ApprovalProcessId,
     #[builder(setter(strip_option), default)]
     pub cancelled_tx_id: Option<CalaTransactionId>,
+    #[builder(default)]
+    pub beneficiary: Option<WithdrawalBeneficiary>,
+    #[builder(setter(strip_option), default)]
+    pub payment_file_id: Option<PaymentFileId>,
 
     events: EntityEvents<WithdrawalEvent>,
 }
@@ -72,6 +92,17 @@ impl Withdrawal {
             .expect("No events for deposit")
     }
 
+    /// Subject that initiated the withdrawal.
+    pub fn initiated_by(&self) -> &str {
+        self.events
+            .iter_all()
+            .find_map(|event| match event {
+                WithdrawalEvent::Initialized { audit_info, .. } => Some(audit_info.sub.as_str()),
+                _ => None,
+            })
+            .expect("Withdrawal was not Initialized")
+    }
+
     pub fn confirm(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, WithdrawalError> {
         match self.is_approved_or_denied() {
             Some(false) => return Err(WithdrawalError::NotApproved(self.id)),
@@ -87,16 +118,49 @@ impl Withdrawal {
             return Err(WithdrawalError::AlreadyCancelled(self.id));
         }
 
+        // Sent payments are settled by the bank's status report
+        if self.status() == WithdrawalStatus::PaymentSent {
+            return Err(WithdrawalError::PaymentAlreadySent(self.id));
+        }
+
+        Ok(self.confirm_unchecked(audit_info))
+    }
+
+    /// Settles a withdrawal whose payment was accepted by the bank.
+    pub fn settle_payment(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaTransactionId>, WithdrawalError> {
+        idempotency_guard!(self.events.iter_all(), WithdrawalEvent::Confirmed { .. });
+        if self.status() != WithdrawalStatus::PaymentSent {
+            return Err(WithdrawalError::PaymentNotSent(self.id));
+        }
+
+        Ok(Idempotent::Executed(self.confirm_unchecked(audit_info)))
+    }
+
+    fn confirm_unchecked(&mut self, audit_info: AuditInfo) -> CalaTransactionId {
         let ledger_tx_id = CalaTransactionId::new();
         self.events.push(WithdrawalEvent::Confirmed {
             ledger_tx_id,
             audit_info,
         });
 
-        Ok(ledger_tx_id)
+        ledger_tx_id
     }
 
     pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, WithdrawalError> {
+        if self.status() == WithdrawalStatus::PaymentSent {
+            return Err(WithdrawalError::PaymentAlreadySent(self.id));
+        }
+
+        self.cancel_unchecked(audit_info)
+    }
+
+    fn cancel_unchecked(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<CalaTransactionId, WithdrawalError> {
         if self.is_confirmed() {
             return Err(WithdrawalError::AlreadyConfirmed(self.id));
         }
@@ -115,6 +179,65 @@ impl Withdrawal {
         Ok(ledger_tx_id)
     }
 
+    /// Identifier of this withdrawal in outgoing payment files.
+    /// Limited to 35 characters by ISO 20022, hence the hyphen-less form.
+    pub fn end_to_end_id(&self) -> String {
+        uuid::Uuid::from(self.id).simple().to_string()
+    }
+
+    pub fn mark_payment_sent(
+        &mut self,
+        payment_file_id: PaymentFileId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            WithdrawalEvent::PaymentSent { payment_file_id: id, .. } if id == &payment_file_id
+        );
+        if self.status() != WithdrawalStatus::PendingConfirmation {
+            return Err(WithdrawalError::NotPendingConfirmation(self.id));
+        }
+        if self.beneficiary.is_none() {
+            return Err(WithdrawalError::MissingBeneficiary(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::PaymentSent {
+            payment_file_id,
+            audit_info,
+        });
+        self.payment_file_id = Some(payment_file_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn reject_payment(
+        &mut self,
+        reason: Option<String>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaTransactionId>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            WithdrawalEvent::PaymentRejected { .. }
+        );
+        let payment_file_id = match self.payment_file_id {
+            Some(id) if self.status() == WithdrawalStatus::PaymentSent => id,
+            _ => return Err(WithdrawalError::PaymentNotSent(self.id)),
+        };
+
+        self.events.push(WithdrawalEvent::PaymentRejected {
+            payment_file_id,
+            reason,
+            audit_info: audit_info.clone(),
+        });
+        self.cancel_unchecked(audit_info).map(Idempotent::Executed)
+    }
+
+    fn is_payment_sent(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, WithdrawalEvent::PaymentSent { .. }))
+    }
+
     fn is_confirmed(&self) -> bool {
         self.events
             .iter_all()
@@ -144,6 +267,7 @@ impl Withdrawal {
             WithdrawalStatus::Cancelled
         } else {
             match self.is_approved_or_denied() {
+                Some(true) if self.is_payment_sent() => WithdrawalStatus::PaymentSent,
                 Some(true) => WithdrawalStatus::PendingConfirmation,
                 Some(false) => WithdrawalStatus::Denied,
                 None => WithdrawalStatus::PendingApproval,
@@ -179,16 +303,22 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                     reference,
                     deposit_account_id,
                     amount,
+                    currency,
                     approval_process_id,
+                    beneficiary,
                     ..
                 } => {
                     builder = builder
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
-                        .amount(*amount)
+                        .amount(Money::new(amount.into_inner(), *currency))
                         .reference(reference.clone())
                         .approval_process_id(*approval_process_id)
+                        .beneficiary(beneficiary.clone())
                 }
+                WithdrawalEvent::PaymentSent {
+                    payment_file_id, ..
+                } => builder = builder.payment_file_id(*payment_file_id),
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.cancelled_tx_id(*ledger_tx_id)
                 }
@@ -207,10 +337,12 @@ pub struct NewWithdrawal {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: Money,
     #[builder(setter(into))]
     pub(super) approval_process_id: ApprovalProcessId,
     reference: Option<String>,
+    #[builder(default)]
+    beneficiary: Option<WithdrawalBeneficiary>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -227,6 +359,10 @@ impl NewWithdrawal {
             Some(reference) => reference.to_string(),
         }
     }
+
+    pub(super) fn status(&self) -> WithdrawalStatus {
+        WithdrawalStatus::PendingApproval
+    }
 }
 
 impl NewWithdrawalBuilder {
@@ -246,8 +382,10 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
                 reference: self.reference(),
                 id: self.id,
                 deposit_account_id: self.deposit_account_id,
-                amount: self.amount,
+                amount: UsdCents::from(self.amount.minor_units()),
+                currency: self.amount.currency(),
                 approval_process_id: self.approval_process_id,
+                beneficiary: self.beneficiary,
                 audit_info: self.audit_info,
             }],
         )
@@ -313,4 +451,105 @@ mod test {
 
         assert!(withdrawal.is_ok());
     }
+
+    fn approved_withdrawal(beneficiary: Option<WithdrawalBeneficiary>) -> Withdrawal {
+        let id = WithdrawalId::new();
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(id)
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .approval_process_id(id)
+            .beneficiary(beneficiary)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let mut withdrawal = Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap();
+        let _ = withdrawal.approval_process_concluded(true, dummy_audit_info());
+        withdrawal
+    }
+
+    fn beneficiary() -> WithdrawalBeneficiary {
+        WithdrawalBeneficiary::new("Jane Doe", "GB82WEST12345698765432", None::<&str>).unwrap()
+    }
+
+    #[test]
+    fn payment_sent_requires_beneficiary() {
+        let mut withdrawal = approved_withdrawal(None);
+        assert!(matches!(
+            withdrawal.mark_payment_sent(PaymentFileId::new(), dummy_audit_info()),
+            Err(WithdrawalError::MissingBeneficiary(_))
+        ));
+    }
+
+    #[test]
+    fn payment_sent_is_idempotent() {
+        let mut withdrawal = approved_withdrawal(Some(beneficiary()));
+        let payment_file_id = PaymentFileId::new();
+        assert!(
+            withdrawal
+                .mark_payment_sent(payment_file_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PaymentSent);
+        assert!(
+            !withdrawal
+                .mark_payment_sent(payment_file_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+    }
+
+    #[test]
+    fn sent_payment_cannot_be_cancelled_but_can_be_rejected() {
+        let mut withdrawal = approved_withdrawal(Some(beneficiary()));
+        withdrawal
+            .mark_payment_sent(PaymentFileId::new(), dummy_audit_info())
+            .unwrap();
+        assert!(matches!(
+            withdrawal.cancel(dummy_audit_info()),
+            Err(WithdrawalError::PaymentAlreadySent(_))
+        ));
+
+        assert!(
+            withdrawal
+                .reject_payment(Some("AC04".to_string()), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Cancelled);
+        assert!(
+            !withdrawal
+                .reject_payment(Some("AC04".to_string()), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+    }
+
+    #[test]
+    fn sent_payment_is_settled_instead_of_confirmed() {
+        let mut withdrawal = approved_withdrawal(Some(beneficiary()));
+        withdrawal
+            .mark_payment_sent(PaymentFileId::new(), dummy_audit_info())
+            .unwrap();
+        assert!(matches!(
+            withdrawal.confirm(dummy_audit_info()),
+            Err(WithdrawalError::PaymentAlreadySent(_))
+        ));
+
+        assert!(
+            withdrawal
+                .settle_payment(dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Confirmed);
+        assert!(
+            !withdrawal
+                .settle_payment(dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+    }
 }
@@ -16,6 +16,18 @@ pub enum WithdrawalError {
     AlreadyCancelled(WithdrawalId),
     #[error("WithdrawalError - NotApproved: {0}")]
     NotApproved(WithdrawalId),
+    #[error("WithdrawalError - ConfirmedByInitiator: {0}")]
+    ConfirmedByInitiator(WithdrawalId),
+    #[error("WithdrawalError - NotPendingConfirmation: {0}")]
+    NotPendingConfirmation(WithdrawalId),
+    #[error("WithdrawalError - MissingBeneficiary: {0}")]
+    MissingBeneficiary(WithdrawalId),
+    #[error("WithdrawalError - InvalidBeneficiary: {0}")]
+    InvalidBeneficiary(String),
+    #[error("WithdrawalError - PaymentAlreadySent: {0}")]
+    PaymentAlreadySent(WithdrawalId),
+    #[error("WithdrawalError - PaymentNotSent: {0}")]
+    PaymentNotSent(WithdrawalId),
     #[error("WithdrawalError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
 }
@@ -1,7 +1,9 @@
+mod beneficiary;
 mod entity;
 pub mod error;
 pub mod repo;
 
+pub use beneficiary::WithdrawalBeneficiary;
 #[cfg(feature = "json-schema")]
 pub use entity::WithdrawalEvent;
 pub(super) use entity::*;
@@ -19,7 +19,13 @@ use super::{entity::*, error::*};
         deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
         approval_process_id(ty = "ApprovalProcessId", update(persist = false)),
         cancelled_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
-        reference(ty = "String", create(accessor = "reference()"))
+        reference(ty = "String", create(accessor = "reference()")),
+        status(
+            ty = "WithdrawalStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        )
     ),
     tbl_prefix = "core",
     post_persist_hook = "publish"
@@ -56,6 +62,25 @@ where
         }
     }
 
+    /// Locks the withdrawals awaiting confirmation until `db` completes so that
+    /// they cannot be confirmed or cancelled concurrently.
+    pub async fn lock_pending_confirmation_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+    ) -> Result<Vec<WithdrawalId>, WithdrawalError> {
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: WithdrawalId"
+            FROM core_withdrawals
+            WHERE status = $1
+            ORDER BY created_at, id
+            FOR UPDATE"#,
+            WithdrawalStatus::PendingConfirmation.to_string()
+        )
+        .fetch_all(&mut **db.tx())
+        .await?;
+        Ok(rows.into_iter().map(|row| row.id).collect())
+    }
+
     async fn publish(
         &self,
         db: &mut es_entity::DbOp<'_>,
@@ -67,3 +92,41 @@ where
             .await
     }
 }
+
+mod withdrawal_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::WithdrawalStatus;
+
+    impl Type<Postgres> for WithdrawalStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for WithdrawalStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for WithdrawalStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for WithdrawalStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
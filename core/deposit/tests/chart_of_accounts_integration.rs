@@ -15,17 +15,20 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
     let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
-    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
 
     let cala_config = CalaLedgerConfig::builder()
         .pool(pool.clone())
         .exec_migrations(false)
         .build()?;
     let cala = CalaLedger::init(cala_config).await?;
-    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
 
     let journal_id = helpers::init_journal(&cala).await?;
 
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = document_storage::DocumentStorage::new(&pool, &storage);
+
     let deposit = CoreDeposit::init(
         &pool,
         &authz,
@@ -34,11 +37,23 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &jobs,
         &cala,
         journal_id,
+        &document_storage,
+        &storage,
+        DepositConfig::default(),
     )
     .await?;
 
-    let storage = Storage::new(&StorageConfig::default());
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
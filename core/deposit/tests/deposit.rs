@@ -4,6 +4,7 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
 use core_deposit::*;
 use helpers::{action, event, object};
 
@@ -13,16 +14,18 @@ async fn deposit() -> anyhow::Result<()> {
 
     let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
     let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
-    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
 
     let cala_config = CalaLedgerConfig::builder()
         .pool(pool.clone())
         .exec_migrations(false)
         .build()?;
     let cala = CalaLedger::init(cala_config).await?;
-    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
 
     let journal_id = helpers::init_journal(&cala).await?;
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = document_storage::DocumentStorage::new(&pool, &storage);
 
     let deposit = CoreDeposit::init(
         &pool,
@@ -32,6 +35,9 @@ async fn deposit() -> anyhow::Result<()> {
         &jobs,
         &cala,
         journal_id,
+        &document_storage,
+        &storage,
+        DepositConfig::default(),
     )
     .await?;
 
@@ -42,6 +48,7 @@ async fn deposit() -> anyhow::Result<()> {
             account_holder_id,
             true,
             DepositAccountType::Individual,
+            vec![CurrencyCode::Usd],
         )
         .await?;
 
@@ -63,3 +70,77 @@ async fn deposit() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn deposit_in_additional_currency() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = document_storage::DocumentStorage::new(&pool, &storage);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &document_storage,
+        &storage,
+        DepositConfig::default(),
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            vec![CurrencyCode::Usd, CurrencyCode::Eur],
+        )
+        .await?;
+
+    let eur = Money::try_from_major(dec!(250.50), CurrencyCode::Eur)?;
+    deposit
+        .record_deposit(&DummySubject, account.id, eur, None)
+        .await?;
+
+    let res = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            Money::try_from_major(dec!(1), CurrencyCode::Usdt)?,
+            None,
+        )
+        .await;
+    assert!(res.is_err());
+
+    let balances = deposit.account_balances(&DummySubject, account.id).await?;
+    assert_eq!(balances.len(), 2);
+    let usd = balances
+        .iter()
+        .find(|b| b.currency == CurrencyCode::Usd)
+        .unwrap();
+    assert!(usd.settled.is_zero());
+    let eur_balance = balances
+        .iter()
+        .find(|b| b.currency == CurrencyCode::Eur)
+        .unwrap();
+    assert_eq!(eur_balance.settled, eur);
+
+    Ok(())
+}
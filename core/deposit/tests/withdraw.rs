@@ -4,6 +4,7 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
 use core_deposit::*;
 
 use helpers::{action, event, object};
@@ -14,16 +15,18 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
 
     let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
     let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
-    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let governance = governance::Governance::new(&pool, &authz, &jobs, &outbox);
 
     let cala_config = CalaLedgerConfig::builder()
         .pool(pool.clone())
         .exec_migrations(false)
         .build()?;
     let cala = CalaLedger::init(cala_config).await?;
-    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
 
     let journal_id = helpers::init_journal(&cala).await?;
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = document_storage::DocumentStorage::new(&pool, &storage);
 
     let deposit = CoreDeposit::init(
         &pool,
@@ -33,6 +36,9 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         &jobs,
         &cala,
         journal_id,
+        &document_storage,
+        &storage,
+        DepositConfig::default(),
     )
     .await?;
 
@@ -43,6 +49,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
             account_holder_id,
             true,
             DepositAccountType::Individual,
+            vec![CurrencyCode::Usd],
         )
         .await?;
 
@@ -55,7 +62,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     // overdraw
     let withdrawal_amount = UsdCents::try_from_usd(dec!(5000000)).unwrap();
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await;
     assert!(matches!(
         withdrawal,
@@ -65,7 +72,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
 
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await?;
 
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
@@ -70,6 +70,7 @@ pub struct Document {
     pub content_type: String,
     pub(super) path_in_storage: String,
     pub reference_id: ReferenceId,
+    pub document_type: DocumentType,
     pub status: DocumentStatus,
     events: EntityEvents<DocumentEvent>,
 }
@@ -151,10 +152,12 @@ impl TryFromEvents<DocumentEvent> for Document {
                     content_type,
                     path_in_storage,
                     reference_id,
+                    document_type,
                     ..
                 } => {
                     builder = builder
                         .id(*id)
+                        .document_type(document_type.clone())
                         .filename(sanitized_filename.clone())
                         .content_type(content_type.clone())
                         .path_in_storage(path_in_storage.clone())
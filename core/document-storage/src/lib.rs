@@ -55,6 +55,34 @@ impl DocumentStorage {
         content_type: impl Into<String> + std::fmt::Debug,
         reference_id: impl Into<ReferenceId> + std::fmt::Debug,
         document_type: impl Into<DocumentType> + std::fmt::Debug,
+    ) -> Result<Document, DocumentStorageError> {
+        let mut db = self.repo.begin_op().await?;
+        let document = self
+            .create_in_op(
+                &mut db,
+                audit_info,
+                filename,
+                content_type,
+                reference_id,
+                document_type,
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(document)
+    }
+
+    /// Records a document whose content is uploaded later, e.g. by a job
+    /// spawned in the same transaction.
+    #[instrument(name = "document_storage.create_in_op", skip(self, db), err)]
+    pub async fn create_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        audit_info: AuditInfo,
+        filename: impl Into<String> + std::fmt::Debug,
+        content_type: impl Into<String> + std::fmt::Debug,
+        reference_id: impl Into<ReferenceId> + std::fmt::Debug,
+        document_type: impl Into<DocumentType> + std::fmt::Debug,
     ) -> Result<Document, DocumentStorageError> {
         let document_id = DocumentId::new();
         let document_type = document_type.into();
@@ -73,11 +101,7 @@ impl DocumentStorage {
             .build()
             .expect("Could not build document");
 
-        let mut db = self.repo.begin_op().await?;
-        let document = self.repo.create_in_op(&mut db, new_document).await?;
-        db.commit().await?;
-
-        Ok(document)
+        self.repo.create_in_op(db, new_document).await
     }
 
     #[instrument(
@@ -118,6 +142,17 @@ impl DocumentStorage {
         Ok(())
     }
 
+    #[instrument(name = "document_storage.upload_failed", skip(self, document), err)]
+    pub async fn upload_failed(
+        &self,
+        document: &mut Document,
+        error: String,
+    ) -> Result<(), DocumentStorageError> {
+        document.upload_failed(error);
+        self.repo.update(document).await?;
+        Ok(())
+    }
+
     #[instrument(name = "document_storage.create_and_upload", skip(self, content), err)]
     pub async fn create_and_upload(
         &self,
@@ -3,12 +3,17 @@ use derive_builder::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use audit::AuditInfo;
 use es_entity::*;
 
-use crate::{policy::ApprovalRules, primitives::*};
+use crate::{
+    policy::{ApprovalRules, ApprovalSla},
+    primitives::*,
+};
+
+const EXPIRED_REASON: &str = "Expired without a decision";
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -21,15 +26,54 @@ pub enum ApprovalProcessEvent {
         process_type: ApprovalProcessType,
         rules: ApprovalRules,
         target_ref: String,
+        amount: Option<UsdCents>,
+        #[serde(default)]
+        sla: Option<ApprovalSla>,
+        /// Subject that made the entity under approval.
+        #[serde(default)]
+        initiated_by: Option<String>,
         audit_info: AuditInfo,
     },
     Approved {
         approver_id: CommitteeMemberId,
+        #[serde(default)]
+        stage: usize,
+        /// Member that voted for the approver under a delegation.
+        #[serde(default)]
+        delegate_id: Option<CommitteeMemberId>,
+        /// Rationale given by the member for the approval.
+        #[serde(default)]
+        comment: Option<String>,
         audit_info: AuditInfo,
     },
     Denied {
         denier_id: CommitteeMemberId,
         reason: String,
+        #[serde(default)]
+        delegate_id: Option<CommitteeMemberId>,
+        audit_info: AuditInfo,
+    },
+    StageApproved {
+        stage: usize,
+        audit_info: AuditInfo,
+    },
+    VoteWithdrawn {
+        member_id: CommitteeMemberId,
+        stage: usize,
+        /// Member that withdrew the vote for `member_id` under a delegation.
+        #[serde(default)]
+        delegate_id: Option<CommitteeMemberId>,
+        audit_info: AuditInfo,
+    },
+    ReminderSent {
+        audit_info: AuditInfo,
+    },
+    Escalated {
+        committee_id: CommitteeId,
+        threshold: usize,
+        audit_info: AuditInfo,
+    },
+    Expired {
         audit_info: AuditInfo,
     },
     Concluded {
@@ -38,6 +82,15 @@ pub enum ApprovalProcessEvent {
     },
 }
 
+/// Progress of one stage of the process' rules.
+#[derive(Debug, Clone)]
+pub struct ApprovalProcessStage {
+    pub index: usize,
+    pub rules: ApprovalRules,
+    pub status: ApprovalStageStatus,
+    pub approvers: HashSet<CommitteeMemberId>,
+}
+
 #[derive(EsEntity, Builder)]
 #[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
 pub struct ApprovalProcess {
@@ -45,6 +98,10 @@ pub struct ApprovalProcess {
     pub process_type: ApprovalProcessType,
     pub policy_id: PolicyId,
     pub rules: ApprovalRules,
+    #[builder(default)]
+    pub amount: Option<UsdCents>,
+    #[builder(default)]
+    pub sla: Option<ApprovalSla>,
     events: EntityEvents<ApprovalProcessEvent>,
 }
 
@@ -63,25 +120,118 @@ impl ApprovalProcess {
                 _ => None,
             })
             .next()
+            .or_else(|| self.is_expired().then_some(EXPIRED_REASON))
+    }
+
+    /// Approvals that have not been withdrawn, with their position in the
+    /// event stream.
+    fn active_approvals(&self) -> Vec<(usize, &ApprovalProcessEvent)> {
+        let mut approvals = Vec::new();
+        for (position, event) in self.events.iter_all().enumerate() {
+            match event {
+                ApprovalProcessEvent::Approved { .. } => approvals.push((position, event)),
+                ApprovalProcessEvent::VoteWithdrawn {
+                    member_id, stage, ..
+                } => approvals.retain(|(_, approval)| {
+                    !matches!(
+                        approval,
+                        ApprovalProcessEvent::Approved { approver_id, stage: s, .. }
+                            if approver_id == member_id && s == stage
+                    )
+                }),
+                _ => {}
+            }
+        }
+        approvals
+    }
+
+    /// The first vote of `member_id` that still counts.
+    fn member_vote(&self, member_id: CommitteeMemberId) -> Option<(usize, &ApprovalProcessEvent)> {
+        let cast_by_member = |event: &ApprovalProcessEvent| match event {
+            ApprovalProcessEvent::Approved { approver_id, .. } => *approver_id == member_id,
+            ApprovalProcessEvent::Denied { denier_id, .. } => *denier_id == member_id,
+            _ => false,
+        };
+        self.active_approvals()
+            .into_iter()
+            .find(|(_, event)| cast_by_member(event))
+            .or_else(|| {
+                self.events.iter_all().enumerate().find(|(_, event)| {
+                    matches!(event, ApprovalProcessEvent::Denied { .. }) && cast_by_member(event)
+                })
+            })
     }
 
     pub fn member_voted_at(
         &self,
         member_id: CommitteeMemberId,
     ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let (position, _) = self.member_vote(member_id)?;
         self.events
             .iter_persisted()
-            .filter_map(|event| match event.event {
-                ApprovalProcessEvent::Approved { approver_id, .. } if approver_id == member_id => {
-                    Some(event.recorded_at)
-                }
-                ApprovalProcessEvent::Denied { denier_id, .. } if denier_id == member_id => {
-                    Some(event.recorded_at)
+            .nth(position)
+            .map(|event| event.recorded_at)
+    }
+
+    /// Whether a vote for `member_id` is standing on the process.
+    pub fn has_member_voted(&self, member_id: CommitteeMemberId) -> bool {
+        self.member_vote(member_id).is_some()
+    }
+
+    /// Whether a vote `delegate_id` cast on behalf of another member is
+    /// standing on the process.
+    pub fn has_voted_as_delegate(&self, delegate_id: CommitteeMemberId) -> bool {
+        self.active_approvals()
+            .into_iter()
+            .map(|(_, event)| event)
+            .chain(
+                self.events
+                    .iter_all()
+                    .filter(|event| matches!(event, ApprovalProcessEvent::Denied { .. })),
+            )
+            .any(|event| match event {
+                ApprovalProcessEvent::Approved {
+                    delegate_id: Some(id),
+                    ..
                 }
-                _ => None,
+                | ApprovalProcessEvent::Denied {
+                    delegate_id: Some(id),
+                    ..
+                } => *id == delegate_id,
+                _ => false,
             })
-            .next()
     }
+
+    /// The delegate that cast the vote of `member_id`, if any.
+    pub fn member_vote_delegate(&self, member_id: CommitteeMemberId) -> Option<CommitteeMemberId> {
+        match self.member_vote(member_id)? {
+            (_, ApprovalProcessEvent::Approved { delegate_id, .. })
+            | (_, ApprovalProcessEvent::Denied { delegate_id, .. }) => *delegate_id,
+            _ => None,
+        }
+    }
+
+    /// The comment attached to the vote of `member_id`. For a denial this is
+    /// its reason.
+    pub fn member_vote_comment(&self, member_id: CommitteeMemberId) -> Option<&str> {
+        match self.member_vote(member_id)? {
+            (_, ApprovalProcessEvent::Approved { comment, .. }) => comment.as_deref(),
+            (_, ApprovalProcessEvent::Denied { reason, .. }) => Some(reason.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Subject that made the entity under approval, which is not allowed to
+    /// approve it as well.
+    pub fn initiated_by(&self) -> Option<&str> {
+        match self.events.iter_all().next().expect("No events") {
+            ApprovalProcessEvent::Initialized { initiated_by, .. } => initiated_by.as_deref(),
+            _ => {
+                panic!("No events")
+            }
+        }
+    }
+
     pub fn target_ref(&self) -> &str {
         match self.events.iter_all().next().expect("No events") {
             ApprovalProcessEvent::Initialized { target_ref, .. } => target_ref,
@@ -95,29 +245,200 @@ impl ApprovalProcess {
         self.rules.committee_id()
     }
 
+    pub fn committee_ids(&self) -> Vec<CommitteeId> {
+        let mut ids = self.rules.committee_ids();
+        if let Some(ApprovalRules::CommitteeThreshold { committee_id, .. }) = self.escalation() {
+            if !ids.contains(&committee_id) {
+                ids.push(committee_id);
+            }
+        }
+        ids
+    }
+
+    /// Rules of the fallback committee once the process has been escalated.
+    pub fn escalation(&self) -> Option<ApprovalRules> {
+        self.events.iter_all().find_map(|event| match event {
+            ApprovalProcessEvent::Escalated {
+                committee_id,
+                threshold,
+                ..
+            } => Some(ApprovalRules::CommitteeThreshold {
+                committee_id: *committee_id,
+                threshold: *threshold,
+            }),
+            _ => None,
+        })
+    }
+
+    /// Rules deciding `stage`. After an escalation the fallback committee can
+    /// decide any stage that has not been approved yet.
+    fn stage_rules(&self, stage: usize) -> Option<ApprovalRules> {
+        let rules = self.rules.stages().get(stage)?.clone();
+        Some(match self.escalation() {
+            Some(escalation) => ApprovalRules::AnyOf {
+                rules: vec![rules, escalation],
+            },
+            None => rules,
+        })
+    }
+
+    pub fn reminder_sent(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, ApprovalProcessEvent::ReminderSent { .. }))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, ApprovalProcessEvent::Expired { .. }))
+    }
+
+    /// The earliest SLA deadline that has not been acted on yet.
+    pub fn next_sla_deadline(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let sla = self.sla.as_ref()?;
+        if self.status() != ApprovalProcessStatus::InProgress {
+            return None;
+        }
+        let started_at = self.created_at();
+        [
+            sla.remind_at(started_at).filter(|_| !self.reminder_sent()),
+            sla.escalate_at(started_at)
+                .filter(|_| self.escalation().is_none()),
+            sla.deny_at(started_at),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+
+    /// Index of the stage being decided. Equals the number of stages once
+    /// every stage has been approved.
+    pub fn current_stage(&self) -> usize {
+        self.events
+            .iter_all()
+            .filter(|event| matches!(event, ApprovalProcessEvent::StageApproved { .. }))
+            .count()
+    }
+
+    pub fn stages(&self) -> Vec<ApprovalProcessStage> {
+        let current_stage = self.current_stage();
+        let denied = self.status() == ApprovalProcessStatus::Denied;
+        self.rules
+            .stages()
+            .iter()
+            .enumerate()
+            .map(|(index, rules)| ApprovalProcessStage {
+                index,
+                rules: match self.stage_rules(index) {
+                    Some(escalated) if index >= current_stage => escalated,
+                    _ => rules.clone(),
+                },
+                status: match index.cmp(&current_stage) {
+                    std::cmp::Ordering::Less => ApprovalStageStatus::Approved,
+                    std::cmp::Ordering::Equal if denied => ApprovalStageStatus::Denied,
+                    std::cmp::Ordering::Equal => ApprovalStageStatus::InProgress,
+                    std::cmp::Ordering::Greater => ApprovalStageStatus::Pending,
+                },
+                approvers: self.approvers_in_stage(index),
+            })
+            .collect()
+    }
+
+    fn current_stage_eligible(
+        &self,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
+    ) -> HashSet<CommitteeMemberId> {
+        self.stage_rules(self.current_stage())
+            .map(|rules| {
+                rules
+                    .committee_ids()
+                    .iter()
+                    .filter_map(|committee_id| eligible.get(committee_id))
+                    .flatten()
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn can_member_vote(
         &self,
         member_id: CommitteeMemberId,
-        eligible: HashSet<CommitteeMemberId>,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
     ) -> bool {
-        eligible.contains(&member_id)
-            && !self.approvers().contains(&member_id)
+        self.current_stage_eligible(eligible).contains(&member_id)
+            && !self
+                .approvers_in_stage(self.current_stage())
+                .contains(&member_id)
             && !self.deniers().contains(&member_id)
     }
 
+    /// Records every stage, starting at the current one, whose rules are met
+    /// by the approvals cast while it was in progress.
+    pub(crate) fn advance_stages(
+        &mut self,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+        );
+        if !self.deniers().is_empty() {
+            return Idempotent::Ignored;
+        }
+
+        let mut advanced = false;
+        loop {
+            let stage = self.current_stage();
+            let stage_approved = match self.stage_rules(stage) {
+                Some(rules) => {
+                    rules.is_approved_or_denied(
+                        eligible,
+                        &self.approvers_in_stage(stage),
+                        &HashSet::new(),
+                    ) == Some(true)
+                }
+                None => false,
+            };
+            if !stage_approved {
+                break;
+            }
+            self.events.push(ApprovalProcessEvent::StageApproved {
+                stage,
+                audit_info: audit_info.clone(),
+            });
+            advanced = true;
+        }
+
+        if advanced {
+            Idempotent::Executed(())
+        } else {
+            Idempotent::Ignored
+        }
+    }
+
     pub(crate) fn check_concluded(
         &mut self,
-        eligible: HashSet<CommitteeMemberId>,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
         audit_info: AuditInfo,
     ) -> Idempotent<(bool, Option<String>)> {
         idempotency_guard!(
             self.events.iter_all(),
             ApprovalProcessEvent::Concluded { .. },
         );
-        if let Some(approved) =
-            self.rules
-                .is_approved_or_denied(&eligible, &self.approvers(), &self.deniers())
-        {
+        let _ = self.advance_stages(eligible, audit_info.clone());
+
+        let stage = self.current_stage();
+        let outcome = if !self.deniers().is_empty() {
+            Some(false)
+        } else if let Some(rules) = self.stage_rules(stage) {
+            rules.is_approved_or_denied(eligible, &self.approvers_in_stage(stage), &HashSet::new())
+        } else {
+            Some(true)
+        };
+        if let Some(approved) = outcome {
             let reason = self
                 .events
                 .iter_all()
@@ -153,23 +474,58 @@ impl ApprovalProcess {
 
     pub(crate) fn approve(
         &mut self,
-        eligible_members: &HashSet<CommitteeMemberId>,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
         approver_id: CommitteeMemberId,
+        delegate_id: Option<CommitteeMemberId>,
+        comment: Option<String>,
         audit_info: AuditInfo,
     ) -> Idempotent<()> {
         use ApprovalProcessEvent::*;
+        let stage = self.current_stage();
         idempotency_guard!(
-            self.events.iter_all(),
+            self.events.iter_all().rev(),
             Concluded {..},
-            Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &approver_id,
+            Denied {denier_id: id,..} if id == &approver_id,
+            Approved {approver_id: id, stage: s, ..} if id == &approver_id && s == &stage,
+            => VoteWithdrawn {member_id: id, stage: s, ..} if id == &approver_id && s == &stage
         );
 
-        if !eligible_members.contains(&approver_id) {
+        if !self.current_stage_eligible(eligible).contains(&approver_id) {
             return Idempotent::Ignored;
         }
 
         self.events.push(ApprovalProcessEvent::Approved {
             approver_id,
+            stage,
+            delegate_id,
+            comment,
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
+
+    /// Withdraws the approval `member_id` cast on the current stage. Votes on
+    /// stages that have already been approved cannot be withdrawn.
+    pub(crate) fn withdraw_vote(
+        &mut self,
+        member_id: CommitteeMemberId,
+        delegate_id: Option<CommitteeMemberId>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+        );
+        let stage = self.current_stage();
+        if !self.approvers_in_stage(stage).contains(&member_id) {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(ApprovalProcessEvent::VoteWithdrawn {
+            member_id,
+            stage,
+            delegate_id,
             audit_info,
         });
 
@@ -178,41 +534,114 @@ impl ApprovalProcess {
 
     pub(crate) fn deny(
         &mut self,
-        eligible_members: &HashSet<CommitteeMemberId>,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
         denier_id: CommitteeMemberId,
+        delegate_id: Option<CommitteeMemberId>,
         reason: String,
         audit_info: AuditInfo,
     ) -> Idempotent<()> {
         use ApprovalProcessEvent::*;
+        let stage = self.current_stage();
         idempotency_guard!(
             self.events.iter_all(),
             Concluded {..},
-            Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &denier_id,
+            Denied {denier_id: id,..} if id == &denier_id,
+            Approved {approver_id: id, stage: s, ..} if id == &denier_id && s == &stage,
         );
 
-        if !eligible_members.contains(&denier_id) {
+        if !self.current_stage_eligible(eligible).contains(&denier_id) {
             return Idempotent::Ignored;
         }
 
         self.events.push(ApprovalProcessEvent::Denied {
             denier_id,
             reason,
+            delegate_id,
             audit_info,
         });
 
         Idempotent::Executed(())
     }
 
-    pub fn approvers(&self) -> HashSet<CommitteeMemberId> {
+    /// Members that can vote on the current stage but have not done so yet.
+    pub fn pending_voters(
+        &self,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
+    ) -> HashSet<CommitteeMemberId> {
+        self.current_stage_eligible(eligible)
+            .into_iter()
+            .filter(|member_id| self.can_member_vote(*member_id, eligible))
+            .collect()
+    }
+
+    pub(crate) fn remind(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+            ApprovalProcessEvent::ReminderSent { .. },
+        );
         self.events
-            .iter_all()
-            .filter_map(|event| match event {
+            .push(ApprovalProcessEvent::ReminderSent { audit_info });
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn escalate(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+            ApprovalProcessEvent::Escalated { .. },
+        );
+        let Some(escalation) = self.sla.as_ref().and_then(|sla| sla.escalation.clone()) else {
+            return Idempotent::Ignored;
+        };
+        self.events.push(ApprovalProcessEvent::Escalated {
+            committee_id: escalation.committee_id,
+            threshold: escalation.threshold,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    /// Denies a process that is still undecided at its deadline.
+    pub(crate) fn expire(&mut self, audit_info: AuditInfo) -> Idempotent<String> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ApprovalProcessEvent::Concluded { .. },
+        );
+        self.events.push(ApprovalProcessEvent::Expired {
+            audit_info: audit_info.clone(),
+        });
+        self.events.push(ApprovalProcessEvent::Concluded {
+            approved: false,
+            audit_info,
+        });
+        Idempotent::Executed(EXPIRED_REASON.to_string())
+    }
+
+    pub fn approvers(&self) -> HashSet<CommitteeMemberId> {
+        self.active_approvals()
+            .into_iter()
+            .filter_map(|(_, event)| match event {
                 ApprovalProcessEvent::Approved { approver_id, .. } => Some(*approver_id),
                 _ => None,
             })
             .collect()
     }
 
+    pub fn approvers_in_stage(&self, stage: usize) -> HashSet<CommitteeMemberId> {
+        self.active_approvals()
+            .into_iter()
+            .filter_map(|(_, event)| match event {
+                ApprovalProcessEvent::Approved {
+                    approver_id,
+                    stage: approved_in,
+                    ..
+                } if *approved_in == stage => Some(*approver_id),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn deniers(&self) -> HashSet<CommitteeMemberId> {
         self.events
             .iter_all()
@@ -234,16 +663,24 @@ impl TryFromEvents<ApprovalProcessEvent> for ApprovalProcess {
                     process_type,
                     policy_id,
                     rules,
+                    amount,
+                    sla,
                     ..
                 } => {
                     builder = builder
                         .id(*id)
                         .process_type(process_type.clone())
                         .policy_id(*policy_id)
-                        .rules(*rules);
+                        .rules(rules.clone())
+                        .amount(*amount)
+                        .sla(sla.clone());
                 }
                 ApprovalProcessEvent::Approved { .. } => {}
                 ApprovalProcessEvent::Denied { .. } => {}
+                ApprovalProcessEvent::StageApproved { .. } => {}
+                ApprovalProcessEvent::ReminderSent { .. } => {}
+                ApprovalProcessEvent::Escalated { .. } => {}
+                ApprovalProcessEvent::Expired { .. } => {}
                 ApprovalProcessEvent::Concluded { .. } => {}
             }
         }
@@ -260,6 +697,13 @@ pub struct NewApprovalProcess {
     pub(super) rules: ApprovalRules,
     #[builder(setter(into))]
     pub(super) target_ref: String,
+    /// Amount the approval tier of the policy is chosen by.
+    #[builder(default)]
+    pub(super) amount: Option<UsdCents>,
+    #[builder(default)]
+    pub(super) sla: Option<ApprovalSla>,
+    #[builder(default)]
+    pub(super) initiated_by: Option<String>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -284,6 +728,9 @@ impl IntoEvents<ApprovalProcessEvent> for NewApprovalProcess {
                 process_type: self.process_type,
                 rules: self.rules,
                 target_ref: self.target_ref,
+                amount: self.amount,
+                sla: self.sla,
+                initiated_by: self.initiated_by,
                 audit_info: self.audit_info,
             }],
         )
@@ -302,7 +749,24 @@ mod tests {
         }
     }
 
+    fn committee() -> CommitteeId {
+        CommitteeId::from(uuid::Uuid::nil())
+    }
+
+    fn eligible(members: &[CommitteeMemberId]) -> HashMap<CommitteeId, HashSet<CommitteeMemberId>> {
+        [(committee(), members.iter().copied().collect())]
+            .into_iter()
+            .collect()
+    }
+
     fn init_events(rules: ApprovalRules) -> EntityEvents<ApprovalProcessEvent> {
+        init_events_with_sla(rules, None)
+    }
+
+    fn init_events_with_sla(
+        rules: ApprovalRules,
+        sla: Option<ApprovalSla>,
+    ) -> EntityEvents<ApprovalProcessEvent> {
         EntityEvents::init(
             ApprovalProcessId::new(),
             [ApprovalProcessEvent::Initialized {
@@ -311,6 +775,9 @@ mod tests {
                 process_type: ApprovalProcessType::from_owned("type".to_string()),
                 rules,
                 target_ref: "target_ref".to_string(),
+                amount: None,
+                sla,
+                initiated_by: None,
                 audit_info: dummy_audit_info(),
             }],
         )
@@ -321,14 +788,14 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let approver = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
-        let eligible = [approver].iter().copied().collect();
+        let eligible = eligible(&[approver]);
         assert!(process
-            .approve(&eligible, approver, audit_info.clone())
+            .approve(&eligible, approver, None, None, audit_info.clone())
             .did_execute());
         assert!(process.approvers().contains(&approver));
     }
@@ -338,13 +805,13 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let approver = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
         assert!(process
-            .approve(&HashSet::new(), approver, audit_info.clone())
+            .approve(&HashMap::new(), approver, None, None, audit_info.clone())
             .was_ignored());
         assert!(process.approvers().is_empty());
     }
@@ -354,17 +821,17 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let approver = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
-        let eligible: HashSet<_> = [approver].iter().copied().collect();
+        let eligible = eligible(&[approver]);
         assert!(process
-            .approve(&eligible, approver, audit_info.clone())
+            .approve(&eligible, approver, None, None, audit_info.clone())
             .did_execute());
         assert!(process
-            .approve(&eligible, approver, audit_info.clone())
+            .approve(&eligible, approver, None, None, audit_info.clone())
             .was_ignored());
     }
 
@@ -373,13 +840,48 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::SystemAutoApprove))
                 .expect("Could not build approval process");
-        let _ = process.check_concluded(HashSet::new(), dummy_audit_info());
+        let _ = process.check_concluded(&HashMap::new(), dummy_audit_info());
         let approver = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
-        let eligible: HashSet<_> = [approver].iter().copied().collect();
+        let eligible = eligible(&[approver]);
+        assert!(process
+            .approve(&eligible, approver, None, None, audit_info.clone())
+            .was_ignored());
+    }
+
+    #[test]
+    fn withdrawn_vote_can_be_cast_again() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: committee(),
+            }))
+            .expect("Could not build approval process");
+        let approver = CommitteeMemberId::new();
+        let eligible = eligible(&[approver]);
+        let comment = Some("Collateral checked".to_string());
         assert!(process
-            .approve(&eligible, approver, audit_info.clone())
+            .approve(&eligible, approver, None, comment, dummy_audit_info())
+            .did_execute());
+        assert_eq!(
+            process.member_vote_comment(approver),
+            Some("Collateral checked")
+        );
+
+        assert!(process
+            .withdraw_vote(approver, None, dummy_audit_info())
+            .did_execute());
+        assert!(process.approvers().is_empty());
+        assert!(process.can_member_vote(approver, &eligible));
+        assert!(process
+            .withdraw_vote(approver, None, dummy_audit_info())
             .was_ignored());
+
+        assert!(process
+            .approve(&eligible, approver, None, None, dummy_audit_info())
+            .did_execute());
+        assert!(process.approvers().contains(&approver));
+        assert_eq!(process.member_vote_comment(approver), None);
     }
 
     #[test]
@@ -387,15 +889,15 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let denier = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
         let reason = String::new();
-        let eligible = [denier].iter().copied().collect();
+        let eligible = eligible(&[denier]);
         assert!(process
-            .deny(&eligible, denier, reason, audit_info.clone())
+            .deny(&eligible, denier, None, reason, audit_info.clone())
             .did_execute());
         assert!(process.deniers().contains(&denier));
     }
@@ -405,14 +907,14 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let denier = CommitteeMemberId::new();
         let reason = String::new();
         let audit_info = dummy_audit_info();
         assert!(process
-            .deny(&HashSet::new(), denier, reason, audit_info.clone())
+            .deny(&HashMap::new(), denier, None, reason, audit_info.clone())
             .was_ignored());
         assert!(process.deniers().is_empty());
     }
@@ -422,17 +924,17 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
                 threshold: 2,
-                committee_id: CommitteeId::new(),
+                committee_id: committee(),
             }))
             .expect("Could not build approval process");
         let denier = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
-        let eligible: HashSet<_> = [denier].iter().copied().collect();
+        let eligible = eligible(&[denier]);
         assert!(process
-            .approve(&eligible, denier, audit_info.clone())
+            .approve(&eligible, denier, None, None, audit_info.clone())
             .did_execute());
         assert!(process
-            .deny(&eligible, denier, String::new(), audit_info.clone())
+            .deny(&eligible, denier, None, String::new(), audit_info.clone())
             .was_ignored());
     }
 
@@ -441,12 +943,178 @@ mod tests {
         let mut process =
             ApprovalProcess::try_from_events(init_events(ApprovalRules::SystemAutoApprove))
                 .expect("Could not build approval process");
-        let _ = process.check_concluded(HashSet::new(), dummy_audit_info());
+        let _ = process.check_concluded(&HashMap::new(), dummy_audit_info());
         let denier = CommitteeMemberId::new();
         let audit_info = dummy_audit_info();
-        let eligible: HashSet<_> = [denier].iter().copied().collect();
+        let eligible = eligible(&[denier]);
         assert!(process
-            .deny(&eligible, denier, String::new(), audit_info.clone())
+            .deny(&eligible, denier, None, String::new(), audit_info.clone())
             .was_ignored());
     }
+
+    #[test]
+    fn sequential_stages_are_decided_in_order() {
+        let risk = CommitteeId::new();
+        let board = CommitteeId::new();
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::Sequential {
+                stages: vec![
+                    ApprovalRules::CommitteeThreshold {
+                        committee_id: risk,
+                        threshold: 1,
+                    },
+                    ApprovalRules::CommitteeThreshold {
+                        committee_id: board,
+                        threshold: 1,
+                    },
+                ],
+            }))
+            .expect("Could not build approval process");
+        let risk_member = CommitteeMemberId::new();
+        let board_member = CommitteeMemberId::new();
+        let eligible: HashMap<_, HashSet<_>> = [
+            (risk, [risk_member].into_iter().collect()),
+            (board, [board_member].into_iter().collect()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(!process.can_member_vote(board_member, &eligible));
+        assert!(!process
+            .approve(&eligible, board_member, None, None, dummy_audit_info())
+            .did_execute());
+        assert!(process
+            .approve(&eligible, risk_member, None, None, dummy_audit_info())
+            .did_execute());
+        assert!(process
+            .advance_stages(&eligible, dummy_audit_info())
+            .did_execute());
+        assert_eq!(process.current_stage(), 1);
+        assert!(!process
+            .check_concluded(&eligible, dummy_audit_info())
+            .did_execute());
+
+        assert!(process.can_member_vote(board_member, &eligible));
+        assert!(process
+            .approve(&eligible, board_member, None, None, dummy_audit_info())
+            .did_execute());
+        assert!(matches!(
+            process.check_concluded(&eligible, dummy_audit_info()),
+            Idempotent::Executed((true, None))
+        ));
+        assert!(process
+            .stages()
+            .iter()
+            .all(|stage| stage.status == ApprovalStageStatus::Approved));
+        assert_eq!(process.stages()[1].approvers, [board_member].into());
+    }
+
+    #[test]
+    fn escalation_lets_fallback_committee_decide() {
+        let fallback = CommitteeId::new();
+        let sla = ApprovalSla {
+            remind_after_hours: Some(4),
+            escalation: Some(crate::policy::ApprovalEscalation {
+                after_hours: 24,
+                committee_id: fallback,
+                threshold: 1,
+            }),
+            deny_after_hours: None,
+        };
+        let mut process = ApprovalProcess::try_from_events(init_events_with_sla(
+            ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: committee(),
+            },
+            Some(sla),
+        ))
+        .expect("Could not build approval process");
+        let member = CommitteeMemberId::new();
+        let fallback_member = CommitteeMemberId::new();
+        let mut eligible = eligible(&[member]);
+        eligible.insert(fallback, [fallback_member].into_iter().collect());
+
+        assert!(!process.can_member_vote(fallback_member, &eligible));
+        assert!(process.remind(dummy_audit_info()).did_execute());
+        assert!(!process.remind(dummy_audit_info()).did_execute());
+        assert!(process.escalate(dummy_audit_info()).did_execute());
+        assert!(!process.escalate(dummy_audit_info()).did_execute());
+        assert_eq!(process.committee_ids(), vec![committee(), fallback]);
+        assert_eq!(
+            process.pending_voters(&eligible),
+            [member, fallback_member].into()
+        );
+
+        assert!(process
+            .approve(&eligible, fallback_member, None, None, dummy_audit_info())
+            .did_execute());
+        assert!(matches!(
+            process.check_concluded(&eligible, dummy_audit_info()),
+            Idempotent::Executed((true, None))
+        ));
+    }
+
+    #[test]
+    fn expire_denies_undecided_process() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 1,
+                committee_id: committee(),
+            }))
+            .expect("Could not build approval process");
+
+        assert!(process.expire(dummy_audit_info()).did_execute());
+        assert!(!process.expire(dummy_audit_info()).did_execute());
+        assert_eq!(process.status(), ApprovalProcessStatus::Denied);
+        assert_eq!(process.denied_reason(), Some(EXPIRED_REASON));
+        assert!(process.is_expired());
+    }
+
+    #[test]
+    fn delegate_vote_records_both_members() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 1,
+                committee_id: committee(),
+            }))
+            .expect("Could not build approval process");
+        let member = CommitteeMemberId::new();
+        let delegate = CommitteeMemberId::new();
+        let eligible = eligible(&[member]);
+
+        assert!(process
+            .approve(&eligible, member, Some(delegate), None, dummy_audit_info())
+            .did_execute());
+        assert!(process.approvers().contains(&member));
+        assert_eq!(process.member_vote_delegate(member), Some(delegate));
+        assert!(!process
+            .approve(&eligible, delegate, None, None, dummy_audit_info())
+            .did_execute());
+    }
+
+    #[test]
+    fn delegated_vote_is_standing_before_own_vote() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 2,
+                committee_id: committee(),
+            }))
+            .expect("Could not build approval process");
+        let member = CommitteeMemberId::new();
+        let delegate = CommitteeMemberId::new();
+        let eligible = eligible(&[member, delegate]);
+
+        assert!(!process.has_voted_as_delegate(delegate));
+        assert!(process
+            .approve(&eligible, member, Some(delegate), None, dummy_audit_info())
+            .did_execute());
+        assert!(process.has_voted_as_delegate(delegate));
+        assert!(!process.has_member_voted(delegate));
+        assert!(!process.has_voted_as_delegate(member));
+
+        assert!(process
+            .withdraw_vote(member, Some(delegate), dummy_audit_info())
+            .did_execute());
+        assert!(!process.has_voted_as_delegate(delegate));
+    }
 }
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{event::GovernanceEvent, primitives::*, Governance};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ApprovalProcessSlaJobConfig<Perms, E> {
+    pub process_id: ApprovalProcessId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for ApprovalProcessSlaJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = ApprovalProcessSlaJobInitializer<Perms, E>;
+}
+
+pub struct ApprovalProcessSlaJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    governance: Governance<Perms, E>,
+}
+
+impl<Perms, E> ApprovalProcessSlaJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(governance: &Governance<Perms, E>) -> Self {
+        Self {
+            governance: governance.clone(),
+        }
+    }
+}
+
+const APPROVAL_PROCESS_SLA_JOB: JobType = JobType::new("approval-process-sla");
+impl<Perms, E> JobInitializer for ApprovalProcessSlaJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        APPROVAL_PROCESS_SLA_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ApprovalProcessSlaJobRunner::<Perms, E> {
+            config: job.config()?,
+            governance: self.governance.clone(),
+        }))
+    }
+}
+
+pub struct ApprovalProcessSlaJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    config: ApprovalProcessSlaJobConfig<Perms, E>,
+    governance: Governance<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ApprovalProcessSlaJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        match self
+            .governance
+            .enforce_process_sla(self.config.process_id)
+            .await?
+        {
+            Some(next_deadline) => Ok(JobCompletion::RescheduleAt(next_deadline)),
+            None => Ok(JobCompletion::Complete),
+        }
+    }
+}
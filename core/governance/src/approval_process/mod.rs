@@ -1,10 +1,12 @@
 mod entity;
 pub mod error;
+mod job;
 mod repo;
 
 #[cfg(feature = "json-schema")]
 pub use entity::ApprovalProcessEvent;
-pub use entity::{ApprovalProcess, NewApprovalProcess};
+pub use entity::{ApprovalProcess, ApprovalProcessStage, NewApprovalProcess};
 pub use repo::approval_process_cursor;
 
+pub(crate) use job::{ApprovalProcessSlaJobConfig, ApprovalProcessSlaJobInitializer};
 pub(crate) use repo::ApprovalProcessRepo;
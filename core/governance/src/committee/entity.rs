@@ -1,10 +1,11 @@
-use std::collections::HashSet;
-
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use std::collections::{HashMap, HashSet};
+
 use es_entity::*;
 
 use audit::AuditInfo;
@@ -31,6 +32,33 @@ pub enum CommitteeEvent {
         member_id: CommitteeMemberId,
         audit_info: AuditInfo,
     },
+    DelegationGranted {
+        delegator_id: CommitteeMemberId,
+        delegate_id: CommitteeMemberId,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    DelegationRevoked {
+        delegator_id: CommitteeMemberId,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Authorises `delegate_id` to vote for `delegator_id` from `starts_at`
+/// until `ends_at`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitteeDelegation {
+    pub delegator_id: CommitteeMemberId,
+    pub delegate_id: CommitteeMemberId,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl CommitteeDelegation {
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.starts_at <= at && at < self.ends_at
+    }
 }
 
 #[derive(EsEntity, Builder)]
@@ -99,6 +127,116 @@ impl Committee {
         }
         members
     }
+
+    /// Delegations of current members, a new delegation of a member replaces
+    /// their previous one.
+    pub fn delegations(&self) -> Vec<CommitteeDelegation> {
+        let mut delegations = HashMap::new();
+        for event in self.events.iter_all() {
+            match event {
+                CommitteeEvent::DelegationGranted {
+                    delegator_id,
+                    delegate_id,
+                    starts_at,
+                    ends_at,
+                    ..
+                } => {
+                    delegations.insert(
+                        *delegator_id,
+                        CommitteeDelegation {
+                            delegator_id: *delegator_id,
+                            delegate_id: *delegate_id,
+                            starts_at: *starts_at,
+                            ends_at: *ends_at,
+                        },
+                    );
+                }
+                CommitteeEvent::DelegationRevoked { delegator_id, .. }
+                | CommitteeEvent::MemberRemoved {
+                    member_id: delegator_id,
+                    ..
+                } => {
+                    delegations.remove(delegator_id);
+                }
+                _ => {}
+            }
+        }
+        let mut delegations: Vec<_> = delegations.into_values().collect();
+        delegations.sort_by_key(|delegation| delegation.starts_at);
+        delegations
+    }
+
+    /// Members `delegate_id` can vote for at `at`.
+    pub fn delegators_of(
+        &self,
+        delegate_id: CommitteeMemberId,
+        at: DateTime<Utc>,
+    ) -> HashSet<CommitteeMemberId> {
+        self.delegations()
+            .into_iter()
+            .filter(|delegation| {
+                delegation.delegate_id == delegate_id && delegation.is_active_at(at)
+            })
+            .map(|delegation| delegation.delegator_id)
+            .collect()
+    }
+
+    pub(crate) fn grant_delegation(
+        &mut self,
+        delegator_id: CommitteeMemberId,
+        delegate_id: CommitteeMemberId,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CommitteeError> {
+        if !self.members().contains(&delegator_id) {
+            return Err(CommitteeError::DelegatorNotMember(delegator_id));
+        }
+        if delegator_id == delegate_id {
+            return Err(CommitteeError::SelfDelegation);
+        }
+        if starts_at >= ends_at {
+            return Err(CommitteeError::InvalidDelegationPeriod);
+        }
+
+        let delegation = CommitteeDelegation {
+            delegator_id,
+            delegate_id,
+            starts_at,
+            ends_at,
+        };
+        if self.delegations().contains(&delegation) {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(CommitteeEvent::DelegationGranted {
+            delegator_id,
+            delegate_id,
+            starts_at,
+            ends_at,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn revoke_delegation(
+        &mut self,
+        delegator_id: CommitteeMemberId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if !self
+            .delegations()
+            .iter()
+            .any(|delegation| delegation.delegator_id == delegator_id)
+        {
+            return Idempotent::Ignored;
+        }
+        self.events.push(CommitteeEvent::DelegationRevoked {
+            delegator_id,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<CommitteeEvent> for Committee {
@@ -111,6 +249,8 @@ impl TryFromEvents<CommitteeEvent> for Committee {
                 }
                 CommitteeEvent::MemberAdded { .. } => {}
                 CommitteeEvent::MemberRemoved { .. } => {}
+                CommitteeEvent::DelegationGranted { .. } => {}
+                CommitteeEvent::DelegationRevoked { .. } => {}
             }
         }
         builder.events(events).build()
@@ -144,3 +284,96 @@ impl IntoEvents<CommitteeEvent> for NewCommittee {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use chrono::Duration;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn committee_with(members: &[CommitteeMemberId]) -> Committee {
+        let id = CommitteeId::new();
+        let mut events = vec![CommitteeEvent::Initialized {
+            id,
+            name: "committee".to_string(),
+            audit_info: dummy_audit_info(),
+        }];
+        events.extend(members.iter().map(|member_id| CommitteeEvent::MemberAdded {
+            member_id: *member_id,
+            audit_info: dummy_audit_info(),
+        }));
+        Committee::try_from_events(EntityEvents::init(id, events)).unwrap()
+    }
+
+    #[test]
+    fn delegation_is_bounded_in_time() {
+        let member = CommitteeMemberId::new();
+        let proxy = CommitteeMemberId::new();
+        let mut committee = committee_with(&[member]);
+        let now = Utc::now();
+
+        assert!(committee
+            .grant_delegation(
+                member,
+                proxy,
+                now,
+                now + Duration::days(7),
+                dummy_audit_info()
+            )
+            .unwrap()
+            .did_execute());
+        assert_eq!(committee.delegators_of(proxy, now), [member].into());
+        assert!(committee
+            .delegators_of(proxy, now + Duration::days(7))
+            .is_empty());
+        assert!(committee
+            .delegators_of(proxy, now - Duration::days(1))
+            .is_empty());
+
+        assert!(committee
+            .revoke_delegation(member, dummy_audit_info())
+            .did_execute());
+        assert!(committee.delegators_of(proxy, now).is_empty());
+    }
+
+    #[test]
+    fn only_members_can_delegate() {
+        let member = CommitteeMemberId::new();
+        let outsider = CommitteeMemberId::new();
+        let mut committee = committee_with(&[member]);
+        let now = Utc::now();
+
+        assert!(matches!(
+            committee.grant_delegation(
+                outsider,
+                member,
+                now,
+                now + Duration::days(1),
+                dummy_audit_info()
+            ),
+            Err(CommitteeError::DelegatorNotMember(_))
+        ));
+        assert!(matches!(
+            committee.grant_delegation(member, outsider, now, now, dummy_audit_info()),
+            Err(CommitteeError::InvalidDelegationPeriod)
+        ));
+
+        let _ = committee.grant_delegation(
+            member,
+            outsider,
+            now,
+            now + Duration::days(1),
+            dummy_audit_info(),
+        );
+        committee.remove_member(member, dummy_audit_info());
+        assert!(committee.delegations().is_empty());
+    }
+}
@@ -10,6 +10,12 @@ pub enum CommitteeError {
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
     #[error("CommitteeError - MemberAlreadyAdded: {0}")]
     MemberAlreadyAdded(crate::primitives::CommitteeMemberId),
+    #[error("CommitteeError - DelegatorNotMember: {0}")]
+    DelegatorNotMember(crate::primitives::CommitteeMemberId),
+    #[error("CommitteeError - SelfDelegation")]
+    SelfDelegation,
+    #[error("CommitteeError - InvalidDelegationPeriod")]
+    InvalidDelegationPeriod,
 }
 
 es_entity::from_es_entity_error!(CommitteeError);
@@ -14,6 +14,20 @@ pub enum GovernanceError {
     ApprovalProcessError(#[from] crate::approval_process::error::ApprovalProcessError),
     #[error("GovernanceError - Audit: {0}")]
     AuditError(#[from] audit::error::AuditError),
+    #[error("GovernanceError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
     #[error("GovernanceError - SubjectIsNotCommitteeMember")]
     SubjectIsNotCommitteeMember,
+    #[error("GovernanceError - DenialReasonRequired")]
+    DenialReasonRequired,
+    #[error("GovernanceError - InitiatorCannotApprove")]
+    InitiatorCannotApprove,
+    #[error("GovernanceError - NotDelegateOf: {0}")]
+    NotDelegateOf(crate::primitives::CommitteeMemberId),
+    #[error("GovernanceError - DelegateAlreadyVoted")]
+    DelegateAlreadyVoted,
+    #[error("GovernanceError - AlreadyVotedAsDelegate")]
+    AlreadyVotedAsDelegate,
+    #[error("GovernanceError - DelegatorIsInitiator: {0}")]
+    DelegatorIsInitiator(crate::primitives::CommitteeMemberId),
 }
@@ -12,4 +12,10 @@ pub enum GovernanceEvent {
         denied_reason: Option<String>,
         target_ref: String,
     },
+    ApprovalProcessReminder {
+        id: ApprovalProcessId,
+        process_type: ApprovalProcessType,
+        target_ref: String,
+        voter_ids: Vec<CommitteeMemberId>,
+    },
 }
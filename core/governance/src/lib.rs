@@ -13,8 +13,9 @@ use tracing::instrument;
 
 use std::collections::{HashMap, HashSet};
 
-use audit::AuditSvc;
+use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
+use job::{JobId, Jobs};
 use outbox::{Outbox, OutboxEventMarker};
 
 pub use approval_process::{error as approval_process_error, *};
@@ -40,6 +41,7 @@ where
     policy_repo: PolicyRepo,
     process_repo: ApprovalProcessRepo,
     authz: Perms,
+    jobs: Jobs,
     outbox: Outbox<E>,
 }
 
@@ -54,6 +56,7 @@ where
             policy_repo: self.policy_repo.clone(),
             process_repo: self.process_repo.clone(),
             authz: self.authz.clone(),
+            jobs: self.jobs.clone(),
             outbox: self.outbox.clone(),
         }
     }
@@ -66,18 +69,21 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<GovernanceObject>,
     E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(pool: &sqlx::PgPool, authz: &Perms, outbox: &Outbox<E>) -> Self {
+    pub fn new(pool: &sqlx::PgPool, authz: &Perms, jobs: &Jobs, outbox: &Outbox<E>) -> Self {
         let committee_repo = CommitteeRepo::new(pool);
         let policy_repo = PolicyRepo::new(pool);
         let process_repo = ApprovalProcessRepo::new(pool);
 
-        Self {
+        let governance = Self {
             committee_repo,
             policy_repo,
             process_repo,
             authz: authz.clone(),
+            jobs: jobs.clone(),
             outbox: outbox.clone(),
-        }
+        };
+        jobs.add_initializer(ApprovalProcessSlaJobInitializer::new(&governance));
+        governance
     }
 
     pub async fn init_policy(
@@ -190,6 +196,117 @@ where
         Ok(policy)
     }
 
+    #[instrument(name = "governance.update_policy_rules", skip(self), err)]
+    pub async fn update_policy_rules(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        policy_id: impl Into<PolicyId> + std::fmt::Debug,
+        rules: ApprovalRules,
+    ) -> Result<Policy, GovernanceError> {
+        let policy_id = policy_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::policy(policy_id),
+                GovernanceAction::POLICY_UPDATE_RULES,
+            )
+            .await?;
+
+        let committee_sizes = self.committee_sizes(&rules.committee_ids()).await?;
+
+        let mut policy = self.policy_repo.find_by_id(policy_id).await?;
+        if policy
+            .update_rules(rules, &committee_sizes, audit_info)?
+            .did_execute()
+        {
+            let mut db_tx = self.policy_repo.begin_op().await?;
+            self.policy_repo
+                .update_in_op(&mut db_tx, &mut policy)
+                .await?;
+            db_tx.commit().await?;
+        }
+
+        Ok(policy)
+    }
+
+    #[instrument(name = "governance.update_policy_rule_tiers", skip(self), err)]
+    pub async fn update_policy_rule_tiers(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        policy_id: impl Into<PolicyId> + std::fmt::Debug,
+        tiers: Vec<ApprovalRuleTier>,
+    ) -> Result<Policy, GovernanceError> {
+        let policy_id = policy_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::policy(policy_id),
+                GovernanceAction::POLICY_UPDATE_RULES,
+            )
+            .await?;
+
+        let committee_ids: Vec<_> = tiers
+            .iter()
+            .flat_map(|tier| tier.rules.committee_ids())
+            .collect();
+        let committee_sizes = self.committee_sizes(&committee_ids).await?;
+
+        let mut policy = self.policy_repo.find_by_id(policy_id).await?;
+        if policy
+            .update_rule_tiers(tiers, &committee_sizes, audit_info)?
+            .did_execute()
+        {
+            let mut db_tx = self.policy_repo.begin_op().await?;
+            self.policy_repo
+                .update_in_op(&mut db_tx, &mut policy)
+                .await?;
+            db_tx.commit().await?;
+        }
+
+        Ok(policy)
+    }
+
+    #[instrument(name = "governance.update_policy_sla", skip(self), err)]
+    pub async fn update_policy_sla(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        policy_id: impl Into<PolicyId> + std::fmt::Debug,
+        sla: Option<ApprovalSla>,
+    ) -> Result<Policy, GovernanceError> {
+        let policy_id = policy_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::policy(policy_id),
+                GovernanceAction::POLICY_UPDATE_RULES,
+            )
+            .await?;
+
+        let committee_ids: Vec<_> = sla
+            .iter()
+            .flat_map(|sla| sla.escalation.iter())
+            .map(|escalation| escalation.committee_id)
+            .collect();
+        let committee_sizes = self.committee_sizes(&committee_ids).await?;
+
+        let mut policy = self.policy_repo.find_by_id(policy_id).await?;
+        if policy
+            .update_sla(sla, &committee_sizes, audit_info)?
+            .did_execute()
+        {
+            let mut db_tx = self.policy_repo.begin_op().await?;
+            self.policy_repo
+                .update_in_op(&mut db_tx, &mut policy)
+                .await?;
+            db_tx.commit().await?;
+        }
+
+        Ok(policy)
+    }
+
     #[instrument(name = "governance.find_all_policies", skip(self), err)]
     pub async fn find_all_policies<T: From<Policy>>(
         &self,
@@ -198,6 +315,16 @@ where
         Ok(self.policy_repo.find_all(ids).await?)
     }
 
+    /// Whether the policy of the given process type selects its rules by amount.
+    #[instrument(name = "governance.policy_has_amount_tiers", skip(self), err)]
+    pub async fn policy_has_amount_tiers(
+        &self,
+        process_type: ApprovalProcessType,
+    ) -> Result<bool, GovernanceError> {
+        let policy = self.policy_repo.find_by_process_type(process_type).await?;
+        Ok(!policy.rule_tiers.is_empty())
+    }
+
     #[instrument(name = "governance.start_process", skip(self, db), err)]
     pub async fn start_process(
         &self,
@@ -205,6 +332,8 @@ where
         id: impl Into<ApprovalProcessId> + std::fmt::Debug,
         target_ref: String,
         process_type: ApprovalProcessType,
+        amount: Option<UsdCents>,
+        initiated_by: Option<&AuditInfo>,
     ) -> Result<ApprovalProcess, GovernanceError> {
         let policy = self.policy_repo.find_by_process_type(process_type).await?;
         let audit_info = self
@@ -215,29 +344,160 @@ where
                 GovernanceAction::APPROVAL_PROCESS_CREATE,
             )
             .await?;
-        let new_process = policy.spawn_process(id.into(), target_ref, audit_info);
+        let new_process = policy.spawn_process(
+            id.into(),
+            target_ref,
+            amount,
+            initiated_by.map(|initiator| initiator.sub.clone()),
+            audit_info,
+        );
         let mut process = self.process_repo.create_in_op(db, new_process).await?;
         let eligible = self.eligible_voters_for_process(&process).await?;
         if self
-            .maybe_fire_concluded_event(db.tx().begin().await?, eligible, &mut process)
+            .maybe_fire_concluded_event(db.tx().begin().await?, &eligible, &mut process)
             .await?
         {
             self.process_repo.update_in_op(db, &mut process).await?;
         }
+        if let Some(deadline) = process.next_sla_deadline() {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    ApprovalProcessSlaJobConfig::<Perms, E> {
+                        process_id: process.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    deadline,
+                )
+                .await?;
+        }
         Ok(process)
     }
 
+    /// Acts on the SLA deadlines of the process that have passed and returns
+    /// the next deadline, if the process is still undecided.
+    #[instrument(name = "governance.enforce_process_sla", skip(self), err)]
+    pub(crate) async fn enforce_process_sla(
+        &self,
+        process_id: ApprovalProcessId,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, GovernanceError> {
+        let mut process = self.process_repo.find_by_id(process_id).await?;
+        let sla = match process.sla.clone() {
+            Some(sla) if process.status() == ApprovalProcessStatus::InProgress => sla,
+            _ => return Ok(None),
+        };
+        let now = chrono::Utc::now();
+        let started_at = process.created_at();
+        let is_due = |deadline: Option<chrono::DateTime<chrono::Utc>>| {
+            deadline.is_some_and(|deadline| deadline <= now)
+        };
+
+        let mut db = self.process_repo.begin_op().await?;
+        let mut changed = false;
+        if is_due(sla.deny_at(started_at)) {
+            let audit_info = self
+                .authz
+                .audit()
+                .record_system_entry_in_tx(
+                    db.tx(),
+                    GovernanceObject::approval_process(process.id),
+                    GovernanceAction::APPROVAL_PROCESS_EXPIRE,
+                )
+                .await?;
+            if let es_entity::Idempotent::Executed(reason) = process.expire(audit_info) {
+                self.outbox
+                    .publish_persisted(
+                        db.tx(),
+                        GovernanceEvent::ApprovalProcessConcluded {
+                            id: process.id,
+                            approved: false,
+                            denied_reason: Some(reason),
+                            process_type: process.process_type.clone(),
+                            target_ref: process.target_ref().to_string(),
+                        },
+                    )
+                    .await?;
+                changed = true;
+            }
+        } else {
+            if is_due(sla.escalate_at(started_at)) && process.escalation().is_none() {
+                let audit_info = self
+                    .authz
+                    .audit()
+                    .record_system_entry_in_tx(
+                        db.tx(),
+                        GovernanceObject::approval_process(process.id),
+                        GovernanceAction::APPROVAL_PROCESS_ESCALATE,
+                    )
+                    .await?;
+                if process.escalate(audit_info).did_execute() {
+                    let eligible = self.eligible_voters_for_process(&process).await?;
+                    self.maybe_fire_concluded_event(
+                        db.tx().begin().await?,
+                        &eligible,
+                        &mut process,
+                    )
+                    .await?;
+                    changed = true;
+                }
+            }
+
+            if is_due(sla.remind_at(started_at))
+                && !process.reminder_sent()
+                && process.status() == ApprovalProcessStatus::InProgress
+            {
+                let audit_info = self
+                    .authz
+                    .audit()
+                    .record_system_entry_in_tx(
+                        db.tx(),
+                        GovernanceObject::approval_process(process.id),
+                        GovernanceAction::APPROVAL_PROCESS_REMIND,
+                    )
+                    .await?;
+                if process.remind(audit_info).did_execute() {
+                    let eligible = self.eligible_voters_for_process(&process).await?;
+                    self.outbox
+                        .publish_persisted(
+                            db.tx(),
+                            GovernanceEvent::ApprovalProcessReminder {
+                                id: process.id,
+                                process_type: process.process_type.clone(),
+                                target_ref: process.target_ref().to_string(),
+                                voter_ids: process.pending_voters(&eligible).into_iter().collect(),
+                            },
+                        )
+                        .await?;
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            self.process_repo
+                .update_in_op(&mut db, &mut process)
+                .await?;
+        }
+        db.commit().await?;
+
+        Ok(process.next_sla_deadline())
+    }
+
     #[instrument(name = "governance.approve_process", skip(self), err)]
     pub async fn approve_process(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         process_id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+        on_behalf_of: Option<CommitteeMemberId>,
+        comment: Option<String>,
     ) -> Result<ApprovalProcess, GovernanceError>
     where
         CommitteeMemberId:
             for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
     {
         let process_id = process_id.into();
+        let comment = comment.filter(|comment| !comment.trim().is_empty());
         let audit_info = self
             .authz
             .enforce_permission(
@@ -249,14 +509,20 @@ where
         let member_id = CommitteeMemberId::try_from(sub)
             .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
         let mut process = self.process_repo.find_by_id(process_id).await?;
-        let eligible = self.eligible_voters_for_process(&process).await?;
+        if process.initiated_by() == Some(audit_info.sub.as_str()) {
+            return Err(GovernanceError::InitiatorCannotApprove);
+        }
+        let committees = self.committees_for_process(&process).await?;
+        let eligible = eligible_voters(&committees);
+        let (voter_id, delegate_id) =
+            self.voter_for(&process, &committees, member_id, on_behalf_of)?;
 
         if process
-            .approve(&eligible, member_id, audit_info)
+            .approve(&eligible, voter_id, delegate_id, comment, audit_info)
             .did_execute()
         {
             let mut db = self.policy_repo.begin_op().await?;
-            self.maybe_fire_concluded_event(db.tx().begin().await?, eligible, &mut process)
+            self.maybe_fire_concluded_event(db.tx().begin().await?, &eligible, &mut process)
                 .await?;
             self.process_repo
                 .update_in_op(&mut db, &mut process)
@@ -272,6 +538,7 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         process_id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+        on_behalf_of: Option<CommitteeMemberId>,
         reason: String,
     ) -> Result<ApprovalProcess, GovernanceError>
     where
@@ -279,6 +546,9 @@ where
             for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
     {
         let process_id = process_id.into();
+        if reason.trim().is_empty() {
+            return Err(GovernanceError::DenialReasonRequired);
+        }
         let audit_info = self
             .authz
             .enforce_permission(
@@ -290,20 +560,17 @@ where
         let member_id = CommitteeMemberId::try_from(sub)
             .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
         let mut process = self.process_repo.find_by_id(process_id).await?;
-        let eligible = if let Some(committee_id) = process.committee_id() {
-            self.committee_repo
-                .find_by_id(committee_id)
-                .await?
-                .members()
-        } else {
-            HashSet::new()
-        };
+        let committees = self.committees_for_process(&process).await?;
+        let eligible = eligible_voters(&committees);
+        let (voter_id, delegate_id) =
+            self.voter_for(&process, &committees, member_id, on_behalf_of)?;
+
         if process
-            .deny(&eligible, member_id, reason, audit_info)
+            .deny(&eligible, voter_id, delegate_id, reason, audit_info)
             .did_execute()
         {
             let mut db = self.policy_repo.begin_op().await?;
-            self.maybe_fire_concluded_event(db.tx().begin().await?, eligible, &mut process)
+            self.maybe_fire_concluded_event(db.tx().begin().await?, &eligible, &mut process)
                 .await?;
             self.process_repo
                 .update_in_op(&mut db, &mut process)
@@ -314,6 +581,49 @@ where
         Ok(process)
     }
 
+    #[instrument(name = "governance.withdraw_vote", skip(self), err)]
+    pub async fn withdraw_vote(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        process_id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+    ) -> Result<ApprovalProcess, GovernanceError>
+    where
+        CommitteeMemberId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let process_id = process_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::approval_process(process_id),
+                GovernanceAction::APPROVAL_PROCESS_WITHDRAW_VOTE,
+            )
+            .await?;
+        let member_id = CommitteeMemberId::try_from(sub)
+            .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
+        let mut process = self.process_repo.find_by_id(process_id).await?;
+
+        let mut withdrawn = process
+            .withdraw_vote(member_id, None, audit_info.clone())
+            .did_execute();
+        let delegators: Vec<_> = process
+            .approvers()
+            .into_iter()
+            .filter(|approver_id| process.member_vote_delegate(*approver_id) == Some(member_id))
+            .collect();
+        for delegator_id in delegators {
+            withdrawn |= process
+                .withdraw_vote(delegator_id, Some(member_id), audit_info.clone())
+                .did_execute();
+        }
+        if withdrawn {
+            self.process_repo.update(&mut process).await?;
+        }
+
+        Ok(process)
+    }
+
     #[instrument(name = "governance.create_committee", skip(self), err)]
     pub async fn create_committee(
         &self,
@@ -345,10 +655,12 @@ where
         Ok(committee)
     }
 
+    /// Advances the process through the stages its votes satisfy and
+    /// concludes it once decided. Returns whether the process changed.
     async fn maybe_fire_concluded_event(
         &self,
         mut db: sqlx::Transaction<'_, sqlx::Postgres>,
-        eligible: HashSet<CommitteeMemberId>,
+        eligible: &HashMap<CommitteeId, HashSet<CommitteeMemberId>>,
         process: &mut ApprovalProcess,
     ) -> Result<bool, GovernanceError> {
         let audit_info = self
@@ -361,6 +673,9 @@ where
             )
             .await?;
 
+        let advanced = process
+            .advance_stages(eligible, audit_info.clone())
+            .did_execute();
         if let es_entity::Idempotent::Executed((approved, denied_reason)) =
             process.check_concluded(eligible, audit_info)
         {
@@ -381,7 +696,10 @@ where
             return Ok(true);
         }
 
-        Ok(false)
+        if advanced {
+            db.commit().await?;
+        }
+        Ok(advanced)
     }
 
     #[instrument(name = "governance.add_member_to_committee", skip(self), err)]
@@ -432,6 +750,71 @@ where
         Ok(committee)
     }
 
+    #[instrument(name = "governance.grant_committee_delegation", skip(self), err)]
+    pub async fn grant_committee_delegation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        committee_id: impl Into<CommitteeId> + std::fmt::Debug,
+        delegator_id: impl Into<CommitteeMemberId> + std::fmt::Debug,
+        delegate_id: impl Into<CommitteeMemberId> + std::fmt::Debug,
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Committee, GovernanceError> {
+        let committee_id = committee_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::committee(committee_id),
+                GovernanceAction::COMMITTEE_UPDATE_DELEGATIONS,
+            )
+            .await?;
+
+        let mut committee = self.committee_repo.find_by_id(committee_id).await?;
+        if committee
+            .grant_delegation(
+                delegator_id.into(),
+                delegate_id.into(),
+                starts_at,
+                ends_at,
+                audit_info,
+            )?
+            .did_execute()
+        {
+            self.committee_repo.update(&mut committee).await?;
+        }
+
+        Ok(committee)
+    }
+
+    #[instrument(name = "governance.revoke_committee_delegation", skip(self), err)]
+    pub async fn revoke_committee_delegation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        committee_id: impl Into<CommitteeId> + std::fmt::Debug,
+        delegator_id: impl Into<CommitteeMemberId> + std::fmt::Debug,
+    ) -> Result<Committee, GovernanceError> {
+        let committee_id = committee_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                GovernanceObject::committee(committee_id),
+                GovernanceAction::COMMITTEE_UPDATE_DELEGATIONS,
+            )
+            .await?;
+
+        let mut committee = self.committee_repo.find_by_id(committee_id).await?;
+        if committee
+            .revoke_delegation(delegator_id.into(), audit_info)
+            .did_execute()
+        {
+            self.committee_repo.update(&mut committee).await?;
+        }
+
+        Ok(committee)
+    }
+
     #[instrument(name = "governance.find_committee_by_id", skip(self), err)]
     pub async fn find_committee_by_id(
         &self,
@@ -554,33 +937,116 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         process: &ApprovalProcess,
-        committee: Option<&Committee>,
+        committees: &[&Committee],
     ) -> Result<bool, GovernanceError>
     where
         CommitteeMemberId:
             for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
     {
-        if let Some(committee) = committee {
-            let member_id = CommitteeMemberId::try_from(sub)
-                .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
-            Ok(process.can_member_vote(member_id, committee.members()))
-        } else {
-            Ok(false)
+        if committees.is_empty() {
+            return Ok(false);
         }
+        let member_id = CommitteeMemberId::try_from(sub)
+            .map_err(|_| GovernanceError::SubjectIsNotCommitteeMember)?;
+        let eligible = committees
+            .iter()
+            .map(|committee| (committee.id, committee.members()))
+            .collect();
+        Ok(process.can_member_vote(member_id, &eligible)
+            || delegators_of(committees.iter().copied(), member_id)
+                .into_iter()
+                .any(|delegator_id| process.can_member_vote(delegator_id, &eligible)))
     }
 
-    async fn eligible_voters_for_process(
+    /// The member a vote is cast for together with the delegate casting it.
+    /// A delegate votes for a single delegator per call, and only while no
+    /// vote of their own is standing on the process. Likewise a member cannot
+    /// vote for themselves while a vote they cast as a delegate is standing.
+    fn voter_for(
         &self,
         process: &ApprovalProcess,
-    ) -> Result<HashSet<CommitteeMemberId>, GovernanceError> {
-        let res = if let Some(committee_id) = process.committee_id() {
-            self.committee_repo
-                .find_by_id(committee_id)
-                .await?
-                .members()
-        } else {
-            HashSet::new()
+        committees: &HashMap<CommitteeId, Committee>,
+        member_id: CommitteeMemberId,
+        on_behalf_of: Option<CommitteeMemberId>,
+    ) -> Result<(CommitteeMemberId, Option<CommitteeMemberId>), GovernanceError>
+    where
+        CommitteeMemberId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let Some(delegator_id) = on_behalf_of else {
+            if process.has_voted_as_delegate(member_id) {
+                return Err(GovernanceError::AlreadyVotedAsDelegate);
+            }
+            return Ok((member_id, None));
         };
-        Ok(res)
+        if !delegators_of(committees.values(), member_id).contains(&delegator_id) {
+            return Err(GovernanceError::NotDelegateOf(delegator_id));
+        }
+        if process.has_member_voted(member_id) {
+            return Err(GovernanceError::DelegateAlreadyVoted);
+        }
+        let initiator = process
+            .initiated_by()
+            .and_then(|sub| {
+                sub.parse::<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>()
+                    .ok()
+            })
+            .and_then(|sub| CommitteeMemberId::try_from(&sub).ok());
+        if initiator == Some(delegator_id) {
+            return Err(GovernanceError::DelegatorIsInitiator(delegator_id));
+        }
+        Ok((delegator_id, Some(member_id)))
+    }
+
+    async fn committees_for_process(
+        &self,
+        process: &ApprovalProcess,
+    ) -> Result<HashMap<CommitteeId, Committee>, GovernanceError> {
+        Ok(self
+            .committee_repo
+            .find_all(&process.committee_ids())
+            .await?)
+    }
+
+    async fn eligible_voters_for_process(
+        &self,
+        process: &ApprovalProcess,
+    ) -> Result<HashMap<CommitteeId, HashSet<CommitteeMemberId>>, GovernanceError> {
+        Ok(eligible_voters(
+            &self.committees_for_process(process).await?,
+        ))
     }
+
+    async fn committee_sizes(
+        &self,
+        committee_ids: &[CommitteeId],
+    ) -> Result<HashMap<CommitteeId, usize>, GovernanceError> {
+        let committees: HashMap<CommitteeId, Committee> =
+            self.committee_repo.find_all(committee_ids).await?;
+        Ok(committees
+            .into_iter()
+            .map(|(id, committee)| (id, committee.n_members()))
+            .collect())
+    }
+}
+
+fn eligible_voters(
+    committees: &HashMap<CommitteeId, Committee>,
+) -> HashMap<CommitteeId, HashSet<CommitteeMemberId>> {
+    committees
+        .iter()
+        .map(|(id, committee)| (*id, committee.members()))
+        .collect()
+}
+
+/// Members whose active delegations let `delegate_id` vote for them.
+fn delegators_of<'a>(
+    committees: impl IntoIterator<Item = &'a Committee>,
+    delegate_id: CommitteeMemberId,
+) -> HashSet<CommitteeMemberId> {
+    let now = chrono::Utc::now();
+    committees
+        .into_iter()
+        .flat_map(|committee| committee.delegators_of(delegate_id, now))
+        .collect()
 }
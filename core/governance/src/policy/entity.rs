@@ -3,10 +3,16 @@ use derive_builder::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
+
 use audit::AuditInfo;
 use es_entity::*;
 
-use super::{error::PolicyError, rules::ApprovalRules};
+use super::{
+    error::PolicyError,
+    rules::{tier_for_amount, ApprovalRuleTier, ApprovalRules},
+    sla::ApprovalSla,
+};
 use crate::{approval_process::NewApprovalProcess, primitives::*};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +30,14 @@ pub enum PolicyEvent {
         rules: ApprovalRules,
         audit_info: AuditInfo,
     },
+    ApprovalRuleTiersUpdated {
+        tiers: Vec<ApprovalRuleTier>,
+        audit_info: AuditInfo,
+    },
+    SlaUpdated {
+        sla: Option<ApprovalSla>,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -32,6 +46,10 @@ pub struct Policy {
     pub id: PolicyId,
     pub process_type: ApprovalProcessType,
     pub rules: ApprovalRules,
+    #[builder(default)]
+    pub rule_tiers: Vec<ApprovalRuleTier>,
+    #[builder(default)]
+    pub sla: Option<ApprovalSla>,
     events: EntityEvents<PolicyEvent>,
 }
 
@@ -46,10 +64,21 @@ impl Policy {
             .expect("No events for policy")
     }
 
+    /// Rules a process for `amount` is subject to. Processes without an
+    /// amount, and policies without tiers, use the policy's base rules.
+    pub fn rules_for(&self, amount: Option<UsdCents>) -> ApprovalRules {
+        amount
+            .and_then(|amount| tier_for_amount(&self.rule_tiers, amount))
+            .map(|tier| tier.rules.clone())
+            .unwrap_or_else(|| self.rules.clone())
+    }
+
     pub(crate) fn spawn_process(
         &self,
         id: ApprovalProcessId,
         target_ref: String,
+        amount: Option<UsdCents>,
+        initiated_by: Option<String>,
         audit_info: AuditInfo,
     ) -> NewApprovalProcess {
         NewApprovalProcess::builder()
@@ -57,7 +86,10 @@ impl Policy {
             .target_ref(target_ref)
             .policy_id(self.id)
             .process_type(self.process_type.clone())
-            .rules(self.rules)
+            .rules(self.rules_for(amount))
+            .amount(amount)
+            .sla(self.sla.clone())
+            .initiated_by(initiated_by)
             .audit_info(audit_info)
             .build()
             .expect("failed to build new approval process")
@@ -92,11 +124,80 @@ impl Policy {
         };
 
         self.events.push(PolicyEvent::ApprovalRulesUpdated {
-            rules: self.rules,
+            rules: self.rules.clone(),
             audit_info,
         });
         Ok(Idempotent::Executed(()))
     }
+
+    pub fn update_rules(
+        &mut self,
+        rules: ApprovalRules,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PolicyError> {
+        if self.rules == rules {
+            return Ok(Idempotent::Ignored);
+        }
+        rules.validate(committee_sizes)?;
+
+        self.rules = rules.clone();
+        self.events
+            .push(PolicyEvent::ApprovalRulesUpdated { rules, audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn update_rule_tiers(
+        &mut self,
+        tiers: Vec<ApprovalRuleTier>,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PolicyError> {
+        if self.rule_tiers == tiers {
+            return Ok(Idempotent::Ignored);
+        }
+
+        for tier in tiers.iter() {
+            tier.rules.validate(committee_sizes)?;
+        }
+
+        if let Some((last, bounded)) = tiers.split_last() {
+            if last.up_to.is_some() || bounded.iter().any(|tier| tier.up_to.is_none()) {
+                return Err(PolicyError::RuleTiersNotOpenEnded);
+            }
+            if bounded
+                .windows(2)
+                .any(|pair| pair[0].up_to >= pair[1].up_to)
+            {
+                return Err(PolicyError::RuleTiersNotAscending);
+            }
+        }
+
+        self.rule_tiers = tiers.clone();
+        self.events
+            .push(PolicyEvent::ApprovalRuleTiersUpdated { tiers, audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Sets the deadlines processes spawned from now on are subject to.
+    pub fn update_sla(
+        &mut self,
+        sla: Option<ApprovalSla>,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PolicyError> {
+        if self.sla == sla {
+            return Ok(Idempotent::Ignored);
+        }
+        if let Some(sla) = &sla {
+            sla.validate(committee_sizes)?;
+        }
+
+        self.sla = sla.clone();
+        self.events
+            .push(PolicyEvent::SlaUpdated { sla, audit_info });
+        Ok(Idempotent::Executed(()))
+    }
 }
 
 impl TryFromEvents<PolicyEvent> for Policy {
@@ -113,9 +214,15 @@ impl TryFromEvents<PolicyEvent> for Policy {
                     builder = builder
                         .id(*id)
                         .process_type(process_type.clone())
-                        .rules(*rules)
+                        .rules(rules.clone())
                 }
-                PolicyEvent::ApprovalRulesUpdated { rules, .. } => builder = builder.rules(*rules),
+                PolicyEvent::ApprovalRulesUpdated { rules, .. } => {
+                    builder = builder.rules(rules.clone())
+                }
+                PolicyEvent::ApprovalRuleTiersUpdated { tiers, .. } => {
+                    builder = builder.rule_tiers(tiers.clone())
+                }
+                PolicyEvent::SlaUpdated { sla, .. } => builder = builder.sla(sla.clone()),
             }
         }
         builder.events(events).build()
@@ -242,4 +349,99 @@ mod test {
 
         assert!(matches!(res, Err(PolicyError::PolicyThresholdTooLow(_, _))));
     }
+
+    #[test]
+    fn rules_follow_amount_tiers() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let committee_id = CommitteeId::new();
+        let committee_rules = ApprovalRules::CommitteeThreshold {
+            committee_id,
+            threshold: 2,
+        };
+        let tiers = vec![
+            ApprovalRuleTier {
+                up_to: Some(UsdCents::from(1_000_000)),
+                rules: ApprovalRules::SystemAutoApprove,
+            },
+            ApprovalRuleTier {
+                up_to: None,
+                rules: committee_rules.clone(),
+            },
+        ];
+        let committee_sizes = [(committee_id, 3)].into_iter().collect();
+        assert!(policy
+            .update_rule_tiers(tiers.clone(), &committee_sizes, dummy_audit_info())
+            .unwrap()
+            .did_execute());
+
+        assert_eq!(
+            policy.rules_for(Some(UsdCents::from(999_999))),
+            ApprovalRules::SystemAutoApprove
+        );
+        assert_eq!(
+            policy.rules_for(Some(UsdCents::from(1_000_001))),
+            committee_rules
+        );
+        assert_eq!(policy.rules_for(None), policy.rules);
+
+        let policy = Policy::try_from_events(policy.events).unwrap();
+        assert_eq!(policy.rule_tiers, tiers);
+    }
+
+    #[test]
+    fn update_to_sequential_rules() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let risk = CommitteeId::new();
+        let board = CommitteeId::new();
+        let rules = ApprovalRules::Sequential {
+            stages: vec![
+                ApprovalRules::CommitteeThreshold {
+                    committee_id: risk,
+                    threshold: 2,
+                },
+                ApprovalRules::CommitteeThreshold {
+                    committee_id: board,
+                    threshold: 1,
+                },
+            ],
+        };
+        let committee_sizes = [(risk, 2), (board, 1)].into_iter().collect();
+        assert!(policy
+            .update_rules(rules.clone(), &committee_sizes, dummy_audit_info())
+            .unwrap()
+            .did_execute());
+        assert_eq!(policy.rules, rules);
+        assert_eq!(policy.committee_id(), None);
+
+        let too_small = [(risk, 1), (board, 1)].into_iter().collect();
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let res = policy.update_rules(rules, &too_small, dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(PolicyError::PolicyThresholdTooHigh(_, 2))
+        ));
+    }
+
+    #[test]
+    fn error_when_rule_tiers_are_not_open_ended_or_ascending() {
+        let mut policy = Policy::try_from_events(init_events()).unwrap();
+        let tier = |up_to: Option<u64>| ApprovalRuleTier {
+            up_to: up_to.map(UsdCents::from),
+            rules: ApprovalRules::SystemAutoApprove,
+        };
+
+        let res = policy.update_rule_tiers(
+            vec![tier(Some(100)), tier(Some(200))],
+            &HashMap::new(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(res, Err(PolicyError::RuleTiersNotOpenEnded)));
+
+        let res = policy.update_rule_tiers(
+            vec![tier(Some(200)), tier(Some(100)), tier(None)],
+            &HashMap::new(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(res, Err(PolicyError::RuleTiersNotAscending)));
+    }
 }
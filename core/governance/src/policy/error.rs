@@ -14,6 +14,16 @@ pub enum PolicyError {
     PolicyThresholdTooHigh(crate::primitives::CommitteeId, usize),
     #[error("PolicyError - Threshold {1} too low for committee {0}")]
     PolicyThresholdTooLow(crate::primitives::CommitteeId, usize),
+    #[error("PolicyError - Rule tiers must have strictly ascending upper bounds")]
+    RuleTiersNotAscending,
+    #[error("PolicyError - The last rule tier, and only the last, must be open-ended")]
+    RuleTiersNotOpenEnded,
+    #[error("PolicyError - Sequential rules can only be used at the top level")]
+    NestedSequentialRules,
+    #[error("PolicyError - Composite rules need at least one rule")]
+    EmptyCompositeRules,
+    #[error("PolicyError - SLA deadlines must be positive and follow each other")]
+    SlaDeadlinesNotAscending,
 }
 
 es_entity::from_es_entity_error!(PolicyError);
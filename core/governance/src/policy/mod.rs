@@ -2,6 +2,7 @@ mod entity;
 pub mod error;
 mod repo;
 mod rules;
+mod sla;
 
 #[cfg(feature = "json-schema")]
 pub use entity::PolicyEvent;
@@ -9,3 +10,4 @@ pub use entity::{NewPolicy, Policy};
 pub use repo::policy_cursor;
 pub(crate) use repo::PolicyRepo;
 pub use rules::*;
+pub use sla::*;
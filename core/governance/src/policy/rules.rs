@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::primitives::CommitteeId;
+use super::error::PolicyError;
+use crate::primitives::{CommitteeId, UsdCents};
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ApprovalRules {
@@ -13,40 +14,196 @@ pub enum ApprovalRules {
         threshold: usize,
     },
     SystemAutoApprove,
+    /// Stages that are decided one after another. A vote only counts towards
+    /// the stage that is in progress when it is cast.
+    Sequential {
+        stages: Vec<ApprovalRules>,
+    },
+    /// Approved once every one of the rules approves.
+    AllOf {
+        rules: Vec<ApprovalRules>,
+    },
+    /// Approved as soon as one of the rules approves.
+    AnyOf {
+        rules: Vec<ApprovalRules>,
+    },
 }
 
 impl ApprovalRules {
+    /// The deciding committee if these are single committee rules.
     pub fn committee_id(&self) -> Option<CommitteeId> {
         match self {
             ApprovalRules::CommitteeThreshold { committee_id, .. } => Some(*committee_id),
-            ApprovalRules::SystemAutoApprove => None,
+            _ => None,
+        }
+    }
+
+    /// Every committee that takes part in the decision, in order of appearance.
+    pub fn committee_ids(&self) -> Vec<CommitteeId> {
+        let mut ids = Vec::new();
+        self.collect_committee_ids(&mut ids);
+        ids
+    }
+
+    fn collect_committee_ids(&self, ids: &mut Vec<CommitteeId>) {
+        match self {
+            ApprovalRules::CommitteeThreshold { committee_id, .. } => {
+                if !ids.contains(committee_id) {
+                    ids.push(*committee_id);
+                }
+            }
+            ApprovalRules::SystemAutoApprove => (),
+            ApprovalRules::Sequential { stages: rules }
+            | ApprovalRules::AllOf { rules }
+            | ApprovalRules::AnyOf { rules } => {
+                for rule in rules {
+                    rule.collect_committee_ids(ids);
+                }
+            }
+        }
+    }
+
+    /// The rules of each stage a process passes through. Anything but
+    /// `Sequential` rules is decided in a single stage.
+    pub fn stages(&self) -> &[ApprovalRules] {
+        match self {
+            ApprovalRules::Sequential { stages } => stages,
+            _ => std::slice::from_ref(self),
         }
     }
 
+    /// `eligible_members` maps each committee to the members that may vote on
+    /// its behalf. `Sequential` rules are evaluated like `AllOf` here, keeping
+    /// the stages apart is up to the caller.
     pub fn is_approved_or_denied<Id: Eq + std::hash::Hash>(
         &self,
-        eligible_members: &HashSet<Id>,
+        eligible_members: &HashMap<CommitteeId, HashSet<Id>>,
         approving_members: &HashSet<Id>,
         denying_members: &HashSet<Id>,
     ) -> Option<bool> {
         if !denying_members.is_empty() {
             return Some(false);
         }
+        self.outcome(eligible_members, approving_members)
+    }
+
+    fn outcome<Id: Eq + std::hash::Hash>(
+        &self,
+        eligible_members: &HashMap<CommitteeId, HashSet<Id>>,
+        approving_members: &HashSet<Id>,
+    ) -> Option<bool> {
         match self {
             ApprovalRules::SystemAutoApprove => Some(true),
-            ApprovalRules::CommitteeThreshold { threshold, .. }
-                if eligible_members.intersection(approving_members).count() >= *threshold =>
-            {
-                Some(true)
+            ApprovalRules::CommitteeThreshold {
+                committee_id,
+                threshold,
+            } => {
+                let eligible = eligible_members.get(committee_id);
+                let n_approving = eligible.map_or(0, |eligible| {
+                    eligible.intersection(approving_members).count()
+                });
+                let n_eligible = eligible.map_or(0, HashSet::len);
+                if n_approving >= *threshold {
+                    Some(true)
+                } else if n_eligible < *threshold {
+                    Some(false)
+                } else {
+                    None
+                }
             }
-            ApprovalRules::CommitteeThreshold { threshold, .. }
-                if eligible_members.len() < *threshold =>
-            {
-                Some(false)
+            ApprovalRules::Sequential { stages: rules } | ApprovalRules::AllOf { rules } => {
+                let mut outcome = Some(true);
+                for rule in rules {
+                    match rule.outcome(eligible_members, approving_members) {
+                        Some(false) => return Some(false),
+                        None => outcome = None,
+                        Some(true) => (),
+                    }
+                }
+                outcome
+            }
+            ApprovalRules::AnyOf { rules } => {
+                let mut outcome = Some(false);
+                for rule in rules {
+                    match rule.outcome(eligible_members, approving_members) {
+                        Some(true) => return Some(true),
+                        None => outcome = None,
+                        Some(false) => (),
+                    }
+                }
+                outcome
             }
-            _ => None,
         }
     }
+
+    pub(crate) fn validate(
+        &self,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+    ) -> Result<(), PolicyError> {
+        self.validate_nested(committee_sizes, false)
+    }
+
+    fn validate_nested(
+        &self,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+        nested: bool,
+    ) -> Result<(), PolicyError> {
+        match self {
+            ApprovalRules::CommitteeThreshold {
+                committee_id,
+                threshold,
+            } => {
+                if *threshold < 1 {
+                    return Err(PolicyError::PolicyThresholdTooLow(
+                        *committee_id,
+                        *threshold,
+                    ));
+                }
+                if *threshold > committee_sizes.get(committee_id).copied().unwrap_or(0) {
+                    return Err(PolicyError::PolicyThresholdTooHigh(
+                        *committee_id,
+                        *threshold,
+                    ));
+                }
+                Ok(())
+            }
+            ApprovalRules::SystemAutoApprove => Ok(()),
+            ApprovalRules::Sequential { .. } if nested => Err(PolicyError::NestedSequentialRules),
+            ApprovalRules::Sequential { stages: rules }
+            | ApprovalRules::AllOf { rules }
+            | ApprovalRules::AnyOf { rules } => {
+                if rules.is_empty() {
+                    return Err(PolicyError::EmptyCompositeRules);
+                }
+                for rule in rules {
+                    rule.validate_nested(committee_sizes, true)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// One band of a tiered policy. A process whose amount is at most `up_to`
+/// (and above the bound of the preceding tier) is governed by `rules`.
+/// The last tier of a policy has no upper bound.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ApprovalRuleTier {
+    pub up_to: Option<UsdCents>,
+    pub rules: ApprovalRules,
+}
+
+impl ApprovalRuleTier {
+    fn covers(&self, amount: UsdCents) -> bool {
+        self.up_to.is_none_or(|up_to| amount <= up_to)
+    }
+}
+
+/// Picks the tier that governs `amount`. Tiers are expected to be ordered by
+/// ascending upper bound.
+pub fn tier_for_amount(tiers: &[ApprovalRuleTier], amount: UsdCents) -> Option<&ApprovalRuleTier> {
+    tiers.iter().find(|tier| tier.covers(amount))
 }
 
 #[cfg(test)]
@@ -57,14 +214,22 @@ mod tests {
         ids.iter().copied().collect()
     }
 
+    fn committee() -> CommitteeId {
+        CommitteeId::from(uuid::Uuid::nil())
+    }
+
+    fn make_eligible(ids: &[u32]) -> HashMap<CommitteeId, HashSet<u32>> {
+        [(committee(), make_set(ids))].into_iter().collect()
+    }
+
     #[test]
     fn test_committee_threshold_approval() {
         let rules = ApprovalRules::CommitteeThreshold {
             threshold: 3,
-            committee_id: CommitteeId::new(),
+            committee_id: committee(),
         };
 
-        let eligible = make_set(&[1, 2, 3, 4, 5]);
+        let eligible = make_eligible(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[1, 2, 3]);
         let denying = HashSet::new();
 
@@ -81,10 +246,10 @@ mod tests {
     fn test_committee_threshold_denial() {
         let rules = ApprovalRules::CommitteeThreshold {
             threshold: 3,
-            committee_id: CommitteeId::new(),
+            committee_id: committee(),
         };
 
-        let eligible = make_set(&[1, 2, 3, 4, 5]);
+        let eligible = make_eligible(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[2, 3, 4]);
         let denying = make_set(&[1]);
 
@@ -101,10 +266,10 @@ mod tests {
     fn test_committee_threshold_pending() {
         let rules = ApprovalRules::CommitteeThreshold {
             threshold: 3,
-            committee_id: CommitteeId::new(),
+            committee_id: committee(),
         };
 
-        let eligible = make_set(&[1, 2, 3, 4, 5]);
+        let eligible = make_eligible(&[1, 2, 3, 4, 5]);
         let approving = make_set(&[1, 2]);
         let denying = HashSet::new();
 
@@ -115,11 +280,12 @@ mod tests {
             "Should be pending when neither condition is met"
         );
         assert!(
-            eligible.intersection(&approving).count() < 3,
+            eligible[&committee()].intersection(&approving).count() < 3,
             "Should have fewer than threshold approved members"
         );
         assert!(
-            eligible.len() - eligible.intersection(&denying).count() > 2,
+            eligible[&committee()].len() - eligible[&committee()].intersection(&denying).count()
+                > 2,
             "Should have more than threshold non-denied members"
         );
     }
@@ -129,29 +295,155 @@ mod tests {
         let rules = ApprovalRules::SystemAutoApprove;
 
         assert_eq!(
-            rules.is_approved_or_denied(&make_set(&[1, 2, 3]), &HashSet::new(), &HashSet::new()),
+            rules.is_approved_or_denied(
+                &make_eligible(&[1, 2, 3]),
+                &HashSet::new(),
+                &HashSet::new()
+            ),
             Some(true),
             "Automatic rules should always approve regardless of inputs"
         );
     }
 
+    #[test]
+    fn all_of_and_any_of_combine_committees() {
+        let risk = CommitteeId::new();
+        let compliance = CommitteeId::new();
+        let risk_rules = ApprovalRules::CommitteeThreshold {
+            committee_id: risk,
+            threshold: 2,
+        };
+        let compliance_rules = ApprovalRules::CommitteeThreshold {
+            committee_id: compliance,
+            threshold: 1,
+        };
+        let eligible: HashMap<_, _> = [(risk, make_set(&[1, 2, 3])), (compliance, make_set(&[4]))]
+            .into_iter()
+            .collect();
+        let all_of = ApprovalRules::AllOf {
+            rules: vec![risk_rules.clone(), compliance_rules.clone()],
+        };
+        let any_of = ApprovalRules::AnyOf {
+            rules: vec![risk_rules, compliance_rules],
+        };
+        let none = HashSet::new();
+
+        assert_eq!(
+            all_of.is_approved_or_denied(&eligible, &make_set(&[1, 2]), &none),
+            None
+        );
+        assert_eq!(
+            all_of.is_approved_or_denied(&eligible, &make_set(&[1, 2, 4]), &none),
+            Some(true)
+        );
+        assert_eq!(
+            any_of.is_approved_or_denied(&eligible, &make_set(&[4]), &none),
+            Some(true)
+        );
+        assert_eq!(
+            any_of.is_approved_or_denied(&eligible, &make_set(&[1]), &none),
+            None
+        );
+        assert_eq!(
+            any_of.is_approved_or_denied(&eligible, &make_set(&[1]), &make_set(&[2])),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn sequential_rules_have_one_stage_each() {
+        let rules = ApprovalRules::Sequential {
+            stages: vec![
+                ApprovalRules::CommitteeThreshold {
+                    committee_id: committee(),
+                    threshold: 1,
+                },
+                ApprovalRules::SystemAutoApprove,
+            ],
+        };
+        assert_eq!(rules.stages().len(), 2);
+        assert_eq!(rules.committee_ids(), vec![committee()]);
+        assert_eq!(ApprovalRules::SystemAutoApprove.stages().len(), 1);
+    }
+
+    #[test]
+    fn validate_rejects_nested_sequential_and_empty_rules() {
+        let sizes = HashMap::new();
+        let nested = ApprovalRules::AllOf {
+            rules: vec![ApprovalRules::Sequential {
+                stages: vec![ApprovalRules::SystemAutoApprove],
+            }],
+        };
+        assert!(matches!(
+            nested.validate(&sizes),
+            Err(PolicyError::NestedSequentialRules)
+        ));
+        assert!(matches!(
+            ApprovalRules::AnyOf { rules: vec![] }.validate(&sizes),
+            Err(PolicyError::EmptyCompositeRules)
+        ));
+        assert!(matches!(
+            ApprovalRules::CommitteeThreshold {
+                committee_id: committee(),
+                threshold: 1
+            }
+            .validate(&sizes),
+            Err(PolicyError::PolicyThresholdTooHigh(_, 1))
+        ));
+    }
+
+    #[test]
+    fn tier_for_amount_picks_first_covering_tier() {
+        let credit_committee = CommitteeId::new();
+        let board = CommitteeId::new();
+        let tiers = [
+            ApprovalRuleTier {
+                up_to: Some(UsdCents::from(1_000_000)),
+                rules: ApprovalRules::SystemAutoApprove,
+            },
+            ApprovalRuleTier {
+                up_to: Some(UsdCents::from(25_000_000)),
+                rules: ApprovalRules::CommitteeThreshold {
+                    committee_id: credit_committee,
+                    threshold: 2,
+                },
+            },
+            ApprovalRuleTier {
+                up_to: None,
+                rules: ApprovalRules::CommitteeThreshold {
+                    committee_id: board,
+                    threshold: 3,
+                },
+            },
+        ];
+
+        let rules_for = |amount: u64| {
+            tier_for_amount(&tiers, UsdCents::from(amount)).map(|tier| tier.rules.committee_id())
+        };
+        assert_eq!(rules_for(500_000), Some(None));
+        assert_eq!(rules_for(1_000_000), Some(None));
+        assert_eq!(rules_for(1_000_001), Some(Some(credit_committee)));
+        assert_eq!(rules_for(30_000_000), Some(Some(board)));
+        assert_eq!(tier_for_amount(&[], UsdCents::from(1)), None);
+    }
+
     #[test]
     fn test_edge_cases() {
         let rules = ApprovalRules::CommitteeThreshold {
             threshold: 3,
-            committee_id: CommitteeId::new(),
+            committee_id: committee(),
         };
 
         // Empty sets
         let empty = HashSet::new();
         assert_eq!(
-            rules.is_approved_or_denied(&empty, &empty, &empty),
+            rules.is_approved_or_denied(&HashMap::new(), &empty, &empty),
             Some(false),
             "Empty eligible set should result in denial"
         );
 
         // Threshold larger than eligible set
-        let small_eligible = make_set(&[1, 2]);
+        let small_eligible = make_eligible(&[1, 2]);
         assert_eq!(
             rules.is_approved_or_denied(&small_eligible, &empty, &empty),
             Some(false),
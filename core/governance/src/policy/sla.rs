@@ -0,0 +1,123 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use super::{error::PolicyError, rules::ApprovalRules};
+use crate::primitives::CommitteeId;
+
+/// Deadlines, counted in hours from the start of a process, after which a
+/// process that is still undecided gets acted on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ApprovalSla {
+    /// Remind the voters that have not voted yet.
+    pub remind_after_hours: Option<u32>,
+    pub escalation: Option<ApprovalEscalation>,
+    /// Deny the process when it is still undecided.
+    pub deny_after_hours: Option<u32>,
+}
+
+/// Lets a fallback committee decide the process alongside its own rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ApprovalEscalation {
+    pub after_hours: u32,
+    pub committee_id: CommitteeId,
+    pub threshold: usize,
+}
+
+impl ApprovalEscalation {
+    pub fn rules(&self) -> ApprovalRules {
+        ApprovalRules::CommitteeThreshold {
+            committee_id: self.committee_id,
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl ApprovalSla {
+    pub fn remind_at(&self, started_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.remind_after_hours
+            .map(|hours| started_at + Duration::hours(hours.into()))
+    }
+
+    pub fn escalate_at(&self, started_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.escalation
+            .as_ref()
+            .map(|escalation| started_at + Duration::hours(escalation.after_hours.into()))
+    }
+
+    pub fn deny_at(&self, started_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.deny_after_hours
+            .map(|hours| started_at + Duration::hours(hours.into()))
+    }
+
+    pub(crate) fn validate(
+        &self,
+        committee_sizes: &HashMap<CommitteeId, usize>,
+    ) -> Result<(), PolicyError> {
+        if let Some(escalation) = &self.escalation {
+            escalation.rules().validate(committee_sizes)?;
+        }
+
+        let deadlines: Vec<u32> = [
+            self.remind_after_hours,
+            self.escalation.as_ref().map(|e| e.after_hours),
+            self.deny_after_hours,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if deadlines.first() == Some(&0) || deadlines.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(PolicyError::SlaDeadlinesNotAscending);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sla(remind: Option<u32>, escalate: Option<u32>, deny: Option<u32>) -> ApprovalSla {
+        ApprovalSla {
+            remind_after_hours: remind,
+            escalation: escalate.map(|after_hours| ApprovalEscalation {
+                after_hours,
+                committee_id: CommitteeId::from(uuid::Uuid::nil()),
+                threshold: 1,
+            }),
+            deny_after_hours: deny,
+        }
+    }
+
+    #[test]
+    fn deadlines_must_follow_each_other() {
+        let sizes = HashMap::from([(CommitteeId::from(uuid::Uuid::nil()), 1)]);
+
+        assert!(sla(Some(4), Some(24), Some(72)).validate(&sizes).is_ok());
+        assert!(sla(None, None, Some(72)).validate(&sizes).is_ok());
+        assert!(matches!(
+            sla(Some(24), Some(24), None).validate(&sizes),
+            Err(PolicyError::SlaDeadlinesNotAscending)
+        ));
+        assert!(matches!(
+            sla(None, Some(48), Some(24)).validate(&sizes),
+            Err(PolicyError::SlaDeadlinesNotAscending)
+        ));
+        assert!(matches!(
+            sla(Some(0), None, None).validate(&sizes),
+            Err(PolicyError::SlaDeadlinesNotAscending)
+        ));
+    }
+
+    #[test]
+    fn escalation_threshold_must_fit_committee() {
+        let sizes = HashMap::new();
+        assert!(matches!(
+            sla(None, Some(24), None).validate(&sizes),
+            Err(PolicyError::PolicyThresholdTooHigh(_, _))
+        ));
+    }
+}
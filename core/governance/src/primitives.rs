@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::{borrow::Cow, fmt::Display, str::FromStr};
 
 use authz::{action_description::*, AllOrOne};
+pub use core_money::UsdCents;
 es_entity::entity_id! { ApprovalProcessId, CommitteeId, PolicyId, CommitteeMemberId }
 
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
@@ -21,6 +22,15 @@ impl ApprovalProcessStatus {
     }
 }
 
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApprovalStageStatus {
+    Approved,
+    Denied,
+    InProgress,
+    Pending,
+}
+
 #[derive(Clone, Eq, Hash, PartialEq, Debug, Serialize, Deserialize, sqlx::Type)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[sqlx(transparent)]
@@ -59,6 +69,8 @@ impl GovernanceAction {
     pub const COMMITTEE_ADD_MEMBER: Self = GovernanceAction::Committee(CommitteeAction::AddMember);
     pub const COMMITTEE_REMOVE_MEMBER: Self =
         GovernanceAction::Committee(CommitteeAction::RemoveMember);
+    pub const COMMITTEE_UPDATE_DELEGATIONS: Self =
+        GovernanceAction::Committee(CommitteeAction::UpdateDelegations);
 
     pub const POLICY_CREATE: Self = GovernanceAction::Policy(PolicyAction::Create);
     pub const POLICY_READ: Self = GovernanceAction::Policy(PolicyAction::Read);
@@ -75,8 +87,16 @@ impl GovernanceAction {
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Approve);
     pub const APPROVAL_PROCESS_DENY: Self =
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Deny);
+    pub const APPROVAL_PROCESS_WITHDRAW_VOTE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::WithdrawVote);
     pub const APPROVAL_PROCESS_CONCLUDE: Self =
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Conclude);
+    pub const APPROVAL_PROCESS_REMIND: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Remind);
+    pub const APPROVAL_PROCESS_ESCALATE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Escalate);
+    pub const APPROVAL_PROCESS_EXPIRE: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Expire);
 
     pub fn entities() -> Vec<(
         GovernanceActionDiscriminants,
@@ -134,6 +154,7 @@ pub enum CommitteeAction {
     RemoveMember,
     Read,
     List,
+    UpdateDelegations,
 }
 
 impl CommitteeAction {
@@ -165,6 +186,9 @@ impl CommitteeAction {
                         PERMISSION_SET_GOVERNANCE_WRITER,
                     ],
                 ),
+                Self::UpdateDelegations => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -224,7 +248,11 @@ pub enum ApprovalProcessAction {
     List,
     Approve,
     Deny,
+    WithdrawVote,
     Conclude,
+    Remind,
+    Escalate,
+    Expire,
 }
 
 impl ApprovalProcessAction {
@@ -251,12 +279,26 @@ impl ApprovalProcessAction {
                     ],
                 ),
                 Self::Approve => {
-                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_APPROVER])
+                }
+                Self::Deny => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_APPROVER])
+                }
+                Self::WithdrawVote => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_APPROVER])
                 }
-                Self::Deny => ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER]),
                 Self::Conclude => {
                     ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
                 }
+                Self::Remind => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                }
+                Self::Escalate => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                }
+                Self::Expire => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -271,6 +313,7 @@ pub type ApprovalProcessAllOrOne = AllOrOne<ApprovalProcessId>;
 
 pub const PERMISSION_SET_GOVERNANCE_WRITER: &str = "governance_writer";
 pub const PERMISSION_SET_GOVERNANCE_VIEWER: &str = "governance_viewer";
+pub const PERMISSION_SET_GOVERNANCE_APPROVER: &str = "governance_approver";
 
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
 #[strum_discriminants(derive(strum::Display, strum::EnumString))]
@@ -8,6 +8,10 @@ use schemars::JsonSchema;
 
 use std::fmt;
 
+mod money;
+
+pub use money::{CurrencyCode, Money};
+
 pub const SATS_PER_BTC: Decimal = dec!(100_000_000);
 pub const CENTS_PER_USD: Decimal = dec!(100);
 
@@ -78,6 +82,14 @@ pub enum ConversionError {
     DecimalError(#[from] rust_decimal::Error),
     #[error("ConversionError - UnexpectedNegativeNumber: {0}")]
     UnexpectedNegativeNumber(rust_decimal::Decimal),
+    #[error("ConversionError - CurrencyMismatch: expected {0}, got {1}")]
+    CurrencyMismatch(CurrencyCode, CurrencyCode),
+    #[error("ConversionError - ExcessPrecision: {0} has too many decimals for {1}")]
+    ExcessPrecision(rust_decimal::Decimal, CurrencyCode),
+    #[error("ConversionError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(String),
+    #[error("ConversionError - Overflow: amount exceeds the range of {0}")]
+    Overflow(CurrencyCode),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -0,0 +1,254 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use std::{fmt, str::FromStr};
+
+use super::{ConversionError, UsdCents};
+
+#[derive(
+    async_graphql::Enum,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CurrencyCode {
+    #[default]
+    Usd,
+    Eur,
+    Usdt,
+}
+
+impl CurrencyCode {
+    pub const ALL: [CurrencyCode; 3] = [CurrencyCode::Usd, CurrencyCode::Eur, CurrencyCode::Usdt];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            CurrencyCode::Usd => "USD",
+            CurrencyCode::Eur => "EUR",
+            CurrencyCode::Usdt => "USDT",
+        }
+    }
+
+    /// Number of decimal places represented by one minor unit.
+    pub fn precision(&self) -> u32 {
+        match self {
+            CurrencyCode::Usd | CurrencyCode::Eur => 2,
+            CurrencyCode::Usdt => 6,
+        }
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CurrencyCode::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ConversionError::UnsupportedCurrency(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct Money {
+    minor_units: u64,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    pub fn new(minor_units: u64, currency: CurrencyCode) -> Self {
+        Self {
+            minor_units,
+            currency,
+        }
+    }
+
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self::new(0, currency)
+    }
+
+    pub fn try_from_major(
+        amount: Decimal,
+        currency: CurrencyCode,
+    ) -> Result<Self, ConversionError> {
+        if amount.is_sign_negative() && !amount.is_zero() {
+            return Err(ConversionError::UnexpectedNegativeNumber(amount));
+        }
+        let minor_units = amount * Decimal::from(10u64.pow(currency.precision()));
+        if minor_units.trunc() != minor_units {
+            return Err(ConversionError::ExcessPrecision(amount, currency));
+        }
+        Ok(Self::new(u64::try_from(minor_units)?, currency))
+    }
+
+    pub fn to_major(self) -> Result<Decimal, ConversionError> {
+        Ok(Decimal::try_from_i128_with_scale(
+            i128::from(self.minor_units),
+            self.currency.precision(),
+        )?)
+    }
+
+    pub fn minor_units(self) -> u64 {
+        self.minor_units
+    }
+
+    pub fn currency(self) -> CurrencyCode {
+        self.currency
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.minor_units == 0
+    }
+
+    pub fn checked_add(self, other: Money) -> Result<Money, ConversionError> {
+        self.ensure_same_currency(other)?;
+        let Some(minor_units) = self.minor_units.checked_add(other.minor_units) else {
+            return Err(ConversionError::Overflow(self.currency));
+        };
+        Ok(Self::new(minor_units, self.currency))
+    }
+
+    pub fn checked_sub(self, other: Money) -> Result<Money, ConversionError> {
+        self.ensure_same_currency(other)?;
+        let Some(minor_units) = self.minor_units.checked_sub(other.minor_units) else {
+            return Err(ConversionError::UnexpectedNegativeNumber(
+                self.to_major()? - other.to_major()?,
+            ));
+        };
+        Ok(Self::new(minor_units, self.currency))
+    }
+
+    fn ensure_same_currency(self, other: Money) -> Result<(), ConversionError> {
+        if self.currency != other.currency {
+            return Err(ConversionError::CurrencyMismatch(
+                self.currency,
+                other.currency,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_major() {
+            Ok(major) => write!(
+                f,
+                "{:.*} {}",
+                self.currency.precision() as usize,
+                major,
+                self.currency
+            ),
+            Err(_) => write!(f, "{} minor units {}", self.minor_units, self.currency),
+        }
+    }
+}
+
+impl From<UsdCents> for Money {
+    fn from(cents: UsdCents) -> Self {
+        Self::new(cents.into_inner(), CurrencyCode::Usd)
+    }
+}
+
+impl TryFrom<Money> for UsdCents {
+    type Error = ConversionError;
+
+    fn try_from(money: Money) -> Result<Self, Self::Error> {
+        if money.currency != CurrencyCode::Usd {
+            return Err(ConversionError::CurrencyMismatch(
+                CurrencyCode::Usd,
+                money.currency,
+            ));
+        }
+        Ok(UsdCents::from(money.minor_units))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn converts_major_units_using_currency_precision() {
+        let eur = Money::try_from_major(dec!(12.34), CurrencyCode::Eur).unwrap();
+        assert_eq!(eur.minor_units(), 1234);
+
+        let usdt = Money::try_from_major(dec!(1.5), CurrencyCode::Usdt).unwrap();
+        assert_eq!(usdt.minor_units(), 1_500_000);
+        assert_eq!(usdt.to_major().unwrap(), dec!(1.500000));
+        assert_eq!(usdt.to_string(), "1.500000 USDT");
+    }
+
+    #[test]
+    fn converts_full_minor_unit_range() {
+        let max = Money::new(u64::MAX, CurrencyCode::Usd);
+        assert_eq!(max.to_major().unwrap() * dec!(100), Decimal::from(u64::MAX));
+    }
+
+    #[test]
+    fn rejects_excess_precision() {
+        assert!(matches!(
+            Money::try_from_major(dec!(0.001), CurrencyCode::Usd),
+            Err(ConversionError::ExcessPrecision(..))
+        ));
+    }
+
+    #[test]
+    fn arithmetic_requires_same_currency() {
+        let usd = Money::new(100, CurrencyCode::Usd);
+        let eur = Money::new(100, CurrencyCode::Eur);
+        assert!(matches!(
+            usd.checked_add(eur),
+            Err(ConversionError::CurrencyMismatch(..))
+        ));
+        assert_eq!(
+            usd.checked_add(usd).unwrap(),
+            Money::new(200, CurrencyCode::Usd)
+        );
+        assert!(usd.checked_sub(Money::new(101, CurrencyCode::Usd)).is_err());
+    }
+
+    #[test]
+    fn addition_overflow_is_an_error() {
+        let max = Money::new(u64::MAX, CurrencyCode::Usd);
+        assert!(matches!(
+            max.checked_add(Money::new(1, CurrencyCode::Usd)),
+            Err(ConversionError::Overflow(CurrencyCode::Usd))
+        ));
+    }
+
+    #[test]
+    fn usd_cents_round_trip() {
+        let money = Money::from(UsdCents::from(250));
+        assert_eq!(UsdCents::try_from(money).unwrap(), UsdCents::from(250));
+        assert!(UsdCents::try_from(Money::new(250, CurrencyCode::Eur)).is_err());
+    }
+
+    #[test]
+    fn parses_currency_codes() {
+        assert_eq!("eur".parse::<CurrencyCode>().unwrap(), CurrencyCode::Eur);
+        assert!("GBP".parse::<CurrencyCode>().is_err());
+    }
+}
@@ -2,9 +2,13 @@ use async_graphql::*;
 
 use crate::graphql::access::PermissionSet;
 use crate::graphql::loader::LanaDataLoader;
+use crate::graphql::primitives::Decimal;
 use crate::primitives::*;
-use lana_app::access::role::Role as DomainRole;
 pub use lana_app::access::role::RolesByNameCursor;
+use lana_app::access::{
+    PermissionCondition as DomainPermissionCondition,
+    role::{Role as DomainRole, RoleCondition as DomainRoleCondition},
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -33,6 +37,29 @@ impl Role {
             .await?;
         Ok(loaded.into_values().collect())
     }
+
+    async fn conditions(&self) -> Vec<RoleCondition> {
+        self.entity
+            .conditions
+            .iter()
+            .map(RoleCondition::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct RoleCondition {
+    action: String,
+    description: String,
+}
+
+impl From<&DomainRoleCondition> for RoleCondition {
+    fn from(condition: &DomainRoleCondition) -> Self {
+        Self {
+            action: condition.action.clone(),
+            description: condition.condition.to_string(),
+        }
+    }
 }
 
 impl From<DomainRole> for Role {
@@ -67,3 +94,52 @@ pub struct RoleRemovePermissionSetsInput {
     pub permission_set_ids: Vec<UUID>,
 }
 crate::mutation_payload! { RoleRemovePermissionSetsPayload, role: Role }
+
+#[derive(OneofObject)]
+pub enum PermissionConditionInput {
+    AtMost(AtMostConditionInput),
+    OneOf(OneOfConditionInput),
+}
+
+#[derive(InputObject)]
+pub struct AtMostConditionInput {
+    pub attribute: String,
+    pub limit: Decimal,
+}
+
+#[derive(InputObject)]
+pub struct OneOfConditionInput {
+    pub attribute: String,
+    pub values: Vec<String>,
+}
+
+impl From<PermissionConditionInput> for DomainPermissionCondition {
+    fn from(input: PermissionConditionInput) -> Self {
+        match input {
+            PermissionConditionInput::AtMost(input) => Self::AtMost {
+                attribute: input.attribute,
+                limit: input.limit.into(),
+            },
+            PermissionConditionInput::OneOf(input) => Self::OneOf {
+                attribute: input.attribute,
+                values: input.values,
+            },
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct RoleAddConditionInput {
+    pub role_id: UUID,
+    pub action: String,
+    pub condition: PermissionConditionInput,
+}
+crate::mutation_payload! { RoleAddConditionPayload, role: Role }
+
+#[derive(InputObject)]
+pub struct RoleRemoveConditionInput {
+    pub role_id: UUID,
+    pub action: String,
+    pub condition: PermissionConditionInput,
+}
+crate::mutation_payload! { RoleRemoveConditionPayload, role: Role }
@@ -1,7 +1,7 @@
 use async_graphql::*;
 
 use crate::{graphql::loader::LanaDataLoader, primitives::*};
-use lana_app::access::user::User as DomainUser;
+use lana_app::access::user::{RoleElevation as DomainRoleElevation, User as DomainUser};
 
 use super::Role;
 
@@ -52,6 +52,17 @@ impl User {
         }
     }
 
+    /// When the current role is revoked, for a time-bounded grant.
+    async fn role_expires_at(&self) -> Option<Timestamp> {
+        self.entity.role_expires_at().map(Into::into)
+    }
+
+    async fn pending_role_elevation(&self) -> Option<RoleElevation> {
+        self.entity
+            .pending_role_elevation()
+            .map(RoleElevation::from)
+    }
+
     async fn email(&self) -> &str {
         &self.entity.email
     }
@@ -83,6 +94,38 @@ impl User {
     }
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RoleElevation {
+    approval_process_id: UUID,
+    expires_at: Timestamp,
+    #[graphql(skip)]
+    role_id: RoleId,
+}
+
+impl From<DomainRoleElevation> for RoleElevation {
+    fn from(elevation: DomainRoleElevation) -> Self {
+        Self {
+            approval_process_id: elevation.approval_process_id.into(),
+            expires_at: elevation.expires_at.into(),
+            role_id: elevation.role_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl RoleElevation {
+    async fn role(&self, ctx: &Context<'_>) -> async_graphql::Result<Role> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let role = loader
+            .load_one(self.role_id)
+            .await?
+            .expect("role not found");
+
+        Ok(role)
+    }
+}
+
 #[derive(InputObject)]
 pub struct UserCreateInput {
     pub email: String,
@@ -94,9 +137,18 @@ mutation_payload! { UserCreatePayload, user: User }
 pub struct UserUpdateRoleInput {
     pub id: UUID,
     pub role_id: UUID,
+    pub expires_at: Option<Timestamp>,
 }
 mutation_payload! { UserUpdateRolePayload, user: User }
 
+#[derive(InputObject)]
+pub struct UserRoleElevationRequestInput {
+    pub id: UUID,
+    pub role_id: UUID,
+    pub expires_at: Timestamp,
+}
+mutation_payload! { UserRoleElevationRequestPayload, user: User }
+
 #[derive(InputObject)]
 pub struct UserRevokeRoleInput {
     pub id: UUID,
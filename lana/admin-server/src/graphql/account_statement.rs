@@ -0,0 +1,105 @@
+use async_graphql::*;
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    AccountStatement as DomainAccountStatement, AccountStatementFormat,
+    AccountStatementsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct AccountStatement {
+    id: ID,
+    account_statement_id: UUID,
+    deposit_account_id: UUID,
+    currency: CurrencyCode,
+    period_start: Date,
+    period_end: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAccountStatement>,
+}
+
+impl From<DomainAccountStatement> for AccountStatement {
+    fn from(statement: DomainAccountStatement) -> Self {
+        AccountStatement {
+            id: statement.id.to_global_id(),
+            account_statement_id: UUID::from(statement.id),
+            deposit_account_id: UUID::from(statement.deposit_account_id),
+            currency: statement.currency,
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountStatement {
+    async fn opening_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.opening_balance.to_major()?.into())
+    }
+
+    async fn closing_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.closing_balance.to_major()?.into())
+    }
+
+    async fn total_credits(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.total_credits.to_major()?.into())
+    }
+
+    async fn total_debits(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.total_debits.to_major()?.into())
+    }
+
+    async fn accrued_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.accrued_balance.to_major()?.into())
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct AccountStatementGenerateInput {
+    pub deposit_account_id: UUID,
+    #[graphql(default)]
+    pub currency: CurrencyCode,
+    pub period_start: Date,
+    pub period_end: Date,
+}
+crate::mutation_payload! { AccountStatementGeneratePayload, account_statement: AccountStatement }
+
+#[derive(InputObject)]
+pub struct AccountStatementDownloadLinkGenerateInput {
+    pub account_statement_id: UUID,
+    pub format: AccountStatementFormat,
+}
+
+#[derive(SimpleObject)]
+pub struct AccountStatementDownloadLinkGeneratePayload {
+    account_statement_id: UUID,
+    format: AccountStatementFormat,
+    link: String,
+}
+
+impl AccountStatementDownloadLinkGeneratePayload {
+    pub fn new(account_statement_id: UUID, format: AccountStatementFormat, link: String) -> Self {
+        Self {
+            account_statement_id,
+            format,
+            link,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::accounting::accounting_period::{
+    AccountingPeriod as DomainAccountingPeriod, AccountingPeriodStatus, AccountingPeriodType,
+    AccountingPeriodsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct AccountingPeriod {
+    id: ID,
+    accounting_period_id: UUID,
+    period_type: AccountingPeriodType,
+    period_start: Date,
+    period_end: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAccountingPeriod>,
+}
+
+impl From<DomainAccountingPeriod> for AccountingPeriod {
+    fn from(period: DomainAccountingPeriod) -> Self {
+        AccountingPeriod {
+            id: period.id.to_global_id(),
+            accounting_period_id: UUID::from(period.id),
+            period_type: period.period_type,
+            period_start: period.period_start.into(),
+            period_end: period.period_end.into(),
+            created_at: period.created_at().into(),
+            entity: Arc::new(period),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountingPeriod {
+    async fn status(&self) -> AccountingPeriodStatus {
+        self.entity.status()
+    }
+
+    async fn closing_transaction_id(&self) -> Option<UUID> {
+        self.entity.closing_transaction_id().map(UUID::from)
+    }
+}
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCreateInput {
+    pub period_type: AccountingPeriodType,
+    pub period_start: Date,
+}
+crate::mutation_payload! { AccountingPeriodCreatePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodStartClosingInput {
+    pub accounting_period_id: UUID,
+}
+crate::mutation_payload! { AccountingPeriodStartClosingPayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCloseInput {
+    pub accounting_period_id: UUID,
+    /// Required when closing an annual period.
+    pub retained_earnings_account_code: Option<String>,
+}
+crate::mutation_payload! { AccountingPeriodClosePayload, accounting_period: AccountingPeriod }
@@ -0,0 +1,91 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::cash_flow::CashFlowActivity;
+use lana_app::accounting::cash_flow::{
+    CashFlowAmount as DomainCashFlowAmount, CashFlowLine as DomainCashFlowLine,
+    CashFlowSection as DomainCashFlowSection, CashFlowStatement as DomainCashFlowStatement,
+};
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+#[derive(SimpleObject)]
+pub struct CashFlowAmount {
+    usd: Decimal,
+    btc: Decimal,
+}
+
+impl From<DomainCashFlowAmount> for CashFlowAmount {
+    fn from(amount: DomainCashFlowAmount) -> Self {
+        Self {
+            usd: amount.usd.into(),
+            btc: amount.btc.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CashFlowLine {
+    ledger_account_id: UUID,
+    name: String,
+    amount: CashFlowAmount,
+}
+
+impl From<DomainCashFlowLine> for CashFlowLine {
+    fn from(line: DomainCashFlowLine) -> Self {
+        Self {
+            ledger_account_id: line.id.into(),
+            name: line.name,
+            amount: line.amount.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CashFlowSection {
+    activity: CashFlowActivity,
+    lines: Vec<CashFlowLine>,
+    total: CashFlowAmount,
+}
+
+impl From<DomainCashFlowSection> for CashFlowSection {
+    fn from(section: DomainCashFlowSection) -> Self {
+        Self {
+            activity: section.activity,
+            lines: section.lines.into_iter().map(CashFlowLine::from).collect(),
+            total: section.total.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CashFlowStatement {
+    name: String,
+    from: Date,
+    until: Date,
+    sections: Vec<CashFlowSection>,
+    opening_cash: CashFlowAmount,
+    closing_cash: CashFlowAmount,
+    net_change_in_cash: CashFlowAmount,
+    unreconciled_difference: CashFlowAmount,
+}
+
+impl From<DomainCashFlowStatement> for CashFlowStatement {
+    fn from(statement: DomainCashFlowStatement) -> Self {
+        let net_change_in_cash = statement.net_change_in_cash().into();
+        let unreconciled_difference = statement.unreconciled_difference().into();
+        Self {
+            name: statement.name,
+            from: statement.from.into(),
+            until: statement.until.into(),
+            sections: statement
+                .sections
+                .into_iter()
+                .map(CashFlowSection::from)
+                .collect(),
+            opening_cash: statement.opening_cash.into(),
+            closing_cash: statement.closing_cash.into(),
+            net_change_in_cash,
+            unreconciled_difference,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use async_graphql::*;
 use crate::{graphql::accounting::AccountCode, primitives::*};
 
 use lana_app::accounting::Chart as DomainChart;
+pub use lana_app::accounting::ChartExportFormat;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -66,3 +67,37 @@ pub struct ChartOfAccountsCsvImportInput {
 pub struct ChartOfAccountsCsvImportPayload {
     pub success: bool,
 }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsJsonImportInput {
+    pub chart_id: UUID,
+    pub file: Upload,
+}
+
+#[derive(SimpleObject)]
+pub struct ChartOfAccountsJsonImportPayload {
+    pub success: bool,
+}
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeRenameInput {
+    pub chart_id: UUID,
+    pub code: String,
+    pub name: String,
+}
+crate::mutation_payload! { ChartOfAccountsNodeRenamePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeMoveInput {
+    pub chart_id: UUID,
+    pub code: String,
+    pub parent_code: Option<String>,
+}
+crate::mutation_payload! { ChartOfAccountsNodeMovePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeDeactivateInput {
+    pub chart_id: UUID,
+    pub code: String,
+}
+crate::mutation_payload! { ChartOfAccountsNodeDeactivatePayload, chart_of_accounts: ChartOfAccounts }
@@ -0,0 +1,108 @@
+use async_graphql::*;
+
+use lana_app::accounting::comparative_statement::{
+    ComparativeAmount as DomainComparativeAmount,
+    ComparativeStatement as DomainComparativeStatement,
+    ComparativeStatementLine as DomainComparativeStatementLine,
+    StatementPeriod as DomainStatementPeriod, error::ComparativeStatementError,
+};
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+#[derive(InputObject)]
+pub struct StatementPeriodInput {
+    pub from: Date,
+    pub until: Date,
+}
+
+impl TryFrom<StatementPeriodInput> for DomainStatementPeriod {
+    type Error = ComparativeStatementError;
+
+    fn try_from(input: StatementPeriodInput) -> Result<Self, Self::Error> {
+        DomainStatementPeriod::new(input.from.into_inner(), input.until.into_inner())
+    }
+}
+
+pub(crate) fn statement_periods(
+    periods: Vec<StatementPeriodInput>,
+) -> Result<Vec<DomainStatementPeriod>, ComparativeStatementError> {
+    periods.into_iter().map(TryFrom::try_from).collect()
+}
+
+#[derive(SimpleObject)]
+pub struct StatementPeriod {
+    from: Date,
+    until: Date,
+}
+
+impl From<DomainStatementPeriod> for StatementPeriod {
+    fn from(period: DomainStatementPeriod) -> Self {
+        Self {
+            from: period.from.into(),
+            until: period.until.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ComparativeAmount {
+    amount: Decimal,
+    variance: Option<Decimal>,
+    variance_percentage: Option<Decimal>,
+}
+
+impl From<DomainComparativeAmount> for ComparativeAmount {
+    fn from(amount: DomainComparativeAmount) -> Self {
+        Self {
+            amount: amount.amount.into(),
+            variance: amount.variance.map(Decimal::from),
+            variance_percentage: amount.variance_percentage.map(Decimal::from),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ComparativeStatementLine {
+    ledger_account_id: UUID,
+    name: String,
+    depth: u32,
+    usd: Vec<ComparativeAmount>,
+    btc: Vec<ComparativeAmount>,
+}
+
+impl From<DomainComparativeStatementLine> for ComparativeStatementLine {
+    fn from(line: DomainComparativeStatementLine) -> Self {
+        Self {
+            ledger_account_id: line.id.into(),
+            name: line.name,
+            depth: line.depth as u32,
+            usd: line.usd.into_iter().map(ComparativeAmount::from).collect(),
+            btc: line.btc.into_iter().map(ComparativeAmount::from).collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ComparativeStatement {
+    name: String,
+    periods: Vec<StatementPeriod>,
+    lines: Vec<ComparativeStatementLine>,
+}
+
+impl From<DomainComparativeStatement> for ComparativeStatement {
+    fn from(statement: DomainComparativeStatement) -> Self {
+        Self {
+            name: statement.name,
+            periods: statement
+                .periods
+                .into_iter()
+                .map(StatementPeriod::from)
+                .collect(),
+            lines: statement
+                .lines
+                .into_iter()
+                .map(ComparativeStatementLine::from)
+                .collect(),
+        }
+    }
+}
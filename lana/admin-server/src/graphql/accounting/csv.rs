@@ -1,7 +1,9 @@
 use async_graphql::*;
 
 use crate::primitives::*;
-pub use lana_app::accounting::csv::{AccountingCsv as DomainAccountingCsv, AccountingCsvStatus};
+pub use lana_app::accounting::csv::{
+    AccountingCsv as DomainAccountingCsv, AccountingCsvFormat, AccountingCsvStatus,
+};
 use std::sync::Arc;
 
 #[derive(SimpleObject, Clone)]
@@ -50,6 +52,41 @@ pub struct LedgerAccountCsvCreateInput {
 }
 crate::mutation_payload! { LedgerAccountCsvCreatePayload, accounting_csv: AccountingCsv }
 
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ComparativeStatementType {
+    BalanceSheet,
+    ProfitAndLoss,
+}
+
+#[derive(InputObject)]
+pub struct ComparativeStatementCsvCreateInput {
+    pub statement_type: ComparativeStatementType,
+    pub periods: Vec<super::StatementPeriodInput>,
+}
+crate::mutation_payload! { ComparativeStatementCsvCreatePayload, accounting_csv: AccountingCsv }
+
+#[derive(InputObject)]
+pub struct CashFlowStatementCsvCreateInput {
+    pub period: super::StatementPeriodInput,
+}
+crate::mutation_payload! { CashFlowStatementCsvCreatePayload, accounting_csv: AccountingCsv }
+
+#[derive(InputObject)]
+pub struct GeneralLedgerExportCreateInput {
+    pub period: super::StatementPeriodInput,
+    #[graphql(default)]
+    pub format: AccountingCsvFormat,
+}
+crate::mutation_payload! { GeneralLedgerExportCreatePayload, accounting_csv: AccountingCsv }
+
+#[derive(InputObject)]
+pub struct TrialBalanceExportCreateInput {
+    pub period: super::StatementPeriodInput,
+    #[graphql(default)]
+    pub format: AccountingCsvFormat,
+}
+crate::mutation_payload! { TrialBalanceExportCreatePayload, accounting_csv: AccountingCsv }
+
 #[derive(InputObject)]
 pub struct AccountingCsvDownloadLinkGenerateInput {
     pub accounting_csv_id: UUID,
@@ -0,0 +1,234 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::fixed_asset::{
+    DepreciationMethod, FixedAsset as DomainFixedAsset, FixedAssetAccountCodes,
+    FixedAssetDepreciation as DomainFixedAssetDepreciation,
+    FixedAssetDisposal as DomainFixedAssetDisposal, FixedAssetRegister as DomainFixedAssetRegister,
+    FixedAssetRegisterLine as DomainFixedAssetRegisterLine, FixedAssetStatus,
+    FixedAssetsByCreatedAtCursor,
+};
+
+use crate::graphql::loader::LanaDataLoader;
+use crate::primitives::*;
+
+use super::ledger_transaction::LedgerTransaction;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct FixedAsset {
+    id: ID,
+    fixed_asset_id: UUID,
+    name: String,
+    description: String,
+    cost: UsdCents,
+    salvage_value: UsdCents,
+    useful_life_months: u32,
+    depreciation_method: DepreciationMethod,
+    acquired_on: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainFixedAsset>,
+}
+
+impl From<DomainFixedAsset> for FixedAsset {
+    fn from(asset: DomainFixedAsset) -> Self {
+        Self {
+            id: asset.id.to_global_id(),
+            fixed_asset_id: UUID::from(asset.id),
+            name: asset.name.clone(),
+            description: asset.description.clone(),
+            cost: asset.cost,
+            salvage_value: asset.salvage_value,
+            useful_life_months: asset.useful_life_months,
+            depreciation_method: asset.depreciation_method,
+            acquired_on: asset.acquired_on.into(),
+            created_at: asset.created_at().into(),
+            entity: Arc::new(asset),
+        }
+    }
+}
+
+#[ComplexObject]
+impl FixedAsset {
+    async fn status(&self) -> FixedAssetStatus {
+        self.entity.status()
+    }
+
+    async fn accumulated_depreciation(&self) -> UsdCents {
+        self.entity.accumulated_depreciation()
+    }
+
+    async fn book_value(&self) -> UsdCents {
+        self.entity.book_value()
+    }
+
+    async fn depreciations(&self) -> Vec<FixedAssetDepreciation> {
+        self.entity
+            .depreciations()
+            .into_iter()
+            .map(FixedAssetDepreciation::from)
+            .collect()
+    }
+
+    async fn disposal(&self) -> Option<FixedAssetDisposal> {
+        self.entity.disposal().map(FixedAssetDisposal::from)
+    }
+
+    async fn acquisition_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.acquisition_tx_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct FixedAssetDepreciation {
+    period_end: Date,
+    amount: UsdCents,
+
+    #[graphql(skip)]
+    entity: DomainFixedAssetDepreciation,
+}
+
+impl From<DomainFixedAssetDepreciation> for FixedAssetDepreciation {
+    fn from(depreciation: DomainFixedAssetDepreciation) -> Self {
+        Self {
+            period_end: depreciation.period_end.into(),
+            amount: depreciation.amount,
+            entity: depreciation,
+        }
+    }
+}
+
+#[ComplexObject]
+impl FixedAssetDepreciation {
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_tx_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct FixedAssetDisposal {
+    disposed_on: Date,
+    proceeds: UsdCents,
+    book_value: UsdCents,
+    accumulated_depreciation: UsdCents,
+    /// Positive for a gain, negative for a loss.
+    gain_or_loss: SignedUsdCents,
+
+    #[graphql(skip)]
+    entity: DomainFixedAssetDisposal,
+}
+
+impl From<DomainFixedAssetDisposal> for FixedAssetDisposal {
+    fn from(disposal: DomainFixedAssetDisposal) -> Self {
+        Self {
+            disposed_on: disposal.disposed_on.into(),
+            proceeds: disposal.proceeds,
+            book_value: disposal.book_value,
+            accumulated_depreciation: disposal.accumulated_depreciation,
+            gain_or_loss: disposal.gain_or_loss,
+            entity: disposal,
+        }
+    }
+}
+
+#[ComplexObject]
+impl FixedAssetDisposal {
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_tx_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct FixedAssetRegister {
+    as_of: Date,
+    total_cost: UsdCents,
+    total_accumulated_depreciation: UsdCents,
+    total_book_value: UsdCents,
+    lines: Vec<FixedAssetRegisterLine>,
+}
+
+impl From<DomainFixedAssetRegister> for FixedAssetRegister {
+    fn from(register: DomainFixedAssetRegister) -> Self {
+        Self {
+            as_of: register.as_of.into(),
+            total_cost: register.total_cost(),
+            total_accumulated_depreciation: register.total_accumulated_depreciation(),
+            total_book_value: register.total_book_value(),
+            lines: register
+                .lines
+                .into_iter()
+                .map(FixedAssetRegisterLine::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct FixedAssetRegisterLine {
+    fixed_asset_id: UUID,
+    name: String,
+    acquired_on: Date,
+    depreciation_method: DepreciationMethod,
+    useful_life_months: u32,
+    cost: UsdCents,
+    accumulated_depreciation: UsdCents,
+    book_value: UsdCents,
+}
+
+impl From<DomainFixedAssetRegisterLine> for FixedAssetRegisterLine {
+    fn from(line: DomainFixedAssetRegisterLine) -> Self {
+        Self {
+            fixed_asset_id: UUID::from(line.fixed_asset_id),
+            name: line.name,
+            acquired_on: line.acquired_on.into(),
+            depreciation_method: line.depreciation_method,
+            useful_life_months: line.useful_life_months,
+            cost: line.cost,
+            accumulated_depreciation: line.accumulated_depreciation,
+            book_value: line.book_value,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct FixedAssetAcquireInput {
+    pub name: String,
+    pub description: String,
+    pub cost: UsdCents,
+    #[graphql(default)]
+    pub salvage_value: UsdCents,
+    pub useful_life_months: u32,
+    pub depreciation_method: DepreciationMethod,
+    pub acquired_on: Date,
+    pub asset_account_code: String,
+    pub accumulated_depreciation_account_code: String,
+    pub depreciation_expense_account_code: String,
+    pub funding_account_code: String,
+}
+crate::mutation_payload! { FixedAssetAcquirePayload, fixed_asset: FixedAsset }
+
+#[derive(InputObject)]
+pub struct FixedAssetDisposeInput {
+    pub fixed_asset_id: UUID,
+    pub disposed_on: Date,
+    #[graphql(default)]
+    pub proceeds: UsdCents,
+    pub proceeds_account_code: String,
+    pub gain_or_loss_account_code: String,
+}
+crate::mutation_payload! { FixedAssetDisposePayload, fixed_asset: FixedAsset }
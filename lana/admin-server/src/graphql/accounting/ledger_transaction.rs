@@ -1,10 +1,11 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::ledger_transaction::{
-    LedgerTransaction as DomainLedgerTransaction, LedgerTransactionCursor,
+pub use lana_app::accounting::{
+    ledger_transaction::{LedgerTransaction as DomainLedgerTransaction, LedgerTransactionCursor},
+    manual_transaction::ManualTransactionLinks as DomainManualTransactionLinks,
 };
 
-use crate::primitives::*;
+use crate::{graphql::loader::LanaDataLoader, primitives::*};
 
 use super::JournalEntry;
 
@@ -35,6 +36,19 @@ impl LedgerTransaction {
             })
             .collect()
     }
+
+    async fn manual_transaction_links(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ManualTransactionLinks>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let links = app
+            .accounting()
+            .manual_transactions()
+            .links_for_ledger_transaction(sub, self.entity.id)
+            .await?;
+        Ok(links.map(ManualTransactionLinks::from))
+    }
 }
 
 impl From<DomainLedgerTransaction> for LedgerTransaction {
@@ -48,3 +62,63 @@ impl From<DomainLedgerTransaction> for LedgerTransaction {
         }
     }
 }
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct ManualTransactionLinks {
+    manual_transaction_id: UUID,
+
+    #[graphql(skip)]
+    entity: DomainManualTransactionLinks,
+}
+
+impl From<DomainManualTransactionLinks> for ManualTransactionLinks {
+    fn from(links: DomainManualTransactionLinks) -> Self {
+        Self {
+            manual_transaction_id: links.manual_transaction_id.into(),
+            entity: links,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransactionLinks {
+    async fn reversal_of(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        load_ledger_transaction(ctx, self.entity.reversal_of).await
+    }
+
+    async fn reversed_by(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        load_ledger_transaction(ctx, self.entity.reversed_by).await
+    }
+
+    async fn correction_of(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        load_ledger_transaction(ctx, self.entity.correction_of).await
+    }
+
+    async fn corrected_by(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        load_ledger_transaction(ctx, self.entity.corrected_by).await
+    }
+}
+
+async fn load_ledger_transaction(
+    ctx: &Context<'_>,
+    id: Option<LedgerTransactionId>,
+) -> async_graphql::Result<Option<LedgerTransaction>> {
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    let loader = ctx.data_unchecked::<LanaDataLoader>();
+    Ok(loader.load_one(id).await?)
+}
@@ -1,13 +1,122 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::manual_transaction::ManualEntryInput;
+pub use lana_app::accounting::manual_transaction::{
+    ManualEntryInput, ManualTransaction as DomainManualTransaction,
+    ManualTransactionEntry as DomainManualTransactionEntry, ManualTransactionStatus,
+    ManualTransactionsByCreatedAtCursor,
+};
 
-use crate::graphql::primitives::*;
-use crate::primitives::Date;
+use crate::graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
 
 use cala_ledger::DebitOrCredit;
 
-use super::ledger_transaction::LedgerTransaction;
+use super::{ledger_transaction::LedgerTransaction, recurring_entry::RecurringEntryTemplate};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ManualTransaction {
+    id: ID,
+    manual_transaction_id: UUID,
+    reference: String,
+    description: String,
+    effective: Option<Date>,
+    approval_process_id: Option<UUID>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainManualTransaction>,
+}
+
+impl From<DomainManualTransaction> for ManualTransaction {
+    fn from(tx: DomainManualTransaction) -> Self {
+        Self {
+            id: tx.id.to_global_id(),
+            manual_transaction_id: UUID::from(tx.id),
+            reference: tx.reference.clone(),
+            description: tx.description.clone(),
+            effective: tx.effective.map(Into::into),
+            approval_process_id: tx.approval_process_id.map(UUID::from),
+            created_at: tx.created_at().into(),
+            entity: Arc::new(tx),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransaction {
+    async fn status(&self) -> ManualTransactionStatus {
+        self.entity.status()
+    }
+
+    async fn rejection_reason(&self) -> Option<&str> {
+        self.entity.rejection_reason()
+    }
+
+    async fn entries(&self) -> Vec<ManualTransactionEntry> {
+        self.entity
+            .entries
+            .iter()
+            .map(ManualTransactionEntry::from)
+            .collect()
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(approval_process_id).await?)
+    }
+
+    /// The recurring entry template this transaction was posted from.
+    async fn recurring_entry_template(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<RecurringEntryTemplate>> {
+        let Some(template_id) = self.entity.recurring_entry_template_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(template_id).await?)
+    }
+
+    /// Present once the transaction has been posted.
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        if self.entity.status() != ManualTransactionStatus::Posted {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_transaction_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ManualTransactionEntry {
+    account_id: UUID,
+    amount: Decimal,
+    currency: String,
+    direction: DebitOrCredit,
+    description: String,
+}
+
+impl From<&DomainManualTransactionEntry> for ManualTransactionEntry {
+    fn from(entry: &DomainManualTransactionEntry) -> Self {
+        Self {
+            account_id: UUID::from(entry.account_id),
+            amount: entry.amount.into(),
+            currency: entry.currency.to_string(),
+            direction: entry.direction,
+            description: entry.description.clone(),
+        }
+    }
+}
 
 #[derive(InputObject)]
 pub struct ManualTransactionExecuteInput {
@@ -16,7 +125,28 @@ pub struct ManualTransactionExecuteInput {
     pub effective: Option<Date>,
     pub entries: Vec<ManualTransactionEntryInput>,
 }
-crate::mutation_payload! { ManualTransactionExecutePayload, transaction: LedgerTransaction }
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct ManualTransactionExecutePayload {
+    manual_transaction: ManualTransaction,
+}
+
+impl From<ManualTransaction> for ManualTransactionExecutePayload {
+    fn from(manual_transaction: ManualTransaction) -> Self {
+        Self { manual_transaction }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransactionExecutePayload {
+    /// The posted transaction, `null` while the manual transaction awaits approval.
+    async fn transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        self.manual_transaction.ledger_transaction(ctx).await
+    }
+}
 
 #[derive(InputObject)]
 pub struct ManualTransactionEntryInput {
@@ -43,3 +173,20 @@ impl TryFrom<ManualTransactionEntryInput> for ManualEntryInput {
         Ok(builder.build().expect("all fields provided"))
     }
 }
+
+#[derive(InputObject)]
+pub struct ManualTransactionReverseInput {
+    pub manual_transaction_id: UUID,
+    pub effective: Option<Date>,
+}
+crate::mutation_payload! { ManualTransactionReversePayload, manual_transaction: ManualTransaction }
+
+#[derive(InputObject)]
+pub struct ManualTransactionCorrectInput {
+    pub manual_transaction_id: UUID,
+    pub description: String,
+    pub reference: Option<String>,
+    pub effective: Option<Date>,
+    pub entries: Vec<ManualTransactionEntryInput>,
+}
+crate::mutation_payload! { ManualTransactionCorrectPayload, manual_transaction: ManualTransaction }
@@ -0,0 +1,143 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::recurring_entry::{
+    RecurringEntryFrequency, RecurringEntryRun as DomainRecurringEntryRun,
+    RecurringEntryTemplate as DomainRecurringEntryTemplate, RecurringEntryTemplateStatus,
+    RecurringEntryTemplatesByCreatedAtCursor,
+};
+
+use crate::graphql::loader::LanaDataLoader;
+use crate::primitives::*;
+
+use super::manual_transaction::{
+    ManualTransaction, ManualTransactionEntry, ManualTransactionEntryInput,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct RecurringEntryTemplate {
+    id: ID,
+    recurring_entry_template_id: UUID,
+    name: String,
+    description: String,
+    frequency: RecurringEntryFrequency,
+    start_date: Date,
+    end_date: Option<Date>,
+    auto_reverse: bool,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainRecurringEntryTemplate>,
+}
+
+impl From<DomainRecurringEntryTemplate> for RecurringEntryTemplate {
+    fn from(template: DomainRecurringEntryTemplate) -> Self {
+        Self {
+            id: template.id.to_global_id(),
+            recurring_entry_template_id: UUID::from(template.id),
+            name: template.name.clone(),
+            description: template.description.clone(),
+            frequency: template.frequency,
+            start_date: template.start_date.into(),
+            end_date: template.end_date.map(Into::into),
+            auto_reverse: template.auto_reverse,
+            created_at: template.created_at().into(),
+            entity: Arc::new(template),
+        }
+    }
+}
+
+#[ComplexObject]
+impl RecurringEntryTemplate {
+    async fn status(&self) -> RecurringEntryTemplateStatus {
+        self.entity.status()
+    }
+
+    async fn next_run_date(&self) -> Option<Date> {
+        self.entity.next_run_date().map(Into::into)
+    }
+
+    async fn entries(&self) -> Vec<ManualTransactionEntry> {
+        self.entity
+            .entries
+            .iter()
+            .map(ManualTransactionEntry::from)
+            .collect()
+    }
+
+    async fn runs(&self) -> Vec<RecurringEntryRun> {
+        self.entity
+            .runs()
+            .into_iter()
+            .map(RecurringEntryRun::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RecurringEntryRun {
+    scheduled_for: Date,
+    error: Option<String>,
+    reversal_date: Option<Date>,
+
+    #[graphql(skip)]
+    entity: DomainRecurringEntryRun,
+}
+
+impl From<DomainRecurringEntryRun> for RecurringEntryRun {
+    fn from(run: DomainRecurringEntryRun) -> Self {
+        Self {
+            scheduled_for: run.scheduled_for.into(),
+            error: run.error.clone(),
+            reversal_date: run.reversal_date.map(Into::into),
+            entity: run,
+        }
+    }
+}
+
+#[ComplexObject]
+impl RecurringEntryRun {
+    /// The transaction posted by this run, `null` if the run failed.
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let Some(id) = self.entity.manual_transaction_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+
+    /// The auto-reversal of this run, once it has been posted.
+    async fn reversal_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let Some(id) = self.entity.reversal_transaction_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct RecurringEntryTemplateCreateInput {
+    pub name: String,
+    pub description: String,
+    pub frequency: RecurringEntryFrequency,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+    #[graphql(default)]
+    pub auto_reverse: bool,
+    pub entries: Vec<ManualTransactionEntryInput>,
+}
+crate::mutation_payload! { RecurringEntryTemplateCreatePayload, recurring_entry_template: RecurringEntryTemplate }
+
+#[derive(InputObject)]
+pub struct RecurringEntryTemplateDeactivateInput {
+    pub recurring_entry_template_id: UUID,
+}
+crate::mutation_payload! { RecurringEntryTemplateDeactivatePayload, recurring_entry_template: RecurringEntryTemplate }
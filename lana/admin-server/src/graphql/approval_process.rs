@@ -2,13 +2,16 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
+use std::collections::HashSet;
+
 use super::{
-    access::User, approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*,
-    withdrawal::*,
+    access::User, accounting::ManualTransaction, approval_rules::*, committee::Committee,
+    credit_facility::*, loader::LanaDataLoader, policy::*, withdrawal::*,
 };
 
 pub use governance::{
-    ApprovalProcess as DomainApprovalProcess, ApprovalProcessStatus,
+    ApprovalProcess as DomainApprovalProcess, ApprovalProcessStage as DomainApprovalProcessStage,
+    ApprovalProcessStatus, ApprovalStageStatus,
     approval_process_cursor::ApprovalProcessesByCreatedAtCursor,
 };
 
@@ -41,13 +44,31 @@ impl From<DomainApprovalProcess> for ApprovalProcess {
 #[ComplexObject]
 impl ApprovalProcess {
     async fn rules(&self) -> ApprovalRules {
-        ApprovalRules::from(self.entity.rules)
+        ApprovalRules::from(self.entity.rules.clone())
+    }
+
+    /// Amount the rule tier of the policy was chosen by.
+    async fn amount(&self) -> Option<UsdCents> {
+        self.entity.amount
     }
 
     async fn denied_reason(&self) -> Option<&str> {
         self.entity.denied_reason()
     }
 
+    /// SLA of the policy at the time the process started.
+    async fn sla(&self) -> Option<ApprovalSla> {
+        self.entity.sla.clone().map(ApprovalSla::from)
+    }
+
+    async fn escalated(&self) -> bool {
+        self.entity.escalation().is_some()
+    }
+
+    async fn expired(&self) -> bool {
+        self.entity.is_expired()
+    }
+
     async fn policy(&self, ctx: &Context<'_>) -> async_graphql::Result<Policy> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let policy = loader
@@ -60,69 +81,100 @@ impl ApprovalProcess {
     async fn subject_can_submit_decision(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
 
-        let committee = if let Some(committee_id) = self.entity.committee_id() {
-            let loader = ctx.data_unchecked::<LanaDataLoader>();
-            let committee = loader
-                .load_one(committee_id)
-                .await?
-                .expect("committee not found");
-            Some(committee.entity)
-        } else {
-            None
-        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let committees: Vec<Committee> = loader
+            .load_many(self.entity.committee_ids())
+            .await?
+            .into_values()
+            .collect();
+        let committees: Vec<_> = committees.iter().map(|c| c.entity.as_ref()).collect();
 
         Ok(app
             .governance()
-            .subject_can_submit_decision(sub, &self.entity, committee.as_ref().map(AsRef::as_ref))
+            .subject_can_submit_decision(sub, &self.entity, &committees)
             .await?)
     }
 
     async fn voters(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<ApprovalProcessVoter>> {
-        if let Some(committee_id) = self.entity.committee_id() {
-            let loader = ctx.data_unchecked::<LanaDataLoader>();
-            let committee = loader
-                .load_one(committee_id)
-                .await?
-                .expect("committee not found");
-            let mut approvers = self.entity.approvers();
-            let mut deniers = self.entity.deniers();
-            let mut voters: Vec<_> = committee
-                .entity
-                .members()
+        let committee_ids = self.entity.committee_ids();
+        if committee_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let members: HashSet<_> = loader
+            .load_many(committee_ids)
+            .await?
+            .into_values()
+            .flat_map(|committee: Committee| committee.entity.members())
+            .collect();
+        let mut approvers = self.entity.approvers();
+        let mut deniers = self.entity.deniers();
+        let mut voters: Vec<_> = members
+            .into_iter()
+            .map(|member_id| ApprovalProcessVoter {
+                still_eligible: true,
+                did_vote: approvers.contains(&member_id) || deniers.contains(&member_id),
+                did_approve: approvers.remove(&member_id),
+                did_deny: deniers.remove(&member_id),
+                user_id: UserId::from(member_id),
+                voted_at: self.entity.member_voted_at(member_id).map(Into::into),
+                comment: self
+                    .entity
+                    .member_vote_comment(member_id)
+                    .map(str::to_string),
+                voted_by_id: self
+                    .entity
+                    .member_vote_delegate(member_id)
+                    .map(UserId::from),
+            })
+            .collect();
+        voters.extend(
+            approvers
                 .into_iter()
                 .map(|member_id| ApprovalProcessVoter {
-                    still_eligible: true,
-                    did_vote: approvers.contains(&member_id) || deniers.contains(&member_id),
-                    did_approve: approvers.remove(&member_id),
-                    did_deny: deniers.remove(&member_id),
                     user_id: UserId::from(member_id),
+                    still_eligible: false,
+                    did_vote: true,
+                    did_approve: true,
+                    did_deny: false,
                     voted_at: self.entity.member_voted_at(member_id).map(Into::into),
+                    comment: self
+                        .entity
+                        .member_vote_comment(member_id)
+                        .map(str::to_string),
+                    voted_by_id: self
+                        .entity
+                        .member_vote_delegate(member_id)
+                        .map(UserId::from),
                 })
-                .collect();
-            voters.extend(
-                approvers
-                    .into_iter()
-                    .map(|member_id| ApprovalProcessVoter {
-                        user_id: UserId::from(member_id),
-                        still_eligible: false,
-                        did_vote: true,
-                        did_approve: true,
-                        did_deny: false,
-                        voted_at: self.entity.member_voted_at(member_id).map(Into::into),
-                    })
-                    .chain(deniers.into_iter().map(|member_id| ApprovalProcessVoter {
+                .chain(deniers.into_iter().map(|member_id| {
+                    ApprovalProcessVoter {
                         user_id: UserId::from(member_id),
                         still_eligible: false,
                         did_vote: true,
                         did_approve: false,
                         did_deny: true,
                         voted_at: self.entity.member_voted_at(member_id).map(Into::into),
-                    })),
-            );
-            Ok(voters)
-        } else {
-            Ok(vec![])
-        }
+                        comment: self
+                            .entity
+                            .member_vote_comment(member_id)
+                            .map(str::to_string),
+                        voted_by_id: self
+                            .entity
+                            .member_vote_delegate(member_id)
+                            .map(UserId::from),
+                    }
+                })),
+        );
+        Ok(voters)
+    }
+
+    async fn stages(&self) -> Vec<ApprovalProcessStage> {
+        self.entity
+            .stages()
+            .into_iter()
+            .map(ApprovalProcessStage::from)
+            .collect()
     }
 
     async fn target(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcessTarget> {
@@ -164,6 +216,30 @@ impl ApprovalProcess {
                     .expect("disbursal not found");
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::ManualTransactionApproval => {
+                let manual_transaction = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<ManualTransactionId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("manual transaction not found");
+                Ok(ApprovalProcessTarget::ManualTransaction(manual_transaction))
+            }
+            ApprovalProcessType::RoleElevationApproval => {
+                let user = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<UserId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("user not found");
+                Ok(ApprovalProcessTarget::User(user))
+            }
         }
     }
 }
@@ -174,6 +250,8 @@ pub enum ApprovalProcessType {
     WithdrawalApproval,
     CreditFacilityApproval,
     DisbursalApproval,
+    ManualTransactionApproval,
+    RoleElevationApproval,
 }
 
 impl From<&governance::ApprovalProcessType> for ApprovalProcessType {
@@ -184,6 +262,10 @@ impl From<&governance::ApprovalProcessType> for ApprovalProcessType {
             Self::CreditFacilityApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_PROCESS {
+            Self::ManualTransactionApproval
+        } else if process_type == &lana_app::governance::APPROVE_ROLE_ELEVATION_PROCESS {
+            Self::RoleElevationApproval
         } else {
             panic!("Unknown approval process type: {:?}", process_type);
         }
@@ -200,6 +282,10 @@ pub struct ApprovalProcessVoter {
     did_approve: bool,
     did_deny: bool,
     voted_at: Option<Timestamp>,
+    /// Rationale given with the vote. For a denial this is its reason.
+    comment: Option<String>,
+    #[graphql(skip)]
+    voted_by_id: Option<UserId>,
 }
 
 #[ComplexObject]
@@ -213,6 +299,52 @@ impl ApprovalProcessVoter {
 
         Ok(users)
     }
+
+    /// Delegate that cast the vote for this voter.
+    async fn voted_by(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<User>> {
+        let Some(voted_by_id) = self.voted_by_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(voted_by_id).await?)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct ApprovalProcessStage {
+    index: usize,
+    status: ApprovalStageStatus,
+    rules: ApprovalRules,
+
+    #[graphql(skip)]
+    approver_ids: Vec<UserId>,
+}
+
+impl From<DomainApprovalProcessStage> for ApprovalProcessStage {
+    fn from(stage: DomainApprovalProcessStage) -> Self {
+        Self {
+            index: stage.index,
+            status: stage.status,
+            rules: ApprovalRules::from(stage.rules),
+            approver_ids: stage.approvers.into_iter().map(UserId::from).collect(),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ApprovalProcessStage {
+    /// Members who approved while this stage was in progress.
+    async fn approvers(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<User>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let users = loader
+            .load_many(self.approver_ids.iter().copied())
+            .await?
+            .into_values()
+            .collect();
+
+        Ok(users)
+    }
 }
 
 #[derive(async_graphql::Union)]
@@ -220,16 +352,29 @@ pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    ManualTransaction(ManualTransaction),
+    User(User),
 }
 
 #[derive(InputObject)]
 pub struct ApprovalProcessApproveInput {
     pub process_id: UUID,
+    /// Committee member whose delegated vote is cast.
+    pub on_behalf_of: Option<UUID>,
+    pub comment: Option<String>,
 }
 crate::mutation_payload! { ApprovalProcessApprovePayload, approval_process: ApprovalProcess }
 
 #[derive(InputObject)]
 pub struct ApprovalProcessDenyInput {
     pub process_id: UUID,
+    /// Committee member whose delegated vote is cast.
+    pub on_behalf_of: Option<UUID>,
 }
 crate::mutation_payload! { ApprovalProcessDenyPayload, approval_process: ApprovalProcess }
+
+#[derive(InputObject)]
+pub struct ApprovalProcessVoteWithdrawInput {
+    pub process_id: UUID,
+}
+crate::mutation_payload! { ApprovalProcessVoteWithdrawPayload, approval_process: ApprovalProcess }
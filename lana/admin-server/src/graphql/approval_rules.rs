@@ -1,11 +1,16 @@
 use async_graphql::*;
 
+use crate::primitives::*;
+
 use super::{committee::Committee, loader::LanaDataLoader};
 
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalRules {
     System(SystemApproval),
     CommitteeThreshold(CommitteeThreshold),
+    Sequential(SequentialApproval),
+    AllOf(AllOfApproval),
+    AnyOf(AnyOfApproval),
 }
 
 impl From<governance::ApprovalRules> for ApprovalRules {
@@ -21,6 +26,73 @@ impl From<governance::ApprovalRules> for ApprovalRules {
             governance::ApprovalRules::SystemAutoApprove => {
                 ApprovalRules::System(SystemApproval { auto_approve: true })
             }
+            governance::ApprovalRules::Sequential { stages } => {
+                ApprovalRules::Sequential(SequentialApproval {
+                    stages: stages.into_iter().map(ApprovalRules::from).collect(),
+                })
+            }
+            governance::ApprovalRules::AllOf { rules } => ApprovalRules::AllOf(AllOfApproval {
+                rules: rules.into_iter().map(ApprovalRules::from).collect(),
+            }),
+            governance::ApprovalRules::AnyOf { rules } => ApprovalRules::AnyOf(AnyOfApproval {
+                rules: rules.into_iter().map(ApprovalRules::from).collect(),
+            }),
+        }
+    }
+}
+
+#[derive(OneofObject)]
+pub enum ApprovalRulesInput {
+    /// Approve processes without any votes, the value is ignored.
+    SystemAutoApprove(bool),
+    CommitteeThreshold(CommitteeThresholdInput),
+    Sequential(Vec<ApprovalRulesInput>),
+    AllOf(Vec<ApprovalRulesInput>),
+    AnyOf(Vec<ApprovalRulesInput>),
+}
+
+impl From<ApprovalRulesInput> for governance::ApprovalRules {
+    fn from(input: ApprovalRulesInput) -> Self {
+        let convert = |rules: Vec<ApprovalRulesInput>| -> Vec<Self> {
+            rules.into_iter().map(Self::from).collect()
+        };
+        match input {
+            ApprovalRulesInput::SystemAutoApprove(_) => Self::SystemAutoApprove,
+            ApprovalRulesInput::CommitteeThreshold(input) => Self::CommitteeThreshold {
+                committee_id: input.committee_id.into(),
+                threshold: input.threshold,
+            },
+            ApprovalRulesInput::Sequential(stages) => Self::Sequential {
+                stages: convert(stages),
+            },
+            ApprovalRulesInput::AllOf(rules) => Self::AllOf {
+                rules: convert(rules),
+            },
+            ApprovalRulesInput::AnyOf(rules) => Self::AnyOf {
+                rules: convert(rules),
+            },
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CommitteeThresholdInput {
+    pub committee_id: UUID,
+    pub threshold: usize,
+}
+
+#[derive(SimpleObject)]
+pub(super) struct ApprovalRuleTier {
+    /// Largest amount covered by this tier, `null` for the open-ended top tier.
+    up_to: Option<UsdCents>,
+    rules: ApprovalRules,
+}
+
+impl From<governance::ApprovalRuleTier> for ApprovalRuleTier {
+    fn from(tier: governance::ApprovalRuleTier) -> Self {
+        Self {
+            up_to: tier.up_to,
+            rules: ApprovalRules::from(tier.rules),
         }
     }
 }
@@ -30,6 +102,23 @@ pub(super) struct SystemApproval {
     auto_approve: bool,
 }
 
+/// Stages decided one after another, votes count towards the stage in
+/// progress when they are cast.
+#[derive(SimpleObject)]
+pub(super) struct SequentialApproval {
+    stages: Vec<ApprovalRules>,
+}
+
+#[derive(SimpleObject)]
+pub(super) struct AllOfApproval {
+    rules: Vec<ApprovalRules>,
+}
+
+#[derive(SimpleObject)]
+pub(super) struct AnyOfApproval {
+    rules: Vec<ApprovalRules>,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub(super) struct CommitteeThreshold {
@@ -49,3 +138,52 @@ impl CommitteeThreshold {
         Ok(committee)
     }
 }
+
+/// Deadlines in hours from the start of a process.
+#[derive(SimpleObject)]
+pub(super) struct ApprovalSla {
+    remind_after_hours: Option<u32>,
+    escalation: Option<ApprovalEscalation>,
+    deny_after_hours: Option<u32>,
+}
+
+impl From<governance::ApprovalSla> for ApprovalSla {
+    fn from(sla: governance::ApprovalSla) -> Self {
+        Self {
+            remind_after_hours: sla.remind_after_hours,
+            escalation: sla.escalation.map(ApprovalEscalation::from),
+            deny_after_hours: sla.deny_after_hours,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub(super) struct ApprovalEscalation {
+    after_hours: u32,
+    threshold: usize,
+    #[graphql(skip)]
+    committee_id: governance::CommitteeId,
+}
+
+impl From<governance::ApprovalEscalation> for ApprovalEscalation {
+    fn from(escalation: governance::ApprovalEscalation) -> Self {
+        Self {
+            after_hours: escalation.after_hours,
+            threshold: escalation.threshold,
+            committee_id: escalation.committee_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ApprovalEscalation {
+    async fn committee(&self, ctx: &Context<'_>) -> async_graphql::Result<Committee> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let committee = loader
+            .load_one(self.committee_id)
+            .await?
+            .expect("committee not found");
+        Ok(committee)
+    }
+}
@@ -1,4 +1,6 @@
-use async_graphql::{ComplexObject, Context, ID, SimpleObject, Union, connection::CursorType};
+use async_graphql::{
+    ComplexObject, Context, Enum, ID, InputObject, SimpleObject, Union, connection::CursorType,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::primitives::*;
@@ -69,6 +71,111 @@ impl From<lana_app::audit::AuditEntry> for AuditEntry {
     }
 }
 
+#[derive(InputObject, Default)]
+pub struct AuditEntryFilter {
+    /// Subject as recorded, e.g. `user:<id>` or `system:<id>`.
+    pub subject: Option<String>,
+    /// Matches every object starting with the given value.
+    pub object: Option<String>,
+    pub action: Option<String>,
+    pub authorized: Option<bool>,
+    pub recorded_after: Option<Timestamp>,
+    pub recorded_before: Option<Timestamp>,
+}
+
+impl From<AuditEntryFilter> for lana_app::audit::AuditFilter {
+    fn from(filter: AuditEntryFilter) -> Self {
+        Self {
+            subject: filter.subject,
+            object: filter.object,
+            action: filter.action,
+            authorized: filter.authorized,
+            recorded_after: filter.recorded_after.map(|t| t.into_inner()),
+            recorded_before: filter.recorded_before.map(|t| t.into_inner()),
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum AuditExportFormat {
+    Csv,
+    Json,
+}
+
+impl From<AuditExportFormat> for lana_app::audit::AuditExportFormat {
+    fn from(format: AuditExportFormat) -> Self {
+        match format {
+            AuditExportFormat::Csv => Self::Csv,
+            AuditExportFormat::Json => Self::Json,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AuditExportInput {
+    pub filter: Option<AuditEntryFilter>,
+    pub format: AuditExportFormat,
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum AuditExportStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl From<lana_app::document::UploadStatus> for AuditExportStatus {
+    fn from(status: lana_app::document::UploadStatus) -> Self {
+        match status {
+            lana_app::document::UploadStatus::Pending => Self::Pending,
+            lana_app::document::UploadStatus::Completed => Self::Completed,
+            lana_app::document::UploadStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct AuditExport {
+    audit_export_id: UUID,
+    filename: String,
+    status: AuditExportStatus,
+    created_at: Timestamp,
+}
+
+impl From<lana_app::document::Document> for AuditExport {
+    fn from(document: lana_app::document::Document) -> Self {
+        Self {
+            audit_export_id: UUID::from(document.id),
+            status: document.upload_status().into(),
+            created_at: document.created_at().into(),
+            filename: document.filename,
+        }
+    }
+}
+
+crate::mutation_payload! { AuditExportPayload, audit_export: AuditExport }
+
+#[derive(SimpleObject)]
+pub struct AuditExportDownloadLink {
+    pub url: String,
+    pub audit_export_id: UUID,
+}
+
+impl From<lana_app::document::GeneratedDocumentDownloadLink> for AuditExportDownloadLink {
+    fn from(result: lana_app::document::GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            url: result.link,
+            audit_export_id: UUID::from(result.document_id),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AuditExportDownloadLinkGenerateInput {
+    pub audit_export_id: UUID,
+}
+crate::mutation_payload! { AuditExportDownloadLinkGeneratePayload, link: AuditExportDownloadLink }
+
 #[derive(Serialize, Deserialize)]
 pub struct AuditCursor {
     id: audit::AuditEntryId,
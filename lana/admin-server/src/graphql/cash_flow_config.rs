@@ -0,0 +1,43 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::cash_flow::ChartOfAccountsIntegrationConfig as DomainChartOfAccountsIntegrationConfig;
+
+#[derive(SimpleObject, Clone)]
+pub struct CashFlowStatementModuleConfig {
+    chart_of_accounts_id: Option<UUID>,
+    chart_of_accounts_cash_codes: Vec<String>,
+    chart_of_accounts_operating_codes: Vec<String>,
+    chart_of_accounts_investing_codes: Vec<String>,
+    chart_of_accounts_financing_codes: Vec<String>,
+
+    #[graphql(skip)]
+    pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
+}
+
+impl From<DomainChartOfAccountsIntegrationConfig> for CashFlowStatementModuleConfig {
+    fn from(value: DomainChartOfAccountsIntegrationConfig) -> Self {
+        let codes = |codes: &[lana_app::accounting::AccountCode]| {
+            codes.iter().map(|code| code.to_string()).collect()
+        };
+        Self {
+            chart_of_accounts_id: Some(value.chart_of_accounts_id.into()),
+            chart_of_accounts_cash_codes: codes(&value.chart_of_accounts_cash_codes),
+            chart_of_accounts_operating_codes: codes(&value.chart_of_accounts_operating_codes),
+            chart_of_accounts_investing_codes: codes(&value.chart_of_accounts_investing_codes),
+            chart_of_accounts_financing_codes: codes(&value.chart_of_accounts_financing_codes),
+            _entity: Arc::new(value),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CashFlowStatementModuleConfigureInput {
+    pub chart_of_accounts_cash_codes: Vec<String>,
+    pub chart_of_accounts_operating_codes: Vec<String>,
+    pub chart_of_accounts_investing_codes: Vec<String>,
+    pub chart_of_accounts_financing_codes: Vec<String>,
+}
+
+crate::mutation_payload! { CashFlowStatementModuleConfigurePayload, cash_flow_config: CashFlowStatementModuleConfig }
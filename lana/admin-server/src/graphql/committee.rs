@@ -4,7 +4,10 @@ use crate::primitives::*;
 
 use super::{access::User, loader::LanaDataLoader};
 
-pub use governance::{Committee as DomainCommittee, committee_cursor::CommitteesByCreatedAtCursor};
+pub use governance::{
+    Committee as DomainCommittee, CommitteeDelegation as DomainCommitteeDelegation,
+    committee_cursor::CommitteesByCreatedAtCursor,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -43,6 +46,57 @@ impl Committee {
 
         Ok(users)
     }
+
+    async fn delegations(&self) -> Vec<CommitteeDelegation> {
+        self.entity
+            .delegations()
+            .into_iter()
+            .map(CommitteeDelegation::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct CommitteeDelegation {
+    starts_at: Timestamp,
+    ends_at: Timestamp,
+    #[graphql(skip)]
+    delegator_id: UserId,
+    #[graphql(skip)]
+    delegate_id: UserId,
+}
+
+impl From<DomainCommitteeDelegation> for CommitteeDelegation {
+    fn from(delegation: DomainCommitteeDelegation) -> Self {
+        Self {
+            starts_at: delegation.starts_at.into(),
+            ends_at: delegation.ends_at.into(),
+            delegator_id: UserId::from(delegation.delegator_id),
+            delegate_id: UserId::from(delegation.delegate_id),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CommitteeDelegation {
+    /// Member whose votes are delegated.
+    async fn delegator(&self, ctx: &Context<'_>) -> async_graphql::Result<User> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader
+            .load_one(self.delegator_id)
+            .await?
+            .expect("user not found"))
+    }
+
+    /// User voting for the delegator.
+    async fn delegate(&self, ctx: &Context<'_>) -> async_graphql::Result<User> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader
+            .load_one(self.delegate_id)
+            .await?
+            .expect("user not found"))
+    }
 }
 
 #[derive(InputObject)]
@@ -64,3 +118,20 @@ pub struct CommitteeRemoveUserInput {
     pub user_id: UUID,
 }
 crate::mutation_payload! { CommitteeRemoveUserPayload, committee: Committee }
+
+#[derive(InputObject)]
+pub struct CommitteeDelegationGrantInput {
+    pub committee_id: UUID,
+    pub delegator_id: UUID,
+    pub delegate_id: UUID,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+crate::mutation_payload! { CommitteeDelegationGrantPayload, committee: Committee }
+
+#[derive(InputObject)]
+pub struct CommitteeDelegationRevokeInput {
+    pub committee_id: UUID,
+    pub delegator_id: UUID,
+}
+crate::mutation_payload! { CommitteeDelegationRevokePayload, committee: Committee }
@@ -15,6 +15,7 @@ pub struct Deposit {
     deposit_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: CurrencyCode,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,7 +28,8 @@ impl From<DomainDeposit> for Deposit {
             id: deposit.id.to_global_id(),
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
-            amount: deposit.amount,
+            amount: UsdCents::from(deposit.amount.minor_units()),
+            currency: deposit.amount.currency(),
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -55,6 +57,8 @@ impl Deposit {
 pub struct DepositRecordInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    #[graphql(default)]
+    pub currency: CurrencyCode,
     pub reference: Option<String>,
 }
 crate::mutation_payload! { DepositRecordPayload, deposit: Deposit }
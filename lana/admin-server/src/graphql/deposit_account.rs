@@ -1,13 +1,19 @@
 use async_graphql::{connection::*, *};
 
-use crate::primitives::*;
+use crate::{
+    graphql::{loader::LanaDataLoader, primitives::Decimal},
+    primitives::*,
+};
 
 pub use lana_app::deposit::{
     DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
     DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
 };
 
-use super::{customer::Customer, deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{
+    account_statement::*, customer::Customer, deposit::*, deposit_account_history::*,
+    scheduled_withdrawal::*, withdrawal::*,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -15,6 +21,7 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    currencies: Vec<CurrencyCode>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +34,7 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            currencies: account.currencies.clone(),
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -49,6 +57,35 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
     }
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct DepositAccountCurrencyBalance {
+    currency: CurrencyCode,
+
+    #[graphql(skip)]
+    balance: lana_app::deposit::DepositAccountCurrencyBalance,
+}
+
+impl From<lana_app::deposit::DepositAccountCurrencyBalance> for DepositAccountCurrencyBalance {
+    fn from(balance: lana_app::deposit::DepositAccountCurrencyBalance) -> Self {
+        Self {
+            currency: balance.currency,
+            balance,
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountCurrencyBalance {
+    async fn settled(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.balance.settled.to_major()?.into())
+    }
+
+    async fn pending(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.balance.pending.to_major()?.into())
+    }
+}
+
 #[ComplexObject]
 impl DepositAccount {
     async fn deposits(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Deposit>> {
@@ -69,6 +106,21 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn scheduled_withdrawals(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<ScheduledWithdrawal>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let schedules = app
+            .deposits()
+            .list_scheduled_withdrawals_for_account(sub, self.entity.id)
+            .await?;
+        Ok(schedules
+            .into_iter()
+            .map(ScheduledWithdrawal::from)
+            .collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -113,12 +165,45 @@ impl DepositAccount {
         .await
     }
 
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AccountStatementsByCreatedAtCursor, AccountStatement, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        crate::list_with_cursor!(
+            AccountStatementsByCreatedAtCursor,
+            AccountStatement,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .list_account_statements_for_account(sub, self.entity.id, query)
+        )
+    }
+
     async fn balance(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccountBalance> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let balance = app.deposits().account_balance(sub, self.entity.id).await?;
         Ok(DepositAccountBalance::from(balance))
     }
 
+    async fn balances(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountCurrencyBalance>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let balances = app.deposits().account_balances(sub, self.entity.id).await?;
+        Ok(balances
+            .into_iter()
+            .map(DepositAccountCurrencyBalance::from)
+            .collect())
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let customer = app
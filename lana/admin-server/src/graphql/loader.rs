@@ -6,8 +6,11 @@ use lana_app::{
     access::{error::CoreAccessError, user::error::UserError},
     accounting::{
         AccountingCsvId, Chart, LedgerAccountId, TransactionTemplateId,
+        accounting_period::error::AccountingPeriodError,
         chart_of_accounts::error::ChartOfAccountsError, csv::error::AccountingCsvError,
-        ledger_transaction::error::LedgerTransactionError,
+        fixed_asset::error::FixedAssetError, ledger_transaction::error::LedgerTransactionError,
+        manual_transaction::error::ManualTransactionError,
+        recurring_entry::error::RecurringEntryTemplateError,
         transaction_templates::error::TransactionTemplateError,
     },
     app::LanaApp,
@@ -19,8 +22,9 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, policy::*, terms_template::*,
+    access::*, account_statement::*, accounting::*, approval_process::*, committee::*,
+    credit_facility::*, custody::*, customer::*, deposit::*, deposit_account::*, document::*,
+    payment_file::*, policy::*, scheduled_withdrawal::*, statement_import::*, terms_template::*,
     withdrawal::*,
 };
 
@@ -239,6 +243,70 @@ impl Loader<DepositId> for LanaLoader {
     }
 }
 
+impl Loader<StatementImportId> for LanaLoader {
+    type Value = StatementImport;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[StatementImportId],
+    ) -> Result<HashMap<StatementImportId, StatementImport>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_statement_imports(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<AccountStatementId> for LanaLoader {
+    type Value = AccountStatement;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[AccountStatementId],
+    ) -> Result<HashMap<AccountStatementId, AccountStatement>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_account_statements(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ScheduledWithdrawalId> for LanaLoader {
+    type Value = ScheduledWithdrawal;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[ScheduledWithdrawalId],
+    ) -> Result<HashMap<ScheduledWithdrawalId, ScheduledWithdrawal>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_scheduled_withdrawals(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<PaymentFileId> for LanaLoader {
+    type Value = PaymentFile;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[PaymentFileId],
+    ) -> Result<HashMap<PaymentFileId, PaymentFile>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_payment_files(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositAccountId> for LanaLoader {
     type Value = DepositAccount;
     type Error = Arc<CoreDepositError>;
@@ -356,6 +424,74 @@ impl Loader<LedgerAccountId> for LanaLoader {
     }
 }
 
+impl Loader<AccountingPeriodId> for LanaLoader {
+    type Value = AccountingPeriod;
+    type Error = Arc<AccountingPeriodError>;
+
+    async fn load(
+        &self,
+        keys: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, AccountingPeriod>, Self::Error> {
+        self.app
+            .accounting()
+            .accounting_periods()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ManualTransactionId> for LanaLoader {
+    type Value = ManualTransaction;
+    type Error = Arc<ManualTransactionError>;
+
+    async fn load(
+        &self,
+        keys: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, ManualTransaction>, Self::Error> {
+        self.app
+            .accounting()
+            .manual_transactions()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<RecurringEntryTemplateId> for LanaLoader {
+    type Value = RecurringEntryTemplate;
+    type Error = Arc<RecurringEntryTemplateError>;
+
+    async fn load(
+        &self,
+        keys: &[RecurringEntryTemplateId],
+    ) -> Result<HashMap<RecurringEntryTemplateId, RecurringEntryTemplate>, Self::Error> {
+        self.app
+            .accounting()
+            .recurring_entry_templates()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<FixedAssetId> for LanaLoader {
+    type Value = FixedAsset;
+    type Error = Arc<FixedAssetError>;
+
+    async fn load(
+        &self,
+        keys: &[FixedAssetId],
+    ) -> Result<HashMap<FixedAssetId, FixedAsset>, Self::Error> {
+        self.app
+            .accounting()
+            .fixed_assets()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<AccountingCsvId> for LanaLoader {
     type Value = AccountingCsv;
     type Error = Arc<AccountingCsvError>;
@@ -1,9 +1,11 @@
+mod account_statement;
 mod accounting;
 mod approval_process;
 mod approval_rules;
 mod audit;
 mod authenticated_subject;
 mod balance_sheet_config;
+mod cash_flow_config;
 mod committee;
 mod credit_config;
 mod credit_facility;
@@ -16,10 +18,13 @@ mod deposit_account_history;
 mod deposit_config;
 mod document;
 mod loader;
+mod payment_file;
 mod price;
 mod primitives;
 mod profit_and_loss_config;
 mod report;
+mod scheduled_withdrawal;
+mod statement_import;
 mod sumsub;
 mod terms;
 mod terms_template;
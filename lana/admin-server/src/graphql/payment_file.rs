@@ -0,0 +1,142 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{loader::LanaDataLoader, withdrawal::Withdrawal};
+
+pub use lana_app::deposit::{
+    PaymentFile as DomainPaymentFile, PaymentFileEntry as DomainPaymentFileEntry,
+    PaymentFileEntryStatus, PaymentFileStatus, PaymentFilesByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct PaymentFile {
+    id: ID,
+    payment_file_id: UUID,
+    message_id: String,
+    status: PaymentFileStatus,
+    total_amount: UsdCents,
+    currency: CurrencyCode,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainPaymentFile>,
+}
+
+impl From<DomainPaymentFile> for PaymentFile {
+    fn from(payment_file: DomainPaymentFile) -> Self {
+        PaymentFile {
+            id: payment_file.id.to_global_id(),
+            payment_file_id: UUID::from(payment_file.id),
+            message_id: payment_file.message_id.clone(),
+            status: payment_file.status(),
+            total_amount: UsdCents::from(payment_file.total_amount().minor_units()),
+            currency: payment_file.currency(),
+            created_at: payment_file.created_at().into(),
+            entity: Arc::new(payment_file),
+        }
+    }
+}
+
+#[ComplexObject]
+impl PaymentFile {
+    async fn last_error(&self) -> Option<&str> {
+        self.entity.last_error()
+    }
+
+    async fn entries(&self) -> Vec<PaymentFileEntry> {
+        self.entity
+            .entries
+            .iter()
+            .map(|entry| PaymentFileEntry::new(&self.entity, entry))
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct PaymentFileEntry {
+    withdrawal_id: UUID,
+    end_to_end_id: String,
+    amount: UsdCents,
+    currency: CurrencyCode,
+    reference: String,
+    beneficiary_name: String,
+    beneficiary_iban: String,
+    beneficiary_bic: Option<String>,
+    status: PaymentFileEntryStatus,
+    rejection_reason: Option<String>,
+
+    #[graphql(skip)]
+    domain_withdrawal_id: WithdrawalId,
+}
+
+impl PaymentFileEntry {
+    fn new(payment_file: &DomainPaymentFile, entry: &DomainPaymentFileEntry) -> Self {
+        PaymentFileEntry {
+            withdrawal_id: UUID::from(entry.withdrawal_id),
+            end_to_end_id: entry.end_to_end_id.clone(),
+            amount: UsdCents::from(entry.amount.minor_units()),
+            currency: entry.amount.currency(),
+            reference: entry.reference.clone(),
+            beneficiary_name: entry.beneficiary.name.clone(),
+            beneficiary_iban: entry.beneficiary.iban.clone(),
+            beneficiary_bic: entry.beneficiary.bic.clone(),
+            status: payment_file.entry_status(entry.withdrawal_id),
+            rejection_reason: payment_file
+                .entry_rejection_reason(entry.withdrawal_id)
+                .map(str::to_string),
+            domain_withdrawal_id: entry.withdrawal_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl PaymentFileEntry {
+    async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Withdrawal> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let withdrawal = loader
+            .load_one(self.domain_withdrawal_id)
+            .await?
+            .expect("withdrawal not found");
+        Ok(withdrawal)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentFileGeneratePayload {
+    payment_file: Option<PaymentFile>,
+}
+
+impl From<Option<PaymentFile>> for PaymentFileGeneratePayload {
+    fn from(payment_file: Option<PaymentFile>) -> Self {
+        Self { payment_file }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PaymentStatusReportImportInput {
+    pub file: Upload,
+}
+crate::mutation_payload! { PaymentStatusReportImportPayload, payment_file: PaymentFile }
+
+#[derive(InputObject)]
+pub struct PaymentFileDownloadLinkGenerateInput {
+    pub payment_file_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentFileDownloadLinkGeneratePayload {
+    payment_file_id: UUID,
+    link: String,
+}
+
+impl PaymentFileDownloadLinkGeneratePayload {
+    pub fn new(payment_file_id: UUID, link: String) -> Self {
+        Self {
+            payment_file_id,
+            link,
+        }
+    }
+}
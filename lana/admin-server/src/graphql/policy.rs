@@ -31,7 +31,20 @@ impl From<DomainPolicy> for Policy {
 #[ComplexObject]
 impl Policy {
     async fn rules(&self) -> ApprovalRules {
-        ApprovalRules::from(self.entity.rules)
+        ApprovalRules::from(self.entity.rules.clone())
+    }
+
+    async fn rule_tiers(&self) -> Vec<ApprovalRuleTier> {
+        self.entity
+            .rule_tiers
+            .iter()
+            .cloned()
+            .map(ApprovalRuleTier::from)
+            .collect()
+    }
+
+    async fn sla(&self) -> Option<ApprovalSla> {
+        self.entity.sla.clone().map(ApprovalSla::from)
     }
 }
 
@@ -43,3 +56,85 @@ pub struct PolicyAssignCommitteeInput {
 }
 
 mutation_payload! { PolicyAssignCommitteePayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct ApprovalRuleTierInput {
+    pub up_to: Option<UsdCents>,
+    /// Committee deciding processes in this tier, omit to auto-approve them.
+    pub committee_id: Option<UUID>,
+    #[graphql(default)]
+    pub threshold: usize,
+}
+
+impl From<ApprovalRuleTierInput> for governance::ApprovalRuleTier {
+    fn from(input: ApprovalRuleTierInput) -> Self {
+        let rules = match input.committee_id {
+            Some(committee_id) => governance::ApprovalRules::CommitteeThreshold {
+                committee_id: committee_id.into(),
+                threshold: input.threshold,
+            },
+            None => governance::ApprovalRules::SystemAutoApprove,
+        };
+        Self {
+            up_to: input.up_to,
+            rules,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PolicyRuleTiersUpdateInput {
+    pub policy_id: UUID,
+    /// Ordered by ascending `upTo`, the last tier must be open-ended. An
+    /// empty list removes the tiers so the base rules apply to every process.
+    pub tiers: Vec<ApprovalRuleTierInput>,
+}
+
+mutation_payload! { PolicyRuleTiersUpdatePayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct PolicyRulesUpdateInput {
+    pub policy_id: UUID,
+    pub rules: ApprovalRulesInput,
+}
+
+mutation_payload! { PolicyRulesUpdatePayload, policy: Policy }
+
+#[derive(InputObject)]
+pub struct ApprovalEscalationInput {
+    pub after_hours: u32,
+    pub committee_id: UUID,
+    pub threshold: usize,
+}
+
+#[derive(InputObject)]
+pub struct PolicySlaUpdateInput {
+    pub policy_id: UUID,
+    pub remind_after_hours: Option<u32>,
+    pub escalation: Option<ApprovalEscalationInput>,
+    pub deny_after_hours: Option<u32>,
+}
+
+impl PolicySlaUpdateInput {
+    /// Leaving every deadline out removes the SLA from the policy.
+    pub fn sla(&self) -> Option<governance::ApprovalSla> {
+        let sla = governance::ApprovalSla {
+            remind_after_hours: self.remind_after_hours,
+            escalation: self
+                .escalation
+                .as_ref()
+                .map(|escalation| governance::ApprovalEscalation {
+                    after_hours: escalation.after_hours,
+                    committee_id: escalation.committee_id.into(),
+                    threshold: escalation.threshold,
+                }),
+            deny_after_hours: self.deny_after_hours,
+        };
+        (sla.remind_after_hours.is_some()
+            || sla.escalation.is_some()
+            || sla.deny_after_hours.is_some())
+        .then_some(sla)
+    }
+}
+
+mutation_payload! { PolicySlaUpdatePayload, policy: Policy }
@@ -0,0 +1,150 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    deposit_account::DepositAccount,
+    loader::LanaDataLoader,
+    withdrawal::{Withdrawal, WithdrawalBeneficiary, WithdrawalBeneficiaryInput},
+};
+
+pub use lana_app::deposit::{
+    ScheduledWithdrawal as DomainScheduledWithdrawal,
+    ScheduledWithdrawalExecution as DomainScheduledWithdrawalExecution, ScheduledWithdrawalStatus,
+    WithdrawalFrequency, WithdrawalRecurrence,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ScheduledWithdrawal {
+    id: ID,
+    scheduled_withdrawal_id: UUID,
+    account_id: UUID,
+    amount: UsdCents,
+    currency: CurrencyCode,
+    frequency: WithdrawalFrequency,
+    interval: u32,
+    start_date: Date,
+    end_date: Option<Date>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainScheduledWithdrawal>,
+}
+
+impl From<DomainScheduledWithdrawal> for ScheduledWithdrawal {
+    fn from(schedule: DomainScheduledWithdrawal) -> Self {
+        ScheduledWithdrawal {
+            id: schedule.id.to_global_id(),
+            scheduled_withdrawal_id: UUID::from(schedule.id),
+            account_id: UUID::from(schedule.deposit_account_id),
+            amount: UsdCents::from(schedule.amount.minor_units()),
+            currency: schedule.amount.currency(),
+            frequency: schedule.recurrence.frequency,
+            interval: schedule.recurrence.interval,
+            start_date: schedule.start_date.into(),
+            end_date: schedule.end_date.map(Into::into),
+            created_at: schedule.created_at().into(),
+            entity: Arc::new(schedule),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ScheduledWithdrawal {
+    async fn reference(&self) -> Option<&str> {
+        self.entity.reference.as_deref()
+    }
+
+    async fn status(&self) -> ScheduledWithdrawalStatus {
+        self.entity.status()
+    }
+
+    async fn next_run_date(&self) -> Option<Date> {
+        self.entity.next_run_date().map(Into::into)
+    }
+
+    async fn last_error(&self) -> Option<String> {
+        self.entity.last_error()
+    }
+
+    async fn executions(&self) -> Vec<ScheduledWithdrawalExecution> {
+        self.entity
+            .executions()
+            .into_iter()
+            .rev()
+            .map(ScheduledWithdrawalExecution::from)
+            .collect()
+    }
+
+    async fn beneficiary(&self) -> Option<WithdrawalBeneficiary> {
+        self.entity
+            .beneficiary
+            .as_ref()
+            .map(WithdrawalBeneficiary::from)
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct ScheduledWithdrawalExecution {
+    scheduled_for: Date,
+    withdrawal_id: Option<UUID>,
+    error: Option<String>,
+
+    #[graphql(skip)]
+    entity: DomainScheduledWithdrawalExecution,
+}
+
+impl From<DomainScheduledWithdrawalExecution> for ScheduledWithdrawalExecution {
+    fn from(execution: DomainScheduledWithdrawalExecution) -> Self {
+        ScheduledWithdrawalExecution {
+            scheduled_for: execution.scheduled_for.into(),
+            withdrawal_id: execution.withdrawal_id.map(UUID::from),
+            error: execution.error.clone(),
+            entity: execution,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ScheduledWithdrawalExecution {
+    async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Withdrawal>> {
+        let Some(withdrawal_id) = self.entity.withdrawal_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(withdrawal_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct ScheduledWithdrawalCreateInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    #[graphql(default)]
+    pub currency: CurrencyCode,
+    pub reference: Option<String>,
+    pub beneficiary: Option<WithdrawalBeneficiaryInput>,
+    pub frequency: WithdrawalFrequency,
+    #[graphql(default = 1)]
+    pub interval: u32,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+}
+crate::mutation_payload! { ScheduledWithdrawalCreatePayload, scheduled_withdrawal: ScheduledWithdrawal }
+
+#[derive(InputObject)]
+pub struct ScheduledWithdrawalCancelInput {
+    pub scheduled_withdrawal_id: UUID,
+}
+crate::mutation_payload! { ScheduledWithdrawalCancelPayload, scheduled_withdrawal: ScheduledWithdrawal }
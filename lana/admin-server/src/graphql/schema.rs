@@ -3,9 +3,10 @@ use async_graphql::{Context, Object, types::connection::*};
 use std::io::Read;
 
 use lana_app::{
-    accounting::csv::AccountingCsvsByCreatedAtCursor,
+    accounting::csv::{AccountingCsvType, AccountingCsvsByCreatedAtCursor},
     accounting_init::constants::{
-        BALANCE_SHEET_NAME, PROFIT_AND_LOSS_STATEMENT_NAME, TRIAL_BALANCE_STATEMENT_NAME,
+        BALANCE_SHEET_NAME, CASH_FLOW_STATEMENT_NAME, PROFIT_AND_LOSS_STATEMENT_NAME,
+        TRIAL_BALANCE_STATEMENT_NAME,
     },
     app::LanaApp,
 };
@@ -13,10 +14,12 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    access::*, accounting::*, approval_process::*, audit::*, authenticated_subject::*,
-    balance_sheet_config::*, committee::*, credit_config::*, credit_facility::*, custody::*,
-    customer::*, dashboard::*, deposit::*, deposit_config::*, document::*, loader::*, policy::*,
-    price::*, profit_and_loss_config::*, report::*, sumsub::*, terms_template::*, withdrawal::*,
+    access::*, account_statement::*, accounting::*, approval_process::*, audit::*,
+    authenticated_subject::*, balance_sheet_config::*, cash_flow_config::*, committee::*,
+    credit_config::*, credit_facility::*, custody::*, customer::*, dashboard::*, deposit::*,
+    deposit_config::*, document::*, loader::*, payment_file::*, policy::*, price::*,
+    profit_and_loss_config::*, report::*, scheduled_withdrawal::*, statement_import::*, sumsub::*,
+    terms_template::*, withdrawal::*,
 };
 
 pub struct Query;
@@ -184,6 +187,117 @@ impl Query {
         )
     }
 
+    async fn scheduled_withdrawal(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ScheduledWithdrawal>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ScheduledWithdrawal,
+            ctx,
+            app.deposits().find_scheduled_withdrawal_by_id(sub, id)
+        )
+    }
+
+    async fn statement_import(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<StatementImport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            StatementImport,
+            ctx,
+            app.deposits().find_statement_import_by_id(sub, id)
+        )
+    }
+
+    async fn statement_imports(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<StatementImportsByCreatedAtCursor, StatementImport, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            StatementImportsByCreatedAtCursor,
+            StatementImport,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_statement_imports(sub, query)
+        )
+    }
+
+    async fn statement_imports_pending_review(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<StatementImportsByCreatedAtCursor, StatementImport, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            StatementImportsByCreatedAtCursor,
+            StatementImport,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .list_statement_imports_pending_review(sub, query)
+        )
+    }
+
+    async fn account_statement(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AccountStatement>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            AccountStatement,
+            ctx,
+            app.deposits().find_account_statement_by_id(sub, id)
+        )
+    }
+
+    async fn payment_file(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<PaymentFile>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            PaymentFile,
+            ctx,
+            app.deposits().find_payment_file_by_id(sub, id)
+        )
+    }
+
+    async fn payment_files(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<PaymentFilesByCreatedAtCursor, PaymentFile, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            PaymentFilesByCreatedAtCursor,
+            PaymentFile,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_payment_files(sub, query)
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -555,6 +669,122 @@ impl Query {
         )
     }
 
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransaction,
+            ctx,
+            app.accounting()
+                .manual_transactions()
+                .find_manual_transaction_by_id(sub, id)
+        )
+    }
+
+    async fn manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transactions()
+                .list_manual_transactions(sub, query)
+        )
+    }
+
+    async fn accounting_period(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AccountingPeriod>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().find_by_id(sub, id)
+        )
+    }
+
+    async fn accounting_periods(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AccountingPeriodsByCreatedAtCursor, AccountingPeriod, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            AccountingPeriodsByCreatedAtCursor,
+            AccountingPeriod,
+            ctx,
+            after,
+            first,
+            |query| app.accounting().accounting_periods().list(sub, query)
+        )
+    }
+
+    async fn recurring_entry_template(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<RecurringEntryTemplate>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            RecurringEntryTemplate,
+            ctx,
+            app.accounting()
+                .recurring_entry_templates()
+                .find_by_id(sub, id)
+        )
+    }
+
+    async fn recurring_entry_templates(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            RecurringEntryTemplatesByCreatedAtCursor,
+            RecurringEntryTemplate,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            RecurringEntryTemplatesByCreatedAtCursor,
+            RecurringEntryTemplate,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .recurring_entry_templates()
+                .list(sub, query)
+        )
+    }
+
     async fn journal_entries(
         &self,
         ctx: &Context<'_>,
@@ -618,6 +848,20 @@ impl Query {
         Ok(ChartOfAccounts::from(chart))
     }
 
+    async fn chart_of_accounts_export(
+        &self,
+        ctx: &Context<'_>,
+        format: ChartExportFormat,
+    ) -> async_graphql::Result<String> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let chart = loader
+            .load_one(CHART_REF)
+            .await?
+            .unwrap_or_else(|| panic!("Chart of accounts not found for ref {:?}", CHART_REF));
+        Ok(app.export_chart_of_accounts(sub, chart.id, format).await?)
+    }
+
     async fn balance_sheet(
         &self,
         ctx: &Context<'_>,
@@ -658,6 +902,108 @@ impl Query {
         Ok(ProfitAndLossStatement::from(profit_and_loss))
     }
 
+    async fn comparative_balance_sheet(
+        &self,
+        ctx: &Context<'_>,
+        periods: Vec<StatementPeriodInput>,
+    ) -> async_graphql::Result<ComparativeStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .accounting()
+            .balance_sheets()
+            .comparative_balance_sheet(
+                sub,
+                BALANCE_SHEET_NAME.to_string(),
+                statement_periods(periods)?,
+            )
+            .await?;
+        Ok(ComparativeStatement::from(statement))
+    }
+
+    async fn comparative_profit_and_loss_statement(
+        &self,
+        ctx: &Context<'_>,
+        periods: Vec<StatementPeriodInput>,
+    ) -> async_graphql::Result<ComparativeStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .accounting()
+            .profit_and_loss()
+            .comparative_pl_statement(
+                sub,
+                PROFIT_AND_LOSS_STATEMENT_NAME.to_string(),
+                statement_periods(periods)?,
+            )
+            .await?;
+        Ok(ComparativeStatement::from(statement))
+    }
+
+    async fn cash_flow_statement(
+        &self,
+        ctx: &Context<'_>,
+        from: Date,
+        until: Date,
+    ) -> async_graphql::Result<CashFlowStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .accounting()
+            .cash_flows()
+            .cash_flow_statement(
+                sub,
+                CASH_FLOW_STATEMENT_NAME.to_string(),
+                from.into_inner(),
+                until.into_inner(),
+            )
+            .await?;
+        Ok(CashFlowStatement::from(statement))
+    }
+
+    async fn fixed_asset(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<FixedAsset>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            FixedAsset,
+            ctx,
+            app.accounting().fixed_assets().find_by_id(sub, id)
+        )
+    }
+
+    async fn fixed_assets(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<FixedAssetsByCreatedAtCursor, FixedAsset, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            FixedAssetsByCreatedAtCursor,
+            FixedAsset,
+            ctx,
+            after,
+            first,
+            |query| app.accounting().fixed_assets().list(sub, query)
+        )
+    }
+
+    async fn fixed_asset_register(
+        &self,
+        ctx: &Context<'_>,
+        as_of: Date,
+    ) -> async_graphql::Result<FixedAssetRegister> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let register = app
+            .accounting()
+            .fixed_assets()
+            .register(sub, as_of.into_inner())
+            .await?;
+        Ok(FixedAssetRegister::from(register))
+    }
+
     async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
         let app = ctx.data_unchecked::<LanaApp>();
         let usd_cents_per_btc = app.price().usd_cents_per_btc().await?;
@@ -681,8 +1027,10 @@ impl Query {
         ctx: &Context<'_>,
         first: i32,
         after: Option<String>,
+        filter: Option<AuditEntryFilter>,
     ) -> async_graphql::Result<Connection<AuditCursor, AuditEntry>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let filter = filter.unwrap_or_default();
         query(
             after,
             None,
@@ -693,6 +1041,7 @@ impl Query {
                 let res = app
                     .list_audit(
                         sub,
+                        filter.into(),
                         es_entity::PaginatedQueryArgs {
                             first,
                             after: after.map(lana_app::audit::AuditCursor::from),
@@ -714,6 +1063,18 @@ impl Query {
         .await
     }
 
+    async fn audit_export(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AuditExport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .find_audit_export(sub, id.into())
+            .await?
+            .map(AuditExport::from))
+    }
+
     async fn deposit_config(
         &self,
         ctx: &Context<'_>,
@@ -768,6 +1129,19 @@ impl Query {
         Ok(config.map(ProfitAndLossStatementModuleConfig::from))
     }
 
+    async fn cash_flow_statement_config(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<CashFlowStatementModuleConfig>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let config = app
+            .accounting()
+            .cash_flows()
+            .get_chart_of_accounts_integration_config(sub, CASH_FLOW_STATEMENT_NAME.to_string())
+            .await?;
+        Ok(config.map(CashFlowStatementModuleConfig::from))
+    }
+
     async fn accounting_csvs_for_ledger_account_id(
         &self,
         ctx: &Context<'_>,
@@ -856,26 +1230,55 @@ impl Mutation {
         input: UserUpdateRoleInput,
     ) -> async_graphql::Result<UserUpdateRolePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-        let UserUpdateRoleInput { id, role_id } = input;
+        let UserUpdateRoleInput {
+            id,
+            role_id,
+            expires_at,
+        } = input;
         exec_mutation!(
             UserUpdateRolePayload,
             User,
             ctx,
-            app.access().update_role_of_user(sub, id, role_id)
+            app.access().update_role_of_user(
+                sub,
+                id,
+                role_id,
+                expires_at.map(|expires_at| expires_at.into_inner())
+            )
         )
     }
 
-    async fn user_revoke_role(
+    async fn user_role_elevation_request(
         &self,
         ctx: &Context<'_>,
-        input: UserRevokeRoleInput,
-    ) -> async_graphql::Result<UserRevokeRolePayload> {
+        input: UserRoleElevationRequestInput,
+    ) -> async_graphql::Result<UserRoleElevationRequestPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let UserRoleElevationRequestInput {
+            id,
+            role_id,
+            expires_at,
+        } = input;
         exec_mutation!(
-            UserRevokeRolePayload,
+            UserRoleElevationRequestPayload,
             User,
             ctx,
-            app.access().revoke_role_from_user(sub, input.id)
+            app.access()
+                .request_role_elevation(sub, id, role_id, expires_at.into_inner())
+        )
+    }
+
+    async fn user_revoke_role(
+        &self,
+        ctx: &Context<'_>,
+        input: UserRevokeRoleInput,
+    ) -> async_graphql::Result<UserRevokeRolePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            UserRevokeRolePayload,
+            User,
+            ctx,
+            app.access().revoke_role_from_user(sub, input.id)
         )
     }
 
@@ -932,6 +1335,46 @@ impl Mutation {
         )
     }
 
+    async fn role_add_condition(
+        &self,
+        ctx: &Context<'_>,
+        input: RoleAddConditionInput,
+    ) -> async_graphql::Result<RoleAddConditionPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            RoleAddConditionPayload,
+            Role,
+            ctx,
+            app.access().add_condition_to_role(
+                sub,
+                input.role_id,
+                input.action,
+                input.condition.into()
+            )
+        )
+    }
+
+    async fn role_remove_condition(
+        &self,
+        ctx: &Context<'_>,
+        input: RoleRemoveConditionInput,
+    ) -> async_graphql::Result<RoleRemoveConditionPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            RoleRemoveConditionPayload,
+            Role,
+            ctx,
+            app.access().remove_condition_from_role(
+                sub,
+                input.role_id,
+                input.action,
+                input.condition.into()
+            )
+        )
+    }
+
     async fn customer_create(
         &self,
         ctx: &Context<'_>,
@@ -1049,7 +1492,7 @@ impl Mutation {
 
         exec_mutation!(
             ManualTransactionExecutePayload,
-            LedgerTransaction,
+            ManualTransaction,
             ctx,
             app.accounting().execute_manual_transaction(
                 sub,
@@ -1062,6 +1505,209 @@ impl Mutation {
         )
     }
 
+    pub async fn manual_transaction_reverse(
+        &self,
+        ctx: &Context<'_>,
+        input: ManualTransactionReverseInput,
+    ) -> async_graphql::Result<ManualTransactionReversePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ManualTransactionReversePayload,
+            ManualTransaction,
+            ctx,
+            app.accounting().reverse_manual_transaction(
+                sub,
+                input.manual_transaction_id,
+                input.effective.map(|ts| ts.into_inner())
+            )
+        )
+    }
+
+    pub async fn manual_transaction_correct(
+        &self,
+        ctx: &Context<'_>,
+        input: ManualTransactionCorrectInput,
+    ) -> async_graphql::Result<ManualTransactionCorrectPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut entries = Vec::with_capacity(input.entries.len());
+        for entry in input.entries.into_iter() {
+            entries.push(entry.try_into()?);
+        }
+
+        exec_mutation!(
+            ManualTransactionCorrectPayload,
+            ManualTransaction,
+            ctx,
+            app.accounting().correct_manual_transaction(
+                sub,
+                CHART_REF.0,
+                input.manual_transaction_id,
+                input.reference,
+                input.description,
+                input.effective.map(|ts| ts.into_inner()),
+                entries
+            )
+        )
+    }
+
+    pub async fn accounting_period_create(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCreateInput,
+    ) -> async_graphql::Result<AccountingPeriodCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodCreatePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().create(
+                sub,
+                input.period_type,
+                input.period_start.into_inner()
+            )
+        )
+    }
+
+    pub async fn accounting_period_start_closing(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodStartClosingInput,
+    ) -> async_graphql::Result<AccountingPeriodStartClosingPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodStartClosingPayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting()
+                .accounting_periods()
+                .start_closing(sub, input.accounting_period_id)
+        )
+    }
+
+    pub async fn accounting_period_close(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCloseInput,
+    ) -> async_graphql::Result<AccountingPeriodClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let retained_earnings_code = input
+            .retained_earnings_account_code
+            .map(|code| code.parse::<lana_app::accounting::AccountCode>())
+            .transpose()?;
+        exec_mutation!(
+            AccountingPeriodClosePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().close_accounting_period(
+                sub,
+                CHART_REF.0,
+                PROFIT_AND_LOSS_STATEMENT_NAME,
+                input.accounting_period_id,
+                retained_earnings_code
+            )
+        )
+    }
+
+    pub async fn recurring_entry_template_create(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringEntryTemplateCreateInput,
+    ) -> async_graphql::Result<RecurringEntryTemplateCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut entries = Vec::with_capacity(input.entries.len());
+        for entry in input.entries.into_iter() {
+            entries.push(entry.try_into()?);
+        }
+
+        exec_mutation!(
+            RecurringEntryTemplateCreatePayload,
+            RecurringEntryTemplate,
+            ctx,
+            app.accounting().create_recurring_entry_template(
+                sub,
+                CHART_REF.0,
+                input.name,
+                input.description,
+                input.frequency,
+                input.start_date.into_inner(),
+                input.end_date.map(|d| d.into_inner()),
+                input.auto_reverse,
+                entries
+            )
+        )
+    }
+
+    pub async fn recurring_entry_template_deactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringEntryTemplateDeactivateInput,
+    ) -> async_graphql::Result<RecurringEntryTemplateDeactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringEntryTemplateDeactivatePayload,
+            RecurringEntryTemplate,
+            ctx,
+            app.accounting()
+                .recurring_entry_templates()
+                .deactivate(sub, input.recurring_entry_template_id)
+        )
+    }
+
+    pub async fn fixed_asset_acquire(
+        &self,
+        ctx: &Context<'_>,
+        input: FixedAssetAcquireInput,
+    ) -> async_graphql::Result<FixedAssetAcquirePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let account_codes = FixedAssetAccountCodes {
+            asset: input.asset_account_code.parse()?,
+            accumulated_depreciation: input.accumulated_depreciation_account_code.parse()?,
+            depreciation_expense: input.depreciation_expense_account_code.parse()?,
+            funding: input.funding_account_code.parse()?,
+        };
+        exec_mutation!(
+            FixedAssetAcquirePayload,
+            FixedAsset,
+            ctx,
+            app.accounting().acquire_fixed_asset(
+                sub,
+                CHART_REF.0,
+                input.name,
+                input.description,
+                input.cost,
+                input.salvage_value,
+                input.useful_life_months,
+                input.depreciation_method,
+                input.acquired_on.into_inner(),
+                account_codes
+            )
+        )
+    }
+
+    pub async fn fixed_asset_dispose(
+        &self,
+        ctx: &Context<'_>,
+        input: FixedAssetDisposeInput,
+    ) -> async_graphql::Result<FixedAssetDisposePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            FixedAssetDisposePayload,
+            FixedAsset,
+            ctx,
+            app.accounting().dispose_fixed_asset(
+                sub,
+                CHART_REF.0,
+                input.fixed_asset_id,
+                input.disposed_on.into_inner(),
+                input.proceeds,
+                input.proceeds_account_code.parse()?,
+                input.gain_or_loss_account_code.parse()?
+            )
+        )
+    }
+
     pub async fn deposit_record(
         &self,
         ctx: &Context<'_>,
@@ -1076,7 +1722,7 @@ impl Mutation {
             app.deposits().record_deposit(
                 sub,
                 input.deposit_account_id,
-                input.amount,
+                Money::new(input.amount.into_inner(), input.currency),
                 input.reference
             )
         )
@@ -1088,6 +1734,10 @@ impl Mutation {
         input: WithdrawalInitiateInput,
     ) -> async_graphql::Result<WithdrawalInitiatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let beneficiary = input
+            .beneficiary
+            .map(DomainWithdrawalBeneficiary::try_from)
+            .transpose()?;
         exec_mutation!(
             WithdrawalInitiatePayload,
             Withdrawal,
@@ -1095,8 +1745,9 @@ impl Mutation {
             app.deposits().initiate_withdrawal(
                 sub,
                 input.deposit_account_id,
-                input.amount,
-                input.reference
+                Money::new(input.amount.into_inner(), input.currency),
+                input.reference,
+                beneficiary
             )
         )
     }
@@ -1130,6 +1781,240 @@ impl Mutation {
         )
     }
 
+    pub async fn scheduled_withdrawal_create(
+        &self,
+        ctx: &Context<'_>,
+        input: ScheduledWithdrawalCreateInput,
+    ) -> async_graphql::Result<ScheduledWithdrawalCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let beneficiary = input
+            .beneficiary
+            .map(DomainWithdrawalBeneficiary::try_from)
+            .transpose()?;
+        let recurrence = WithdrawalRecurrence::new(input.frequency, input.interval)?;
+        exec_mutation!(
+            ScheduledWithdrawalCreatePayload,
+            ScheduledWithdrawal,
+            ctx,
+            app.deposits().create_scheduled_withdrawal(
+                sub,
+                input.deposit_account_id,
+                Money::new(input.amount.into_inner(), input.currency),
+                input.reference,
+                beneficiary,
+                recurrence,
+                input.start_date.into_inner(),
+                input.end_date.map(|d| d.into_inner())
+            )
+        )
+    }
+
+    pub async fn scheduled_withdrawal_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: ScheduledWithdrawalCancelInput,
+    ) -> async_graphql::Result<ScheduledWithdrawalCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ScheduledWithdrawalCancelPayload,
+            ScheduledWithdrawal,
+            ctx,
+            app.deposits()
+                .cancel_scheduled_withdrawal(sub, input.scheduled_withdrawal_id)
+        )
+    }
+
+    pub async fn deposit_statement_import(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositStatementImportInput,
+    ) -> async_graphql::Result<DepositStatementImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?;
+        let mut data = Vec::new();
+        file.content.read_to_end(&mut data)?;
+        exec_mutation!(
+            DepositStatementImportPayload,
+            StatementImport,
+            ctx,
+            app.deposits()
+                .import_deposit_statement(sub, input.format, file.filename, data)
+        )
+    }
+
+    pub async fn statement_line_resolve(
+        &self,
+        ctx: &Context<'_>,
+        input: StatementLineResolveInput,
+    ) -> async_graphql::Result<StatementLineResolvePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let line_idx = usize::try_from(input.line_idx)?;
+        exec_mutation!(
+            StatementLineResolvePayload,
+            StatementImport,
+            ctx,
+            app.deposits().resolve_statement_line(
+                sub,
+                input.statement_import_id,
+                line_idx,
+                input.deposit_account_id
+            )
+        )
+    }
+
+    pub async fn statement_line_dismiss(
+        &self,
+        ctx: &Context<'_>,
+        input: StatementLineDismissInput,
+    ) -> async_graphql::Result<StatementLineDismissPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let line_idx = usize::try_from(input.line_idx)?;
+        exec_mutation!(
+            StatementLineDismissPayload,
+            StatementImport,
+            ctx,
+            app.deposits()
+                .dismiss_statement_line(sub, input.statement_import_id, line_idx)
+        )
+    }
+
+    pub async fn statement_import_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: StatementImportDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<StatementImportDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .generate_statement_import_download_link(sub, input.statement_import_id)
+            .await?;
+        Ok(StatementImportDownloadLinkGeneratePayload::new(
+            input.statement_import_id,
+            link.link,
+        ))
+    }
+
+    pub async fn payment_file_generate(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<PaymentFileGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let payment_file = app
+            .deposits()
+            .generate_payment_file(sub)
+            .await?
+            .map(PaymentFile::from);
+        if let Some(payment_file) = payment_file.as_ref() {
+            let loader = ctx.data_unchecked::<LanaDataLoader>();
+            loader
+                .feed_one(payment_file.entity.id, payment_file.clone())
+                .await;
+        }
+        Ok(PaymentFileGeneratePayload::from(payment_file))
+    }
+
+    pub async fn account_statement_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountStatementGenerateInput,
+    ) -> async_graphql::Result<AccountStatementGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountStatementGeneratePayload,
+            AccountStatement,
+            ctx,
+            app.deposits().generate_account_statement(
+                sub,
+                input.deposit_account_id,
+                input.currency,
+                input.period_start.into_inner(),
+                input.period_end.into_inner()
+            )
+        )
+    }
+
+    async fn audit_export(
+        &self,
+        ctx: &Context<'_>,
+        input: AuditExportInput,
+    ) -> async_graphql::Result<AuditExportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let export = app
+            .export_audit(
+                sub,
+                input.filter.unwrap_or_default().into(),
+                input.format.into(),
+            )
+            .await?;
+        Ok(AuditExportPayload::from(AuditExport::from(export)))
+    }
+
+    async fn audit_export_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: AuditExportDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<AuditExportDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let result = app
+            .generate_audit_export_download_link(sub, input.audit_export_id.into())
+            .await?;
+        Ok(AuditExportDownloadLinkGeneratePayload::from(
+            AuditExportDownloadLink::from(result),
+        ))
+    }
+
+    pub async fn account_statement_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountStatementDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<AccountStatementDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .generate_account_statement_download_link(sub, input.account_statement_id, input.format)
+            .await?;
+        Ok(AccountStatementDownloadLinkGeneratePayload::new(
+            input.account_statement_id,
+            input.format,
+            link.link,
+        ))
+    }
+
+    pub async fn payment_status_report_import(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentStatusReportImportInput,
+    ) -> async_graphql::Result<PaymentStatusReportImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?;
+        let mut data = Vec::new();
+        file.content.read_to_end(&mut data)?;
+        exec_mutation!(
+            PaymentStatusReportImportPayload,
+            PaymentFile,
+            ctx,
+            app.deposits().import_payment_status_report(sub, data)
+        )
+    }
+
+    pub async fn payment_file_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentFileDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<PaymentFileDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .generate_payment_file_download_link(sub, input.payment_file_id)
+            .await?;
+        Ok(PaymentFileDownloadLinkGeneratePayload::new(
+            input.payment_file_id,
+            link,
+        ))
+    }
+
     async fn terms_template_create(
         &self,
         ctx: &Context<'_>,
@@ -1515,6 +2400,45 @@ impl Mutation {
         )
     }
 
+    async fn committee_delegation_grant(
+        &self,
+        ctx: &Context<'_>,
+        input: CommitteeDelegationGrantInput,
+    ) -> async_graphql::Result<CommitteeDelegationGrantPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CommitteeDelegationGrantPayload,
+            Committee,
+            ctx,
+            app.governance().grant_committee_delegation(
+                sub,
+                input.committee_id,
+                input.delegator_id,
+                input.delegate_id,
+                input.starts_at.into_inner(),
+                input.ends_at.into_inner()
+            )
+        )
+    }
+
+    async fn committee_delegation_revoke(
+        &self,
+        ctx: &Context<'_>,
+        input: CommitteeDelegationRevokeInput,
+    ) -> async_graphql::Result<CommitteeDelegationRevokePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CommitteeDelegationRevokePayload,
+            Committee,
+            ctx,
+            app.governance().revoke_committee_delegation(
+                sub,
+                input.committee_id,
+                input.delegator_id
+            )
+        )
+    }
+
     async fn policy_assign_committee(
         &self,
         ctx: &Context<'_>,
@@ -1534,6 +2458,54 @@ impl Mutation {
         )
     }
 
+    async fn policy_rules_update(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicyRulesUpdateInput,
+    ) -> async_graphql::Result<PolicyRulesUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PolicyRulesUpdatePayload,
+            Policy,
+            ctx,
+            app.governance()
+                .update_policy_rules(sub, input.policy_id, input.rules.into())
+        )
+    }
+
+    async fn policy_rule_tiers_update(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicyRuleTiersUpdateInput,
+    ) -> async_graphql::Result<PolicyRuleTiersUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PolicyRuleTiersUpdatePayload,
+            Policy,
+            ctx,
+            app.governance().update_policy_rule_tiers(
+                sub,
+                input.policy_id,
+                input.tiers.into_iter().map(Into::into).collect()
+            )
+        )
+    }
+
+    async fn policy_sla_update(
+        &self,
+        ctx: &Context<'_>,
+        input: PolicySlaUpdateInput,
+    ) -> async_graphql::Result<PolicySlaUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PolicySlaUpdatePayload,
+            Policy,
+            ctx,
+            app.governance()
+                .update_policy_sla(sub, input.policy_id, input.sla())
+        )
+    }
+
     async fn approval_process_approve(
         &self,
         ctx: &Context<'_>,
@@ -1544,7 +2516,12 @@ impl Mutation {
             ApprovalProcessApprovePayload,
             ApprovalProcess,
             ctx,
-            app.governance().approve_process(sub, input.process_id)
+            app.governance().approve_process(
+                sub,
+                input.process_id,
+                input.on_behalf_of.map(Into::into),
+                input.comment
+            )
         )
     }
 
@@ -1559,7 +2536,26 @@ impl Mutation {
             ApprovalProcessDenyPayload,
             ApprovalProcess,
             ctx,
-            app.governance().deny_process(sub, input.process_id, reason)
+            app.governance().deny_process(
+                sub,
+                input.process_id,
+                input.on_behalf_of.map(Into::into),
+                reason
+            )
+        )
+    }
+
+    async fn approval_process_vote_withdraw(
+        &self,
+        ctx: &Context<'_>,
+        input: ApprovalProcessVoteWithdrawInput,
+    ) -> async_graphql::Result<ApprovalProcessVoteWithdrawPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ApprovalProcessVoteWithdrawPayload,
+            ApprovalProcess,
+            ctx,
+            app.governance().withdraw_vote(sub, input.process_id)
         )
     }
 
@@ -1647,6 +2643,99 @@ impl Mutation {
         Ok(ChartOfAccountsCsvImportPayload { success: res })
     }
 
+    async fn chart_of_accounts_json_import(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsJsonImportInput,
+    ) -> async_graphql::Result<ChartOfAccountsJsonImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let ChartOfAccountsJsonImportInput { chart_id, file } = input;
+
+        let mut file = file.value(ctx)?.content;
+
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+
+        let res = app
+            .accounting()
+            .import_json(sub, chart_id.into(), data, TRIAL_BALANCE_STATEMENT_NAME)
+            .await?;
+
+        Ok(ChartOfAccountsJsonImportPayload { success: res })
+    }
+
+    async fn chart_of_accounts_node_rename(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeRenameInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeRenamePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let ChartOfAccountsNodeRenameInput {
+            chart_id,
+            code,
+            name,
+        } = input;
+
+        let chart = app
+            .accounting()
+            .chart_of_accounts()
+            .rename_node(sub, ChartId::from(chart_id), &code.parse()?, name.parse()?)
+            .await?;
+
+        Ok(ChartOfAccountsNodeRenamePayload::from(
+            ChartOfAccounts::from(chart),
+        ))
+    }
+
+    async fn chart_of_accounts_node_move(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeMoveInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeMovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let ChartOfAccountsNodeMoveInput {
+            chart_id,
+            code,
+            parent_code,
+        } = input;
+        let parent_code = parent_code.map(|code| code.parse()).transpose()?;
+
+        let chart = app
+            .accounting()
+            .chart_of_accounts()
+            .move_node(sub, ChartId::from(chart_id), &code.parse()?, parent_code)
+            .await?;
+
+        Ok(ChartOfAccountsNodeMovePayload::from(ChartOfAccounts::from(
+            chart,
+        )))
+    }
+
+    async fn chart_of_accounts_node_deactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeDeactivateInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeDeactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let ChartOfAccountsNodeDeactivateInput { chart_id, code } = input;
+
+        let chart = app
+            .accounting()
+            .deactivate_chart_node(
+                sub,
+                chart_id.into(),
+                code.parse()?,
+                BALANCE_SHEET_NAME,
+                PROFIT_AND_LOSS_STATEMENT_NAME,
+                CASH_FLOW_STATEMENT_NAME,
+            )
+            .await?;
+
+        Ok(ChartOfAccountsNodeDeactivatePayload::from(
+            ChartOfAccounts::from(chart),
+        ))
+    }
+
     async fn balance_sheet_configure(
         &self,
         ctx: &Context<'_>,
@@ -1722,6 +2811,47 @@ impl Mutation {
         ))
     }
 
+    async fn cash_flow_statement_configure(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowStatementModuleConfigureInput,
+    ) -> async_graphql::Result<CashFlowStatementModuleConfigurePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let chart = loader
+            .load_one(CHART_REF)
+            .await?
+            .unwrap_or_else(|| panic!("Chart of accounts not found for ref {:?}", CHART_REF));
+
+        let parse = |codes: Vec<String>| {
+            codes
+                .iter()
+                .map(|code| code.parse())
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let config_values = lana_app::cash_flow::ChartOfAccountsIntegrationConfig::builder()
+            .chart_of_accounts_id(chart.id)
+            .chart_of_accounts_cash_codes(parse(input.chart_of_accounts_cash_codes)?)
+            .chart_of_accounts_operating_codes(parse(input.chart_of_accounts_operating_codes)?)
+            .chart_of_accounts_investing_codes(parse(input.chart_of_accounts_investing_codes)?)
+            .chart_of_accounts_financing_codes(parse(input.chart_of_accounts_financing_codes)?)
+            .build()?;
+        let config = app
+            .accounting()
+            .cash_flows()
+            .set_chart_of_accounts_integration_config(
+                sub,
+                CASH_FLOW_STATEMENT_NAME.to_string(),
+                chart.as_ref(),
+                config_values,
+            )
+            .await?;
+        Ok(CashFlowStatementModuleConfigurePayload::from(
+            CashFlowStatementModuleConfig::from(config),
+        ))
+    }
+
     pub async fn ledger_account_csv_create(
         &self,
         ctx: &Context<'_>,
@@ -1738,6 +2868,103 @@ impl Mutation {
         Ok(LedgerAccountCsvCreatePayload::from(csv))
     }
 
+    pub async fn comparative_statement_csv_create(
+        &self,
+        ctx: &Context<'_>,
+        input: ComparativeStatementCsvCreateInput,
+    ) -> async_graphql::Result<ComparativeStatementCsvCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let (csv_type, reference) = match input.statement_type {
+            ComparativeStatementType::BalanceSheet => {
+                (AccountingCsvType::BalanceSheet, BALANCE_SHEET_NAME)
+            }
+            ComparativeStatementType::ProfitAndLoss => (
+                AccountingCsvType::ProfitAndLoss,
+                PROFIT_AND_LOSS_STATEMENT_NAME,
+            ),
+        };
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_comparative_statement_csv(
+                sub,
+                csv_type,
+                reference.to_string(),
+                statement_periods(input.periods)?,
+            )
+            .await?;
+
+        let csv = AccountingCsv::from(csv);
+        Ok(ComparativeStatementCsvCreatePayload::from(csv))
+    }
+
+    pub async fn cash_flow_statement_csv_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CashFlowStatementCsvCreateInput,
+    ) -> async_graphql::Result<CashFlowStatementCsvCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_cash_flow_statement_csv(
+                sub,
+                CASH_FLOW_STATEMENT_NAME.to_string(),
+                lana_app::accounting::comparative_statement::StatementPeriod::try_from(
+                    input.period,
+                )?,
+            )
+            .await?;
+
+        let csv = AccountingCsv::from(csv);
+        Ok(CashFlowStatementCsvCreatePayload::from(csv))
+    }
+
+    pub async fn general_ledger_export_create(
+        &self,
+        ctx: &Context<'_>,
+        input: GeneralLedgerExportCreateInput,
+    ) -> async_graphql::Result<GeneralLedgerExportCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_general_ledger_export(
+                sub,
+                lana_app::accounting::comparative_statement::StatementPeriod::try_from(
+                    input.period,
+                )?,
+                input.format,
+            )
+            .await?;
+
+        let csv = AccountingCsv::from(csv);
+        Ok(GeneralLedgerExportCreatePayload::from(csv))
+    }
+
+    pub async fn trial_balance_export_create(
+        &self,
+        ctx: &Context<'_>,
+        input: TrialBalanceExportCreateInput,
+    ) -> async_graphql::Result<TrialBalanceExportCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let csv = app
+            .accounting()
+            .csvs()
+            .create_trial_balance_export(
+                sub,
+                TRIAL_BALANCE_STATEMENT_NAME.to_string(),
+                lana_app::accounting::comparative_statement::StatementPeriod::try_from(
+                    input.period,
+                )?,
+                input.format,
+            )
+            .await?;
+
+        let csv = AccountingCsv::from(csv);
+        Ok(TrialBalanceExportCreatePayload::from(csv))
+    }
+
     pub async fn accounting_csv_download_link_generate(
         &self,
         ctx: &Context<'_>,
@@ -0,0 +1,161 @@
+use async_graphql::*;
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+use super::{deposit::Deposit, deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    ImportedStatementLine, StatementEntryDirection, StatementFormat,
+    StatementImport as DomainStatementImport, StatementImportStatus,
+    StatementImportsByCreatedAtCursor, StatementLineStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct StatementImport {
+    id: ID,
+    statement_import_id: UUID,
+    format: StatementFormat,
+    filename: String,
+    status: StatementImportStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainStatementImport>,
+}
+
+impl From<DomainStatementImport> for StatementImport {
+    fn from(statement_import: DomainStatementImport) -> Self {
+        StatementImport {
+            id: statement_import.id.to_global_id(),
+            statement_import_id: UUID::from(statement_import.id),
+            format: statement_import.format,
+            filename: statement_import.filename.clone(),
+            status: statement_import.status(),
+            created_at: statement_import.created_at().into(),
+            entity: Arc::new(statement_import),
+        }
+    }
+}
+
+#[ComplexObject]
+impl StatementImport {
+    async fn lines(&self) -> Vec<StatementImportLine> {
+        self.entity
+            .lines
+            .iter()
+            .map(StatementImportLine::from)
+            .collect()
+    }
+
+    async fn lines_pending_review(&self) -> Vec<StatementImportLine> {
+        self.entity
+            .lines_pending_review()
+            .map(StatementImportLine::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct StatementImportLine {
+    line_idx: i32,
+    booking_date: Date,
+    direction: StatementEntryDirection,
+    amount: Decimal,
+    currency: String,
+    reference: Option<String>,
+    bank_reference: Option<String>,
+    statement_account: Option<String>,
+    remittance_info: Option<String>,
+    counterparty: Option<String>,
+    status: StatementLineStatus,
+    review_reason: Option<String>,
+
+    #[graphql(skip)]
+    deposit_account_id: Option<DepositAccountId>,
+    #[graphql(skip)]
+    deposit_id: Option<DepositId>,
+}
+
+impl From<&ImportedStatementLine> for StatementImportLine {
+    fn from(line: &ImportedStatementLine) -> Self {
+        StatementImportLine {
+            line_idx: line.idx as i32,
+            booking_date: line.line.booking_date.into(),
+            direction: line.line.direction,
+            amount: line.line.amount.into(),
+            currency: line.line.currency.clone(),
+            reference: line.line.reference.clone(),
+            bank_reference: line.line.bank_reference.clone(),
+            statement_account: line.line.statement_account.clone(),
+            remittance_info: line.line.remittance_info.clone(),
+            counterparty: line.line.counterparty.clone(),
+            status: line.status,
+            review_reason: line.review_reason.clone(),
+            deposit_account_id: line.deposit_account_id,
+            deposit_id: line.deposit_id,
+        }
+    }
+}
+
+#[ComplexObject]
+impl StatementImportLine {
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<DepositAccount>> {
+        let Some(deposit_account_id) = self.deposit_account_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_account_id).await?)
+    }
+
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Deposit>> {
+        let Some(deposit_id) = self.deposit_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositStatementImportInput {
+    pub file: Upload,
+    pub format: StatementFormat,
+}
+crate::mutation_payload! { DepositStatementImportPayload, statement_import: StatementImport }
+
+#[derive(InputObject)]
+pub struct StatementLineResolveInput {
+    pub statement_import_id: UUID,
+    pub line_idx: i32,
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { StatementLineResolvePayload, statement_import: StatementImport }
+
+#[derive(InputObject)]
+pub struct StatementLineDismissInput {
+    pub statement_import_id: UUID,
+    pub line_idx: i32,
+}
+crate::mutation_payload! { StatementLineDismissPayload, statement_import: StatementImport }
+
+#[derive(InputObject)]
+pub struct StatementImportDownloadLinkGenerateInput {
+    pub statement_import_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct StatementImportDownloadLinkGeneratePayload {
+    statement_import_id: UUID,
+    link: String,
+}
+
+impl StatementImportDownloadLinkGeneratePayload {
+    pub fn new(statement_import_id: UUID, link: String) -> Self {
+        Self {
+            statement_import_id,
+            link,
+        }
+    }
+}
@@ -4,10 +4,12 @@ use crate::primitives::*;
 
 use super::{
     approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+    payment_file::PaymentFile,
 };
 
 pub use lana_app::deposit::{
-    Withdrawal as DomainWithdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor,
+    Withdrawal as DomainWithdrawal, WithdrawalBeneficiary as DomainWithdrawalBeneficiary,
+    WithdrawalStatus, WithdrawalsByCreatedAtCursor, error::CoreDepositError,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -18,6 +20,7 @@ pub struct Withdrawal {
     account_id: UUID,
     approval_process_id: UUID,
     amount: UsdCents,
+    currency: CurrencyCode,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -32,7 +35,8 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
             approval_process_id: UUID::from(withdraw.approval_process_id),
-            amount: withdraw.amount,
+            amount: UsdCents::from(withdraw.amount.minor_units()),
+            currency: withdraw.amount.currency(),
             entity: Arc::new(withdraw),
         }
     }
@@ -54,6 +58,21 @@ impl Withdrawal {
             .unwrap_or_else(|| self.entity.status()))
     }
 
+    async fn beneficiary(&self) -> Option<WithdrawalBeneficiary> {
+        self.entity
+            .beneficiary
+            .as_ref()
+            .map(WithdrawalBeneficiary::from)
+    }
+
+    async fn payment_file(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<PaymentFile>> {
+        let Some(payment_file_id) = self.entity.payment_file_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(payment_file_id).await?)
+    }
+
     async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
@@ -91,11 +110,48 @@ impl Withdrawal {
     // }
 }
 
+#[derive(SimpleObject)]
+pub struct WithdrawalBeneficiary {
+    name: String,
+    iban: String,
+    bic: Option<String>,
+}
+
+impl From<&DomainWithdrawalBeneficiary> for WithdrawalBeneficiary {
+    fn from(beneficiary: &DomainWithdrawalBeneficiary) -> Self {
+        WithdrawalBeneficiary {
+            name: beneficiary.name.clone(),
+            iban: beneficiary.iban.clone(),
+            bic: beneficiary.bic.clone(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalBeneficiaryInput {
+    pub name: String,
+    pub iban: String,
+    pub bic: Option<String>,
+}
+
+impl TryFrom<WithdrawalBeneficiaryInput> for DomainWithdrawalBeneficiary {
+    type Error = CoreDepositError;
+
+    fn try_from(input: WithdrawalBeneficiaryInput) -> Result<Self, Self::Error> {
+        Ok(DomainWithdrawalBeneficiary::new(
+            input.name, input.iban, input.bic,
+        )?)
+    }
+}
+
 #[derive(InputObject)]
 pub struct WithdrawalInitiateInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    #[graphql(default)]
+    pub currency: CurrencyCode,
     pub reference: Option<String>,
+    pub beneficiary: Option<WithdrawalBeneficiaryInput>,
 }
 crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
 
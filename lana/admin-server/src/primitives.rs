@@ -5,10 +5,12 @@ use serde::{Deserialize, Serialize};
 
 pub use lana_app::{
     primitives::{
-        ApprovalProcessId, ChartId, CommitteeId, CreditFacilityId, CustodianId, CustomerDocumentId,
-        CustomerId, DepositAccountId, DepositId, DisbursalId, DisbursalStatus, DocumentId,
-        LedgerTransactionId, ManualTransactionId, PaymentAllocationId, PaymentId, PermissionSetId,
-        PolicyId, ReportId, ReportProgress, RoleId, Satoshis, SignedSatoshis, SignedUsdCents,
+        AccountStatementId, AccountingPeriodId, ApprovalProcessId, ChartId, CommitteeId,
+        CreditFacilityId, CurrencyCode, CustodianId, CustomerDocumentId, CustomerId,
+        DepositAccountId, DepositId, DisbursalId, DisbursalStatus, DocumentId, FixedAssetId,
+        LedgerTransactionId, ManualTransactionId, Money, PaymentAllocationId, PaymentFileId,
+        PaymentId, PermissionSetId, PolicyId, RecurringEntryTemplateId, ReportId, ReportProgress,
+        RoleId, Satoshis, ScheduledWithdrawalId, SignedSatoshis, SignedUsdCents, StatementImportId,
         Subject, TermsTemplateId, UsdCents, UserId, WithdrawalId,
     },
     terms::CollateralizationState,
@@ -128,7 +130,14 @@ impl_to_global_id! {
     ApprovalProcessId,
     DepositAccountId,
     LedgerTransactionId,
-    PaymentAllocationId
+    PaymentAllocationId,
+    StatementImportId,
+    PaymentFileId,
+    AccountStatementId,
+    ScheduledWithdrawalId,
+    AccountingPeriodId,
+    RecurringEntryTemplateId,
+    FixedAssetId
 }
 
 use cala_ledger::EntryId;
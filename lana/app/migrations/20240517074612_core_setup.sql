@@ -98,8 +98,10 @@ CREATE TABLE core_withdrawals (
   approval_process_id UUID REFERENCES approval_processes(id),
   cancelled_tx_id UUID DEFAULT NULL,
   reference VARCHAR NOT NULL UNIQUE,
+  status VARCHAR NOT NULL,
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_withdrawals_status ON core_withdrawals(status);
 
 CREATE TABLE core_withdrawal_events (
   id UUID NOT NULL REFERENCES core_withdrawals(id),
@@ -110,6 +112,74 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_statement_imports (
+  id UUID PRIMARY KEY,
+  status VARCHAR NOT NULL,
+  content_hash VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_statement_imports_status ON core_statement_imports(status);
+
+CREATE TABLE core_statement_import_events (
+  id UUID NOT NULL REFERENCES core_statement_imports(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_payment_files (
+  id UUID PRIMARY KEY,
+  message_id VARCHAR NOT NULL UNIQUE,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_payment_files_status ON core_payment_files(status);
+
+CREATE TABLE core_payment_file_events (
+  id UUID NOT NULL REFERENCES core_payment_files(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_account_statements (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_account_statements_deposit_account_id ON core_account_statements(deposit_account_id);
+
+CREATE TABLE core_account_statement_events (
+  id UUID NOT NULL REFERENCES core_account_statements(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_scheduled_withdrawals (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_scheduled_withdrawals_deposit_account_id ON core_scheduled_withdrawals(deposit_account_id);
+CREATE INDEX idx_core_scheduled_withdrawals_status ON core_scheduled_withdrawals(status);
+
+CREATE TABLE core_scheduled_withdrawal_events (
+  id UUID NOT NULL REFERENCES core_scheduled_withdrawals(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE customers (
   id UUID PRIMARY KEY,
   authentication_id UUID UNIQUE DEFAULT NULL,
@@ -405,7 +475,7 @@ CREATE TABLE accounting_csv_events (
 CREATE TABLE core_manual_transactions (
   id UUID PRIMARY KEY,
   reference VARCHAR NOT NULL UNIQUE,
-  ledger_transaction_id UUID NOT NULL,
+  ledger_transaction_id UUID NOT NULL UNIQUE,
   created_at TIMESTAMPTZ NOT NULL
 );
 
@@ -418,6 +488,57 @@ CREATE TABLE core_manual_transaction_events (
   UNIQUE(id, sequence)
 );
 
+-- Supports the general ledger export, which selects journal entries by effective date.
+CREATE INDEX idx_cala_transactions_journal_id_effective ON cala_transactions(journal_id, effective);
+
+CREATE TABLE core_recurring_entry_templates (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_recurring_entry_template_events (
+  id UUID NOT NULL REFERENCES core_recurring_entry_templates(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_fixed_assets (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_fixed_asset_events (
+  id UUID NOT NULL REFERENCES core_fixed_assets(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_accounting_periods (
+  id UUID PRIMARY KEY,
+  period_type VARCHAR NOT NULL,
+  period_start DATE NOT NULL,
+  period_end DATE NOT NULL,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_accounting_periods_status ON core_accounting_periods(status);
+CREATE INDEX idx_core_accounting_periods_dates ON core_accounting_periods(period_start, period_end);
+
+CREATE TABLE core_accounting_period_events (
+  id UUID NOT NULL REFERENCES core_accounting_periods(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE jobs (
   id UUID NOT NULL UNIQUE,
   unique_per_type BOOLEAN NOT NULL,
@@ -464,7 +585,22 @@ CREATE TABLE audit_entries (
   object VARCHAR NOT NULL,
   action VARCHAR NOT NULL,
   authorized BOOLEAN NOT NULL,
-  recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+  recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  chain_sequence BIGINT UNIQUE,
+  previous_hash VARCHAR,
+  hash VARCHAR
+);
+CREATE INDEX idx_audit_entries_subject ON audit_entries(subject, id);
+CREATE INDEX idx_audit_entries_object ON audit_entries(object varchar_pattern_ops);
+CREATE INDEX idx_audit_entries_action ON audit_entries(action, id);
+CREATE INDEX idx_audit_entries_recorded_at ON audit_entries(recorded_at);
+CREATE INDEX idx_audit_entries_denied ON audit_entries(id) WHERE NOT authorized;
+CREATE INDEX idx_audit_entries_unsealed ON audit_entries(id) WHERE chain_sequence IS NULL;
+
+CREATE TABLE audit_anchors (
+  chain_sequence BIGINT PRIMARY KEY,
+  digest VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
 CREATE TABLE core_credit_facility_histories (
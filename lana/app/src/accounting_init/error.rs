@@ -30,6 +30,10 @@ pub enum AccountingInitError {
     ProfitAndLossStatementChartIntegrationConfigBuilderError(
         #[from] crate::profit_and_loss::ChartOfAccountsIntegrationConfigBuilderError,
     ),
+    #[error("AccountingInitError - CashFlowStatementChartIntegrationConfigBuilderError: {0}")]
+    CashFlowStatementChartIntegrationConfigBuilderError(
+        #[from] crate::cash_flow::ChartOfAccountsIntegrationConfigBuilderError,
+    ),
     #[error("AccountingInitError - LedgerError: {0}")]
     LedgerError(#[from] cala_ledger::error::LedgerError),
     #[error("AccountingInitError - JournalError: {0}")]
@@ -42,6 +46,8 @@ pub enum AccountingInitError {
     ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
     #[error("AccountingInitError - BalanceSheetError: {0}")]
     BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("AccountingInitError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("AccountingInitError - SeedFileError: {0}")]
     SeedFileError(#[from] std::io::Error),
 }
@@ -7,6 +7,7 @@ use crate::{
     accounting::{Accounting, ChartOfAccounts},
     app::AccountingInitConfig,
     balance_sheet::BalanceSheets,
+    cash_flow::CashFlowStatements,
     credit::Credit,
     deposit::Deposits,
     primitives::CalaJournalId,
@@ -37,6 +38,7 @@ impl StatementsInit {
             accounting.trial_balances(),
             accounting.profit_and_loss(),
             accounting.balance_sheets(),
+            accounting.cash_flows(),
         )
         .await?;
         Ok(())
@@ -60,6 +62,7 @@ impl ChartsInit {
             deposit,
             accounting.balance_sheets(),
             accounting.profit_and_loss(),
+            accounting.cash_flows(),
             accounting_init_config,
         )
         .await
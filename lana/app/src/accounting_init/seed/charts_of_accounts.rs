@@ -7,7 +7,9 @@ use crate::{
 
 use rbac_types::Subject;
 
-use super::module_config::{balance_sheet::*, credit::*, deposit::*, profit_and_loss::*};
+use super::module_config::{
+    balance_sheet::*, cash_flow::*, credit::*, deposit::*, profit_and_loss::*,
+};
 
 pub(crate) async fn init(
     chart_of_accounts: &ChartOfAccounts,
@@ -16,6 +18,7 @@ pub(crate) async fn init(
     deposit: &Deposits,
     balance_sheet: &BalanceSheets,
     profit_and_loss: &ProfitAndLossStatements,
+    cash_flows: &CashFlowStatements,
     accounting_init_config: AccountingInitConfig,
 ) -> Result<(), AccountingInitError> {
     let chart_id = create_chart_of_accounts(chart_of_accounts).await?;
@@ -28,6 +31,7 @@ pub(crate) async fn init(
             deposit,
             balance_sheet,
             profit_and_loss,
+            cash_flows,
             chart_id,
             path,
             accounting_init_config,
@@ -62,6 +66,7 @@ async fn seed_chart_of_accounts(
     deposit: &Deposits,
     balance_sheet: &BalanceSheets,
     profit_and_loss: &ProfitAndLossStatements,
+    cash_flows: &CashFlowStatements,
     chart_id: ChartId,
     chart_of_accounts_seed_path: PathBuf,
     accounting_init_config: AccountingInitConfig,
@@ -71,6 +76,7 @@ async fn seed_chart_of_accounts(
         deposit_config_path,
         balance_sheet_config_path,
         profit_and_loss_config_path,
+        cash_flow_config_path,
 
         chart_of_accounts_seed_path: _,
     } = accounting_init_config;
@@ -124,5 +130,13 @@ async fn seed_chart_of_accounts(
             });
     }
 
+    if let Some(config_path) = cash_flow_config_path {
+        cash_flow_module_configure(cash_flows, &chart, config_path)
+            .await
+            .unwrap_or_else(|e| {
+                dbg!(&e); // TODO: handle the un-returned error differently
+            });
+    }
+
     Ok(())
 }
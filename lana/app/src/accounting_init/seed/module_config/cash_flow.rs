@@ -0,0 +1,65 @@
+use std::{fs, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{
+    accounting::Chart,
+    accounting_init::{AccountingInitError, constants::CASH_FLOW_STATEMENT_NAME},
+    cash_flow::{
+        CashFlowStatements, ChartOfAccountsIntegrationConfig, error::CashFlowStatementError,
+    },
+};
+
+use rbac_types::Subject;
+
+#[derive(Deserialize)]
+struct CashFlowStatementConfigData {
+    cash_codes: Vec<String>,
+    operating_codes: Vec<String>,
+    investing_codes: Vec<String>,
+    financing_codes: Vec<String>,
+}
+
+pub(in crate::accounting_init::seed) async fn cash_flow_module_configure(
+    cash_flows: &CashFlowStatements,
+    chart: &Chart,
+    config_path: PathBuf,
+) -> Result<(), AccountingInitError> {
+    let data = fs::read_to_string(config_path)?;
+    let CashFlowStatementConfigData {
+        cash_codes,
+        operating_codes,
+        investing_codes,
+        financing_codes,
+    } = serde_json::from_str(&data)?;
+
+    let parse = |codes: Vec<String>| {
+        codes
+            .iter()
+            .map(|code| code.parse())
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let config_values = ChartOfAccountsIntegrationConfig::builder()
+        .chart_of_accounts_id(chart.id)
+        .chart_of_accounts_cash_codes(parse(cash_codes)?)
+        .chart_of_accounts_operating_codes(parse(operating_codes)?)
+        .chart_of_accounts_investing_codes(parse(investing_codes)?)
+        .chart_of_accounts_financing_codes(parse(financing_codes)?)
+        .build()?;
+
+    match cash_flows
+        .set_chart_of_accounts_integration_config(
+            &Subject::System,
+            CASH_FLOW_STATEMENT_NAME.to_string(),
+            chart,
+            config_values,
+        )
+        .await
+    {
+        Ok(_) => (),
+        Err(CashFlowStatementError::CashFlowStatementConfigAlreadyExists) => (),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use crate::accounting_init::*;
 
-use constants::{BALANCE_SHEET_NAME, PROFIT_AND_LOSS_STATEMENT_NAME, TRIAL_BALANCE_STATEMENT_NAME};
+use constants::{
+    BALANCE_SHEET_NAME, CASH_FLOW_STATEMENT_NAME, PROFIT_AND_LOSS_STATEMENT_NAME,
+    TRIAL_BALANCE_STATEMENT_NAME,
+};
 
 pub(crate) async fn init(
     trial_balances: &TrialBalances,
     pl_statements: &ProfitAndLossStatements,
     balance_sheets: &BalanceSheets,
+    cash_flows: &CashFlowStatements,
 ) -> Result<StatementsInit, AccountingInitError> {
     create_trial_balances(trial_balances).await?;
 
@@ -13,6 +17,8 @@ pub(crate) async fn init(
 
     create_balance_sheets(balance_sheets).await?;
 
+    create_cash_flow_statements(cash_flows).await?;
+
     Ok(StatementsInit)
 }
 
@@ -41,3 +47,16 @@ async fn create_balance_sheets(balance_sheets: &BalanceSheets) -> Result<(), Acc
 
     Ok(())
 }
+
+async fn create_cash_flow_statements(
+    cash_flows: &CashFlowStatements,
+) -> Result<(), AccountingInitError> {
+    cash_flows
+        .create_cash_flow_statement(
+            CASH_FLOW_STATEMENT_NAME.to_string(),
+            PROFIT_AND_LOSS_STATEMENT_NAME.to_string(),
+        )
+        .await?;
+
+    Ok(())
+}
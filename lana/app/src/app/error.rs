@@ -22,6 +22,8 @@ pub enum ApplicationError {
     ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
     #[error("ApplicationError - BalanceSheetError: {0}")]
     BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("ApplicationError - CashFlowStatementError: {0}")]
+    CashFlowStatementError(#[from] crate::cash_flow::error::CashFlowStatementError),
     #[error("ApplicationError - CoreAccessError: {0}")]
     CoreAccessError(#[from] crate::access::error::CoreAccessError),
     #[error("ApplicationError - UserOnboardingError: {0}")]
@@ -42,12 +44,20 @@ pub enum ApplicationError {
     DashboardError(#[from] dashboard::error::DashboardError),
     #[error("ApplicationError - CalaInit: {0}")]
     CalaError(#[from] cala_ledger::error::LedgerError),
+    #[error("ApplicationError - CoreAccountingError: {0}")]
+    CoreAccountingError(#[from] core_accounting::error::CoreAccountingError),
     #[error("ApplicationError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
     #[error("ApplicationError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::CoreDepositError),
     #[error("ApplicationError - StorageError: {0}")]
     StorageError(#[from] crate::storage::error::StorageError),
+    #[error("ApplicationError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] crate::document::error::DocumentStorageError),
+    #[error("ApplicationError - AuditExportNotFound: {0}")]
+    AuditExportNotFound(crate::document::DocumentId),
+    #[error("ApplicationError - AuditExportNotReady: {0}")]
+    AuditExportNotReady(crate::document::DocumentId),
     #[error("ApplicationError - ApplicantError: {0}")]
     ApplicantError(#[from] crate::applicant::error::ApplicantError),
     #[error("ApplicationError - CustodyError: {0}")]
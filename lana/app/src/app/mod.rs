@@ -1,17 +1,25 @@
 mod config;
 mod error;
 
+use serde::Serialize;
 use sqlx::PgPool;
 use tracing::instrument;
 
+use std::collections::HashMap;
+
 use authz::PermissionCheck;
 
 use crate::{
     access::Access,
-    accounting::Accounting,
-    accounting_init::{ChartsInit, JournalInit, StatementsInit},
+    accounting::{AccountCode, Accounting, ChartExportFormat, ChartId},
+    accounting_init::{
+        ChartsInit, JournalInit, StatementsInit,
+        constants::{BALANCE_SHEET_NAME, CASH_FLOW_STATEMENT_NAME, PROFIT_AND_LOSS_STATEMENT_NAME},
+    },
     applicant::Applicants,
-    audit::{Audit, AuditCursor, AuditEntry},
+    audit::{Audit, AuditCursor, AuditEntry, AuditExportFormat, AuditFilter},
+    audit_chain::{AUDIT_CHAIN_SEAL_INTERVAL, AuditChainSealInitializer, AuditChainSealJobConfig},
+    audit_export::{AUDIT_EXPORT_DOCUMENT, AuditExportInitializer, AuditExportJobConfig},
     authorization::{AppAction, AppObject, AuditAction, Authorization, seed},
     credit::Credit,
     custody::Custody,
@@ -19,9 +27,12 @@ use crate::{
     customer_sync::CustomerSync,
     dashboard::Dashboard,
     deposit::Deposits,
-    document::DocumentStorage,
+    document::{
+        Document, DocumentId, DocumentStorage, GeneratedDocumentDownloadLink, ReferenceId,
+        UploadStatus,
+    },
     governance::Governance,
-    job::Jobs,
+    job::{JobId, Jobs},
     notification::Notification,
     outbox::Outbox,
     price::Price,
@@ -36,8 +47,8 @@ use error::ApplicationError;
 
 #[derive(Clone)]
 pub struct LanaApp {
-    _pool: PgPool,
-    _jobs: Jobs,
+    pool: PgPool,
+    jobs: Jobs,
     audit: Audit,
     authz: Authorization,
     accounting: Accounting,
@@ -52,6 +63,7 @@ pub struct LanaApp {
     outbox: Outbox,
     governance: Governance,
     dashboard: Dashboard,
+    documents: DocumentStorage,
     _user_onboarding: UserOnboarding,
     _customer_sync: CustomerSync,
 }
@@ -67,13 +79,15 @@ impl LanaApp {
         let mut access_config = config.access;
         access_config.action_descriptions = rbac_types::LanaAction::action_descriptions();
         access_config.predefined_roles = seed::PREDEFINED_ROLES;
-
-        let access = Access::init(&pool, access_config, &authz, &outbox).await?;
+        access_config.separation_of_duties = rbac_types::SEPARATION_OF_DUTIES;
 
         let mut jobs = Jobs::new(&pool, config.job_execution);
+        let governance = Governance::new(&pool, &authz, &jobs, &outbox);
+
+        let access =
+            Access::init(&pool, access_config, &authz, &jobs, &governance, &outbox).await?;
 
         let dashboard = Dashboard::init(&pool, &authz, &jobs, &outbox).await?;
-        let governance = Governance::new(&pool, &authz, &outbox);
         let price = Price::new();
         let storage = Storage::new(&config.storage);
         let documents = DocumentStorage::new(&pool, &storage);
@@ -89,14 +103,17 @@ impl LanaApp {
             .expect("cala config");
         let cala = cala_ledger::CalaLedger::init(cala_config).await?;
         let journal_init = JournalInit::journal(&cala).await?;
-        let accounting = Accounting::new(
+        let accounting = Accounting::init(
             &pool,
             &authz,
             &cala,
             journal_init.journal_id,
             &storage,
             &jobs,
-        );
+            &governance,
+            &outbox,
+        )
+        .await?;
 
         StatementsInit::statements(&accounting).await?;
 
@@ -109,6 +126,9 @@ impl LanaApp {
             &jobs,
             &cala,
             journal_init.journal_id,
+            &documents,
+            &storage,
+            config.deposit,
         )
         .await?;
         let customer_sync =
@@ -145,11 +165,20 @@ impl LanaApp {
         ChartsInit::charts_of_accounts(&accounting, &credit, &deposits, config.accounting_init)
             .await?;
 
+        jobs.add_initializer_and_spawn_unique(
+            AuditChainSealInitializer::new(&audit, &storage),
+            AuditChainSealJobConfig {
+                job_interval: AUDIT_CHAIN_SEAL_INTERVAL,
+            },
+        )
+        .await?;
+        jobs.add_initializer(AuditExportInitializer::new(&audit, &documents));
+
         jobs.start_poll().await?;
 
         Ok(Self {
-            _pool: pool,
-            _jobs: jobs,
+            pool,
+            jobs,
             audit,
             authz,
             accounting,
@@ -164,6 +193,7 @@ impl LanaApp {
             outbox,
             governance,
             dashboard,
+            documents,
             _user_onboarding: user_onboarding,
             _customer_sync: customer_sync,
         })
@@ -201,6 +231,7 @@ impl LanaApp {
     pub async fn list_audit(
         &self,
         sub: &Subject,
+        filter: AuditFilter,
         query: es_entity::PaginatedQueryArgs<AuditCursor>,
     ) -> Result<es_entity::PaginatedQueryRet<AuditEntry, AuditCursor>, ApplicationError> {
         use crate::audit::AuditSvc;
@@ -213,7 +244,176 @@ impl LanaApp {
             )
             .await?;
 
-        self.audit.list(query).await.map_err(ApplicationError::from)
+        self.audit
+            .list(&filter, query)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// Queues an export of the audit entries matching `filter`. The returned
+    /// document stays pending until the export job has uploaded it.
+    #[instrument(name = "lana.audit.export_audit", skip(self), err)]
+    pub async fn export_audit(
+        &self,
+        sub: &Subject,
+        filter: AuditFilter,
+        format: AuditExportFormat,
+    ) -> Result<Document, ApplicationError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                AppObject::all_audits(),
+                AppAction::Audit(AuditAction::Export),
+            )
+            .await?;
+
+        let filename = format!(
+            "audit-export-{}.{}",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S"),
+            format.file_extension()
+        );
+        let mut db = es_entity::DbOp::init(&self.pool).await?;
+        let document = self
+            .documents
+            .create_in_op(
+                &mut db,
+                audit_info.clone(),
+                filename,
+                format.content_type(),
+                ReferenceId::new(),
+                AUDIT_EXPORT_DOCUMENT,
+            )
+            .await?;
+        self.jobs
+            .create_and_spawn_in_op(
+                &mut db,
+                JobId::from(uuid::Uuid::from(document.id)),
+                AuditExportJobConfig {
+                    document_id: document.id,
+                    filter,
+                    format,
+                    audit_info,
+                },
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(document)
+    }
+
+    #[instrument(name = "lana.audit.find_audit_export", skip(self), err)]
+    pub async fn find_audit_export(
+        &self,
+        sub: &Subject,
+        id: DocumentId,
+    ) -> Result<Option<Document>, ApplicationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AppObject::all_audits(),
+                AppAction::Audit(AuditAction::Export),
+            )
+            .await?;
+
+        Ok(self
+            .documents
+            .find_by_id(id)
+            .await?
+            .filter(|document| document.document_type == AUDIT_EXPORT_DOCUMENT))
+    }
+
+    #[instrument(
+        name = "lana.audit.generate_audit_export_download_link",
+        skip(self),
+        err
+    )]
+    pub async fn generate_audit_export_download_link(
+        &self,
+        sub: &Subject,
+        id: DocumentId,
+    ) -> Result<GeneratedDocumentDownloadLink, ApplicationError> {
+        let document = self
+            .find_audit_export(sub, id)
+            .await?
+            .ok_or(ApplicationError::AuditExportNotFound(id))?;
+        if document.upload_status() != UploadStatus::Completed {
+            return Err(ApplicationError::AuditExportNotReady(id));
+        }
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                AppObject::all_audits(),
+                AppAction::Audit(AuditAction::Export),
+            )
+            .await?;
+        Ok(self
+            .documents
+            .generate_download_link(audit_info, id)
+            .await?)
+    }
+
+    /// Exports the chart annotated with the module integration configs that reference
+    /// each of its nodes.
+    #[instrument(name = "lana.accounting.export_chart_of_accounts", skip(self), err)]
+    pub async fn export_chart_of_accounts(
+        &self,
+        sub: &Subject,
+        chart_id: ChartId,
+        format: ChartExportFormat,
+    ) -> Result<String, ApplicationError> {
+        let mut integrations = HashMap::new();
+        if let Some(config) = self
+            .deposits
+            .get_chart_of_accounts_integration_config(sub)
+            .await?
+        {
+            collect_integration_references(&mut integrations, "deposit", &config);
+        }
+        if let Some(config) = self
+            .credit
+            .chart_of_accounts_integrations()
+            .get_config(sub)
+            .await
+            .map_err(crate::credit::error::CoreCreditError::from)?
+        {
+            collect_integration_references(&mut integrations, "credit", &config);
+        }
+        if let Some(config) = self
+            .accounting
+            .balance_sheets()
+            .get_chart_of_accounts_integration_config(sub, BALANCE_SHEET_NAME.to_string())
+            .await?
+        {
+            collect_integration_references(&mut integrations, "balance_sheet", &config);
+        }
+        if let Some(config) = self
+            .accounting
+            .profit_and_loss()
+            .get_chart_of_accounts_integration_config(
+                sub,
+                PROFIT_AND_LOSS_STATEMENT_NAME.to_string(),
+            )
+            .await?
+        {
+            collect_integration_references(&mut integrations, "profit_and_loss", &config);
+        }
+        if let Some(config) = self
+            .accounting
+            .cash_flows()
+            .get_chart_of_accounts_integration_config(sub, CASH_FLOW_STATEMENT_NAME.to_string())
+            .await?
+        {
+            collect_integration_references(&mut integrations, "cash_flow", &config);
+        }
+
+        Ok(self
+            .accounting
+            .chart_of_accounts()
+            .export(sub, chart_id, format, &integrations)
+            .await?)
     }
 
     pub fn accounting(&self) -> &Accounting {
@@ -250,3 +450,30 @@ impl LanaApp {
         crate::authorization::get_visible_navigation_items(&self.authz, sub).await
     }
 }
+
+/// Records `<module>.<field>` against every account code held by the config.
+fn collect_integration_references(
+    integrations: &mut HashMap<AccountCode, Vec<String>>,
+    module: &str,
+    config: &impl Serialize,
+) {
+    let serde_json::Value::Object(fields) =
+        serde_json::to_value(config).expect("Could not serialize integration config")
+    else {
+        return;
+    };
+    for (field, value) in fields {
+        let values = match value {
+            serde_json::Value::Array(values) => values,
+            value => vec![value],
+        };
+        for value in values {
+            if let Ok(code) = serde_json::from_value::<AccountCode>(value) {
+                integrations
+                    .entry(code)
+                    .or_default()
+                    .push(format!("{module}.{field}"));
+            }
+        }
+    }
+}
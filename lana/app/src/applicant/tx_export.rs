@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
 use audit::SystemSubject;
-use core_money::UsdCents;
+use core_money::Money;
 use lana_events::LanaEvent;
 
 use super::{error::ApplicantError, sumsub_auth::*};
@@ -85,6 +85,7 @@ impl JobRunner for SumsubExportJobRunner {
                     id,
                     deposit_account_id,
                     amount,
+                    currency,
                 })) => {
                     let account = self
                         .deposits
@@ -95,7 +96,7 @@ impl JobRunner for SumsubExportJobRunner {
                         &message,
                         id,
                         account.account_holder_id.into(),
-                        amount,
+                        Money::new(amount.into_inner(), currency),
                     )
                     .await?
                 }
@@ -103,6 +104,7 @@ impl JobRunner for SumsubExportJobRunner {
                     id,
                     deposit_account_id,
                     amount,
+                    currency,
                 })) => {
                     let account = self
                         .deposits
@@ -113,7 +115,7 @@ impl JobRunner for SumsubExportJobRunner {
                         &message,
                         id,
                         account.account_holder_id.into(),
-                        amount,
+                        Money::new(amount.into_inner(), currency),
                     )
                     .await?
                 }
@@ -137,7 +139,7 @@ impl SumsubExportJobRunner {
         message: &outbox::PersistentOutboxEvent<LanaEvent>,
         withdrawal_id: WithdrawalId,
         customer_id: CustomerId,
-        amount: UsdCents,
+        amount: Money,
     ) -> Result<(), ApplicantError> {
         message.inject_trace_parent();
         self.sumsub_client
@@ -146,8 +148,8 @@ impl SumsubExportJobRunner {
                 withdrawal_id.to_string(),
                 "Withdrawal",
                 &SumsubTransactionDirection::Out.to_string(),
-                usd_cents_to_dollars(amount),
-                "USD",
+                money_to_major_units(amount),
+                amount.currency().code(),
             )
             .await
     }
@@ -162,7 +164,7 @@ impl SumsubExportJobRunner {
         message: &outbox::PersistentOutboxEvent<LanaEvent>,
         deposit_id: DepositId,
         customer_id: CustomerId,
-        amount: UsdCents,
+        amount: Money,
     ) -> Result<(), ApplicantError> {
         message.inject_trace_parent();
         self.sumsub_client
@@ -171,16 +173,15 @@ impl SumsubExportJobRunner {
                 deposit_id.to_string(),
                 "Deposit",
                 &SumsubTransactionDirection::In.to_string(),
-                usd_cents_to_dollars(amount),
-                "USD",
+                money_to_major_units(amount),
+                amount.currency().code(),
             )
             .await
     }
 }
 
-pub fn usd_cents_to_dollars(cents: UsdCents) -> f64 {
-    // Use the into_inner method to get the value in cents
-    (cents.into_inner() as f64) / 100.0
+pub fn money_to_major_units(amount: Money) -> f64 {
+    (amount.minor_units() as f64) / 10f64.powi(amount.currency().precision() as i32)
 }
 
 /// Direction of the transaction from Sumsub's perspective
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    audit::*,
+    job::*,
+    storage::{Storage, error::StorageError},
+};
+
+pub const AUDIT_CHAIN_SEAL_INTERVAL: Duration = Duration::from_secs(60);
+
+const AUDIT_ANCHORS_FOLDER: &str = "audit-anchors/";
+
+/// Anchors are published as `<sequence>-<digest>` so that the trusted anchors
+/// can be read back from a listing alone.
+async fn publish_anchor(storage: &Storage, anchor: &AuditAnchor) -> Result<(), StorageError> {
+    let path = format!(
+        "{AUDIT_ANCHORS_FOLDER}{:020}-{}",
+        anchor.chain_sequence, anchor.digest
+    );
+    storage
+        .upload(anchor.digest.clone().into_bytes(), &path, "text/plain")
+        .await
+}
+
+/// Loads the anchor digests published outside of Postgres by the seal job.
+pub async fn published_anchors(storage: &Storage) -> anyhow::Result<HashMap<i64, String>> {
+    storage
+        .list(AUDIT_ANCHORS_FOLDER)
+        .await?
+        .into_iter()
+        .map(|path| {
+            let name = path.trim_start_matches(AUDIT_ANCHORS_FOLDER);
+            let (sequence, digest) = name
+                .split_once('-')
+                .ok_or_else(|| anyhow::anyhow!("malformed audit anchor {path}"))?;
+            Ok((sequence.parse()?, digest.to_owned()))
+        })
+        .collect()
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AuditChainSealJobConfig {
+    pub job_interval: Duration,
+}
+impl JobConfig for AuditChainSealJobConfig {
+    type Initializer = AuditChainSealInitializer;
+}
+
+pub struct AuditChainSealInitializer {
+    audit: Audit,
+    storage: Storage,
+}
+
+impl AuditChainSealInitializer {
+    pub fn new(audit: &Audit, storage: &Storage) -> Self {
+        Self {
+            audit: audit.clone(),
+            storage: storage.clone(),
+        }
+    }
+}
+
+const AUDIT_CHAIN_SEAL_JOB: JobType = JobType::new("audit-chain-seal");
+impl JobInitializer for AuditChainSealInitializer {
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        AUDIT_CHAIN_SEAL_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(AuditChainSealJobRunner {
+            config: job.config()?,
+            audit: self.audit.clone(),
+            storage: self.storage.clone(),
+        }))
+    }
+}
+
+pub struct AuditChainSealJobRunner {
+    config: AuditChainSealJobConfig,
+    audit: Audit,
+    storage: Storage,
+}
+
+#[async_trait]
+impl JobRunner for AuditChainSealJobRunner {
+    #[tracing::instrument(
+        name = "lana.audit_chain.jobs.seal.run",
+        skip_all,
+        fields(sealed, anchored_sequence),
+        err
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let sealed = self.audit.seal_pending().await?;
+        tracing::Span::current().record("sealed", sealed);
+
+        if let Some(anchor) = self.audit.anchor().await? {
+            tracing::Span::current().record("anchored_sequence", anchor.chain_sequence);
+            tracing::info!(
+                chain_sequence = anchor.chain_sequence,
+                digest = %anchor.digest,
+                "audit chain anchored"
+            );
+        }
+        // Republishing the latest anchor also covers an upload that failed
+        // after the anchor was recorded.
+        if let Some(anchor) = self.audit.latest_anchor().await? {
+            publish_anchor(&self.storage, &anchor).await?;
+        }
+
+        Ok(JobCompletion::RescheduleIn(self.config.job_interval))
+    }
+}
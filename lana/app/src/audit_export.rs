@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    audit::*,
+    document::{DocumentId, DocumentStorage, DocumentType, UploadStatus},
+    job::*,
+};
+
+pub const AUDIT_EXPORT_DOCUMENT: DocumentType = DocumentType::new("audit_export");
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AuditExportJobConfig {
+    pub document_id: DocumentId,
+    pub filter: AuditFilter,
+    pub format: AuditExportFormat,
+    pub audit_info: AuditInfo,
+}
+impl JobConfig for AuditExportJobConfig {
+    type Initializer = AuditExportInitializer;
+}
+
+pub struct AuditExportInitializer {
+    audit: Audit,
+    documents: DocumentStorage,
+}
+
+impl AuditExportInitializer {
+    pub fn new(audit: &Audit, documents: &DocumentStorage) -> Self {
+        Self {
+            audit: audit.clone(),
+            documents: documents.clone(),
+        }
+    }
+}
+
+const AUDIT_EXPORT_JOB: JobType = JobType::new("audit-export");
+impl JobInitializer for AuditExportInitializer {
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        AUDIT_EXPORT_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(AuditExportJobRunner {
+            config: job.config()?,
+            audit: self.audit.clone(),
+            documents: self.documents.clone(),
+        }))
+    }
+}
+
+pub struct AuditExportJobRunner {
+    config: AuditExportJobConfig,
+    audit: Audit,
+    documents: DocumentStorage,
+}
+
+#[async_trait]
+impl JobRunner for AuditExportJobRunner {
+    #[tracing::instrument(name = "lana.audit_export.jobs.export.run", skip_all, err)]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut document = self
+            .documents
+            .find_by_id(self.config.document_id)
+            .await?
+            .ok_or("audit export document not found")?;
+        if document.upload_status() != UploadStatus::Pending {
+            return Ok(JobCompletion::Complete);
+        }
+
+        match self
+            .audit
+            .export(&self.config.filter, self.config.format)
+            .await
+        {
+            Ok(content) => {
+                self.documents
+                    .upload(content, &mut document, self.config.audit_info.clone())
+                    .await?;
+            }
+            Err(e) => {
+                self.documents
+                    .upload_failed(&mut document, e.to_string())
+                    .await?;
+            }
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -65,7 +65,6 @@ pub async fn get_visible_navigation_items(
                     CoreDepositAction::WITHDRAWAL_READ,
                     CoreDepositAction::WITHDRAWAL_LIST,
                     CoreDepositAction::WITHDRAWAL_INITIATE,
-                    CoreDepositAction::WITHDRAWAL_CONFIRM,
                     CoreDepositAction::WITHDRAWAL_CANCEL,
                     CoreDepositAction::WITHDRAWAL_CONCLUDE_APPROVAL_PROCESS,
                 ],
@@ -4,6 +4,8 @@
 pub mod accounting_init;
 pub mod app;
 pub mod applicant;
+pub mod audit_chain;
+pub mod audit_export;
 pub mod authorization;
 pub mod primitives;
 pub mod report;
@@ -16,7 +18,7 @@ pub mod storage {
 pub mod document {
     pub use document_storage::{
         Document, DocumentId, DocumentRepo, DocumentStatus, DocumentType,
-        GeneratedDocumentDownloadLink, NewDocument, ReferenceId, error,
+        GeneratedDocumentDownloadLink, NewDocument, ReferenceId, UploadStatus, error,
     };
     pub type DocumentStorage = document_storage::DocumentStorage;
 }
@@ -45,6 +47,7 @@ pub mod rbac {
     pub use rbac_types::PermissionSetName;
 }
 pub mod access {
+    pub use authz::PermissionCondition;
     pub use core_access::{Role, RoleId, UserId, config, error, permission_set, role, user};
     pub type Access = core_access::CoreAccess<crate::audit::Audit, lana_events::LanaEvent>;
 }
@@ -78,6 +81,8 @@ pub mod governance {
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
+    pub use core_access::APPROVE_ROLE_ELEVATION_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_PROCESS;
     pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
 }
 
@@ -87,17 +92,28 @@ pub mod audit {
         primitives::Subject,
     };
 
-    pub use audit::{AuditCursor, AuditEntryId, AuditInfo, AuditSvc, error};
+    pub use audit::{
+        AuditAnchor, AuditChainBreak, AuditChainBrokenLink, AuditChainVerification, AuditCursor,
+        AuditEntryId, AuditExportFormat, AuditFilter, AuditInfo, AuditSvc, error,
+    };
     pub type Audit = audit::Audit<Subject, LanaObject, LanaAction>;
     pub type AuditEntry = audit::AuditEntry<Subject, LanaObject, LanaAction>;
 }
 
 pub mod deposit {
     pub use core_deposit::{
-        ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
-        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositId,
-        DepositsByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalStatus,
-        WithdrawalsByCreatedAtCursor, error,
+        AccountStatement, AccountStatementFormat, AccountStatementId,
+        AccountStatementsByCreatedAtCursor, ChartOfAccountsIntegrationConfig, CoreDepositEvent,
+        Deposit, DepositAccount, DepositAccountBalance, DepositAccountCurrencyBalance,
+        DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositConfig, DepositId,
+        DepositsByCreatedAtCursor, ImportedStatementLine, PaymentFile, PaymentFileEntry,
+        PaymentFileEntryStatus, PaymentFileId, PaymentFileStatus, PaymentFilesByCreatedAtCursor,
+        ScheduledWithdrawal, ScheduledWithdrawalExecution, ScheduledWithdrawalId,
+        ScheduledWithdrawalStatus, ScheduledWithdrawalsByCreatedAtCursor, StatementEntryDirection,
+        StatementFormat, StatementImport, StatementImportId, StatementImportStatus,
+        StatementImportsByCreatedAtCursor, StatementLine, StatementLineStatus, Withdrawal,
+        WithdrawalBeneficiary, WithdrawalFrequency, WithdrawalId, WithdrawalRecurrence,
+        WithdrawalStatus, WithdrawalsByCreatedAtCursor, error,
     };
 
     pub type Deposits =
@@ -106,12 +122,17 @@ pub mod deposit {
 
 pub mod accounting {
     pub use core_accounting::{
-        AccountCode, AccountingCsvId, CalaAccountId, ChartId, LedgerAccountId,
-        TransactionTemplateId, chart_of_accounts, csv, error, journal, ledger_account,
-        ledger_transaction, manual_transaction, transaction_templates, {Chart, tree},
+        AccountCode, AccountingCsvId, AccountingPeriodId, CalaAccountId, ChartExportFormat,
+        ChartId, FixedAssetId, LedgerAccountId, RecurringEntryTemplateId, TransactionTemplateId,
+        accounting_period, cash_flow, chart_of_accounts, comparative_statement, csv, error,
+        fixed_asset, journal, ledger_account, ledger_transaction, manual_transaction,
+        recurring_entry, transaction_templates, {Chart, tree},
     };
 
-    pub type Accounting = core_accounting::CoreAccounting<crate::authorization::Authorization>;
+    pub type Accounting = core_accounting::CoreAccounting<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
     pub type ChartOfAccounts =
         core_accounting::ChartOfAccounts<crate::authorization::Authorization>;
 }
@@ -127,6 +148,12 @@ pub mod balance_sheet {
     pub type BalanceSheets = core_accounting::BalanceSheets<crate::authorization::Authorization>;
 }
 
+pub mod cash_flow {
+    pub use core_accounting::cash_flow::*;
+    pub type CashFlowStatements =
+        core_accounting::CashFlowStatements<crate::authorization::Authorization>;
+}
+
 pub mod trial_balance {
     pub use core_accounting::trial_balance::*;
     pub type TrialBalances = core_accounting::TrialBalances<crate::authorization::Authorization>;
@@ -3,14 +3,20 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 pub use core_access::{PermissionSetId, RoleId, UserId};
-pub use core_accounting::{BalanceRange, Chart, ChartId, LedgerTransactionId, ManualTransactionId};
+pub use core_accounting::{
+    AccountingPeriodId, BalanceRange, Chart, ChartId, FixedAssetId, LedgerTransactionId,
+    ManualTransactionId, RecurringEntryTemplateId,
+};
 pub use core_credit::{
     CollateralAction, CreditFacilityId, CreditFacilityStatus, DisbursalId, DisbursalStatus,
     PaymentAllocationId, PaymentId, TermsTemplateId,
 };
 pub use core_custody::CustodianId;
 pub use core_customer::{CustomerDocumentId, CustomerId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    AccountStatementId, DepositAccountHolderId, DepositAccountId, DepositId, PaymentFileId,
+    ScheduledWithdrawalId, StatementImportId, WithdrawalId,
+};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use document_storage::{DocumentId, ReferenceId};
@@ -26,7 +26,7 @@ async fn create_user_with_role(
         .create_user(superuser_subject, random_email())
         .await?;
     let user = access
-        .update_role_of_user(superuser_subject, user.id, role_id)
+        .update_role_of_user(superuser_subject, user.id, role_id, None)
         .await?;
     Ok(Subject::from(user.id))
 }
@@ -170,3 +170,136 @@ async fn bank_manager_permissions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[file_serial]
+async fn separation_of_duties_is_enforced_on_roles() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let audit = Audit::new(&pool);
+    let authz = Authorization::init(&pool, &audit).await?;
+    let (access, superuser_subject) = helpers::init_access(&pool, &authz).await?;
+
+    let permission_sets = access
+        .list_permission_sets(
+            &superuser_subject,
+            es_entity::PaginatedQueryArgs {
+                first: 100,
+                after: None,
+            },
+        )
+        .await?
+        .entities;
+    let permission_set_id = |name: &str| {
+        permission_sets
+            .iter()
+            .find(|permission_set| permission_set.name == name)
+            .map(|permission_set| permission_set.id)
+            .expect("permission set not found")
+    };
+
+    let res = access
+        .create_role(
+            &superuser_subject,
+            format!("maker-checker-{}", Uuid::new_v4()),
+            [
+                permission_set_id(core_deposit::PERMISSION_SET_DEPOSIT_WRITER),
+                permission_set_id(core_deposit::PERMISSION_SET_DEPOSIT_APPROVER),
+            ],
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(lana_app::access::error::CoreAccessError::SeparationOfDutiesViolation(..))
+    ));
+
+    let maker = access
+        .create_role(
+            &superuser_subject,
+            format!("maker-{}", Uuid::new_v4()),
+            [permission_set_id(
+                core_deposit::PERMISSION_SET_DEPOSIT_WRITER,
+            )],
+        )
+        .await?;
+    let res = access
+        .add_permission_sets_to_role(
+            &superuser_subject,
+            maker.id,
+            [permission_set_id(
+                governance::PERMISSION_SET_GOVERNANCE_APPROVER,
+            )],
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(lana_app::access::error::CoreAccessError::SeparationOfDutiesViolation(..))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[file_serial]
+async fn role_conditions_are_enforced_with_attributes() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let audit = Audit::new(&pool);
+    let authz = Authorization::init(&pool, &audit).await?;
+    let (access, superuser_subject) = helpers::init_access(&pool, &authz).await?;
+
+    let bank_manager_role = access
+        .find_role_by_name(&superuser_subject, ROLE_NAME_BANK_MANAGER)
+        .await?;
+    let bank_manager_subject =
+        create_user_with_role(&access, &superuser_subject, bank_manager_role.id).await?;
+
+    let action = LanaAction::from(core_deposit::CoreDepositAction::WITHDRAWAL_INITIATE);
+    let condition = authz::PermissionCondition::AtMost {
+        attribute: core_deposit::ATTRIBUTE_AMOUNT_USD.to_string(),
+        limit: rust_decimal_macros::dec!(1000),
+    };
+    access
+        .add_condition_to_role(
+            &superuser_subject,
+            bank_manager_role.id,
+            action.to_string(),
+            condition.clone(),
+        )
+        .await?;
+
+    let small = authz::Attributes::new().with(
+        core_deposit::ATTRIBUTE_AMOUNT_USD,
+        rust_decimal_macros::dec!(500),
+    );
+    let large = authz::Attributes::new().with(
+        core_deposit::ATTRIBUTE_AMOUNT_USD,
+        rust_decimal_macros::dec!(5000),
+    );
+    let enforce = |attributes| {
+        authz.enforce_permission_with_attributes(
+            &bank_manager_subject,
+            core_deposit::CoreDepositObject::all_withdrawals(),
+            core_deposit::CoreDepositAction::WITHDRAWAL_INITIATE,
+            attributes,
+        )
+    };
+    let within_limit = enforce(&small).await;
+    let over_limit = enforce(&large).await;
+
+    access
+        .remove_condition_from_role(
+            &superuser_subject,
+            bank_manager_role.id,
+            action.to_string(),
+            condition,
+        )
+        .await?;
+
+    assert!(within_limit.is_ok());
+    assert!(matches!(over_limit, Err(AuthorizationError::NotAuthorized)));
+    assert!(
+        enforce(&large).await.is_ok(),
+        "removing the condition lifts the limit"
+    );
+
+    Ok(())
+}
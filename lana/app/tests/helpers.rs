@@ -5,6 +5,8 @@ use rand::Rng;
 use lana_app::{
     access::{Access, config::AccessConfig},
     authorization::{Authorization, seed},
+    governance::Governance,
+    job::{JobExecutorConfig, Jobs},
     outbox::Outbox,
     primitives::Subject,
 };
@@ -30,9 +32,12 @@ pub async fn init_access(
         superuser_email: Some(superuser_email.clone()),
         action_descriptions: rbac_types::LanaAction::action_descriptions(),
         predefined_roles: seed::PREDEFINED_ROLES,
+        separation_of_duties: rbac_types::SEPARATION_OF_DUTIES,
     };
 
-    let access = Access::init(pool, config, authz, &outbox).await?;
+    let jobs = Jobs::new(pool, JobExecutorConfig::default());
+    let governance = Governance::new(pool, authz, &jobs, &outbox);
+    let access = Access::init(pool, config, authz, &jobs, &governance, &outbox).await?;
 
     let superuser = access
         .users()
@@ -31,7 +31,7 @@ async fn bank_manager_lifecycle() -> anyhow::Result<()> {
         .await?;
 
     let bank_manager = access
-        .update_role_of_user(&superuser_subject, user.id, bank_manager_role.id)
+        .update_role_of_user(&superuser_subject, user.id, bank_manager_role.id, None)
         .await
         .expect("Could not update role of user");
 
@@ -53,6 +53,8 @@ struct Cli {
 #[derive(Subcommand)]
 enum UtilsCommands {
     Genencryptionkey,
+    /// Walks the audit hash chain and reports the first broken link
+    VerifyAuditChain,
 }
 
 pub async fn run() -> anyhow::Result<()> {
@@ -63,34 +65,42 @@ pub async fn run() -> anyhow::Result<()> {
             let key = ChaCha20Poly1305::generate_key(&mut OsRng);
             println!("{}", hex::encode(key));
         }
+        Some(UtilsCommands::VerifyAuditChain) => {
+            let config = init_config(cli)?;
+            verify_audit_chain(config).await?;
+        }
         None => {
-            let sa_creds_base64 = if cli.sa_creds_base64_raw.is_empty() {
-                None
-            } else {
-                Some(cli.sa_creds_base64_raw)
-            };
-
-            let config = Config::init(
-                cli.config,
-                EnvSecrets {
-                    pg_con: cli.pg_con,
-                    sumsub_key: cli.sumsub_key,
-                    sumsub_secret: cli.sumsub_secret,
-                    sa_creds_base64,
-                    smtp_username: cli.smtp_username,
-                    smtp_password: cli.smtp_password,
-                    custodian_encryption_key: cli.custodian_encryption_key,
-                },
-                cli.dev_env_name_prefix,
-            )?;
-
-            run_cmd(&cli.lana_home, config).await?;
+            let lana_home = cli.lana_home.clone();
+            let config = init_config(cli)?;
+            run_cmd(&lana_home, config).await?;
         }
     }
 
     Ok(())
 }
 
+fn init_config(cli: Cli) -> anyhow::Result<Config> {
+    let sa_creds_base64 = if cli.sa_creds_base64_raw.is_empty() {
+        None
+    } else {
+        Some(cli.sa_creds_base64_raw)
+    };
+
+    Config::init(
+        cli.config,
+        EnvSecrets {
+            pg_con: cli.pg_con,
+            sumsub_key: cli.sumsub_key,
+            sumsub_secret: cli.sumsub_secret,
+            sa_creds_base64,
+            smtp_username: cli.smtp_username,
+            smtp_password: cli.smtp_password,
+            custodian_encryption_key: cli.custodian_encryption_key,
+        },
+        cli.dev_env_name_prefix,
+    )
+}
+
 async fn run_cmd(lana_home: &str, config: Config) -> anyhow::Result<()> {
     tracing_utils::init_tracer(config.tracing)?;
     store_server_pid(lana_home, std::process::id())?;
@@ -146,6 +156,37 @@ async fn run_cmd(lana_home: &str, config: Config) -> anyhow::Result<()> {
     reason
 }
 
+async fn verify_audit_chain(config: Config) -> anyhow::Result<()> {
+    use lana_app::{
+        audit::{Audit, AuditSvc},
+        audit_chain::{AUDIT_CHAIN_SEAL_INTERVAL, published_anchors},
+        storage::Storage,
+    };
+
+    let anchors = published_anchors(&Storage::new(&config.app.storage)).await?;
+    let unsealed_before = chrono::Utc::now() - AUDIT_CHAIN_SEAL_INTERVAL;
+
+    let pool = sqlx::PgPool::connect(&config.db.pg_con).await?;
+    let res = Audit::new(&pool)
+        .verify_chain(anchors, unsealed_before)
+        .await?;
+    println!(
+        "verified {} audit entries against {} published anchors",
+        res.verified_entries, res.anchors_checked
+    );
+    if let Some(link) = res.broken_link {
+        anyhow::bail!("audit chain broken at {link}");
+    }
+    if res.stale_unsealed_entries > 0 {
+        anyhow::bail!(
+            "{} audit entries are still unsealed after one seal interval",
+            res.stale_unsealed_entries
+        );
+    }
+    println!("audit chain intact");
+    Ok(())
+}
+
 pub fn store_server_pid(lana_home: &str, pid: u32) -> anyhow::Result<()> {
     create_lana_dir(lana_home)?;
     let _ = fs::remove_file(format!("{lana_home}/server-pid"));
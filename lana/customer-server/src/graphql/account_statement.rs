@@ -0,0 +1,73 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{AccountStatement as DomainAccountStatement, AccountStatementFormat};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct AccountStatement {
+    id: ID,
+    account_statement_id: UUID,
+    deposit_account_id: UUID,
+    currency: CurrencyCode,
+    period_start: Date,
+    period_end: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainAccountStatement>,
+}
+
+impl From<DomainAccountStatement> for AccountStatement {
+    fn from(statement: DomainAccountStatement) -> Self {
+        AccountStatement {
+            id: statement.id.to_global_id(),
+            account_statement_id: UUID::from(statement.id),
+            deposit_account_id: UUID::from(statement.deposit_account_id),
+            currency: statement.currency,
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            created_at: statement.created_at().into(),
+
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountStatement {
+    async fn opening_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.opening_balance.to_major()?.into())
+    }
+
+    async fn closing_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.closing_balance.to_major()?.into())
+    }
+
+    async fn total_credits(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.total_credits.to_major()?.into())
+    }
+
+    async fn total_debits(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.total_debits.to_major()?.into())
+    }
+
+    async fn accrued_balance(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.entity.accrued_balance.to_major()?.into())
+    }
+
+    async fn download_link(
+        &self,
+        ctx: &Context<'_>,
+        format: AccountStatementFormat,
+    ) -> async_graphql::Result<String> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .for_subject(sub)?
+            .generate_account_statement_download_link(self.entity.id, format)
+            .await?;
+        Ok(link.link)
+    }
+}
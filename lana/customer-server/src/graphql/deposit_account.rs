@@ -3,11 +3,11 @@ use async_graphql::{connection::*, *};
 use crate::primitives::*;
 
 pub use lana_app::deposit::{
-    DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    AccountStatementsByCreatedAtCursor, DepositAccount as DomainDepositAccount,
+    DepositAccountHistoryCursor, DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
 };
 
-use super::{deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{account_statement::*, deposit::*, deposit_account_history::*, withdrawal::*};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -15,6 +15,7 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    currencies: Vec<CurrencyCode>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +28,7 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            currencies: account.currencies.clone(),
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -49,6 +51,35 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
     }
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct DepositAccountCurrencyBalance {
+    currency: CurrencyCode,
+
+    #[graphql(skip)]
+    balance: lana_app::deposit::DepositAccountCurrencyBalance,
+}
+
+impl From<lana_app::deposit::DepositAccountCurrencyBalance> for DepositAccountCurrencyBalance {
+    fn from(balance: lana_app::deposit::DepositAccountCurrencyBalance) -> Self {
+        Self {
+            currency: balance.currency,
+            balance,
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountCurrencyBalance {
+    async fn settled(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.balance.settled.to_major()?.into())
+    }
+
+    async fn pending(&self) -> async_graphql::Result<Decimal> {
+        Ok(self.balance.pending.to_major()?.into())
+    }
+}
+
 #[ComplexObject]
 impl DepositAccount {
     async fn balance(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccountBalance> {
@@ -61,6 +92,22 @@ impl DepositAccount {
         Ok(DepositAccountBalance::from(balance))
     }
 
+    async fn balances(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountCurrencyBalance>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let balances = app
+            .deposits()
+            .for_subject(sub)?
+            .account_balances(self.entity.id)
+            .await?;
+        Ok(balances
+            .into_iter()
+            .map(DepositAccountCurrencyBalance::from)
+            .collect())
+    }
+
     async fn deposits(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Deposit>> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let deposits = app
@@ -81,6 +128,43 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AccountStatementsByCreatedAtCursor, AccountStatement, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        query(
+            after,
+            None,
+            Some(first),
+            None,
+            |after, _, first, _| async move {
+                let first = first.expect("First always exists");
+                let query_args = es_entity::PaginatedQueryArgs { first, after };
+                let res = app
+                    .deposits()
+                    .for_subject(sub)?
+                    .list_account_statements_for_account(self.entity.id, query_args)
+                    .await?;
+
+                let mut connection = Connection::new(false, res.has_next_page);
+                connection
+                    .edges
+                    .extend(res.entities.into_iter().map(|statement| {
+                        let cursor = AccountStatementsByCreatedAtCursor::from(&statement);
+                        Edge::new(cursor, AccountStatement::from(statement))
+                    }));
+                Ok::<_, async_graphql::Error>(connection)
+            },
+        )
+        .await
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
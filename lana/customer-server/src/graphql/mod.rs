@@ -1,5 +1,6 @@
 #[macro_use]
 pub mod macros;
+mod account_statement;
 mod authenticated_subject;
 mod credit_facility;
 mod customer;
@@ -11,6 +11,7 @@ pub struct Withdrawal {
     withdrawal_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: CurrencyCode,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -24,7 +25,8 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             created_at: withdraw.created_at().into(),
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
-            amount: withdraw.amount,
+            amount: UsdCents::from(withdraw.amount.minor_units()),
+            currency: withdraw.amount.currency(),
             entity: Arc::new(withdraw),
         }
     }
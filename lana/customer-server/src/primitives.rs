@@ -5,9 +5,9 @@ pub use std::sync::Arc;
 
 pub use lana_app::{
     primitives::{
-        CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
-        DisbursalId, DisbursalStatus, PaymentAllocationId, Satoshis, Subject, UsdCents,
-        WithdrawalId,
+        AccountStatementId, CreditFacilityId, CreditFacilityStatus, CurrencyCode, CustomerId,
+        DepositAccountId, DepositId, DisbursalId, DisbursalStatus, PaymentAllocationId, Satoshis,
+        Subject, UsdCents, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -58,6 +58,16 @@ impl Date {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Decimal(rust_decimal::Decimal);
+scalar!(Decimal);
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self(value)
+    }
+}
+
 pub trait ToGlobalId {
     fn to_global_id(&self) -> async_graphql::types::ID;
 }
@@ -81,5 +91,6 @@ impl_to_global_id! {
     WithdrawalId,
     CreditFacilityId,
     DisbursalId,
-    PaymentAllocationId
+    PaymentAllocationId,
+    AccountStatementId
 }
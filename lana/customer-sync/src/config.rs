@@ -1,3 +1,4 @@
+use core_deposit::CurrencyCode;
 use kratos_admin::KratosAdminConfig;
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,8 @@ pub struct CustomerSyncConfig {
     pub kratos_admin: KratosAdminConfig,
     #[serde(default = "default_create_deposit_account_on_customer_create")]
     pub create_deposit_account_on_customer_create: bool,
+    #[serde(default = "default_deposit_account_currencies")]
+    pub deposit_account_currencies: Vec<CurrencyCode>,
 }
 
 impl Default for CustomerSyncConfig {
@@ -21,6 +24,7 @@ impl Default for CustomerSyncConfig {
             customer_status_sync_active: default_customer_status_sync_active(),
             create_deposit_account_on_customer_create:
                 default_create_deposit_account_on_customer_create(),
+            deposit_account_currencies: default_deposit_account_currencies(),
         }
     }
 }
@@ -42,3 +46,7 @@ fn default_customer_status_sync_active() -> bool {
 fn default_create_deposit_account_on_customer_create() -> bool {
     false
 }
+
+fn default_deposit_account_currencies() -> Vec<CurrencyCode> {
+    vec![CurrencyCode::Usd]
+}
@@ -217,6 +217,7 @@ where
                     id,
                     active,
                     customer_type,
+                    self.config.deposit_account_currencies.clone(),
                 )
                 .await
             {
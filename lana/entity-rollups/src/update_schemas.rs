@@ -9,7 +9,10 @@ use std::{
 };
 
 use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
-use core_accounting::event_schema::{AccountingCsvEvent, ChartEvent, ManualTransactionEvent};
+use core_accounting::event_schema::{
+    AccountingCsvEvent, AccountingPeriodEvent, ChartEvent, FixedAssetEvent, ManualTransactionEvent,
+    RecurringEntryTemplateEvent,
+};
 use core_credit::event_schema::{
     CollateralEvent, CreditFacilityEvent, DisbursalEvent, InterestAccrualCycleEvent,
     LiquidationProcessEvent, ObligationEvent, PaymentAllocationEvent, PaymentEvent,
@@ -17,7 +20,10 @@ use core_credit::event_schema::{
 };
 use core_custody::event_schema::CustodianEvent;
 use core_customer::event_schema::CustomerEvent;
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    AccountStatementEvent, DepositAccountEvent, DepositEvent, PaymentFileEvent,
+    ScheduledWithdrawalEvent, StatementImportEvent, WithdrawalEvent,
+};
 use document_storage::event_schema::DocumentEvent;
 use governance::event_schema::{ApprovalProcessEvent, CommitteeEvent, PolicyEvent};
 use schemars::schema_for;
@@ -90,6 +96,28 @@ pub fn update_schemas(schemas_out_dir: &str) -> anyhow::Result<()> {
             filename: "withdrawal_event_schema.json",
             generate_schema: || serde_json::to_value(schema_for!(WithdrawalEvent)).unwrap(),
         },
+        SchemaInfo {
+            name: "StatementImportEvent",
+            filename: "statement_import_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(StatementImportEvent)).unwrap(),
+        },
+        SchemaInfo {
+            name: "PaymentFileEvent",
+            filename: "payment_file_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(PaymentFileEvent)).unwrap(),
+        },
+        SchemaInfo {
+            name: "AccountStatementEvent",
+            filename: "account_statement_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(AccountStatementEvent)).unwrap(),
+        },
+        SchemaInfo {
+            name: "ScheduledWithdrawalEvent",
+            filename: "scheduled_withdrawal_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(ScheduledWithdrawalEvent)).unwrap()
+            },
+        },
         SchemaInfo {
             name: "CollateralEvent",
             filename: "collateral_event_schema.json",
@@ -152,6 +180,23 @@ pub fn update_schemas(schemas_out_dir: &str) -> anyhow::Result<()> {
             filename: "manual_transaction_event_schema.json",
             generate_schema: || serde_json::to_value(schema_for!(ManualTransactionEvent)).unwrap(),
         },
+        SchemaInfo {
+            name: "AccountingPeriodEvent",
+            filename: "accounting_period_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(AccountingPeriodEvent)).unwrap(),
+        },
+        SchemaInfo {
+            name: "RecurringEntryTemplateEvent",
+            filename: "recurring_entry_template_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(RecurringEntryTemplateEvent)).unwrap()
+            },
+        },
+        SchemaInfo {
+            name: "FixedAssetEvent",
+            filename: "fixed_asset_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(FixedAssetEvent)).unwrap(),
+        },
     ];
 
     let schemas_dir = Path::new(schemas_out_dir);
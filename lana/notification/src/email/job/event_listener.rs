@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use job::{
     CurrentJob, Job, JobCompletion, JobConfig, JobInitializer, JobRunner, JobType, RetrySettings,
 };
-use lana_events::{CoreCreditEvent, LanaEvent};
+use lana_events::{CoreCreditEvent, GovernanceEvent, LanaEvent};
 use outbox::Outbox;
 
 use crate::email::EmailNotification;
@@ -91,15 +91,33 @@ impl EmailEventListenerRunner {
         db: &mut es_entity::DbOp<'_>,
         event: &LanaEvent,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        if let LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
-            id,
-            credit_facility_id,
-            amount,
-        }) = event
-        {
-            self.email_notification
-                .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
-                .await?;
+        match event {
+            LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
+                id,
+                credit_facility_id,
+                amount,
+            }) => {
+                self.email_notification
+                    .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
+                    .await?;
+            }
+            LanaEvent::Governance(GovernanceEvent::ApprovalProcessReminder {
+                id,
+                process_type,
+                target_ref,
+                voter_ids,
+            }) => {
+                self.email_notification
+                    .send_approval_reminder_notification(
+                        db,
+                        id,
+                        process_type,
+                        target_ref,
+                        voter_ids,
+                    )
+                    .await?;
+            }
+            _ => {}
         }
         Ok(())
     }
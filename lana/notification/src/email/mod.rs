@@ -6,15 +6,19 @@ mod smtp;
 pub mod templates;
 
 use ::job::{JobId, Jobs};
-use core_access::user::Users;
+use core_access::{
+    UserId,
+    user::{User, Users},
+};
 use core_credit::{CoreCredit, CreditFacilityId, ObligationId, ObligationType};
 use core_customer::Customers;
+use governance::{ApprovalProcessId, ApprovalProcessType, CommitteeMemberId};
 use job::{EmailSenderConfig, EmailSenderInitializer};
 use lana_events::LanaEvent;
 
 use crate::{Authorization, LanaAudit};
 use smtp::SmtpClient;
-use templates::{EmailTemplate, EmailType, OverduePaymentEmailData};
+use templates::{ApprovalReminderEmailData, EmailTemplate, EmailType, OverduePaymentEmailData};
 
 pub use config::EmailConfig;
 pub use error::EmailError;
@@ -113,4 +117,32 @@ impl EmailNotification {
         }
         Ok(())
     }
+
+    pub async fn send_approval_reminder_notification(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        process_id: &ApprovalProcessId,
+        process_type: &ApprovalProcessType,
+        target_ref: &str,
+        voter_ids: &[CommitteeMemberId],
+    ) -> Result<(), EmailError> {
+        let user_ids: Vec<UserId> = voter_ids.iter().copied().map(UserId::from).collect();
+        let voters = self.users.find_all::<User>(&user_ids).await?;
+
+        let email_data = ApprovalReminderEmailData {
+            process_id: process_id.to_string(),
+            process_type: process_type.to_string(),
+            target_ref: target_ref.to_string(),
+        };
+        for voter in voters.into_values() {
+            let email_config = EmailSenderConfig {
+                recipient: voter.email,
+                email_type: EmailType::ApprovalReminder(email_data.clone()),
+            };
+            self.jobs
+                .create_and_spawn_in_op(db, JobId::new(), email_config)
+                .await?;
+        }
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ use crate::email::error::EmailError;
 #[derive(Serialize, Deserialize)]
 pub enum EmailType {
     OverduePayment(OverduePaymentEmailData),
+    ApprovalReminder(ApprovalReminderEmailData),
     General { subject: String, body: String },
 }
 
@@ -24,12 +25,17 @@ impl EmailTemplate {
         handlebars.register_template_string("styles", include_str!("partials/styles.hbs"))?;
         handlebars.register_template_string("general", include_str!("views/general.hbs"))?;
         handlebars.register_template_string("overdue", include_str!("views/overdue.hbs"))?;
+        handlebars.register_template_string(
+            "approval_reminder",
+            include_str!("views/approval_reminder.hbs"),
+        )?;
         Ok(Self { handlebars })
     }
 
     pub fn render_email(&self, email_type: &EmailType) -> Result<(String, String), EmailError> {
         match email_type {
             EmailType::OverduePayment(data) => self.render_overdue_payment_email(data),
+            EmailType::ApprovalReminder(data) => self.render_approval_reminder_email(data),
             EmailType::General { subject, body } => self.generic_email_template(subject, body),
         }
     }
@@ -69,6 +75,24 @@ impl EmailTemplate {
         let html_body = self.handlebars.render("overdue", &data)?;
         Ok((subject, html_body))
     }
+
+    fn render_approval_reminder_email(
+        &self,
+        data: &ApprovalReminderEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = format!(
+            "Lana Bank: Approval Pending - {} ({})",
+            data.process_type, data.target_ref
+        );
+        let data = json!({
+            "subject": &subject,
+            "process_id": &data.process_id,
+            "process_type": &data.process_type,
+            "target_ref": &data.target_ref,
+        });
+        let html_body = self.handlebars.render("approval_reminder", &data)?;
+        Ok((subject, html_body))
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -80,3 +104,10 @@ pub struct OverduePaymentEmailData {
     pub due_date: DateTime<Utc>,
     pub customer_email: String,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ApprovalReminderEmailData {
+    pub process_id: String,
+    pub process_type: String,
+    pub target_ref: String,
+}
@@ -229,6 +229,7 @@ impl FromStr for AppAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum AuditAction {
     List,
+    Export,
 }
 
 impl AuditAction {
@@ -241,6 +242,7 @@ impl AuditAction {
                     variant,
                     &[PERMISSION_SET_APP_VIEWER, PERMISSION_SET_APP_WRITER],
                 ),
+                Self::Export => ActionDescription::new(variant, &[PERMISSION_SET_APP_WRITER]),
             };
             res.push(action_description);
         }
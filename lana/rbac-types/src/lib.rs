@@ -16,8 +16,30 @@ pub use object::*;
 const SYSTEM_SUBJECT_ID: Uuid = uuid!("00000000-0000-0000-0000-000000000000");
 pub const ROLE_NAME_ACCOUNTANT: &str = "accountant";
 pub const ROLE_NAME_ADMIN: &str = "admin";
+pub const ROLE_NAME_APPROVER: &str = "approver";
 pub const ROLE_NAME_BANK_MANAGER: &str = "bank-manager";
 
+/// Pairs of permission sets that must never be held by the same user, so that
+/// whoever makes an entity cannot also be the one checking it.
+pub const SEPARATION_OF_DUTIES: &[(&str, &str)] = &[
+    (
+        core_deposit::PERMISSION_SET_DEPOSIT_WRITER,
+        core_deposit::PERMISSION_SET_DEPOSIT_APPROVER,
+    ),
+    (
+        core_deposit::PERMISSION_SET_DEPOSIT_WRITER,
+        governance::PERMISSION_SET_GOVERNANCE_APPROVER,
+    ),
+    (
+        core_credit::PERMISSION_SET_CREDIT_WRITER,
+        governance::PERMISSION_SET_GOVERNANCE_APPROVER,
+    ),
+    (
+        core_accounting::PERMISSION_SET_ACCOUNTING_WRITER,
+        governance::PERMISSION_SET_GOVERNANCE_APPROVER,
+    ),
+];
+
 #[derive(Clone, PartialEq, Eq, Copy, async_graphql::Enum)]
 pub enum PermissionSetName {
     AccessViewer,
@@ -35,8 +57,10 @@ pub enum PermissionSetName {
     DashboardViewer,
     DepositViewer,
     DepositWriter,
+    DepositApprover,
     GovernanceViewer,
     GovernanceWriter,
+    GovernanceApprover,
 }
 
 impl std::str::FromStr for PermissionSetName {
@@ -67,9 +91,11 @@ impl std::str::FromStr for PermissionSetName {
 
             core_deposit::PERMISSION_SET_DEPOSIT_VIEWER => Ok(DepositViewer),
             core_deposit::PERMISSION_SET_DEPOSIT_WRITER => Ok(DepositWriter),
+            core_deposit::PERMISSION_SET_DEPOSIT_APPROVER => Ok(DepositApprover),
 
             governance::PERMISSION_SET_GOVERNANCE_VIEWER => Ok(GovernanceViewer),
             governance::PERMISSION_SET_GOVERNANCE_WRITER => Ok(GovernanceWriter),
+            governance::PERMISSION_SET_GOVERNANCE_APPROVER => Ok(GovernanceApprover),
             _ => Err(strum::ParseError::VariantNotFound),
         }
     }
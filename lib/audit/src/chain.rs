@@ -0,0 +1,310 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
+
+use std::collections::HashMap;
+
+use crate::primitives::AuditEntryId;
+
+/// `previous_hash` of the first entry in the chain.
+pub const AUDIT_CHAIN_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+pub(crate) fn entry_hash(
+    previous_hash: &str,
+    id: AuditEntryId,
+    subject: &str,
+    object: &str,
+    action: &str,
+    authorized: bool,
+    recorded_at: DateTime<Utc>,
+) -> String {
+    let content = serde_json::json!([
+        id,
+        subject,
+        object,
+        action,
+        authorized,
+        recorded_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+    ]);
+    let mut hasher = Sha256::new();
+    hasher.update(previous_hash.as_bytes());
+    hasher.update(content.to_string().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditAnchor {
+    pub chain_sequence: i64,
+    pub digest: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditChainBreak {
+    SequenceGap { expected: i64 },
+    PreviousHashMismatch,
+    HashMismatch,
+    AnchorMismatch,
+    MissingAnchoredEntry,
+}
+
+impl std::fmt::Display for AuditChainBreak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SequenceGap { expected } => {
+                write!(f, "sequence gap, expected sequence {expected}")
+            }
+            Self::PreviousHashMismatch => {
+                write!(f, "previous hash does not match the preceding entry")
+            }
+            Self::HashMismatch => write!(f, "hash does not match the entry content"),
+            Self::AnchorMismatch => write!(f, "hash does not match the anchored digest"),
+            Self::MissingAnchoredEntry => write!(f, "anchored entry is missing from the chain"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditChainBrokenLink {
+    pub chain_sequence: i64,
+    pub entry_id: Option<AuditEntryId>,
+    pub reason: AuditChainBreak,
+}
+
+impl std::fmt::Display for AuditChainBrokenLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.entry_id {
+            Some(id) => write!(
+                f,
+                "entry {id} at sequence {}: {}",
+                self.chain_sequence, self.reason
+            ),
+            None => write!(f, "sequence {}: {}", self.chain_sequence, self.reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditChainVerification {
+    pub verified_entries: u64,
+    pub anchors_checked: usize,
+    pub broken_link: Option<AuditChainBrokenLink>,
+    pub stale_unsealed_entries: u64,
+}
+
+impl AuditChainVerification {
+    pub fn is_intact(&self) -> bool {
+        self.broken_link.is_none()
+    }
+}
+
+pub(crate) struct SealedEntry<'a> {
+    pub id: AuditEntryId,
+    pub subject: &'a str,
+    pub object: &'a str,
+    pub action: &'a str,
+    pub authorized: bool,
+    pub recorded_at: DateTime<Utc>,
+    pub chain_sequence: i64,
+    pub previous_hash: &'a str,
+    pub hash: &'a str,
+}
+
+/// Walks sealed entries in sequence order and stops at the first broken link.
+pub(crate) struct ChainVerifier {
+    anchors: HashMap<i64, String>,
+    expected_sequence: i64,
+    previous_hash: String,
+    verified_entries: u64,
+}
+
+impl ChainVerifier {
+    pub fn new(anchors: HashMap<i64, String>) -> Self {
+        Self {
+            anchors,
+            expected_sequence: 1,
+            previous_hash: AUDIT_CHAIN_GENESIS_HASH.to_string(),
+            verified_entries: 0,
+        }
+    }
+
+    pub fn last_sequence(&self) -> i64 {
+        self.expected_sequence - 1
+    }
+
+    pub fn check(&mut self, entry: SealedEntry<'_>) -> Result<(), AuditChainBrokenLink> {
+        let broken = |reason| AuditChainBrokenLink {
+            chain_sequence: entry.chain_sequence,
+            entry_id: Some(entry.id),
+            reason,
+        };
+
+        if entry.chain_sequence != self.expected_sequence {
+            return Err(broken(AuditChainBreak::SequenceGap {
+                expected: self.expected_sequence,
+            }));
+        }
+        if entry.previous_hash != self.previous_hash {
+            return Err(broken(AuditChainBreak::PreviousHashMismatch));
+        }
+        let hash = entry_hash(
+            entry.previous_hash,
+            entry.id,
+            entry.subject,
+            entry.object,
+            entry.action,
+            entry.authorized,
+            entry.recorded_at,
+        );
+        if hash != entry.hash {
+            return Err(broken(AuditChainBreak::HashMismatch));
+        }
+        if self
+            .anchors
+            .get(&entry.chain_sequence)
+            .is_some_and(|digest| digest != entry.hash)
+        {
+            return Err(broken(AuditChainBreak::AnchorMismatch));
+        }
+
+        self.previous_hash = hash;
+        self.expected_sequence += 1;
+        self.verified_entries += 1;
+        Ok(())
+    }
+
+    pub fn finish(self, broken_link: Option<AuditChainBrokenLink>) -> AuditChainVerification {
+        let broken_link = broken_link.or_else(|| {
+            self.anchors
+                .keys()
+                .filter(|sequence| **sequence >= self.expected_sequence)
+                .min()
+                .map(|sequence| AuditChainBrokenLink {
+                    chain_sequence: *sequence,
+                    entry_id: None,
+                    reason: AuditChainBreak::MissingAnchoredEntry,
+                })
+        });
+        AuditChainVerification {
+            verified_entries: self.verified_entries,
+            anchors_checked: self.anchors.len(),
+            broken_link,
+            stale_unsealed_entries: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Entry {
+        id: AuditEntryId,
+        subject: String,
+        recorded_at: DateTime<Utc>,
+        chain_sequence: i64,
+        previous_hash: String,
+        hash: String,
+    }
+
+    impl Entry {
+        fn sealed(&self) -> SealedEntry<'_> {
+            SealedEntry {
+                id: self.id,
+                subject: &self.subject,
+                object: "customer/*",
+                action: "customer:read",
+                authorized: true,
+                recorded_at: self.recorded_at,
+                chain_sequence: self.chain_sequence,
+                previous_hash: &self.previous_hash,
+                hash: &self.hash,
+            }
+        }
+    }
+
+    fn chain(len: i64) -> Vec<Entry> {
+        let mut previous_hash = AUDIT_CHAIN_GENESIS_HASH.to_string();
+        (1..=len)
+            .map(|sequence| {
+                let id = AuditEntryId::from(sequence * 10);
+                let subject = format!("user:{sequence}");
+                let recorded_at = Utc::now();
+                let hash = entry_hash(
+                    &previous_hash,
+                    id,
+                    &subject,
+                    "customer/*",
+                    "customer:read",
+                    true,
+                    recorded_at,
+                );
+                Entry {
+                    id,
+                    subject,
+                    recorded_at,
+                    chain_sequence: sequence,
+                    previous_hash: std::mem::replace(&mut previous_hash, hash.clone()),
+                    hash,
+                }
+            })
+            .collect()
+    }
+
+    fn verify(entries: &[Entry], anchors: HashMap<i64, String>) -> AuditChainVerification {
+        let mut verifier = ChainVerifier::new(anchors);
+        let broken = entries
+            .iter()
+            .find_map(|entry| verifier.check(entry.sealed()).err());
+        verifier.finish(broken)
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let entries = chain(3);
+        let anchors = HashMap::from([(3, entries[2].hash.clone())]);
+
+        let res = verify(&entries, anchors);
+
+        assert!(res.is_intact());
+        assert_eq!(res.verified_entries, 3);
+    }
+
+    #[test]
+    fn tampered_entry_is_first_broken_link() {
+        let mut entries = chain(3);
+        entries[1].subject = "user:intruder".to_string();
+
+        let res = verify(&entries, HashMap::new());
+
+        let broken = res.broken_link.expect("chain should be broken");
+        assert_eq!(broken.entry_id, Some(entries[1].id));
+        assert_eq!(broken.reason, AuditChainBreak::HashMismatch);
+        assert_eq!(res.verified_entries, 1);
+    }
+
+    #[test]
+    fn deleted_entry_is_reported_as_gap() {
+        let mut entries = chain(3);
+        entries.remove(1);
+
+        let res = verify(&entries, HashMap::new());
+
+        let broken = res.broken_link.expect("chain should be broken");
+        assert_eq!(broken.chain_sequence, 3);
+        assert_eq!(broken.reason, AuditChainBreak::SequenceGap { expected: 2 });
+    }
+
+    #[test]
+    fn truncated_tail_is_caught_by_anchor() {
+        let mut entries = chain(3);
+        let anchors = HashMap::from([(3, entries[2].hash.clone())]);
+        entries.pop();
+
+        let res = verify(&entries, anchors);
+
+        let broken = res.broken_link.expect("chain should be broken");
+        assert_eq!(broken.chain_sequence, 3);
+        assert_eq!(broken.reason, AuditChainBreak::MissingAnchoredEntry);
+    }
+}
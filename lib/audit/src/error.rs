@@ -10,4 +10,8 @@ pub enum AuditError {
     ObjectParseError(String),
     #[error("AuditError - ActionParseError: Could not parse '{0}'")]
     ActionParseError(String),
+    #[error("AuditError - CsvError: {0}")]
+    CsvError(#[from] csv::Error),
+    #[error("AuditError - JsonError: {0}")]
+    JsonError(#[from] serde_json::Error),
 }
@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use crate::{AuditEntry, error::AuditError, primitives::AuditEntryId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditExportFormat {
+    Csv,
+    Json,
+}
+
+impl AuditExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AuditExportRecord {
+    id: AuditEntryId,
+    subject: String,
+    object: String,
+    action: String,
+    authorized: bool,
+    recorded_at: DateTime<Utc>,
+    previous_hash: Option<String>,
+    hash: Option<String>,
+}
+
+impl<S, O, A> From<&AuditEntry<S, O, A>> for AuditExportRecord
+where
+    S: fmt::Display,
+    O: fmt::Display,
+    A: fmt::Display,
+{
+    fn from(entry: &AuditEntry<S, O, A>) -> Self {
+        Self {
+            id: entry.id,
+            subject: entry.subject.to_string(),
+            object: entry.object.to_string(),
+            action: entry.action.to_string(),
+            authorized: entry.authorized,
+            recorded_at: entry.recorded_at,
+            previous_hash: entry.previous_hash.clone(),
+            hash: entry.hash.clone(),
+        }
+    }
+}
+
+/// Renders an export batch by batch, so that only the rendered output is
+/// held rather than every entry.
+pub(crate) enum ExportWriter {
+    Csv(csv::Writer<Vec<u8>>),
+    Json { buf: Vec<u8>, empty: bool },
+}
+
+impl ExportWriter {
+    pub(crate) fn new(format: AuditExportFormat) -> Self {
+        match format {
+            AuditExportFormat::Csv => Self::Csv(csv::Writer::from_writer(vec![])),
+            AuditExportFormat::Json => Self::Json {
+                buf: vec![b'['],
+                empty: true,
+            },
+        }
+    }
+
+    pub(crate) fn write<S, O, A>(
+        &mut self,
+        entries: &[AuditEntry<S, O, A>],
+    ) -> Result<(), AuditError>
+    where
+        S: fmt::Display,
+        O: fmt::Display,
+        A: fmt::Display,
+    {
+        for record in entries.iter().map(AuditExportRecord::from) {
+            match self {
+                Self::Csv(wtr) => wtr.serialize(record)?,
+                Self::Json { buf, empty } => {
+                    if !*empty {
+                        buf.push(b',');
+                    }
+                    serde_json::to_writer(&mut *buf, &record)?;
+                    *empty = false;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Result<Vec<u8>, AuditError> {
+        match self {
+            Self::Csv(wtr) => Ok(wtr
+                .into_inner()
+                .expect("Could not flush audit export writer")),
+            Self::Json { mut buf, .. } => {
+                buf.push(b']');
+                Ok(buf)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, authorized: bool) -> AuditEntry<String, String, String> {
+        AuditEntry {
+            id: AuditEntryId::from(id),
+            subject: "user:1".to_string(),
+            object: "deposit/withdrawal/1".to_string(),
+            action: "deposit:withdrawal:confirm".to_string(),
+            authorized,
+            recorded_at: "2025-01-01T00:00:00Z".parse().unwrap(),
+            previous_hash: None,
+            hash: None,
+        }
+    }
+
+    fn render(
+        batches: &[Vec<AuditEntry<String, String, String>>],
+        format: AuditExportFormat,
+    ) -> Vec<u8> {
+        let mut writer = ExportWriter::new(format);
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn renders_csv_with_header() {
+        let csv = render(
+            &[vec![entry(2, true)], vec![entry(1, false)]],
+            AuditExportFormat::Csv,
+        );
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("id,subject,object,action,authorized,recorded_at,previous_hash,hash")
+        );
+        assert_eq!(
+            lines.next(),
+            Some(
+                "2,user:1,deposit/withdrawal/1,deposit:withdrawal:confirm,true,2025-01-01T00:00:00Z,,"
+            )
+        );
+        assert_eq!(lines.count(), 1);
+    }
+
+    #[test]
+    fn renders_json_array() {
+        let json = render(
+            &[vec![entry(2, true)], vec![entry(1, false)]],
+            AuditExportFormat::Json,
+        );
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value[0]["id"], 2);
+        assert_eq!(value[1]["id"], 1);
+        assert_eq!(value[1]["authorized"], false);
+    }
+
+    #[test]
+    fn renders_empty_json_array() {
+        let json = render(&[], AuditExportFormat::Json);
+        assert_eq!(json, b"[]");
+    }
+}
@@ -3,10 +3,17 @@
 
 use std::{fmt, marker::PhantomData, str::FromStr};
 
+mod chain;
 pub mod error;
+mod export;
 mod primitives;
 mod svc_trait;
 
+pub use chain::{
+    AUDIT_CHAIN_GENESIS_HASH, AuditAnchor, AuditChainBreak, AuditChainBrokenLink,
+    AuditChainVerification,
+};
+pub use export::AuditExportFormat;
 pub use primitives::*;
 pub use svc_trait::*;
 
@@ -33,6 +33,21 @@ pub struct AuditEntry<S, O, A> {
     pub action: A,
     pub authorized: bool,
     pub recorded_at: DateTime<Utc>,
+    /// Hash of the preceding entry in the chain, `None` until the entry is sealed.
+    pub previous_hash: Option<String>,
+    pub hash: Option<String>,
+}
+
+/// Narrows down audit entries, an entry has to match every field that is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditFilter {
+    pub subject: Option<String>,
+    /// Matches every object starting with the given value.
+    pub object: Option<String>,
+    pub action: Option<String>,
+    pub authorized: Option<bool>,
+    pub recorded_after: Option<DateTime<Utc>>,
+    pub recorded_before: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -2,7 +2,16 @@ use async_trait::async_trait;
 
 use std::{collections::HashMap, fmt, str::FromStr};
 
-use crate::{AuditEntry, error::AuditError, primitives::*};
+use crate::{
+    AuditEntry,
+    chain::{self, AuditAnchor, AuditChainVerification, ChainVerifier, SealedEntry},
+    error::AuditError,
+    export::{self, AuditExportFormat},
+    primitives::*,
+};
+
+const EXPORT_BATCH_SIZE: usize = 1000;
+const CHAIN_BATCH_SIZE: i64 = 1000;
 
 pub trait SystemSubject {
     fn system() -> Self;
@@ -110,6 +119,7 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
 
     async fn list(
         &self,
+        filter: &AuditFilter,
         query: es_entity::PaginatedQueryArgs<AuditCursor>,
     ) -> Result<
         es_entity::PaginatedQueryRet<
@@ -120,17 +130,31 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
     > {
         let after_id: Option<AuditEntryId> = query.after.map(|cursor| cursor.id);
         let limit = query.first;
+        let object_prefix = filter.object.as_deref().map(escape_like);
 
         let rows = sqlx::query!(
             r#"
-                SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at
+                SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at,
+                       previous_hash, hash
                 FROM audit_entries
-                WHERE COALESCE(id < $1, true)
+                WHERE ($1::BIGINT IS NULL OR id < $1)
+                  AND ($3::VARCHAR IS NULL OR subject = $3)
+                  AND ($4::VARCHAR IS NULL OR object LIKE $4 || '%')
+                  AND ($5::VARCHAR IS NULL OR action = $5)
+                  AND ($6::BOOLEAN IS NULL OR authorized = $6)
+                  AND ($7::TIMESTAMPTZ IS NULL OR recorded_at >= $7)
+                  AND ($8::TIMESTAMPTZ IS NULL OR recorded_at < $8)
                 ORDER BY id DESC
                 LIMIT $2
                 "#,
             after_id as Option<AuditEntryId>,
             (limit + 1) as i64,
+            filter.subject,
+            object_prefix,
+            filter.action,
+            filter.authorized,
+            filter.recorded_after,
+            filter.recorded_before,
         )
         .fetch_all(self.pool())
         .await?;
@@ -157,6 +181,8 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
                         .map_err(|_| AuditError::ActionParseError(raw_event.action))?,
                     authorized: raw_event.authorized,
                     recorded_at: raw_event.recorded_at,
+                    previous_hash: raw_event.previous_hash,
+                    hash: raw_event.hash,
                 })
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -174,13 +200,236 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
         })
     }
 
+    /// Renders every entry matching `filter`, newest first. Entries are
+    /// rendered as each batch is loaded rather than collected up front.
+    async fn export(
+        &self,
+        filter: &AuditFilter,
+        format: AuditExportFormat,
+    ) -> Result<Vec<u8>, AuditError> {
+        let mut writer = export::ExportWriter::new(format);
+        let mut query = es_entity::PaginatedQueryArgs {
+            first: EXPORT_BATCH_SIZE,
+            after: None,
+        };
+        loop {
+            let res = self.list(filter, query).await?;
+            writer.write(&res.entities)?;
+            match res.end_cursor {
+                Some(cursor) => {
+                    query = es_entity::PaginatedQueryArgs {
+                        first: EXPORT_BATCH_SIZE,
+                        after: Some(cursor),
+                    }
+                }
+                None => break,
+            }
+        }
+
+        writer.finish()
+    }
+
+    /// Links every unsealed entry into the hash chain, in the order they became visible.
+    async fn seal_pending(&self) -> Result<usize, AuditError> {
+        let mut sealed = 0;
+        loop {
+            let mut tx = self.pool().begin().await?;
+            let head = sqlx::query!(
+                r#"
+                    SELECT chain_sequence AS "chain_sequence!", hash AS "hash!"
+                    FROM audit_entries
+                    WHERE chain_sequence IS NOT NULL
+                    ORDER BY chain_sequence DESC
+                    LIMIT 1
+                    "#,
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+            let (mut sequence, mut previous_hash) = match head {
+                Some(head) => (head.chain_sequence, head.hash),
+                None => (0, chain::AUDIT_CHAIN_GENESIS_HASH.to_string()),
+            };
+
+            let pending = sqlx::query!(
+                r#"
+                    SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at
+                    FROM audit_entries
+                    WHERE chain_sequence IS NULL
+                    ORDER BY id
+                    LIMIT $1
+                    "#,
+                CHAIN_BATCH_SIZE,
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+            if pending.is_empty() {
+                break;
+            }
+            let n_pending = pending.len();
+
+            let mut ids = Vec::with_capacity(n_pending);
+            let mut sequences = Vec::with_capacity(n_pending);
+            let mut previous_hashes = Vec::with_capacity(n_pending);
+            let mut hashes = Vec::with_capacity(n_pending);
+            for entry in pending {
+                sequence += 1;
+                let hash = chain::entry_hash(
+                    &previous_hash,
+                    entry.id,
+                    &entry.subject,
+                    &entry.object,
+                    &entry.action,
+                    entry.authorized,
+                    entry.recorded_at,
+                );
+                ids.push(i64::from(entry.id));
+                sequences.push(sequence);
+                previous_hashes.push(std::mem::replace(&mut previous_hash, hash.clone()));
+                hashes.push(hash);
+            }
+
+            sqlx::query!(
+                r#"
+                    UPDATE audit_entries AS e
+                    SET chain_sequence = u.chain_sequence,
+                        previous_hash = u.previous_hash,
+                        hash = u.hash
+                    FROM UNNEST($1::BIGINT[], $2::BIGINT[], $3::VARCHAR[], $4::VARCHAR[])
+                      AS u(id, chain_sequence, previous_hash, hash)
+                    WHERE e.id = u.id
+                    "#,
+                &ids,
+                &sequences,
+                &previous_hashes,
+                &hashes,
+            )
+            .execute(&mut *tx)
+            .await?;
+            tx.commit().await?;
+
+            sealed += n_pending;
+            if n_pending < CHAIN_BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(sealed)
+    }
+
+    /// Records the digest of the current chain head, `None` if it is already anchored.
+    async fn anchor(&self) -> Result<Option<AuditAnchor>, AuditError> {
+        let anchor = sqlx::query_as!(
+            AuditAnchor,
+            r#"
+                INSERT INTO audit_anchors (chain_sequence, digest)
+                SELECT chain_sequence, hash
+                FROM audit_entries
+                WHERE chain_sequence IS NOT NULL
+                ORDER BY chain_sequence DESC
+                LIMIT 1
+                ON CONFLICT (chain_sequence) DO NOTHING
+                RETURNING chain_sequence, digest, created_at
+                "#,
+        )
+        .fetch_optional(self.pool())
+        .await?;
+
+        Ok(anchor)
+    }
+
+    async fn latest_anchor(&self) -> Result<Option<AuditAnchor>, AuditError> {
+        let anchor = sqlx::query_as!(
+            AuditAnchor,
+            r#"
+                SELECT chain_sequence, digest, created_at
+                FROM audit_anchors
+                ORDER BY chain_sequence DESC
+                LIMIT 1
+                "#,
+        )
+        .fetch_optional(self.pool())
+        .await?;
+
+        Ok(anchor)
+    }
+
+    /// Walks the sealed chain from the start and reports the first broken link.
+    ///
+    /// `trusted_anchors` must come from outside the database, otherwise an
+    /// attacker with write access could rewrite the chain and its anchors
+    /// together. Entries recorded before `unsealed_before` that are still
+    /// unsealed are reported as stale.
+    async fn verify_chain(
+        &self,
+        trusted_anchors: HashMap<i64, String>,
+        unsealed_before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<AuditChainVerification, AuditError> {
+        let stale_unsealed_entries = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM audit_entries
+                WHERE chain_sequence IS NULL AND recorded_at < $1
+                "#,
+            unsealed_before,
+        )
+        .fetch_one(self.pool())
+        .await?;
+
+        let mut verifier = ChainVerifier::new(trusted_anchors);
+        let mut broken_link = None;
+        'pages: loop {
+            let rows = sqlx::query!(
+                r#"
+                    SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at,
+                           chain_sequence AS "chain_sequence!", previous_hash AS "previous_hash!", hash AS "hash!"
+                    FROM audit_entries
+                    WHERE chain_sequence > $1
+                    ORDER BY chain_sequence
+                    LIMIT $2
+                    "#,
+                verifier.last_sequence(),
+                CHAIN_BATCH_SIZE,
+            )
+            .fetch_all(self.pool())
+            .await?;
+            let n_rows = rows.len();
+
+            for row in rows.iter() {
+                if let Err(link) = verifier.check(SealedEntry {
+                    id: row.id,
+                    subject: &row.subject,
+                    object: &row.object,
+                    action: &row.action,
+                    authorized: row.authorized,
+                    recorded_at: row.recorded_at,
+                    chain_sequence: row.chain_sequence,
+                    previous_hash: &row.previous_hash,
+                    hash: &row.hash,
+                }) {
+                    broken_link = Some(link);
+                    break 'pages;
+                }
+            }
+
+            if n_rows < CHAIN_BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(AuditChainVerification {
+            stale_unsealed_entries: stale_unsealed_entries as u64,
+            ..verifier.finish(broken_link)
+        })
+    }
+
     async fn find_all<T: From<AuditEntry<Self::Subject, Self::Object, Self::Action>>>(
         &self,
         ids: &[AuditEntryId],
     ) -> Result<HashMap<AuditEntryId, T>, AuditError> {
         let raw_entries = sqlx::query!(
             r#"
-                SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at
+                SELECT id AS "id: AuditEntryId", subject, object, action, authorized, recorded_at,
+                       previous_hash, hash
                 FROM audit_entries
                 WHERE id = ANY($1)
                 "#,
@@ -208,6 +457,8 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
                         .map_err(|_| AuditError::ActionParseError(raw_entry.action))?,
                     authorized: raw_entry.authorized,
                     recorded_at: raw_entry.recorded_at,
+                    previous_hash: raw_entry.previous_hash,
+                    hash: raw_entry.hash,
                 };
                 Ok((raw_entry.id, T::from(audit_entry)))
             })
@@ -216,3 +467,11 @@ pub trait AuditSvc: Clone + Sync + Send + 'static {
         Ok(audit_entries)
     }
 }
+
+/// Escapes the `LIKE` wildcards in `prefix` so it only matches literally.
+fn escape_like(prefix: &str) -> String {
+    prefix
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use audit::{AuditInfo, AuditSvc};
 
-use super::error::AuthorizationError;
+use super::{Attributes, error::AuthorizationError};
 
 #[async_trait]
 pub trait PermissionCheck: Clone + Sync + Send + 'static {
@@ -10,6 +10,8 @@ pub trait PermissionCheck: Clone + Sync + Send + 'static {
 
     fn audit(&self) -> &Self::Audit;
 
+    /// Fails for subjects whose roles only grant `action` under conditions, as
+    /// there are no attributes to check them against.
     async fn enforce_permission(
         &self,
         sub: &<Self::Audit as AuditSvc>::Subject,
@@ -17,6 +19,16 @@ pub trait PermissionCheck: Clone + Sync + Send + 'static {
         action: impl Into<<Self::Audit as AuditSvc>::Action> + std::fmt::Debug + Send,
     ) -> Result<AuditInfo, AuthorizationError>;
 
+    /// Like `enforce_permission` but additionally requires the conditions that the
+    /// subject's roles attach to `action` to hold for `attributes`.
+    async fn enforce_permission_with_attributes(
+        &self,
+        sub: &<Self::Audit as AuditSvc>::Subject,
+        object: impl Into<<Self::Audit as AuditSvc>::Object> + std::fmt::Debug + Send,
+        action: impl Into<<Self::Audit as AuditSvc>::Action> + std::fmt::Debug + Send,
+        attributes: &Attributes,
+    ) -> Result<AuditInfo, AuthorizationError>;
+
     async fn evaluate_permission(
         &self,
         sub: &<Self::Audit as AuditSvc>::Subject,
@@ -0,0 +1,141 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use std::{collections::HashMap, fmt};
+
+/// Restricts a permission granted through a role to requests whose attributes
+/// satisfy it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PermissionCondition {
+    AtMost {
+        attribute: String,
+        limit: Decimal,
+    },
+    OneOf {
+        attribute: String,
+        values: Vec<String>,
+    },
+}
+
+impl PermissionCondition {
+    pub fn attribute(&self) -> &str {
+        match self {
+            Self::AtMost { attribute, .. } | Self::OneOf { attribute, .. } => attribute,
+        }
+    }
+
+    /// A condition whose attribute was not supplied is never satisfied.
+    pub fn is_satisfied_by(&self, attributes: &Attributes) -> bool {
+        match (self, attributes.get(self.attribute())) {
+            (Self::AtMost { limit, .. }, Some(AttributeValue::Number(value))) => value <= limit,
+            (Self::OneOf { values, .. }, Some(AttributeValue::Text(value))) => {
+                values.iter().any(|v| v == value)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for PermissionCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AtMost { attribute, limit } => write!(f, "{attribute} at most {limit}"),
+            Self::OneOf { attribute, values } => {
+                write!(f, "{attribute} is one of {}", values.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeValue {
+    Number(Decimal),
+    Text(String),
+}
+
+impl From<Decimal> for AttributeValue {
+    fn from(value: Decimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Attributes of the request being authorized, checked against the
+/// conditions of the subject's roles.
+#[derive(Debug, Clone, Default)]
+pub struct Attributes(HashMap<&'static str, AttributeValue>);
+
+impl Attributes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &'static str, value: impl Into<AttributeValue>) -> Self {
+        self.0.insert(name, value.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AttributeValue> {
+        self.0.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount_limit() -> PermissionCondition {
+        PermissionCondition::AtMost {
+            attribute: "amount_usd".to_string(),
+            limit: Decimal::from(50_000),
+        }
+    }
+
+    #[test]
+    fn at_most_condition() {
+        let condition = amount_limit();
+
+        assert!(
+            condition.is_satisfied_by(&Attributes::new().with("amount_usd", Decimal::from(50_000)))
+        );
+        assert!(
+            !condition
+                .is_satisfied_by(&Attributes::new().with("amount_usd", Decimal::from(50_001)))
+        );
+    }
+
+    #[test]
+    fn one_of_condition() {
+        let condition = PermissionCondition::OneOf {
+            attribute: "customer_type".to_string(),
+            values: vec!["Individual".to_string()],
+        };
+
+        assert!(condition.is_satisfied_by(&Attributes::new().with("customer_type", "Individual")));
+        assert!(!condition.is_satisfied_by(&Attributes::new().with("customer_type", "Bank")));
+    }
+
+    #[test]
+    fn missing_attribute_is_not_satisfied() {
+        assert!(!amount_limit().is_satisfied_by(&Attributes::new()));
+        assert!(!amount_limit().is_satisfied_by(&Attributes::new().with("amount_usd", "50")));
+    }
+
+    #[test]
+    fn condition_text() {
+        assert_eq!(amount_limit().to_string(), "amount_usd at most 50000");
+    }
+}
@@ -2,9 +2,9 @@ use async_trait::async_trait;
 
 use std::fmt;
 
-use audit::{AuditCursor, AuditEntry, AuditInfo, AuditSvc, error::AuditError};
+use audit::{AuditCursor, AuditEntry, AuditFilter, AuditInfo, AuditSvc, error::AuditError};
 
-use crate::{PermissionCheck, error::AuthorizationError};
+use crate::{Attributes, PermissionCheck, error::AuthorizationError};
 
 #[derive(Clone)]
 pub struct DummyAudit<A, O> {
@@ -106,6 +106,7 @@ where
 
     async fn list(
         &self,
+        _filter: &AuditFilter,
         _query: es_entity::PaginatedQueryArgs<AuditCursor>,
     ) -> Result<
         es_entity::PaginatedQueryRet<
@@ -164,6 +165,16 @@ where
         Ok(dummy_audit_info())
     }
 
+    async fn enforce_permission_with_attributes(
+        &self,
+        _sub: &<Self::Audit as AuditSvc>::Subject,
+        _object: impl Into<<Self::Audit as AuditSvc>::Object> + std::fmt::Debug + Send,
+        _action: impl Into<<Self::Audit as AuditSvc>::Action> + std::fmt::Debug + Send,
+        _attributes: &Attributes,
+    ) -> Result<AuditInfo, AuthorizationError> {
+        Ok(dummy_audit_info())
+    }
+
     async fn evaluate_permission(
         &self,
         _sub: &<Self::Audit as AuditSvc>::Subject,
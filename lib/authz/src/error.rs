@@ -13,6 +13,8 @@ pub enum AuthorizationError {
     AuditError(#[from] audit::error::AuditError),
     #[error("AuthorizationError - RoleParseError: Could not parse '{0}'")]
     RoleParseError(String),
+    #[error("AuthorizationError - ConditionParseError: Could not parse '{0}'")]
+    ConditionParseError(String),
 }
 
 impl From<CasbinError> for AuthorizationError {
@@ -1,6 +1,7 @@
 pub mod action_description;
 mod all_or_one;
 mod check_trait;
+mod condition;
 #[cfg(feature = "test-dummy")]
 pub mod dummy;
 pub mod error;
@@ -23,6 +24,7 @@ use error::AuthorizationError;
 
 pub use all_or_one::*;
 pub use check_trait::PermissionCheck;
+pub use condition::*;
 
 const MODEL: &str = include_str!("./rbac.conf");
 
@@ -128,6 +130,61 @@ where
         Ok(())
     }
 
+    /// Restricts every permission on `action` granted through `role` to requests
+    /// satisfying `condition`, see [`PermissionCheck::enforce_permission_with_attributes`].
+    pub async fn add_condition_to_role<R>(
+        &self,
+        role: &R,
+        action: impl Into<Audit::Action>,
+        condition: &PermissionCondition,
+    ) -> Result<(), AuthorizationError>
+    where
+        for<'a> &'a R: Into<Role>,
+    {
+        let mut enforcer = self.enforcer.write().await;
+        match enforcer
+            .add_named_policy(
+                "p2",
+                vec![
+                    role.into().to_string(),
+                    action.into().to_string(),
+                    serde_json::to_string(condition).expect("Could not serialize condition"),
+                ],
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => match AuthorizationError::from(e) {
+                AuthorizationError::PermissionAlreadyExistsForRole(_) => Ok(()),
+                e => Err(e),
+            },
+        }
+    }
+
+    pub async fn remove_condition_from_role<R>(
+        &self,
+        role: &R,
+        action: impl Into<Audit::Action>,
+        condition: &PermissionCondition,
+    ) -> Result<(), AuthorizationError>
+    where
+        for<'a> &'a R: Into<Role>,
+    {
+        let mut enforcer = self.enforcer.write().await;
+        enforcer
+            .remove_named_policy(
+                "p2",
+                vec![
+                    role.into().to_string(),
+                    action.into().to_string(),
+                    serde_json::to_string(condition).expect("Could not serialize condition"),
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn assign_role_to_subject<R>(
         &self,
         sub: impl Into<Audit::Subject>,
@@ -218,6 +275,7 @@ where
         sub: &Audit::Subject,
         object: impl Into<Audit::Object> + std::fmt::Debug,
         action: impl Into<Audit::Action> + std::fmt::Debug,
+        attributes: Option<&Attributes>,
     ) -> Result<(), AuthorizationError> {
         let object = object.into();
         let action = action.into();
@@ -226,9 +284,88 @@ where
         enforcer.load_policy().await?;
 
         match enforcer.enforce((sub.to_string(), object.to_string(), action.to_string())) {
-            Ok(true) => Ok(()),
-            Ok(false) => Err(AuthorizationError::NotAuthorized),
-            Err(e) => Err(AuthorizationError::Casbin(e)),
+            Ok(true) => (),
+            Ok(false) => return Err(AuthorizationError::NotAuthorized),
+            Err(e) => return Err(AuthorizationError::Casbin(e)),
+        }
+
+        match attributes {
+            Some(attributes) => Self::inspect_conditions(
+                &enforcer,
+                sub.to_string(),
+                object.to_string(),
+                action.to_string(),
+                attributes,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Passes if any of the subject's roles granting the permission has all of
+    /// its conditions on `action` satisfied.
+    fn inspect_conditions(
+        enforcer: &Enforcer,
+        sub: String,
+        object: String,
+        action: String,
+        attributes: &Attributes,
+    ) -> Result<(), AuthorizationError> {
+        let conditions = enforcer
+            .get_named_policy("p2")
+            .into_iter()
+            .filter(|c| c[1] == action)
+            .collect::<Vec<_>>();
+        if conditions.is_empty() {
+            return Ok(());
+        }
+
+        let mut conditioned = false;
+        for role in enforcer
+            .get_grouping_policy()
+            .into_iter()
+            .filter(|r| r[0] == sub)
+            .map(|r| r[1].clone())
+        {
+            if !enforcer.enforce((role.clone(), object.clone(), action.clone()))? {
+                continue;
+            }
+
+            let mut satisfied = true;
+            for c in conditions.iter().filter(|c| c[0] == role) {
+                conditioned = true;
+                let condition: PermissionCondition = serde_json::from_str(&c[2])
+                    .map_err(|_| AuthorizationError::ConditionParseError(c[2].clone()))?;
+                satisfied &= condition.is_satisfied_by(attributes);
+            }
+            if satisfied {
+                return Ok(());
+            }
+        }
+
+        if conditioned {
+            Err(AuthorizationError::NotAuthorized)
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn enforce(
+        &self,
+        sub: &Audit::Subject,
+        object: Audit::Object,
+        action: Audit::Action,
+        attributes: &Attributes,
+    ) -> Result<AuditInfo, AuthorizationError> {
+        let result = self
+            .inspect_permission(sub, object, action, Some(attributes))
+            .await;
+        match result {
+            Ok(()) => Ok(self.audit.record_entry(sub, object, action, true).await?),
+            Err(AuthorizationError::NotAuthorized) => {
+                self.audit.record_entry(sub, object, action, false).await?;
+                Err(AuthorizationError::NotAuthorized)
+            }
+            Err(e) => Err(e),
         }
     }
 }
@@ -252,18 +389,21 @@ where
         object: impl Into<<Self::Audit as AuditSvc>::Object> + std::fmt::Debug + Send,
         action: impl Into<<Self::Audit as AuditSvc>::Action> + std::fmt::Debug + Send,
     ) -> Result<AuditInfo, AuthorizationError> {
-        let object = object.into();
-        let action = action.into();
+        // No attributes satisfy no condition, so conditioned roles fail closed.
+        self.enforce(sub, object.into(), action.into(), &Attributes::new())
+            .await
+    }
 
-        let result = self.inspect_permission(sub, object, action).await;
-        match result {
-            Ok(()) => Ok(self.audit.record_entry(sub, object, action, true).await?),
-            Err(AuthorizationError::NotAuthorized) => {
-                self.audit.record_entry(sub, object, action, false).await?;
-                Err(AuthorizationError::NotAuthorized)
-            }
-            Err(e) => Err(e),
-        }
+    #[instrument(name = "authz.enforce_permission_with_attributes", skip(self))]
+    async fn enforce_permission_with_attributes(
+        &self,
+        sub: &<Self::Audit as AuditSvc>::Subject,
+        object: impl Into<<Self::Audit as AuditSvc>::Object> + std::fmt::Debug + Send,
+        action: impl Into<<Self::Audit as AuditSvc>::Action> + std::fmt::Debug + Send,
+        attributes: &Attributes,
+    ) -> Result<AuditInfo, AuthorizationError> {
+        self.enforce(sub, object.into(), action.into(), attributes)
+            .await
     }
 
     #[instrument(name = "authz.inspect_permission", skip(self))]
@@ -280,7 +420,7 @@ where
         if enforce {
             Ok(Some(self.enforce_permission(sub, object, action).await?))
         } else {
-            self.inspect_permission(sub, object, action)
+            self.inspect_permission(sub, object, action, None)
                 .await
                 .map(|_| None)
         }
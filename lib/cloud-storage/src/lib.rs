@@ -111,6 +111,37 @@ impl Storage {
         Ok(signed_url)
     }
 
+    /// Lists the paths under `prefix`, relative to the root folder.
+    pub async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let root = self.path_with_prefix("");
+        let client = self.client().await?;
+
+        let mut paths = Vec::new();
+        let mut page_token = None;
+        loop {
+            let req = ListObjectsRequest {
+                bucket: self.bucket_name().to_owned(),
+                prefix: Some(self.path_with_prefix(prefix)),
+                page_token,
+                ..Default::default()
+            };
+            let result = client.list_objects(&req).await?;
+            paths.extend(
+                result
+                    .items
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|item| item.name.strip_prefix(&root).map(str::to_owned)),
+            );
+            page_token = result.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(paths)
+    }
+
     pub async fn _list(&self, filter_prefix: String) -> anyhow::Result<Vec<String>> {
         let full_prefix = self.path_with_prefix(&filter_prefix);
         let bucket = self.bucket_name();
@@ -1,17 +1,46 @@
-with nodes as(
-    select
-        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), '') as code,
-        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), '.') as dotted_code,
-        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), ' ') as spaced_code,
-        json_value(event, "$.spec.name.name") as name,
-        json_value(event, "$.ledger_account_set_id") as account_set_id,
+with chart_events as (
+    select *
     from {{ ref('stg_core_chart_events') }}
     where _sdc_batched_at >= (
         select coalesce(max(_sdc_batched_at), '1900-01-01')
         from {{ ref('stg_core_chart_events') }}
         where event_type = 'initialized'
     )
-    and event_type = 'node_added'
+),
+
+nodes as (
+    select
+        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), '') as code,
+        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), '.') as dotted_code,
+        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.spec.code.sections"), ' ') as spaced_code,
+        json_value(event, "$.spec.name.name") as name,
+        json_value(event, "$.ledger_account_set_id") as account_set_id,
+    from chart_events
+    where event_type = 'node_added'
+),
+
+renames as (
+    select
+        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.code.sections"), '') as code,
+        json_value(event, "$.name.name") as name,
+    from chart_events
+    where event_type = 'node_renamed'
+    qualify row_number() over (partition by code order by sequence desc) = 1
+),
+
+deactivated as (
+    select
+        {{ target.schema }}.udf_json_array_to_code(json_extract(event, "$.code.sections"), '') as code,
+    from chart_events
+    where event_type = 'node_deactivated'
 )
 
-select * from nodes
+select
+    nodes.code,
+    nodes.dotted_code,
+    nodes.spaced_code,
+    coalesce(renames.name, nodes.name) as name,
+    nodes.account_set_id,
+from nodes
+left join renames using (code)
+where nodes.code not in (select code from deactivated)